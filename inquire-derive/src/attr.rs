@@ -0,0 +1,176 @@
+use std::fmt;
+
+use syn::{Attribute, Expr, GenericArgument, Ident, PathArguments, Type};
+
+/// Prompt used to ask for the value of a field.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PromptKind {
+    Text,
+    Password,
+    Confirm,
+    CustomType,
+    Select,
+    Date,
+    Editor,
+}
+
+impl PromptKind {
+    const VALUES: &'static [PromptKind] = &[
+        PromptKind::Text,
+        PromptKind::Password,
+        PromptKind::Confirm,
+        PromptKind::CustomType,
+        PromptKind::Select,
+        PromptKind::Date,
+        PromptKind::Editor,
+    ];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            PromptKind::Text => "text",
+            PromptKind::Password => "password",
+            PromptKind::Confirm => "confirm",
+            PromptKind::CustomType => "custom_type",
+            PromptKind::Select => "select",
+            PromptKind::Date => "date",
+            PromptKind::Editor => "editor",
+        }
+    }
+
+    /// Default prompt for fields of the given type.
+    pub(crate) fn from_type(ty: &Type) -> Self {
+        let ident = match ty {
+            Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+            _ => None,
+        };
+
+        match ident.as_deref() {
+            Some("bool") => PromptKind::Confirm,
+            Some("String") => PromptKind::Text,
+            Some("Date") => PromptKind::Date,
+            _ => PromptKind::CustomType,
+        }
+    }
+
+    fn supports(self, option: &str) -> bool {
+        match option {
+            "placeholder" => matches!(
+                self,
+                PromptKind::Text | PromptKind::Confirm | PromptKind::CustomType
+            ),
+            "default" => self != PromptKind::Password,
+            "validator" => !matches!(self, PromptKind::Confirm | PromptKind::Select),
+            "options" => self == PromptKind::Select,
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for PromptKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Options of a field, parsed from its `#[inquire(...)]` attributes.
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    pub(crate) kind: Option<PromptKind>,
+    pub(crate) skip: bool,
+    pub(crate) message: Option<Expr>,
+    pub(crate) help: Option<Expr>,
+    pub(crate) default: Option<Expr>,
+    pub(crate) placeholder: Option<Expr>,
+    pub(crate) validators: Vec<Expr>,
+    pub(crate) formatter: Option<Expr>,
+    pub(crate) options: Option<Expr>,
+    /// Options set, to check that the prompt supports them.
+    set: Vec<Ident>,
+}
+
+impl FieldAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut field = FieldAttrs::default();
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident("inquire")) {
+            attr.parse_nested_meta(|meta| {
+                let ident = match meta.path.get_ident() {
+                    Some(ident) => ident.clone(),
+                    None => return Err(meta.error("unsupported inquire attribute")),
+                };
+                let name = ident.to_string();
+
+                if let Some(kind) = PromptKind::VALUES.iter().find(|k| k.name() == name) {
+                    if field.kind.is_some() {
+                        return Err(meta.error("the prompt of the field is already set"));
+                    }
+                    field.kind = Some(*kind);
+                    return Ok(());
+                }
+
+                if name == "skip" {
+                    field.skip = true;
+                    return Ok(());
+                }
+
+                let slot = match name.as_str() {
+                    "message" => &mut field.message,
+                    "help" => &mut field.help,
+                    "default" => &mut field.default,
+                    "placeholder" => &mut field.placeholder,
+                    "formatter" => &mut field.formatter,
+                    "options" => &mut field.options,
+                    "validator" => {
+                        field.validators.push(meta.value()?.parse()?);
+                        field.set.push(ident);
+                        return Ok(());
+                    }
+                    _ => {
+                        return Err(meta.error(format!("unsupported inquire attribute `{}`", name)))
+                    }
+                };
+
+                if slot.is_some() {
+                    return Err(meta.error(format!("`{}` is set more than once", name)));
+                }
+                *slot = Some(meta.value()?.parse()?);
+                field.set.push(ident);
+
+                Ok(())
+            })?;
+        }
+
+        Ok(field)
+    }
+
+    /// Checks that the prompt of the field supports the options set.
+    pub(crate) fn validate(&self, kind: PromptKind) -> syn::Result<()> {
+        if let Some(option) = self.set.iter().find(|o| !kind.supports(&o.to_string())) {
+            return Err(syn::Error::new(
+                option.span(),
+                format!("`{}` is not supported by `{}` prompts", option, kind),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the inner type of `Option<T>`.
+pub(crate) fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,147 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Field, Fields};
+
+use crate::attr::{option_inner_type, FieldAttrs, PromptKind};
+
+pub(crate) fn derive_inquire(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "`Inquire` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "`Inquire` can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let prompts = fields
+        .iter()
+        .map(prompt_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let idents = fields.iter().map(|f| &f.ident);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Prompts the user for the value of each field, in order.
+            pub fn prompt() -> ::inquire::error::InquireResult<Self> {
+                #(#prompts)*
+
+                ::std::result::Result::Ok(Self { #(#idents),* })
+            }
+        }
+    })
+}
+
+/// Statement binding the field to the answer of its prompt.
+fn prompt_field(field: &Field) -> syn::Result<TokenStream> {
+    let ident = field.ident.as_ref().expect("named field");
+    let attrs = FieldAttrs::parse(&field.attrs)?;
+
+    if attrs.skip {
+        return Ok(quote! {
+            let #ident = ::std::default::Default::default();
+        });
+    }
+
+    let (ty, skippable) = match option_inner_type(&field.ty) {
+        Some(inner) => (inner, true),
+        None => (&field.ty, false),
+    };
+    let kind = match attrs.kind {
+        Some(kind) => kind,
+        None => PromptKind::from_type(ty),
+    };
+    attrs.validate(kind)?;
+
+    let message = if let Some(message) = &attrs.message {
+        quote!(#message)
+    } else {
+        let message = default_message(&ident.to_string());
+        quote!(#message)
+    };
+
+    let constructor = match kind {
+        PromptKind::Text => quote!(::inquire::Text::new(#message)),
+        PromptKind::Password => quote!(::inquire::Password::new(#message)),
+        PromptKind::Confirm => quote!(::inquire::Confirm::new(#message)),
+        PromptKind::CustomType => quote!(::inquire::CustomType::<#ty>::new(#message)),
+        PromptKind::Select => {
+            if let Some(options) = &attrs.options {
+                quote!(::inquire::Select::new(#message, #options))
+            } else {
+                quote!(::inquire::Select::<#ty>::from_enum(#message))
+            }
+        }
+        PromptKind::Date => quote!(::inquire::DateSelect::new(#message)),
+        PromptKind::Editor => quote!(::inquire::Editor::new(#message)),
+    };
+
+    let mut setters = vec![];
+    if let Some(help) = &attrs.help {
+        setters.push(quote_spanned!(help.span()=> .with_help_message(#help)));
+    }
+    if let Some(default) = &attrs.default {
+        let setter = match kind {
+            PromptKind::Select => quote_spanned!(default.span()=> .with_starting_cursor(#default)),
+            PromptKind::Editor => quote_spanned!(default.span()=> .with_predefined_text(#default)),
+            _ => quote_spanned!(default.span()=> .with_default(#default)),
+        };
+        setters.push(setter);
+    }
+    if let Some(placeholder) = &attrs.placeholder {
+        setters.push(quote_spanned!(placeholder.span()=> .with_placeholder(#placeholder)));
+    }
+    for validator in &attrs.validators {
+        setters.push(quote_spanned!(validator.span()=> .with_validator(#validator)));
+    }
+    if let Some(formatter) = &attrs.formatter {
+        setters.push(quote_spanned!(formatter.span()=> .with_formatter(#formatter)));
+    }
+
+    let prompt = if skippable {
+        quote!(prompt_skippable)
+    } else {
+        quote!(prompt)
+    };
+
+    Ok(quote! {
+        let #ident = #constructor #(#setters)* .#prompt()?;
+    })
+}
+
+/// Message of the prompt of a field without one, e.g. `First name:` for
+/// `first_name`.
+fn default_message(field: &str) -> String {
+    let words = field.trim_start_matches("r#").replace('_', " ");
+    let words = words.trim();
+    let mut chars = words.chars();
+
+    match chars.next() {
+        Some(first) => format!("{}{}:", first.to_uppercase(), chars.as_str()),
+        None => String::from(":"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::default_message;
+
+    #[test]
+    fn default_message_is_humanized_field_name() {
+        assert_eq!("First name:", default_message("first_name"));
+        assert_eq!("Type:", default_message("r#type"));
+        assert_eq!("Age:", default_message("_age"));
+    }
+}
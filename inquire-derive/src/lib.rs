@@ -1,14 +1,127 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
+//! Derive macros for [inquire](https://docs.rs/inquire), re-exported by it
+//! with the `macros` feature.
+//!
+//! # `#[derive(Inquire)]`
+//!
+//! Derives a `prompt()` constructor for structs with named fields, asking
+//! the user for the value of each field, in order, and returning
+//! `InquireResult<Self>`.
+//!
+//! The prompt of each field is chosen from its type, unless set in its
+//! `#[inquire(...)]` attribute:
+//!
+//! - `bool`: [`Confirm`](https://docs.rs/inquire/*/inquire/struct.Confirm.html)
+//! - `String`: [`Text`](https://docs.rs/inquire/*/inquire/struct.Text.html)
+//! - `Date`: [`DateSelect`](https://docs.rs/inquire/*/inquire/struct.DateSelect.html)
+//! - Numbers, `char` and any other type: [`CustomType`](https://docs.rs/inquire/*/inquire/struct.CustomType.html)
+//!
+//! Fields of type `Option<T>` are prompted as `T`, and are `None` when the user
+//! skips the prompt by pressing ESC.
+//!
+//! ## Field attributes
+//!
+//! - `text`, `password`, `confirm`, `custom_type`, `select`, `date` or
+//!   `editor`: prompt used for the field.
+//! - `message = "..."`: message of the prompt. Defaults to the name of the
+//!   field, e.g. `First name:` for `first_name`.
+//! - `help = "..."`: help message of the prompt.
+//! - `default = ...`: default value of the prompt. This is the starting
+//!   cursor of `select` prompts and the predefined text of `editor` prompts.
+//!   Not supported by `password` prompts.
+//! - `placeholder = "..."`: placeholder of `text`, `confirm` and `custom_type`
+//!   prompts.
+//! - `validator = ...`: validator of `text`, `password`, `custom_type`, `date`
+//!   and `editor` prompts. May be repeated.
+//! - `formatter = ...`: formatter of the prompt.
+//! - `options = ...`: options of `select` prompts. Without options, the
+//!   variants of the enum type of the field are selected, see
+//!   `#[derive(InquireSelect)]`.
+//! - `skip`: the field is not prompted and set to its `Default` value.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use inquire::{min_length, Inquire};
+//!
+//! #[derive(Inquire)]
+//! struct Account {
+//!     #[inquire(message = "What's your name?", validator = min_length!(1))]
+//!     name: String,
+//!     #[inquire(password, validator = min_length!(8))]
+//!     password: String,
+//!     #[inquire(help = "In years", default = 18)]
+//!     age: u8,
+//!     #[inquire(select, options = vec!["Free", "Pro"])]
+//!     plan: &'static str,
+//!     newsletter: bool,
+//!     #[inquire(skip)]
+//!     id: u64,
+//! }
+//!
+//! let account = Account::prompt()?;
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+//!
+//! # `#[derive(InquireSelect)]`
+//!
+//! Implements `InquireEnumVariants` for enums without variant fields, allowing
+//! their variants to be selected with `Select::from_enum` and
+//! `MultiSelect::from_enum`, which return the selected variants. `Display` is
+//! implemented as well, writing the name of the variant, unless the enum has
+//! the `#[inquire(no_display)]` attribute.
+//!
+//! ## Variant attributes
+//!
+//! - `name = "..."`: name of the variant. Defaults to its identifier.
+//! - `help = "..."`: help message displayed while the variant is highlighted.
+//! - `order = ...`: variants are listed by ascending order, 0 by default,
+//!   and then in their declaration order.
+//! - `skip`: the variant can not be selected.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use inquire::{InquireSelect, Select};
+//!
+//! #[derive(Clone, Copy, InquireSelect)]
+//! enum Plan {
+//!     #[inquire(help = "Up to 3 projects")]
+//!     Free,
+//!     #[inquire(name = "Professional", help = "Unlimited projects", order = -1)]
+//!     Pro,
+//!     #[inquire(skip)]
+//!     Legacy,
+//! }
+//!
+//! let plan: Plan = Select::from_enum("Plan:").prompt()?;
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+mod attr;
+mod expand;
+mod select;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives a `prompt()` constructor asking the user for the value of each
+/// field, see the [crate documentation](crate).
+#[proc_macro_derive(Inquire, attributes(inquire))]
+pub fn derive_inquire(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand::derive_inquire(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Implements `InquireEnumVariants` and `Display` for enums, see the
+/// [crate documentation](crate).
+#[proc_macro_derive(InquireSelect, attributes(inquire))]
+pub fn derive_inquire_select(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
+    select::derive_inquire_select(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
@@ -0,0 +1,138 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, LitInt, LitStr};
+
+/// Options of a variant, parsed from its `#[inquire(...)]` attributes.
+#[derive(Default)]
+struct VariantAttrs {
+    name: Option<LitStr>,
+    help: Option<LitStr>,
+    order: Option<i64>,
+    skip: bool,
+}
+
+impl VariantAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut variant = VariantAttrs::default();
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident("inquire")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    variant.name = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("help") {
+                    variant.help = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("order") {
+                    variant.order = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                } else if meta.path.is_ident("skip") {
+                    variant.skip = true;
+                } else {
+                    return Err(meta.error("unsupported inquire attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(variant)
+    }
+}
+
+/// Whether the enum opted out of the generated `Display` implementation
+/// with `#[inquire(no_display)]`.
+fn no_display(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut no_display = false;
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("inquire")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("no_display") {
+                no_display = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported inquire attribute"))
+            }
+        })?;
+    }
+
+    Ok(no_display)
+}
+
+pub(crate) fn derive_inquire_select(input: DeriveInput) -> syn::Result<TokenStream> {
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "`InquireSelect` can only be derived for enums",
+            ))
+        }
+    };
+
+    let mut selectable = vec![];
+    let mut names = vec![];
+    let mut helps = vec![];
+
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                "`InquireSelect` can only be derived for enums without variant fields",
+            ));
+        }
+
+        let ident = &variant.ident;
+        let attrs = VariantAttrs::parse(&variant.attrs)?;
+
+        if !attrs.skip {
+            selectable.push((attrs.order.unwrap_or(0), ident));
+        }
+
+        let name = match attrs.name {
+            Some(name) => name.value(),
+            None => ident.to_string(),
+        };
+        names.push(quote!(Self::#ident => #name));
+
+        if let Some(help) = attrs.help {
+            helps.push(quote!(Self::#ident => ::std::option::Option::Some(#help)));
+        }
+    }
+
+    // stable sort, keeping the declaration order of variants of equal order
+    selectable.sort_by_key(|(order, _)| *order);
+    let selectable = selectable.iter().map(|(_, ident)| ident);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let display = if no_display(&input.attrs)? {
+        quote!()
+    } else {
+        quote! {
+            impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(match self {
+                        #(#names,)*
+                    })
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::inquire::InquireEnumVariants for #name #ty_generics #where_clause {
+            fn variants() -> ::std::vec::Vec<Self> {
+                ::std::vec![#(Self::#selectable),*]
+            }
+
+            fn help(&self) -> ::std::option::Option<&'static str> {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#helps,)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+
+        #display
+    })
+}
@@ -0,0 +1,32 @@
+use inquire::{InquireEnumVariants, InquireSelect};
+
+#[derive(Clone, Copy, Debug, PartialEq, InquireSelect)]
+enum Plan {
+    #[inquire(help = "Up to 3 projects")]
+    Free,
+    #[inquire(name = "Professional", help = "Unlimited projects")]
+    Pro,
+    #[inquire(order = -1)]
+    Team,
+    #[inquire(skip)]
+    Legacy,
+}
+
+#[test]
+fn variants_are_ordered_and_skipped() {
+    assert_eq!(vec![Plan::Team, Plan::Free, Plan::Pro], Plan::variants());
+}
+
+#[test]
+fn variants_are_displayed_with_their_names() {
+    assert_eq!("Free", Plan::Free.to_string());
+    assert_eq!("Professional", Plan::Pro.to_string());
+    assert_eq!("Legacy", Plan::Legacy.to_string());
+}
+
+#[test]
+fn variants_have_their_help_messages() {
+    assert_eq!(Some("Up to 3 projects"), Plan::Free.help());
+    assert_eq!(Some("Unlimited projects"), Plan::Pro.help());
+    assert_eq!(None, Plan::Team.help());
+}
@@ -0,0 +1,14 @@
+use inquire::InquireSelect;
+
+#[derive(Clone, InquireSelect)]
+enum Shape {
+    Circle(f64),
+    Square { side: f64 },
+}
+
+#[derive(Clone, InquireSelect)]
+struct Point {
+    x: i32,
+}
+
+fn main() {}
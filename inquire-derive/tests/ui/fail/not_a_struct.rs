@@ -0,0 +1,12 @@
+use inquire::Inquire;
+
+#[derive(Inquire)]
+enum Size {
+    Small,
+    Large,
+}
+
+#[derive(Inquire)]
+struct Point(i32, i32);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use inquire::Inquire;
+
+#[derive(Inquire)]
+struct Order {
+    #[inquire(text, editor)]
+    notes: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use inquire::Inquire;
+
+#[derive(Inquire)]
+struct Order {
+    #[inquire(colour = "red")]
+    size: String,
+}
+
+fn main() {}
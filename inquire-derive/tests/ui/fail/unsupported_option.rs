@@ -0,0 +1,9 @@
+use inquire::Inquire;
+
+#[derive(Inquire)]
+struct Login {
+    #[inquire(password, default = "hunter2")]
+    password: String,
+}
+
+fn main() {}
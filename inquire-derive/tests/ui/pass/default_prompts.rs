@@ -0,0 +1,18 @@
+use inquire::error::InquireResult;
+use inquire::Inquire;
+
+#[derive(Inquire)]
+struct Profile {
+    name: String,
+    age: u8,
+    height: f64,
+    initial: char,
+    subscribed: bool,
+    birthday: time::Date,
+    nickname: Option<String>,
+    score: Option<i64>,
+}
+
+fn main() {
+    let _: fn() -> InquireResult<Profile> = Profile::prompt;
+}
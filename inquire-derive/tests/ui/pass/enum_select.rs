@@ -0,0 +1,42 @@
+use inquire::{error::InquireResult, Inquire, InquireEnumVariants, InquireSelect, MultiSelect, Select};
+
+#[derive(Clone, Copy, InquireSelect)]
+enum Plan {
+    #[inquire(help = "Up to 3 projects")]
+    Free,
+    #[inquire(name = "Professional", help = "Unlimited projects", order = -1)]
+    Pro,
+    #[inquire(skip)]
+    Legacy,
+}
+
+#[derive(Clone, InquireSelect)]
+#[inquire(no_display)]
+enum Size {
+    Small,
+    Large,
+}
+
+impl std::fmt::Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Size::Small => f.write_str("S"),
+            Size::Large => f.write_str("L"),
+        }
+    }
+}
+
+#[derive(Inquire)]
+struct Order {
+    #[inquire(select)]
+    plan: Plan,
+    #[inquire(select, default = 1)]
+    size: Option<Size>,
+}
+
+fn main() {
+    let _: fn() -> InquireResult<Plan> = || Select::from_enum("Plan:").prompt();
+    let _: fn() -> InquireResult<Vec<Size>> = || MultiSelect::from_enum("Sizes:").prompt();
+    let _: fn() -> InquireResult<Order> = Order::prompt;
+    let _: Vec<Plan> = Plan::variants();
+}
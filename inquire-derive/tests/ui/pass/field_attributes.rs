@@ -0,0 +1,48 @@
+use inquire::{error::InquireResult, min_length, validator::Validation, Inquire};
+
+#[derive(Inquire)]
+struct Account {
+    #[inquire(
+        message = "What's your name?",
+        help = "As written in your ID",
+        default = "Ana",
+        placeholder = "Name",
+        validator = min_length!(1),
+        validator = |input: &str| Ok(Validation::Valid),
+        formatter = &|input| input.to_uppercase()
+    )]
+    name: String,
+    #[inquire(password, message = "Password:", validator = min_length!(8))]
+    password: String,
+    #[inquire(confirm, default = true, placeholder = "y/n", formatter = &|answer| answer.to_string())]
+    newsletter: bool,
+    #[inquire(custom_type, default = 18, validator = |age: &u8| Ok(if *age < 150 {
+        Validation::Valid
+    } else {
+        Validation::Invalid("Too old".into())
+    }))]
+    age: u8,
+    #[inquire(select, options = vec!["Free", "Pro"], default = 1)]
+    plan: &'static str,
+    #[inquire(date, default = time::macros::date!(2024 - 01 - 01))]
+    start: time::Date,
+    #[inquire(editor, default = "Hello", help = "Say hi")]
+    bio: String,
+    #[inquire(text)]
+    city: Option<String>,
+    #[inquire(skip)]
+    id: u64,
+}
+
+#[derive(Inquire)]
+struct Wrapper<T>
+where
+    T: Clone + std::str::FromStr + ToString + 'static,
+{
+    value: T,
+}
+
+fn main() {
+    let _: fn() -> InquireResult<Account> = Account::prompt;
+    let _: fn() -> InquireResult<Wrapper<u32>> = Wrapper::<u32>::prompt;
+}
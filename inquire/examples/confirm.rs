@@ -1,4 +1,8 @@
-use inquire::{ui::RenderConfig, Confirm};
+use inquire::{
+    keymap::KeyMap,
+    ui::{Output, RenderConfig},
+    Confirm,
+};
 
 fn main() {
     let ans = Confirm::new("Do you live in Brazil?")
@@ -35,7 +39,10 @@ fn main() {
             true => String::from("si"),
             false => String::from("no"),
         },
+        key_map: KeyMap::default(),
         render_config: RenderConfig::default(),
+        output: Output::default(),
+        timeout: None,
     }
     .prompt()
     .unwrap();
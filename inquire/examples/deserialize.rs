@@ -0,0 +1,55 @@
+use inquire::{error::InquireResult, DeserializePrompt};
+use serde::Deserialize;
+
+fn main() -> InquireResult<()> {
+    let config: Config = DeserializePrompt::new().prompt()?;
+
+    println!(
+        "Starting {} with {} workers, logging {:?} messages{}.",
+        config.name,
+        config.workers,
+        config.log_level,
+        if config.verbose { " verbosely" } else { "" }
+    );
+    println!(
+        "Connecting to {}:{}{}.",
+        config.database.host,
+        config.database.port,
+        if config.database.password.is_some() {
+            " with a password"
+        } else {
+            ""
+        }
+    );
+    if let Some(proxy) = config.proxy {
+        println!("Using proxy {}.", proxy);
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize)]
+struct Config {
+    name: String,
+    workers: u8,
+    verbose: bool,
+    log_level: LogLevel,
+    database: Database,
+    proxy: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Database {
+    host: String,
+    port: u16,
+    password: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
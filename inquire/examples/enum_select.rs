@@ -0,0 +1,57 @@
+use inquire::{error::InquireResult, InquireSelect, MultiSelect, Select};
+
+fn main() -> InquireResult<()> {
+    let currency: Currency = Select::from_enum("Currency:").prompt()?;
+
+    match currency {
+        Currency::BRL | Currency::USD | Currency::CAD | Currency::EUR | Currency::GBP => {
+            bank_transfer();
+        }
+        Currency::BTC | Currency::LTC => crypto_transfer(),
+    }
+
+    let notifications: Vec<Notification> =
+        MultiSelect::from_enum("How should we notify you?").prompt()?;
+
+    println!("Notifying you through {} channels.", notifications.len());
+
+    Ok(())
+}
+
+fn bank_transfer() {
+    // ask for bank account
+    // transfer funds
+}
+
+fn crypto_transfer() {
+    // ask for wallet address
+    // transfer funds
+}
+
+#[derive(Debug, Copy, Clone, InquireSelect)]
+#[allow(clippy::upper_case_acronyms)]
+enum Currency {
+    #[inquire(name = "Brazilian real")]
+    BRL,
+    #[inquire(name = "US dollar", order = -1)]
+    USD,
+    #[inquire(name = "Canadian dollar")]
+    CAD,
+    #[inquire(name = "Euro", order = -1)]
+    EUR,
+    #[inquire(name = "Pound sterling")]
+    GBP,
+    #[inquire(name = "Bitcoin", help = "Transfers may take up to an hour")]
+    BTC,
+    #[inquire(name = "Litecoin", help = "Transfers may take up to 30 minutes")]
+    LTC,
+}
+
+#[derive(Debug, Copy, Clone, InquireSelect)]
+enum Notification {
+    Email,
+    #[inquire(name = "SMS", help = "Carrier charges may apply")]
+    Sms,
+    #[inquire(name = "Push notifications")]
+    Push,
+}
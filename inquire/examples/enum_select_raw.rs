@@ -1,9 +1,9 @@
 use std::fmt::{Display, Formatter};
 
-use inquire::{error::InquireResult, Select};
+use inquire::{error::InquireResult, InquireEnumVariants, Select};
 
 fn main() -> InquireResult<()> {
-    let ans: Currency = Select::new("Currency:", Currency::VARIANTS.to_vec()).prompt()?;
+    let ans: Currency = Select::from_enum("Currency:").prompt()?;
 
     match ans {
         Currency::BRL | Currency::USD | Currency::CAD | Currency::EUR | Currency::GBP => {
@@ -37,17 +37,26 @@ enum Currency {
     LTC,
 }
 
-impl Currency {
-    // could be generated by macro
-    const VARIANTS: &'static [Currency] = &[
-        Self::BRL,
-        Self::USD,
-        Self::CAD,
-        Self::EUR,
-        Self::GBP,
-        Self::BTC,
-        Self::LTC,
-    ];
+// could be generated with #[derive(InquireSelect)], see the enum_select example
+impl InquireEnumVariants for Currency {
+    fn variants() -> Vec<Self> {
+        vec![
+            Self::BRL,
+            Self::USD,
+            Self::CAD,
+            Self::EUR,
+            Self::GBP,
+            Self::BTC,
+            Self::LTC,
+        ]
+    }
+
+    fn help(&self) -> Option<&'static str> {
+        match self {
+            Self::BTC | Self::LTC => Some("Crypto transfers may take up to an hour"),
+            _ => None,
+        }
+    }
 }
 
 impl Display for Currency {
@@ -0,0 +1,20 @@
+use inquire::{error::InquireResult, Select};
+use strum::{Display, VariantArray};
+
+fn main() -> InquireResult<()> {
+    let size: Size = Select::from_enum("Size:").prompt()?;
+
+    println!("Ordering a {} pizza.", size);
+
+    Ok(())
+}
+
+#[derive(Debug, Copy, Clone, Display, VariantArray)]
+enum Size {
+    #[strum(to_string = "small")]
+    Small,
+    #[strum(to_string = "medium")]
+    Medium,
+    #[strum(to_string = "large")]
+    Large,
+}
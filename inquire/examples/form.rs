@@ -1,5 +1,6 @@
 use inquire::{
-    error::InquireResult, min_length, Confirm, DateSelect, MultiSelect, Password, Select, Text,
+    error::InquireResult, list_option::ListOption, min_length, Confirm, DateSelect, Form,
+    MultiSelect, Password, Select, Text,
 };
 
 fn main() -> InquireResult<()> {
@@ -16,7 +17,6 @@ fn main() -> InquireResult<()> {
         "Avocado",
         "Pineapple",
     ];
-    let pineapple_index = fruits.len() - 1;
 
     let languages = vec![
         "C++",
@@ -29,29 +29,50 @@ fn main() -> InquireResult<()> {
         "Go",
     ];
 
-    let _workplace = Text::new("Where do you work?")
-        .with_help_message("Don't worry, this will not be sold to third-party advertisers.")
-        .with_validator(min_length!(5, "Minimum of 5 characters"))
-        .with_default("Unemployed")
-        .prompt_skippable()?;
+    let answers = Form::new()
+        .step(
+            "workplace",
+            Text::new("Where do you work?")
+                .with_help_message("Don't worry, this will not be sold to third-party advertisers.")
+                .with_validator(min_length!(5, "Minimum of 5 characters"))
+                .with_default("Unemployed"),
+        )
+        .step(
+            "fruits",
+            MultiSelect::new("What are your favorite fruits?", fruits),
+        )
+        .step(
+            "pizza",
+            Confirm::new("Do you eat pizza?").with_default(true),
+        )
+        .step_if(
+            "pineapple_pizza",
+            Confirm::new("Do you put pineapple on it?").with_default(false),
+            |answers| {
+                let eats_pizza = answers.get::<bool>("pizza") == Some(&true);
+                let likes_pineapple = match answers.get::<Vec<ListOption<&str>>>("fruits") {
+                    Some(fruits) => fruits.iter().any(|o| o.value == "Pineapple"),
+                    None => false,
+                };
 
-    let _eats_pineapple = MultiSelect::new("What are your favorite fruits?", fruits)
-        .raw_prompt()?
-        .into_iter()
-        .any(|o| o.index == pineapple_index);
+                eats_pizza && likes_pineapple
+            },
+        )
+        .step(
+            "language",
+            Select::new("What is your favorite programming language?", languages),
+        )
+        .step(
+            "password",
+            Password::new("Password:").with_validator(min_length!(8, "Minimum of 8 characters")),
+        )
+        .step("when", DateSelect::new("When are you going to travel?"))
+        .with_summary_message("Shall we proceed with these answers?")
+        .prompt()?;
 
-    let _eats_pizza = Confirm::new("Do you eat pizza?")
-        .with_default(true)
-        .prompt_skippable()?;
-
-    let _language =
-        Select::new("What is your favorite programming language?", languages).prompt_skippable()?;
-
-    let _password = Password::new("Password:")
-        .with_validator(min_length!(8, "Minimum of 8 characters"))
-        .prompt_skippable()?;
-
-    let _when = DateSelect::new("When are you going to travel?").prompt_skippable()?;
+    if answers.get::<bool>("pineapple_pizza") == Some(&true) {
+        println!("Bold choice.");
+    }
 
     println!("Based on our ML-powered analysis, we were able to conclude absolutely nothing.");
 
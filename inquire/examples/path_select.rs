@@ -0,0 +1,34 @@
+use inquire::{autocompletion::PathCompleter, PathSelect, PathSelectionMode, Text};
+
+fn main() {
+    let project = PathSelect::new("Project directory:")
+        .with_selection_mode(PathSelectionMode::Directory)
+        .prompt();
+
+    let project = match project {
+        Ok(project) => project,
+        Err(error) => {
+            println!("Error with questionnaire, try again later: {error:?}");
+            return;
+        }
+    };
+
+    let manifest = PathSelect::new("Manifest:")
+        .with_starting_directory(&project)
+        .with_extensions(&["toml", "json"])
+        .prompt();
+
+    let output = Text::new("Output file:")
+        .with_token_autocomplete(PathCompleter::new())
+        .with_help_message("Paths are completed with tab, ~ standing for your home directory")
+        .prompt();
+
+    match (manifest, output) {
+        (Ok(manifest), Ok(output)) => {
+            println!("Building {} into {output}", manifest.display());
+        }
+        (Err(error), _) | (_, Err(error)) => {
+            println!("Error with questionnaire, try again later: {error:?}");
+        }
+    }
+}
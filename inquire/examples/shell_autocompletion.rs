@@ -0,0 +1,99 @@
+use inquire::{
+    autocompletion::{Suggestion, TokenAutocomplete},
+    ui::{Color, StyleSheet},
+    CustomUserError, Text,
+};
+
+fn main() {
+    let ans = Text::new("$")
+        .with_token_autocomplete(GitCompleter)
+        .with_help_message("Try `git ch` and `git checkout f`, completing with tab")
+        .prompt();
+
+    match ans {
+        Ok(command) => println!("Running: {command}"),
+        Err(error) => println!("Error with questionnaire, try again later: {error:?}"),
+    }
+}
+
+const SUBCOMMANDS: &[(&str, &str)] = &[
+    ("add", "Add file contents to the index"),
+    ("branch", "List, create, or delete branches"),
+    ("checkout", "Switch branches or restore working tree files"),
+    (
+        "cherry-pick",
+        "Apply the changes introduced by some existing commits",
+    ),
+    ("commit", "Record changes to the repository"),
+    (
+        "diff",
+        "Show changes between commits, commit and working tree, etc",
+    ),
+    ("log", "Show commit logs"),
+    ("merge", "Join two or more development histories together"),
+    ("push", "Update remote refs along with associated objects"),
+    ("rebase", "Reapply commits on top of another base tip"),
+    ("status", "Show the working tree status"),
+];
+
+const BRANCHES: &[&str] = &["main", "feature/autocompletion", "fix/render-glitch"];
+
+/// Completes the word under the cursor of a `git` command line: subcommands
+/// in second position, and branch names after the subcommands taking one.
+#[derive(Clone)]
+pub struct GitCompleter;
+
+impl TokenAutocomplete for GitCompleter {
+    fn suggest(&mut self, input: &str, cursor: usize) -> Result<Vec<Suggestion>, CustomUserError> {
+        let start = input[..cursor].rfind(' ').map(|i| i + 1).unwrap_or(0);
+        let end = input[cursor..]
+            .find(' ')
+            .map(|i| cursor + i)
+            .unwrap_or(input.len());
+        let token = &input[start..end];
+
+        let previous_words: Vec<&str> = input[..start].split_whitespace().collect();
+
+        let suggestions = match previous_words.as_slice() {
+            [] => vec![Suggestion::new("git", start..end)],
+            ["git"] => SUBCOMMANDS
+                .iter()
+                .filter(|(name, _)| name.starts_with(token))
+                .map(|(name, description)| {
+                    Suggestion::new(*name, start..end).with_description(*description)
+                })
+                .collect(),
+            ["git", "checkout" | "merge" | "rebase" | "branch"] => BRANCHES
+                .iter()
+                .filter(|name| name.starts_with(token))
+                .map(|name| {
+                    Suggestion::new(*name, start..end)
+                        .with_style(StyleSheet::new().with_fg(Color::DarkGreen))
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        Ok(suggestions)
+    }
+
+    fn complete(
+        &mut self,
+        input: &str,
+        cursor: usize,
+        highlighted_suggestion: Option<&Suggestion>,
+    ) -> Result<Option<Suggestion>, CustomUserError> {
+        if let Some(suggestion) = highlighted_suggestion {
+            return Ok(Some(suggestion.clone()));
+        }
+
+        // without a highlighted suggestion, complete the token when it is
+        // the only candidate.
+        let mut suggestions = self.suggest(input, cursor)?;
+        if suggestions.len() == 1 {
+            Ok(suggestions.pop())
+        } else {
+            Ok(None)
+        }
+    }
+}
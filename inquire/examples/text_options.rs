@@ -1,4 +1,10 @@
-use inquire::{error::CustomUserError, length, required, ui::RenderConfig, Text};
+use inquire::{
+    error::CustomUserError,
+    keymap::KeyMap,
+    length, required,
+    ui::{Output, RenderConfig},
+    NewlinePolicy, Text,
+};
 
 fn main() {
     let answer = Text::new("What's your name?")
@@ -19,8 +25,17 @@ fn main() {
         formatter: Text::DEFAULT_FORMATTER,
         validators: Vec::new(),
         page_size: Text::DEFAULT_PAGE_SIZE,
+        auto_page_size: false,
         autocompleter: None,
+        token_autocompleter: None,
+        paste_newline_policy: NewlinePolicy::Strip,
+        multiline: false,
+        history: None,
+        vim_mode: false,
+        key_map: KeyMap::default(),
         render_config: RenderConfig::default(),
+        output: Output::default(),
+        timeout: None,
     }
     .prompt()
     .unwrap();
@@ -0,0 +1,357 @@
+//! Trait and structs used by prompts to provide autocompletion features.
+//!
+//! Autocompleters receive the user input to a given prompt and may return
+//! a list of suggestions, selectable by the user as an option to be completed.
+//!
+//! When the user presses the autocompletion hotkey (`tab` by default) the
+//! autocompleter receives the current text input and the currently highlighted
+//! selection, if any. Then the developer may return a [Replacement] action
+//! where the current user text input is replaced or not by a provided string.
+//!
+//! Check the example files to see some usages, recommended are `expense_tracker.rs`
+//! and `complex_autocompletion.rs`.
+//!
+//! Autocompleters completing only a part of the input, such as the word or
+//! the path segment under the cursor, implement [TokenAutocomplete] instead,
+//! which receives the position of the cursor and returns [Suggestion]s
+//! replacing a span of the input, with optional descriptions and styles.
+//! Implementations of [Autocomplete] are adapted to it by
+//! [AutocompleteAdapter], which `with_autocomplete` uses.
+//!
+//! Check `shell_autocompletion.rs` for an example.
+//!
+//! Filesystem paths are completed by the built-in [PathCompleter].
+
+mod path;
+
+pub use path::PathCompleter;
+
+use std::ops::Range;
+
+use dyn_clone::DynClone;
+
+use crate::{ui::StyleSheet, CustomUserError};
+
+/// Used when an autocompletion is triggered for the user's text input.
+///
+/// `None` means that no completion will be made.
+/// `Some(String)` will replace the current text input with the `String` in `Some`.
+pub type Replacement = Option<String>;
+
+/// Mechanism to implement autocompletion features for text inputs. The `Autocomplete` trait has two provided methods: `get_suggestions` and `get_completion`.
+///
+/// - `get_suggestions` is called whenever the user's text input is modified, e.g. a new letter is typed, returning a `Vec<String>`. The `Vec<String>` is the list of suggestions that the prompt displays to the user according to their text input. The user can then navigate through the list and if they submit while highlighting one of these suggestions, the suggestion is treated as the final answer.
+/// - `get_completion` is called whenever the user presses the autocompletion hotkey (`tab` by default), with the current text input and the text of the currently highlighted suggestion, if any, as parameters. This method should return whether any text replacement (an autocompletion) should be made. If the prompt receives a replacement to be made, it substitutes the current text input for the string received from the `get_completion` call.
+///
+/// For example, in the `complex_autocompletion.rs` example file, the `FilePathCompleter` scans the file system based on the current text input, storing a list of paths that match the current text input.
+///
+/// Every time `get_suggestions` is called, the method returns the list of paths that match the user input. When the user presses the autocompletion hotkey, the `FilePathCompleter` checks whether there is any path selected from the list, if there is, it decides to replace the current text input for it. The interesting piece of functionality is that if there isn't a path selected from the list, the `FilePathCompleter` calculates the longest common prefix amongst all scanned paths and updates the text input to an unambiguous new value. Similar to how terminals work when traversing paths.
+pub trait Autocomplete: DynClone {
+    /// List of input suggestions to be displayed to the user upon typing the
+    /// text input.
+    ///
+    /// If the user presses the autocompletion hotkey (`tab` as default) with
+    /// a suggestion highlighted, the user's text input will be replaced by the
+    /// content of the suggestion string.
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError>;
+
+    /// Standalone autocompletion that can be implemented based solely on the user's
+    /// input.
+    ///
+    /// If the user presses the autocompletion hotkey (`tab` as default) and
+    /// there are no suggestions highlighted (1), this function will be called in an
+    /// attempt to autocomplete the user's input.
+    ///
+    /// If the returned value is of the `Some` variant, the text input will be replaced
+    /// by the content of the string.
+    ///
+    /// (1) This applies where either there are no suggestions at all, or there are
+    /// some displayed but the user hasn't highlighted any.
+    fn get_completion(
+        &mut self,
+        input: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError>;
+}
+
+impl Clone for Box<dyn Autocomplete> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+/// Empty struct and implementation of Autocomplete trait. Used for the default
+/// autocompleter of `Text` prompts.
+#[derive(Clone, Default)]
+pub struct NoAutoCompletion;
+
+impl Autocomplete for NoAutoCompletion {
+    fn get_suggestions(&mut self, _: &str) -> Result<Vec<String>, CustomUserError> {
+        Ok(vec![])
+    }
+
+    fn get_completion(
+        &mut self,
+        _: &str,
+        _: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        Ok(Replacement::None)
+    }
+}
+
+impl<F> Autocomplete for F
+where
+    F: Fn(&str) -> Result<Vec<String>, CustomUserError> + Clone,
+{
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        (self)(input)
+    }
+
+    fn get_completion(
+        &mut self,
+        _: &str,
+        suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        Ok(suggestion)
+    }
+}
+
+/// Suggestion of a [TokenAutocomplete], replacing a span of the user's text
+/// input when used.
+///
+/// # Example
+///
+/// ```
+/// use inquire::autocompletion::Suggestion;
+///
+/// let input = "git ch";
+/// let suggestion = Suggestion::new("checkout", 4..input.len())
+///     .with_description("Switch branches or restore working tree files");
+///
+/// assert_eq!(("git checkout".to_owned(), 12), suggestion.apply(input));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Suggestion {
+    /// Text replacing the span of the input when the suggestion is used.
+    pub replacement: String,
+
+    /// Byte range of the input replaced by the suggestion, usually the token
+    /// under the cursor.
+    pub span: Range<usize>,
+
+    /// Text displayed in the list of suggestions, the replacement itself if
+    /// `None`.
+    pub label: Option<String>,
+
+    /// Description displayed in a second column of the list of suggestions,
+    /// aligned across the displayed suggestions.
+    pub description: Option<String>,
+
+    /// Style sheet of the label, applied when the suggestion is not
+    /// highlighted. Defaults to the [`option`](crate::ui::RenderConfig::option)
+    /// style sheet of the render config.
+    pub style: Option<StyleSheet>,
+}
+
+impl Suggestion {
+    /// Creates a suggestion replacing the given byte range of the input.
+    pub fn new<S>(replacement: S, span: Range<usize>) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            replacement: replacement.into(),
+            span,
+            label: None,
+            description: None,
+            style: None,
+        }
+    }
+
+    /// Sets the text displayed in the list of suggestions.
+    pub fn with_label<S>(mut self, label: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the description displayed next to the label.
+    pub fn with_description<S>(mut self, description: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the style sheet of the label.
+    pub fn with_style(mut self, style: StyleSheet) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Text displayed in the list of suggestions.
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.replacement)
+    }
+
+    /// Replaces the span of the input by the suggestion, returning the new
+    /// input and the byte index of the end of the replacement, where the
+    /// cursor is placed.
+    ///
+    /// Spans out of the bounds of the input are clamped to them, and spans
+    /// not on character boundaries replace the whole input.
+    pub fn apply(&self, input: &str) -> (String, usize) {
+        let end = self.span.end.min(input.len());
+        let start = self.span.start.min(end);
+
+        let (prefix, suffix) = match (input.get(..start), input.get(end..)) {
+            (Some(prefix), Some(suffix)) => (prefix, suffix),
+            _ => ("", ""),
+        };
+
+        let cursor = prefix.len() + self.replacement.len();
+        (format!("{}{}{}", prefix, self.replacement, suffix), cursor)
+    }
+}
+
+/// Mechanism to implement autocompletion features aware of the position of
+/// the cursor, for example to complete only the word under it.
+///
+/// - `suggest` is called whenever the user's text input or the position of
+///   the cursor changes, returning the list of suggestions displayed to the
+///   user. If the user submits while highlighting one of them, the input with
+///   the suggestion applied is treated as the final answer.
+/// - `complete` is called whenever the user presses the autocompletion hotkey
+///   (`tab` by default), with the highlighted suggestion, if any. The
+///   suggestion it returns, if any, is applied to the input.
+///
+/// The cursor is given as a byte index of the input, and so are the spans of
+/// the suggestions.
+///
+/// Functions and closures with the signature of `suggest` implement this
+/// trait, using the highlighted suggestion as the completion.
+pub trait TokenAutocomplete: DynClone {
+    /// List of suggestions to be displayed to the user for the input and
+    /// the position of the cursor.
+    fn suggest(&mut self, input: &str, cursor: usize) -> Result<Vec<Suggestion>, CustomUserError>;
+
+    /// Completion applied to the input when the user presses the
+    /// autocompletion hotkey. Defaults to the highlighted suggestion.
+    fn complete(
+        &mut self,
+        _: &str,
+        _: usize,
+        highlighted_suggestion: Option<&Suggestion>,
+    ) -> Result<Option<Suggestion>, CustomUserError> {
+        Ok(highlighted_suggestion.cloned())
+    }
+}
+
+impl Clone for Box<dyn TokenAutocomplete> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+impl<F> TokenAutocomplete for F
+where
+    F: Fn(&str, usize) -> Result<Vec<Suggestion>, CustomUserError> + Clone,
+{
+    fn suggest(&mut self, input: &str, cursor: usize) -> Result<Vec<Suggestion>, CustomUserError> {
+        (self)(input, cursor)
+    }
+}
+
+/// Adapter implementing [TokenAutocomplete] for an [Autocomplete], whose
+/// suggestions and completions replace the whole input.
+#[derive(Clone)]
+pub struct AutocompleteAdapter {
+    inner: Box<dyn Autocomplete>,
+}
+
+impl AutocompleteAdapter {
+    /// Creates an adapter for the given autocompleter.
+    pub fn new<AC>(autocompleter: AC) -> Self
+    where
+        AC: Autocomplete + 'static,
+    {
+        Self {
+            inner: Box::new(autocompleter),
+        }
+    }
+}
+
+impl From<Box<dyn Autocomplete>> for AutocompleteAdapter {
+    fn from(inner: Box<dyn Autocomplete>) -> Self {
+        Self { inner }
+    }
+}
+
+impl TokenAutocomplete for AutocompleteAdapter {
+    fn suggest(&mut self, input: &str, _: usize) -> Result<Vec<Suggestion>, CustomUserError> {
+        let suggestions = self
+            .inner
+            .get_suggestions(input)?
+            .into_iter()
+            .map(|suggestion| Suggestion::new(suggestion, 0..input.len()))
+            .collect();
+
+        Ok(suggestions)
+    }
+
+    fn complete(
+        &mut self,
+        input: &str,
+        _: usize,
+        highlighted_suggestion: Option<&Suggestion>,
+    ) -> Result<Option<Suggestion>, CustomUserError> {
+        let highlighted = highlighted_suggestion.map(|s| s.replacement.clone());
+        let replacement = self.inner.get_completion(input, highlighted)?;
+
+        Ok(replacement.map(|value| Suggestion::new(value, 0..input.len())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AutocompleteAdapter, Suggestion, TokenAutocomplete};
+    use crate::CustomUserError;
+
+    #[test]
+    fn suggestions_replace_their_span() {
+        let suggestion = Suggestion::new("checkout", 4..6);
+
+        assert_eq!(
+            ("git checkout -b".to_owned(), 12),
+            suggestion.apply("git ch -b")
+        );
+        assert_eq!(
+            ("checkout".to_owned(), 8),
+            Suggestion::new("checkout", 0..99).apply("ch")
+        );
+    }
+
+    #[test]
+    fn suggestions_off_char_boundaries_replace_the_input() {
+        let suggestion = Suggestion::new("été", 1..2);
+
+        assert_eq!(("été".to_owned(), 5), suggestion.apply("é"));
+    }
+
+    #[test]
+    fn adapted_suggestions_replace_the_whole_input() {
+        let suggest =
+            |input: &str| -> Result<Vec<String>, CustomUserError> { Ok(vec![format!("{input}!")]) };
+        let mut adapter = AutocompleteAdapter::new(suggest);
+
+        let suggestions = adapter.suggest("hey", 1).unwrap();
+        assert_eq!(vec![Suggestion::new("hey!", 0..3)], suggestions);
+
+        let completion = adapter.complete("hey", 1, suggestions.first()).unwrap();
+        assert_eq!(Some(Suggestion::new("hey!", 0..3)), completion);
+        assert_eq!(None, adapter.complete("hey", 1, None).unwrap());
+    }
+}
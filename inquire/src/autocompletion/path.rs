@@ -0,0 +1,312 @@
+use std::path::{is_separator, PathBuf, MAIN_SEPARATOR};
+
+use crate::{
+    path_utils::{expand_tilde, read_entries, PathFilter},
+    CustomUserError,
+};
+
+use super::{Suggestion, TokenAutocomplete};
+
+/// Autocompleter of filesystem paths, suggesting the entries of the directory
+/// typed so far whose name starts with the last segment of the input. Only the
+/// input before the cursor is completed, the rest being kept as is.
+///
+/// - A leading `~` stands for the home directory of the current user, and is
+///   kept as typed in the suggestions.
+/// - Hidden files, whose name starts with a dot, are only suggested when
+///   enabled with [`with_hidden_files`](Self::with_hidden_files) or when the
+///   last segment of the input starts with a dot.
+/// - Suggestions can be restricted to directories, or to files with some
+///   extensions, directories being suggested regardless so that the user can
+///   go through them.
+///
+/// When the user presses the autocompletion hotkey without highlighting a
+/// suggestion, the input is completed up to the longest prefix common to all
+/// suggestions, as in shells.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{autocompletion::PathCompleter, Text};
+///
+/// let picture = Text::new("Profile picture:")
+///     .with_token_autocomplete(PathCompleter::new().with_extensions(&["png", "jpg"]))
+///     .prompt();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PathCompleter {
+    filter: PathFilter,
+    input: Option<String>,
+    paths: Vec<String>,
+}
+
+impl PathCompleter {
+    /// Creates a completer suggesting all files and directories, except
+    /// hidden ones.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether hidden files and directories are suggested.
+    pub fn with_hidden_files(mut self, show_hidden: bool) -> Self {
+        self.filter.show_hidden = show_hidden;
+        self.input = None;
+        self
+    }
+
+    /// Only suggests directories.
+    pub fn with_directories_only(mut self) -> Self {
+        self.filter.directories_only = true;
+        self.input = None;
+        self
+    }
+
+    /// Only suggests files with one of the given extensions, ignoring case,
+    /// along with directories. Extensions may be given with or without their
+    /// leading dot.
+    pub fn with_extensions(mut self, extensions: &[&str]) -> Self {
+        self.filter = self.filter.with_extensions(extensions);
+        self.input = None;
+        self
+    }
+
+    fn update_input(&mut self, input: &str) {
+        if self.input.as_deref() == Some(input) {
+            return;
+        }
+
+        self.input = Some(input.to_owned());
+        self.paths = self.scan(input);
+    }
+
+    fn scan(&self, input: &str) -> Vec<String> {
+        if input == "~" {
+            return vec![format!("~{}", MAIN_SEPARATOR)];
+        }
+
+        let (typed_dir, prefix) = match input.rfind(is_separator) {
+            Some(index) => input.split_at(index + 1),
+            None => ("", input),
+        };
+
+        let dir = match typed_dir {
+            "" => PathBuf::from("."),
+            typed_dir => expand_tilde(typed_dir),
+        };
+
+        let filter = PathFilter {
+            show_hidden: self.filter.show_hidden || prefix.starts_with('.'),
+            ..self.filter.clone()
+        };
+
+        // unreadable or missing directories have nothing to suggest, which
+        // should not abort the prompt.
+        let entries = read_entries(&dir, &filter).unwrap_or_default();
+
+        entries
+            .iter()
+            .filter(|entry| entry.name.starts_with(prefix))
+            .map(|entry| format!("{}{}", typed_dir, entry))
+            .collect()
+    }
+
+    fn longest_common_prefix(&self) -> &str {
+        let Some(first) = self.paths.first() else {
+            return "";
+        };
+
+        let len = self.paths[1..].iter().fold(first.len(), |len, path| {
+            first[..len]
+                .char_indices()
+                .zip(path.chars())
+                .find(|((_, a), b)| a != b)
+                .map(|((index, _), _)| index)
+                .unwrap_or_else(|| len.min(path.len()))
+        });
+
+        &first[..len]
+    }
+}
+
+impl TokenAutocomplete for PathCompleter {
+    fn suggest(&mut self, input: &str, cursor: usize) -> Result<Vec<Suggestion>, CustomUserError> {
+        let typed = input.get(..cursor).unwrap_or(input);
+        self.update_input(typed);
+
+        let suggestions = self
+            .paths
+            .iter()
+            .map(|path| Suggestion::new(path.as_str(), 0..typed.len()))
+            .collect();
+
+        Ok(suggestions)
+    }
+
+    fn complete(
+        &mut self,
+        input: &str,
+        cursor: usize,
+        highlighted_suggestion: Option<&Suggestion>,
+    ) -> Result<Option<Suggestion>, CustomUserError> {
+        if highlighted_suggestion.is_some() {
+            return Ok(highlighted_suggestion.cloned());
+        }
+
+        let typed = input.get(..cursor).unwrap_or(input);
+        self.update_input(typed);
+
+        let prefix = self.longest_common_prefix();
+        if prefix.len() > typed.len() {
+            Ok(Some(Suggestion::new(prefix, 0..typed.len())))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::MAIN_SEPARATOR};
+
+    use super::PathCompleter;
+    use crate::autocompletion::{Suggestion, TokenAutocomplete};
+
+    fn tree() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("docs")).unwrap();
+        fs::create_dir(dir.path().join("dist")).unwrap();
+        fs::create_dir(dir.path().join(".cache")).unwrap();
+        fs::write(dir.path().join("diagram.svg"), "").unwrap();
+        fs::write(dir.path().join("notes.txt"), "").unwrap();
+        dir
+    }
+
+    fn input(dir: &tempfile::TempDir, rest: &str) -> String {
+        format!("{}{}{}", dir.path().display(), MAIN_SEPARATOR, rest)
+    }
+
+    fn suggested_paths(completer: &mut PathCompleter, input: &str) -> Vec<String> {
+        completer
+            .suggest(input, input.len())
+            .unwrap()
+            .into_iter()
+            .map(|suggestion| suggestion.replacement)
+            .collect()
+    }
+
+    fn completion(
+        completer: &mut PathCompleter,
+        input: &str,
+        highlighted: Option<&Suggestion>,
+    ) -> Option<String> {
+        completer
+            .complete(input, input.len(), highlighted)
+            .unwrap()
+            .map(|suggestion| suggestion.apply(input).0)
+    }
+
+    #[test]
+    fn suggests_entries_starting_with_the_last_segment() {
+        let dir = tree();
+        let mut completer = PathCompleter::new();
+
+        let suggestions = suggested_paths(&mut completer, &input(&dir, "di"));
+        assert_eq!(
+            vec![
+                input(&dir, &format!("dist{}", MAIN_SEPARATOR)),
+                input(&dir, "diagram.svg"),
+            ],
+            suggestions
+        );
+
+        let suggestions = suggested_paths(&mut completer, &input(&dir, ""));
+        assert_eq!(4, suggestions.len());
+    }
+
+    #[test]
+    fn hidden_files_are_suggested_when_enabled_or_typed() {
+        let dir = tree();
+        let cache = input(&dir, &format!(".cache{}", MAIN_SEPARATOR));
+
+        let suggestions = suggested_paths(&mut PathCompleter::new(), &input(&dir, ""));
+        assert!(!suggestions.contains(&cache));
+
+        let suggestions = suggested_paths(
+            &mut PathCompleter::new().with_hidden_files(true),
+            &input(&dir, ""),
+        );
+        assert!(suggestions.contains(&cache));
+
+        let suggestions = suggested_paths(&mut PathCompleter::new(), &input(&dir, "."));
+        assert_eq!(vec![cache], suggestions);
+    }
+
+    #[test]
+    fn suggestions_are_filtered_by_kind_and_extension() {
+        let dir = tree();
+
+        let suggestions = suggested_paths(
+            &mut PathCompleter::new().with_directories_only(),
+            &input(&dir, ""),
+        );
+        assert_eq!(2, suggestions.len());
+
+        let suggestions = suggested_paths(
+            &mut PathCompleter::new().with_extensions(&["SVG"]),
+            &input(&dir, "d"),
+        );
+        assert_eq!(3, suggestions.len());
+        assert!(!suggestions.contains(&input(&dir, "notes.txt")));
+    }
+
+    #[test]
+    fn completes_the_longest_common_prefix() {
+        let dir = tree();
+        let mut completer = PathCompleter::new();
+
+        assert_eq!(
+            Some(input(&dir, &format!("dist{}", MAIN_SEPARATOR))),
+            completion(&mut completer, &input(&dir, "dis"), None)
+        );
+        assert_eq!(None, completion(&mut completer, &input(&dir, "d"), None));
+        assert_eq!(
+            Some(input(&dir, "notes.txt")),
+            completion(&mut completer, &input(&dir, "n"), None)
+        );
+        let picked = Suggestion::new("picked", 0..2);
+        assert_eq!(
+            Some(String::from("picked")),
+            completion(&mut completer, "di", Some(&picked))
+        );
+    }
+
+    #[test]
+    fn only_the_input_before_the_cursor_is_completed() {
+        let dir = tree();
+        let typed = input(&dir, "dis");
+        let line = format!("{} --release", typed);
+
+        let completion = PathCompleter::new()
+            .complete(&line, typed.len(), None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            format!(
+                "{} --release",
+                input(&dir, &format!("dist{}", MAIN_SEPARATOR))
+            ),
+            completion.apply(&line).0
+        );
+    }
+
+    #[test]
+    fn missing_directories_have_no_suggestions() {
+        let dir = tree();
+
+        let suggestions = suggested_paths(
+            &mut PathCompleter::new(),
+            &input(&dir, &format!("missing{}", MAIN_SEPARATOR)),
+        );
+        assert!(suggestions.is_empty());
+    }
+}
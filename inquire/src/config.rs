@@ -4,10 +4,19 @@ use std::sync::Mutex;
 
 use once_cell::sync::Lazy;
 
-use crate::ui::RenderConfig;
+use crate::{keymap::KeyMap, ui::RenderConfig};
 
 static GLOBAL_RENDER_CONFIGURATION: Lazy<Mutex<RenderConfig<'static>>> =
-    Lazy::new(|| Mutex::new(RenderConfig::default()));
+    Lazy::new(|| Mutex::new(default_render_config()));
+
+/// Render configuration used until one is set with [`set_global_render_config`].
+fn default_render_config() -> RenderConfig<'static> {
+    #[cfg(feature = "background-detection")]
+    return RenderConfig::default_detecting_background();
+
+    #[cfg(not(feature = "background-detection"))]
+    RenderConfig::default()
+}
 
 pub fn get_configuration() -> RenderConfig<'static> {
     *GLOBAL_RENDER_CONFIGURATION.lock().unwrap()
@@ -20,6 +29,77 @@ pub fn set_global_render_config(config: RenderConfig<'static>) {
     *guard = config;
 }
 
+static GLOBAL_KEY_MAP: Lazy<Mutex<KeyMap>> = Lazy::new(|| Mutex::new(KeyMap::default()));
+
+pub fn get_key_map() -> KeyMap {
+    GLOBAL_KEY_MAP.lock().unwrap().clone()
+}
+
+/// Acquires a write lock to the global KeyMap object
+/// and updates the inner value with the provided argument.
+pub fn set_global_key_map(key_map: KeyMap) {
+    let mut guard = GLOBAL_KEY_MAP.lock().unwrap();
+    *guard = key_map;
+}
+
+static GLOBAL_ACCESSIBLE_MODE: Lazy<Mutex<bool>> =
+    Lazy::new(|| Mutex::new(accessible_mode_from_env()));
+
+/// Whether the `INQUIRE_ACCESSIBLE` environment variable enables the
+/// accessible mode, being set to a value other than `0` or `false`.
+fn accessible_mode_from_env() -> bool {
+    match std::env::var("INQUIRE_ACCESSIBLE") {
+        Ok(value) => !matches!(value.trim(), "" | "0" | "false"),
+        Err(_) => false,
+    }
+}
+
+pub fn get_accessible_mode() -> bool {
+    *GLOBAL_ACCESSIBLE_MODE.lock().unwrap()
+}
+
+/// Enables or disables the accessible mode of all future prompts, overriding
+/// the `INQUIRE_ACCESSIBLE` environment variable.
+///
+/// The accessible mode is meant for screen readers and braille displays.
+/// Instead of redrawing the prompt on each key press, the question is printed
+/// once, followed by the numbered options of list prompts, and answers are
+/// read line by line from the standard input. Options are picked by typing
+/// their numbers or values, dates by typing them as `YYYY-MM-DD`, and
+/// validation errors are printed as plain lines. Colors and cursor movements
+/// are never used.
+///
+/// An empty line submits the default answer, if any, and a line containing
+/// only ESC, or the end of the input, cancels the prompt.
+///
+/// It applies to [`Text`](crate::Text), [`Password`](crate::Password),
+/// [`Confirm`](crate::Confirm), [`CustomType`](crate::CustomType),
+/// [`Select`](crate::Select), [`MultiSelect`](crate::MultiSelect) and
+/// `DateSelect` prompts started with their blocking `prompt` methods, on
+/// which timeouts are ignored.
+pub fn set_global_accessible_mode(enabled: bool) {
+    let mut guard = GLOBAL_ACCESSIBLE_MODE.lock().unwrap();
+    *guard = enabled;
+}
+
+static GLOBAL_VIM_MODE: Lazy<Mutex<bool>> = Lazy::new(|| Mutex::new(DEFAULT_VIM_MODE));
+
+pub fn get_vim_mode() -> bool {
+    *GLOBAL_VIM_MODE.lock().unwrap()
+}
+
+/// Enables or disables the vim mode of all future prompts created afterwards,
+/// which can still be set on each prompt with their `with_vim_mode` methods.
+///
+/// The vim mode adds modal editing to the text inputs of [`Text`](crate::Text),
+/// [`CustomType`](crate::CustomType) and [`Password`](crate::Password)
+/// prompts, and navigation with the `hjkl` keys to [`Select`](crate::Select)
+/// and [`MultiSelect`](crate::MultiSelect) prompts.
+pub fn set_global_vim_mode(enabled: bool) {
+    let mut guard = GLOBAL_VIM_MODE.lock().unwrap();
+    *guard = enabled;
+}
+
 /// Default page size when displaying options to the user.
 pub const DEFAULT_PAGE_SIZE: usize = 7;
 
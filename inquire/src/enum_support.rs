@@ -1,20 +1,63 @@
-use std::str::FromStr;
+/// Enum whose variants can be selected with
+/// [`Select::from_enum`](crate::Select::from_enum) and
+/// [`MultiSelect::from_enum`](crate::MultiSelect::from_enum).
+///
+/// This trait can be derived with `#[derive(InquireSelect)]` when the `macros`
+/// feature is enabled. With the `strum` feature, it is implemented for all
+/// types implementing `strum::VariantArray`, listing the variants in their
+/// declaration order.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fmt::{Display, Formatter};
+///
+/// use inquire::{InquireEnumVariants, Select};
+///
+/// #[derive(Clone, Copy, Debug)]
+/// enum Plan {
+///     Free,
+///     Pro,
+/// }
+///
+/// impl InquireEnumVariants for Plan {
+///     fn variants() -> Vec<Self> {
+///         vec![Plan::Free, Plan::Pro]
+///     }
+///
+///     fn help(&self) -> Option<&'static str> {
+///         match self {
+///             Plan::Free => Some("Up to 3 projects"),
+///             Plan::Pro => Some("Unlimited projects"),
+///         }
+///     }
+/// }
+///
+/// impl Display for Plan {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{:?}", self)
+///     }
+/// }
+///
+/// let plan: Plan = Select::from_enum("Plan:").prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+pub trait InquireEnumVariants: Sized {
+    /// Variants that can be selected, in the order they are displayed.
+    fn variants() -> Vec<Self>;
 
-use crate::{
-    error::InquireResult,
-    list_option::{FromListOption, ListOption},
-};
-
-pub trait InquireEnumVariants {
-    fn get_variants() -> &'static [&'static str];
+    /// Help message of the variant, displayed while it is highlighted.
+    fn help(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 #[cfg(feature = "strum")]
 impl<T> InquireEnumVariants for T
 where
-    T: strum::VariantNames,
+    T: strum::VariantArray + Clone,
 {
-    fn get_variants() -> &'static [&'static str] {
-        T::VARIANTS
+    fn variants() -> Vec<Self> {
+        T::VARIANTS.to_vec()
     }
 }
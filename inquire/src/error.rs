@@ -32,6 +32,25 @@ pub enum InquireError {
     /// pressing Ctrl+C will trigger SIGINT.
     OperationInterrupted,
 
+    /// The operation was aborted by the application, through the cancellation
+    /// future passed to one of the `prompt_async_with_cancellation` methods.
+    ///
+    /// Unlike [`OperationCanceled`](InquireError::OperationCanceled), this
+    /// error is never caused by a user input.
+    OperationAborted,
+
+    /// The user did not answer the prompt before its
+    /// [`Timeout`](crate::Timeout) expired.
+    Timeout,
+
+    /// The user asked to return to the previous step of a
+    /// [`Form`](crate::Form).
+    ///
+    /// Forms handle this error themselves, so it is only returned by prompts
+    /// run on their own when [`Command::Back`](crate::keymap::Command::Back)
+    /// is bound in their key map.
+    NavigatedBack,
+
     /// Error while executing IO operations.
     Custom(CustomUserError),
 }
@@ -84,6 +103,9 @@ impl fmt::Display for InquireError {
             InquireError::OperationInterrupted => {
                 f.write_str("Operation was interrupted by the user")
             }
+            InquireError::OperationAborted => f.write_str("Operation was aborted"),
+            InquireError::Timeout => f.write_str("Prompt was not answered in time"),
+            InquireError::NavigatedBack => f.write_str("User navigated back to the previous step"),
             InquireError::Custom(err) => write!(f, "User-provided error: {}", err),
         }
     }
@@ -49,6 +49,7 @@
 #[cfg(feature = "date")]
 use crate::date_utils::DateFromStr;
 use crate::list_option::ListOption;
+use std::{path::Path, time::Duration};
 /// Type alias for formatters that receive a string slice as the input,
 /// required by [Text](crate::Text) and [Password](crate::Password) for example.
 ///
@@ -149,6 +150,43 @@ pub type MultiOptionFormatter<'a, T> = &'a dyn Fn(&[ListOption<&T>]) -> String;
 /// ```
 pub type CustomTypeFormatter<'a, T> = &'a dyn Fn(T) -> String;
 
+/// Type alias for formatters used in [`PathSelect`](crate::PathSelect) prompts.
+///
+/// Formatters receive the selected path and return a [String] to be displayed
+/// to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use inquire::formatter::PathFormatter;
+///
+/// let formatter: PathFormatter = &|path| match path.file_name() {
+///     Some(name) => name.to_string_lossy().into_owned(),
+///     None => path.display().to_string(),
+/// };
+/// assert_eq!(String::from("main.rs"), formatter(Path::new("src/main.rs")));
+/// ```
+pub type PathFormatter<'a> = &'a dyn Fn(&Path) -> String;
+
+/// Type alias for formatters of the time left to answer a prompt, displayed
+/// below it when its [`Timeout`](crate::Timeout) shows a countdown.
+///
+/// Unlike other formatters, it is a function pointer, so that it can be part
+/// of a [`RenderConfig`](crate::ui::RenderConfig), see
+/// [`RenderConfig::countdown_formatter`](crate::ui::RenderConfig::countdown_formatter).
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use inquire::formatter::CountdownFormatter;
+///
+/// let formatter: CountdownFormatter = |remaining| format!("{}s restantes", remaining.as_secs());
+/// assert_eq!(String::from("5s restantes"), formatter(Duration::from_secs(5)));
+/// ```
+pub type CountdownFormatter = fn(Duration) -> String;
+
 #[cfg(feature = "date")]
 
 /// Type alias for formatters used in [`DateSelect`](crate::DateSelect) prompts.
@@ -224,6 +262,38 @@ pub const DEFAULT_BOOL_FORMATTER: BoolFormatter<'_> = &|ans| {
     }
 };
 
+/// Path formatter used by default in [`PathSelect`](crate::PathSelect)
+/// prompts. Displays the path as is.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use inquire::formatter::DEFAULT_PATH_FORMATTER;
+///
+/// let formatter = DEFAULT_PATH_FORMATTER;
+/// assert_eq!(String::from("src/main.rs"), formatter(Path::new("src/main.rs")));
+/// ```
+pub const DEFAULT_PATH_FORMATTER: PathFormatter<'_> = &|path| path.display().to_string();
+
+/// Countdown formatter used by default, displaying the remaining time in
+/// seconds, rounded up.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use inquire::formatter::DEFAULT_COUNTDOWN_FORMATTER;
+///
+/// let formatter = DEFAULT_COUNTDOWN_FORMATTER;
+/// assert_eq!(String::from("5s remaining"), formatter(Duration::from_secs(5)));
+/// assert_eq!(String::from("5s remaining"), formatter(Duration::from_millis(4200)));
+/// ```
+pub const DEFAULT_COUNTDOWN_FORMATTER: CountdownFormatter = |remaining| {
+    let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    format!("{}s remaining", seconds)
+};
+
 #[cfg(feature = "date")]
 /// String formatter used by default in [`DateSelect`](crate::DateSelect) prompts.
 /// Prints the selected date in the format: Month Day, Year.
@@ -0,0 +1,590 @@
+//! Input history shared by [`Text`](crate::Text) and [`CustomType`](crate::CustomType) prompts.
+//!
+//! A [`History`] keeps the previous answers submitted to the prompts it is
+//! given to. Inside of the prompt, the user may go through these entries with
+//! the up and down arrows, or search them with `ctrl+r`, similarly to a shell.
+//!
+//! The entries are kept in memory by default, and may be persisted by any
+//! [`HistoryStorage`], such as the [`FileStorage`] provided by this module.
+//!
+//! ```no_run
+//! use inquire::{history::History, Text};
+//!
+//! let history = History::from_file(".command_history")?.with_max_size(100);
+//!
+//! loop {
+//!     let command = Text::new("$").with_history(history.clone()).prompt()?;
+//!
+//!     if command == "exit" {
+//!         break;
+//!     }
+//! }
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+use std::{
+    fs, io,
+    path::PathBuf,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    error::InquireResult,
+    input::{Input, InputActionResult},
+    prompts::prompt::ActionResult,
+    InputAction,
+};
+
+/// Persistence layer of a [`History`].
+///
+/// Entries are loaded once, when the history is created, and the whole list
+/// is stored again every time an entry is added to the history.
+pub trait HistoryStorage: Send {
+    /// Loads the stored entries, from the oldest to the newest.
+    fn load(&mut self) -> InquireResult<Vec<String>>;
+
+    /// Replaces the stored entries by the given ones, ordered from the oldest
+    /// to the newest.
+    fn store(&mut self, entries: &[String]) -> InquireResult<()>;
+}
+
+/// [`HistoryStorage`] keeping the entries in a text file, one per line.
+///
+/// Line breaks and backslashes of the entries are escaped, so answers of
+/// multi-line prompts are stored as a single line as well. A missing file is
+/// loaded as an empty history and created on the first store.
+#[derive(Clone, Debug)]
+pub struct FileStorage {
+    path: PathBuf,
+}
+
+impl FileStorage {
+    /// Creates a storage backed by the file at the given path.
+    pub fn new<P>(path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { path: path.into() }
+    }
+}
+
+impl HistoryStorage for FileStorage {
+    fn load(&mut self) -> InquireResult<Vec<String>> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(content.lines().map(unescape).collect())
+    }
+
+    fn store(&mut self, entries: &[String]) -> InquireResult<()> {
+        let mut content = String::new();
+
+        for entry in entries {
+            content.push_str(&escape(entry));
+            content.push('\n');
+        }
+
+        fs::write(&self.path, content)?;
+
+        Ok(())
+    }
+}
+
+fn escape(entry: &str) -> String {
+    let mut escaped = String::with_capacity(entry.len());
+
+    for c in entry.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn unescape(line: &str) -> String {
+    let mut entry = String::with_capacity(line.len());
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            entry.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => entry.push('\n'),
+            Some('r') => entry.push('\r'),
+            Some(c) => entry.push(c),
+            None => entry.push('\\'),
+        }
+    }
+
+    entry
+}
+
+struct HistoryInner {
+    entries: Vec<String>,
+    max_size: usize,
+    dedupe: bool,
+    storage: Option<Box<dyn HistoryStorage>>,
+}
+
+impl HistoryInner {
+    fn truncate(&mut self) {
+        if self.entries.len() > self.max_size {
+            let excess = self.entries.len() - self.max_size;
+            self.entries.drain(..excess);
+        }
+    }
+}
+
+/// Previous answers of the prompts this history is given to.
+///
+/// Clones of a `History` share the same entries, so a single history can be
+/// handed to several prompts, e.g. to every iteration of a REPL loop. Entries
+/// are only added when a prompt is successfully submitted.
+///
+/// By default, the history keeps at most [`History::DEFAULT_MAX_SIZE`]
+/// entries, dropping the oldest ones, and an entry submitted again is moved
+/// to the end of the history instead of being duplicated.
+#[derive(Clone)]
+pub struct History {
+    inner: Arc<Mutex<HistoryInner>>,
+}
+
+impl History {
+    /// Default maximum amount of entries kept in a history.
+    pub const DEFAULT_MAX_SIZE: usize = 1000;
+
+    /// Creates an empty history, kept in memory.
+    pub fn new() -> Self {
+        Self::from_entries(vec![], None)
+    }
+
+    /// Creates a history persisted by the given storage, loading its entries.
+    pub fn with_storage<S>(mut storage: S) -> InquireResult<Self>
+    where
+        S: HistoryStorage + 'static,
+    {
+        let entries = storage.load()?;
+
+        Ok(Self::from_entries(entries, Some(Box::new(storage))))
+    }
+
+    /// Creates a history persisted in the file at the given path, see
+    /// [`FileStorage`].
+    pub fn from_file<P>(path: P) -> InquireResult<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        Self::with_storage(FileStorage::new(path))
+    }
+
+    fn from_entries(entries: Vec<String>, storage: Option<Box<dyn HistoryStorage>>) -> Self {
+        let mut inner = HistoryInner {
+            entries,
+            max_size: Self::DEFAULT_MAX_SIZE,
+            dedupe: true,
+            storage,
+        };
+        inner.truncate();
+
+        Self {
+            inner: Arc::new(Mutex::new(inner)),
+        }
+    }
+
+    /// Sets the maximum amount of entries kept in the history, dropping the
+    /// oldest ones when exceeded.
+    pub fn with_max_size(self, max_size: usize) -> Self {
+        {
+            let mut inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+            inner.max_size = max_size;
+            inner.truncate();
+        }
+        self
+    }
+
+    /// Sets whether an entry submitted again replaces its previous
+    /// occurrences, instead of being added as a duplicate.
+    pub fn with_dedupe(self, dedupe: bool) -> Self {
+        self.inner
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .dedupe = dedupe;
+        self
+    }
+
+    /// Entries of the history, from the oldest to the newest.
+    pub fn entries(&self) -> Vec<String> {
+        self.inner
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entries
+            .clone()
+    }
+
+    /// Adds an entry to the end of the history and updates its storage, if
+    /// any.
+    ///
+    /// Blank entries are ignored.
+    pub fn push(&self, entry: &str) -> InquireResult<()> {
+        if entry.trim().is_empty() {
+            return Ok(());
+        }
+
+        let mut inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if inner.dedupe {
+            inner.entries.retain(|e| e != entry);
+        }
+        inner.entries.push(entry.to_owned());
+        inner.truncate();
+
+        let HistoryInner {
+            entries, storage, ..
+        } = &mut *inner;
+
+        match storage {
+            Some(storage) => storage.store(entries),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct HistorySearch {
+    query: Input,
+    original: Input,
+    match_index: Option<usize>,
+    failing: bool,
+}
+
+/// Navigation of a prompt through the entries of its history, by recalling
+/// them one at a time or by an incremental reverse search.
+pub(crate) struct HistoryNavigator {
+    history: History,
+    entries: Vec<String>,
+    index: Option<usize>,
+    draft: Option<Input>,
+    search: Option<HistorySearch>,
+}
+
+impl HistoryNavigator {
+    pub fn new(history: History) -> Self {
+        let entries = history.entries();
+
+        Self {
+            history,
+            entries,
+            index: None,
+            draft: None,
+            search: None,
+        }
+    }
+
+    /// Replaces the input by the entry before the one currently recalled, or
+    /// by the newest entry, saving the current input to restore it later.
+    pub fn previous(&mut self, input: &mut Input) -> ActionResult {
+        let index = match self.index {
+            Some(0) => return ActionResult::Clean,
+            Some(index) => index - 1,
+            None if self.entries.is_empty() => return ActionResult::Clean,
+            None => {
+                self.draft = Some(input.clone());
+                self.entries.len() - 1
+            }
+        };
+
+        self.index = Some(index);
+        *input = Input::new_with(self.entries[index].as_str());
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Replaces the input by the entry after the one currently recalled, or
+    /// by the input saved before recalling the newest entry.
+    pub fn next(&mut self, input: &mut Input) -> ActionResult {
+        let Some(index) = self.index else {
+            return ActionResult::Clean;
+        };
+
+        if index + 1 < self.entries.len() {
+            self.index = Some(index + 1);
+            *input = Input::new_with(self.entries[index + 1].as_str());
+        } else {
+            self.index = None;
+            if let Some(draft) = self.draft.take() {
+                *input = draft;
+            }
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Rest of the newest entry starting with the given text, if any.
+    pub fn completion(&self, text: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .rev()
+            .filter_map(|entry| entry.strip_prefix(text))
+            .find(|rest| !rest.is_empty())
+    }
+
+    /// Query of the ongoing search, along with whether it matches an entry.
+    pub fn search_query(&self) -> Option<(&Input, bool)> {
+        self.search.as_ref().map(|s| (&s.query, !s.failing))
+    }
+
+    /// Starts a reverse search, or moves an ongoing one to the next older
+    /// entry matching its query.
+    pub fn search(&mut self, input: &mut Input) -> ActionResult {
+        let older = match &self.search {
+            Some(search) if search.failing => return ActionResult::Clean,
+            Some(search) => search.match_index,
+            None => {
+                self.search = Some(HistorySearch {
+                    query: Input::new(),
+                    original: input.clone(),
+                    match_index: None,
+                    failing: false,
+                });
+                return ActionResult::NeedsRedraw;
+            }
+        };
+
+        match older {
+            Some(end) => self.find_match(end, input),
+            None => ActionResult::Clean,
+        }
+    }
+
+    /// Edits the query of the ongoing search, if any, with the given action.
+    ///
+    /// Cursor movements end the search, keeping the matched entry as the
+    /// input, and are not handled here. Returns `None` when the action should
+    /// be applied to the input instead.
+    pub fn handle_search_input(
+        &mut self,
+        action: &InputAction,
+        input: &mut Input,
+    ) -> Option<ActionResult> {
+        let search = self.search.as_mut()?;
+
+        if let InputAction::MoveCursor(..) = action {
+            self.accept_search();
+            return None;
+        }
+
+        let result = match search.query.handle(action.clone()) {
+            InputActionResult::ContentChanged => {
+                let end = match search.match_index {
+                    Some(index) => index + 1,
+                    None => self.entries.len(),
+                };
+                self.find_match(end, input);
+                ActionResult::NeedsRedraw
+            }
+            result => result.into(),
+        };
+
+        Some(result)
+    }
+
+    /// Ends the ongoing search, keeping the matched entry as the input.
+    pub fn accept_search(&mut self) {
+        if let Some(search) = self.search.take() {
+            self.index = search.match_index;
+            if self.index.is_some() && self.draft.is_none() {
+                self.draft = Some(search.original);
+            }
+        }
+    }
+
+    /// Ends the ongoing search, restoring the input it started from.
+    /// Returns whether a search was ongoing.
+    pub fn cancel_search(&mut self, input: &mut Input) -> bool {
+        match self.search.take() {
+            Some(search) => {
+                *input = search.original;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Adds a submitted answer to the history.
+    ///
+    /// Errors of the storage are ignored, the answer having been submitted
+    /// already: it is still kept in memory for the following prompts.
+    pub fn record(&self, entry: &str) {
+        let _ = self.history.push(entry);
+    }
+
+    /// Looks for the newest entry before `end` matching the search query.
+    fn find_match(&mut self, end: usize, input: &mut Input) -> ActionResult {
+        let Some(search) = self.search.as_mut() else {
+            return ActionResult::Clean;
+        };
+
+        let query = search.query.content();
+
+        if query.is_empty() {
+            search.match_index = None;
+            search.failing = false;
+            *input = search.original.clone();
+            return ActionResult::NeedsRedraw;
+        }
+
+        let found = self
+            .entries
+            .iter()
+            .enumerate()
+            .take(end)
+            .rev()
+            .find_map(|(i, entry)| entry.find(query).map(|pos| (i, entry, pos)));
+
+        // like in shells, the last match stays displayed when none is found.
+        if let Some((index, entry, pos)) = found {
+            search.match_index = Some(index);
+            let cursor = entry[..pos].graphemes(true).count();
+            *input = Input::new_with(entry.as_str()).with_cursor(cursor);
+        }
+        search.failing = found.is_none();
+
+        ActionResult::NeedsRedraw
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::{input::Input, InputAction};
+
+    use super::{escape, unescape, History, HistoryNavigator};
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("inquire-history-{}-{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn push_dedupes_and_truncates_entries() {
+        let history = History::new().with_max_size(3);
+
+        for entry in ["a", "b", "", "a", "c", "d"] {
+            history.push(entry).unwrap();
+        }
+
+        assert_eq!(vec!["a", "c", "d"], history.entries());
+    }
+
+    #[test]
+    fn push_keeps_duplicates_without_dedupe() {
+        let history = History::new().with_dedupe(false);
+
+        history.push("a").unwrap();
+        history.push("a").unwrap();
+
+        assert_eq!(vec!["a", "a"], history.entries());
+    }
+
+    #[test]
+    fn escaped_entries_round_trip() {
+        for entry in ["plain", "two\nlines", "back\\slash", "\\n", "trailing\\"] {
+            assert_eq!(entry, unescape(&escape(entry)));
+        }
+    }
+
+    #[test]
+    fn file_history_is_persisted() {
+        let path = temp_path("persisted");
+        let _ = std::fs::remove_file(&path);
+
+        let history = History::from_file(&path).unwrap();
+        history.push("first").unwrap();
+        history.push("multi\nline").unwrap();
+
+        let reloaded = History::from_file(&path).unwrap();
+        assert_eq!(vec!["first", "multi\nline"], reloaded.entries());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn navigator_restores_the_draft() {
+        let history = History::new();
+        history.push("first").unwrap();
+        history.push("second").unwrap();
+
+        let mut navigator = HistoryNavigator::new(history);
+        let mut input = Input::new_with("draft");
+
+        navigator.previous(&mut input);
+        assert_eq!("second", input.content());
+        navigator.previous(&mut input);
+        navigator.previous(&mut input);
+        assert_eq!("first", input.content());
+        navigator.next(&mut input);
+        navigator.next(&mut input);
+        assert_eq!("draft", input.content());
+    }
+
+    #[test]
+    fn completion_is_taken_from_the_newest_matching_entry() {
+        let history = History::new();
+        for entry in ["cargo build", "cargo test", "cargo"] {
+            history.push(entry).unwrap();
+        }
+
+        let navigator = HistoryNavigator::new(history);
+        assert_eq!(Some(" test"), navigator.completion("cargo"));
+        assert_eq!(Some("uild"), navigator.completion("cargo b"));
+        assert_eq!(None, navigator.completion("cargo test"));
+        assert_eq!(None, navigator.completion("git"));
+    }
+
+    #[test]
+    fn reverse_search_finds_older_matches() {
+        let history = History::new();
+        for entry in ["cargo build", "git status", "cargo test"] {
+            history.push(entry).unwrap();
+        }
+
+        let mut navigator = HistoryNavigator::new(history);
+        let mut input = Input::new();
+
+        navigator.search(&mut input);
+        for c in "carg".chars() {
+            navigator.handle_search_input(&InputAction::Write(c), &mut input);
+        }
+        assert_eq!("cargo test", input.content());
+
+        navigator.search(&mut input);
+        assert_eq!("cargo build", input.content());
+
+        navigator.handle_search_input(&InputAction::Write('x'), &mut input);
+        assert_eq!("cargo build", input.content());
+        assert_eq!(
+            Some(false),
+            navigator.search_query().map(|(_, found)| found)
+        );
+
+        assert!(navigator.cancel_search(&mut input));
+        assert_eq!("", input.content());
+    }
+}
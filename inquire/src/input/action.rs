@@ -1,57 +1,143 @@
 use crate::{
-    ui::{Key, KeyModifiers},
+    keymap::{Command, KeyScope},
+    ui::Key,
     InnerAction,
 };
 
 use super::{LineDirection, Magnitude};
 
 /// Set of actions for a text input handler.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum InputAction {
     /// Deletes a substring of the input according to the magnitude and the
     /// direction to delete.
+    ///
+    /// Deleted words and lines are saved to the kill ring of the input.
     Delete(Magnitude, LineDirection),
     /// Moves the cursor according to the magnitude and the direction to move.
     MoveCursor(Magnitude, LineDirection),
     /// Writes a character to the content, according to the current cursor
     /// position.
     Write(char),
+    /// Inserts a string to the content, e.g. pasted text, according to the
+    /// current cursor position.
+    Insert(String),
+    /// Reverts the last edit of the content, typed characters being reverted
+    /// a word at a time.
+    Undo,
+    /// Reapplies the last edit reverted by [`Undo`](Self::Undo).
+    Redo,
+    /// Inserts the most recently deleted word or line at the cursor.
+    Yank,
+    /// Right after a [`Yank`](Self::Yank), replaces the inserted text by the
+    /// text deleted before it.
+    YankPop,
+}
+
+/// Describes how line breaks in pasted text are handled by prompts whose
+/// input is a single line, where typing a line break submits the prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NewlinePolicy {
+    /// Line breaks are removed, joining the lines together.
+    Strip,
+    /// Each line break is replaced by the given character.
+    Replace(char),
+    /// Pasted text containing line breaks is discarded.
+    Reject,
+}
+
+impl NewlinePolicy {
+    /// Default policy, stripping line breaks.
+    pub const DEFAULT: Self = Self::Strip;
+
+    /// Applies the policy to the pasted text, returning the content to be
+    /// inserted, if any.
+    pub fn apply(self, text: &str) -> Option<String> {
+        if !text.contains(['\n', '\r']) {
+            return Some(text.to_string());
+        }
+
+        let text = text.replace("\r\n", "\n");
+        let is_newline = |c: char| c == '\n' || c == '\r';
+
+        match self {
+            Self::Strip => Some(text.chars().filter(|c| !is_newline(*c)).collect()),
+            Self::Replace(replacement) => Some(
+                text.chars()
+                    .map(|c| if is_newline(c) { replacement } else { c })
+                    .collect(),
+            ),
+            Self::Reject => None,
+        }
+    }
+}
+
+/// Replaces the `\r\n` and `\r` line breaks of pasted text by `\n`, as
+/// inserted into multi-line inputs.
+pub(crate) fn normalize_newlines(text: &str) -> String {
+    text.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+impl Default for NewlinePolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl InputAction {
+    /// Derives an action on a multi-line input from a command, moving the
+    /// cursor across lines and inserting line breaks besides the commands of
+    /// single-line inputs.
+    pub(crate) fn from_multiline_command(command: Command) -> Option<Self> {
+        let action = match command {
+            Command::MoveUp => Self::MoveCursor(Magnitude::Char, LineDirection::Up),
+            Command::MoveDown => Self::MoveCursor(Magnitude::Char, LineDirection::Down),
+            Command::InsertNewline => Self::Write('\n'),
+            command => Self::from_command(command, &())?,
+        };
+
+        Some(action)
+    }
 }
 
 impl InnerAction for InputAction {
     type Config = ();
 
-    fn from_key(key: Key, _config: &()) -> Option<Self>
-    where
-        Self: Sized,
-    {
-        let action = match key {
-            Key::Backspace => Self::Delete(Magnitude::Char, LineDirection::Left),
-            Key::Char('h', m) if m.contains(KeyModifiers::CONTROL) => {
-                // Ctrl+Backspace is tricky, we don't want to handle a Ctrl+H
-                // but also don't want ctrl+h to simply write h.
-                // Let's catch this combination and ignore it.
-                return None;
-            }
-
-            Key::Delete(m) if m.contains(KeyModifiers::CONTROL) => {
-                Self::Delete(Magnitude::Word, LineDirection::Right)
-            }
-            Key::Delete(_) => Self::Delete(Magnitude::Char, LineDirection::Right),
-
-            Key::Home => Self::MoveCursor(Magnitude::Line, LineDirection::Left),
-            Key::Left(m) if m.contains(KeyModifiers::CONTROL) => {
-                Self::MoveCursor(Magnitude::Word, LineDirection::Left)
-            }
-            Key::Left(_) => Self::MoveCursor(Magnitude::Char, LineDirection::Left),
-
-            Key::End => Self::MoveCursor(Magnitude::Line, LineDirection::Right),
-            Key::Right(m) if m.contains(KeyModifiers::CONTROL) => {
-                Self::MoveCursor(Magnitude::Word, LineDirection::Right)
-            }
-            Key::Right(_) => Self::MoveCursor(Magnitude::Char, LineDirection::Right),
+    fn key_scopes(_config: &()) -> &'static [KeyScope] {
+        &[KeyScope::Input]
+    }
 
+    fn from_command(command: Command, _config: &()) -> Option<Self> {
+        let action = match command {
+            Command::MoveCharLeft => Self::MoveCursor(Magnitude::Char, LineDirection::Left),
+            Command::MoveCharRight => Self::MoveCursor(Magnitude::Char, LineDirection::Right),
+            Command::MoveWordLeft => Self::MoveCursor(Magnitude::Word, LineDirection::Left),
+            Command::MoveWordRight => Self::MoveCursor(Magnitude::Word, LineDirection::Right),
+            Command::MoveLineStart => Self::MoveCursor(Magnitude::Line, LineDirection::Left),
+            Command::MoveLineEnd => Self::MoveCursor(Magnitude::Line, LineDirection::Right),
+
+            Command::DeleteCharLeft => Self::Delete(Magnitude::Char, LineDirection::Left),
+            Command::DeleteCharRight => Self::Delete(Magnitude::Char, LineDirection::Right),
+            Command::DeleteWordLeft => Self::Delete(Magnitude::Word, LineDirection::Left),
+            Command::DeleteWordRight => Self::Delete(Magnitude::Word, LineDirection::Right),
+            Command::DeleteLineLeft => Self::Delete(Magnitude::Line, LineDirection::Left),
+            Command::DeleteLineRight => Self::Delete(Magnitude::Line, LineDirection::Right),
+
+            Command::Undo => Self::Undo,
+            Command::Redo => Self::Redo,
+            Command::Yank => Self::Yank,
+            Command::YankPop => Self::YankPop,
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+
+    fn from_unbound_key(key: Key, _config: &()) -> Option<Self> {
+        let action = match key {
             Key::Char(c, _) => Self::Write(c),
+            Key::Paste(text) => Self::Insert(NewlinePolicy::DEFAULT.apply(&text)?),
             _ => return None,
         };
 
@@ -62,11 +148,36 @@ impl InnerAction for InputAction {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::ui::KeyModifiers;
+
+    #[test]
+    fn paste_results_in_insert_with_newlines_stripped() {
+        assert_eq!(
+            InputAction::from_key(Key::Paste(String::from("tok\r\nen\n")), &()),
+            Some(InputAction::Insert(String::from("token")))
+        );
+    }
+
+    #[test]
+    fn newline_policies() {
+        let text = "a\r\nb\rc\nd";
+
+        assert_eq!(Some(String::from("abcd")), NewlinePolicy::Strip.apply(text));
+        assert_eq!(
+            Some(String::from("a b c d")),
+            NewlinePolicy::Replace(' ').apply(text)
+        );
+        assert_eq!(None, NewlinePolicy::Reject.apply(text));
+        assert_eq!(
+            Some(String::from("single line")),
+            NewlinePolicy::Reject.apply("single line")
+        );
+    }
 
     #[test]
     fn backspace_results_in_delete_char_left() {
         assert_eq!(
-            InputAction::from_key(Key::Backspace, &()),
+            InputAction::from_key(Key::Backspace(KeyModifiers::NONE), &()),
             Some(InputAction::Delete(Magnitude::Char, LineDirection::Left))
         );
     }
@@ -131,7 +242,7 @@ mod test {
     #[test]
     fn home_moves_to_beginning_of_line() {
         assert_eq!(
-            InputAction::from_key(Key::Home, &()),
+            InputAction::from_key(Key::Home(KeyModifiers::NONE), &()),
             Some(InputAction::MoveCursor(
                 Magnitude::Line,
                 LineDirection::Left
@@ -142,7 +253,7 @@ mod test {
     #[test]
     fn end_moves_to_end_of_line() {
         assert_eq!(
-            InputAction::from_key(Key::End, &()),
+            InputAction::from_key(Key::End(KeyModifiers::NONE), &()),
             Some(InputAction::MoveCursor(
                 Magnitude::Line,
                 LineDirection::Right
@@ -1,6 +1,11 @@
 pub mod action;
 #[cfg(test)]
 mod test;
+mod vim;
+
+pub(crate) use vim::Vim;
+
+use std::cmp::min;
 
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -17,28 +22,81 @@ pub enum Magnitude {
 pub enum LineDirection {
     Left,
     Right,
+    Up,
+    Down,
 }
 
 fn is_alphanumeric(grapheme: &str) -> bool {
     grapheme.unicode_words().count() > 0
 }
 
-#[derive(Clone, Debug, PartialEq)]
+fn is_line_break(grapheme: &str) -> bool {
+    grapheme == "\n" || grapheme == "\r\n"
+}
+
+/// Maximum amount of killed texts kept in the kill ring of an input.
+const KILL_RING_SIZE: usize = 32;
+
+/// Last edit made to an input, deciding whether the next one is grouped
+/// with it into a single undo step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Edit {
+    /// Typed character, grouped with the following ones until a whitespace
+    /// is typed after a word.
+    Write { whitespace: bool },
+    /// Deleted character, grouped with the following ones deleted in the
+    /// same direction.
+    DeleteChar(LineDirection),
+    /// Word or line deleted and saved to the kill ring, to which a following
+    /// kill is appended.
+    Kill,
+    /// Text of the kill ring inserted at `start`, which may be replaced by
+    /// older texts of the kill ring.
+    Yank {
+        start: usize,
+        length: usize,
+        index: usize,
+    },
+    /// Any other edit, never grouped.
+    Other,
+}
+
+impl Edit {
+    fn continues(self, previous: Option<Edit>) -> bool {
+        match (previous, self) {
+            (Some(Edit::Write { whitespace: prev }), Edit::Write { whitespace }) => {
+                prev || !whitespace
+            }
+            (Some(Edit::DeleteChar(prev)), Edit::DeleteChar(dir)) => prev == dir,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct Input {
     content: String,
     placeholder: Option<String>,
     cursor: usize,
     length: usize,
+    undo_stack: Vec<(String, usize)>,
+    redo_stack: Vec<(String, usize)>,
+    kill_ring: Vec<String>,
+    last_edit: Option<Edit>,
+}
+
+// the edit history is not part of the value of the input.
+impl PartialEq for Input {
+    fn eq(&self, other: &Self) -> bool {
+        self.content == other.content
+            && self.placeholder == other.placeholder
+            && self.cursor == other.cursor
+    }
 }
 
 impl Input {
     pub fn new() -> Self {
-        Self {
-            content: String::new(),
-            placeholder: None,
-            cursor: 0,
-            length: 0,
-        }
+        Self::new_with(String::new())
     }
 
     pub fn new_with<S>(content: S) -> Self
@@ -54,6 +112,10 @@ impl Input {
             placeholder: None,
             length: len,
             cursor: len,
+            undo_stack: vec![],
+            redo_stack: vec![],
+            kill_ring: vec![],
+            last_edit: None,
         }
     }
 
@@ -83,23 +145,202 @@ impl Input {
     }
 
     pub fn handle(&mut self, action: InputAction) -> InputActionResult {
-        match action {
-            InputAction::MoveCursor(mag, dir) => match dir {
-                LineDirection::Left => self.move_left(mag),
-                LineDirection::Right => self.move_right(mag),
-            },
-            InputAction::Delete(mag, dir) => match dir {
-                LineDirection::Left => self.backwards_delete(mag),
-                LineDirection::Right => self.forwards_delete(mag),
-            },
-            InputAction::Write(c) => self.insert(c),
+        let last_edit = self.last_edit.take();
+        let before = (self.content.clone(), self.cursor);
+
+        let (result, edit) = match action {
+            InputAction::MoveCursor(mag, dir) => {
+                let result = match dir {
+                    LineDirection::Left => self.move_left(mag),
+                    LineDirection::Right => self.move_right(mag),
+                    LineDirection::Up => self.move_up(mag),
+                    LineDirection::Down => self.move_down(mag),
+                };
+                (result, Edit::Other)
+            }
+            InputAction::Delete(Magnitude::Char, dir) => {
+                (self.delete(Magnitude::Char, dir), Edit::DeleteChar(dir))
+            }
+            InputAction::Delete(mag, dir) => (self.kill(mag, dir, last_edit), Edit::Kill),
+            InputAction::Write(c) => (
+                self.insert(c),
+                Edit::Write {
+                    whitespace: c.is_whitespace(),
+                },
+            ),
+            InputAction::Insert(text) => (self.insert_str(&text), Edit::Other),
+            InputAction::Yank => self.yank(),
+            InputAction::YankPop => self.yank_pop(last_edit),
+            InputAction::Undo => return self.undo(),
+            InputAction::Redo => return self.redo(),
+        };
+
+        if result == InputActionResult::ContentChanged {
+            if !edit.continues(last_edit) {
+                self.undo_stack.push(before);
+            }
+            self.redo_stack.clear();
+            self.last_edit = Some(edit);
+        }
+
+        result
+    }
+
+    fn delete(&mut self, mag: Magnitude, dir: LineDirection) -> InputActionResult {
+        match dir {
+            LineDirection::Left => self.backwards_delete(mag),
+            LineDirection::Right => self.forwards_delete(mag),
+            // line breaks are deleted as any other character
+            LineDirection::Up | LineDirection::Down => InputActionResult::Clean,
+        }
+    }
+
+    /// Deletes a word or a line, saving the deleted text to the kill ring.
+    /// Consecutive kills are joined into a single text, as in emacs.
+    fn kill(
+        &mut self,
+        mag: Magnitude,
+        dir: LineDirection,
+        last_edit: Option<Edit>,
+    ) -> InputActionResult {
+        let old_content = self.content.clone();
+
+        let result = self.delete(mag, dir);
+        if result != InputActionResult::ContentChanged {
+            return result;
+        }
+
+        // in both directions, the cursor ends up where the deleted text was.
+        let start = self.pre_cursor().len();
+        let end = start + old_content.len() - self.content.len();
+        let killed = &old_content[start..end];
+
+        match (last_edit, self.kill_ring.last_mut()) {
+            (Some(Edit::Kill), Some(last)) if dir == LineDirection::Left => {
+                last.insert_str(0, killed);
+            }
+            (Some(Edit::Kill), Some(last)) => last.push_str(killed),
+            _ => {
+                if self.kill_ring.len() == KILL_RING_SIZE {
+                    self.kill_ring.remove(0);
+                }
+                self.kill_ring.push(killed.to_owned());
+            }
+        }
+
+        result
+    }
+
+    /// Inserts the most recently killed text at the cursor.
+    fn yank(&mut self) -> (InputActionResult, Edit) {
+        let Some(text) = self.kill_ring.last().cloned() else {
+            return (InputActionResult::Clean, Edit::Other);
+        };
+
+        let start = self.cursor;
+        let result = self.insert_str(&text);
+        let edit = Edit::Yank {
+            start,
+            length: self.cursor - start,
+            index: self.kill_ring.len() - 1,
+        };
+
+        (result, edit)
+    }
+
+    /// Replaces the text inserted by the previous yank by the text killed
+    /// before it, going around the kill ring.
+    fn yank_pop(&mut self, last_edit: Option<Edit>) -> (InputActionResult, Edit) {
+        let Some(Edit::Yank {
+            start,
+            length,
+            index,
+        }) = last_edit
+        else {
+            return (InputActionResult::Clean, Edit::Other);
+        };
+
+        if self.kill_ring.len() < 2 {
+            // keeps the yank replaceable, should the kill ring grow.
+            self.last_edit = last_edit;
+            return (InputActionResult::Clean, Edit::Other);
+        }
+
+        let index = index.checked_sub(1).unwrap_or(self.kill_ring.len() - 1);
+        let text = self.kill_ring[index].clone();
+
+        self.cursor = start;
+        self.delete_chars_at_right(length);
+        self.insert_str(&text);
+
+        let edit = Edit::Yank {
+            start,
+            length: self.cursor - start,
+            index,
+        };
+
+        (InputActionResult::ContentChanged, edit)
+    }
+
+    fn undo(&mut self) -> InputActionResult {
+        match self.undo_stack.pop() {
+            Some(state) => {
+                let current = self.restore(state);
+                self.redo_stack.push(current);
+                InputActionResult::ContentChanged
+            }
+            None => InputActionResult::Clean,
+        }
+    }
+
+    fn redo(&mut self) -> InputActionResult {
+        match self.redo_stack.pop() {
+            Some(state) => {
+                let current = self.restore(state);
+                self.undo_stack.push(current);
+                InputActionResult::ContentChanged
+            }
+            None => InputActionResult::Clean,
+        }
+    }
+
+    /// Replaces the content and cursor of the input, returning the previous
+    /// ones.
+    fn restore(&mut self, (content, cursor): (String, usize)) -> (String, usize) {
+        let previous = (std::mem::replace(&mut self.content, content), self.cursor);
+        self.update_length();
+        self.cursor = min(cursor, self.length);
+
+        previous
+    }
+
+    /// Deletes the graphemes from `start` to `end`, as a single undo step,
+    /// and moves the cursor to `start`. Returns the deleted text.
+    fn delete_range(&mut self, start: usize, end: usize) -> String {
+        let before = (self.content.clone(), self.cursor);
+
+        self.cursor = start;
+        let at = self.pre_cursor().len();
+        if self.delete_chars_at_right(end - start) == InputActionResult::Clean {
+            return String::new();
         }
+
+        self.undo_stack.push(before);
+        self.redo_stack.clear();
+        self.last_edit = Some(Edit::Other);
+
+        let (old_content, _) = &self.undo_stack[self.undo_stack.len() - 1];
+        let removed = old_content.len() - self.content.len();
+        old_content[at..at + removed].to_owned()
     }
 
     pub fn clear(&mut self) {
         self.content.clear();
         self.cursor = 0;
         self.length = 0;
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.last_edit = None;
     }
 
     pub fn content(&self) -> &str {
@@ -136,7 +377,7 @@ impl Input {
         match mag {
             Magnitude::Char => self.cursor = self.cursor.saturating_sub(1),
             Magnitude::Word => self.cursor = self.prev_word_index(),
-            Magnitude::Line => self.cursor = 0,
+            Magnitude::Line => self.cursor = self.line_start(self.cursor),
         }
 
         InputActionResult::PositionChanged
@@ -149,7 +390,7 @@ impl Input {
                 match mag {
                     Magnitude::Char => self.cursor = self.cursor.saturating_add(1),
                     Magnitude::Word => self.cursor = self.next_word_index(),
-                    Magnitude::Line => self.cursor = self.length,
+                    Magnitude::Line => self.cursor = self.line_end(self.cursor),
                 }
 
                 InputActionResult::PositionChanged
@@ -164,6 +405,74 @@ impl Input {
         }
     }
 
+    /// Moves the cursor to the line above, keeping its column when the line is
+    /// long enough. With [`Magnitude::Line`], moves it to the start of the
+    /// input instead.
+    fn move_up(&mut self, mag: Magnitude) -> InputActionResult {
+        let line_start = self.line_start(self.cursor);
+        if line_start == 0 {
+            return InputActionResult::Clean;
+        }
+
+        self.cursor = match mag {
+            Magnitude::Char | Magnitude::Word => {
+                let column = self.cursor - line_start;
+                let prev_line_start = self.line_start(line_start - 1);
+
+                min(prev_line_start + column, line_start - 1)
+            }
+            Magnitude::Line => 0,
+        };
+
+        InputActionResult::PositionChanged
+    }
+
+    /// Moves the cursor to the line below, keeping its column when the line is
+    /// long enough. With [`Magnitude::Line`], moves it to the end of the input
+    /// instead.
+    fn move_down(&mut self, mag: Magnitude) -> InputActionResult {
+        let line_end = self.line_end(self.cursor);
+        if line_end >= self.length {
+            return InputActionResult::Clean;
+        }
+
+        self.cursor = match mag {
+            Magnitude::Char | Magnitude::Word => {
+                let column = self.cursor - self.line_start(self.cursor);
+                let next_line_start = line_end + 1;
+
+                min(next_line_start + column, self.line_end(next_line_start))
+            }
+            Magnitude::Line => self.length,
+        };
+
+        InputActionResult::PositionChanged
+    }
+
+    /// Index of the first grapheme of the line containing the given index.
+    fn line_start(&self, index: usize) -> usize {
+        self.content
+            .graphemes(true)
+            .take(index)
+            .enumerate()
+            .filter(|(_, g)| is_line_break(g))
+            .last()
+            .map(|(idx, _)| idx + 1)
+            .unwrap_or(0)
+    }
+
+    /// Index of the line break ending the line containing the given index, or
+    /// the length of the input on the last line.
+    fn line_end(&self, index: usize) -> usize {
+        self.content
+            .graphemes(true)
+            .enumerate()
+            .skip(index)
+            .find(|(_, g)| is_line_break(g))
+            .map(|(idx, _)| idx)
+            .unwrap_or(self.length)
+    }
+
     fn next_word_index(&mut self) -> usize {
         let graphemes = self.content.graphemes(true).enumerate().skip(self.cursor);
         let mut seen_word = false;
@@ -230,6 +539,22 @@ impl Input {
         InputActionResult::ContentChanged
     }
 
+    fn insert_str(&mut self, text: &str) -> InputActionResult {
+        if text.is_empty() {
+            return InputActionResult::Clean;
+        }
+
+        let at = self.pre_cursor().len();
+        self.content.insert_str(at, text);
+
+        let old_length = self.length;
+        self.update_length();
+        let inserted = self.length.saturating_sub(old_length);
+        self.cursor = self.cursor.saturating_add(inserted);
+
+        InputActionResult::ContentChanged
+    }
+
     fn backwards_delete(&mut self, mag: Magnitude) -> InputActionResult {
         if self.cursor == 0 {
             return InputActionResult::Clean;
@@ -239,7 +564,7 @@ impl Input {
         let new_cursor_pos = match mag {
             Magnitude::Char => self.cursor.saturating_sub(1),
             Magnitude::Word => self.prev_word_index(),
-            Magnitude::Line => 0,
+            Magnitude::Line => self.line_start(self.cursor),
         };
 
         if new_cursor_pos == cur_cursor_pos {
@@ -255,7 +580,7 @@ impl Input {
         let end = match mag {
             Magnitude::Char => start.saturating_add(1),
             Magnitude::Word => self.next_word_index(),
-            Magnitude::Line => self.length(),
+            Magnitude::Line => self.line_end(start),
         };
 
         let len = end - start;
@@ -1,6 +1,6 @@
 use unicode_segmentation::UnicodeSegmentation;
 
-use super::Input;
+use super::{Input, Vim};
 use crate::{
     input::{InputActionResult, LineDirection, Magnitude},
     InputAction,
@@ -455,3 +455,373 @@ fn generic_user_scenario() {
     assert_eq!("great idea! you", input.pre_cursor());
     assert_eq!(15, input.cursor());
 }
+
+#[test]
+fn insert_string_in_the_middle_of_content() {
+    let mut input = Input::new_with("great idea!").with_cursor(6);
+
+    let result = input.handle(InputAction::Insert(String::from("🌍 ")));
+    assert_eq!(InputActionResult::ContentChanged, result);
+    assert_eq!("great 🌍 idea!", input.content());
+    assert_eq!("great 🌍 ", input.pre_cursor());
+    assert_eq!(8, input.cursor());
+    assert_eq!(13, input.length());
+
+    let result = input.handle(InputAction::Insert(String::new()));
+    assert_eq!(InputActionResult::Clean, result);
+    assert_eq!(8, input.cursor());
+}
+
+#[test]
+fn insert_string_merging_with_previous_grapheme() {
+    let heart = '♥';
+    let vs16 = '\u{fe0f}';
+
+    let mut input = Input::new_with(heart.to_string());
+
+    input.handle(InputAction::Insert(format!("{vs16}ab")));
+    assert_eq!(format!("{heart}{vs16}ab"), input.content());
+    assert_eq!(3, input.length());
+    assert_eq!(3, input.cursor());
+}
+
+#[test]
+fn vertical_moves_keep_the_column_across_lines() {
+    let mut input = Input::new_with("first line\nab\nthird line").with_cursor(8);
+
+    let result = input.handle(InputAction::MoveCursor(Magnitude::Char, LineDirection::Up));
+    assert_eq!(InputActionResult::Clean, result);
+    assert_eq!(8, input.cursor());
+
+    // the second line is shorter, the cursor is placed at its end
+    let result = input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Down,
+    ));
+    assert_eq!(InputActionResult::PositionChanged, result);
+    assert_eq!("first line\nab", input.pre_cursor());
+
+    let result = input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Down,
+    ));
+    assert_eq!(InputActionResult::PositionChanged, result);
+    assert_eq!("first line\nab\nth", input.pre_cursor());
+
+    let result = input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Down,
+    ));
+    assert_eq!(InputActionResult::Clean, result);
+
+    input.handle(InputAction::MoveCursor(Magnitude::Char, LineDirection::Up));
+    input.handle(InputAction::MoveCursor(Magnitude::Char, LineDirection::Up));
+    assert_eq!("fi", input.pre_cursor());
+
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Line,
+        LineDirection::Down,
+    ));
+    assert_eq!(input.length(), input.cursor());
+}
+
+#[test]
+fn line_magnitude_applies_to_the_current_line() {
+    let mut input = Input::new_with("first\nsecond line\nthird").with_cursor(12);
+
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Line,
+        LineDirection::Right,
+    ));
+    assert_eq!("first\nsecond line", input.pre_cursor());
+
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Line,
+        LineDirection::Left,
+    ));
+    assert_eq!("first\n", input.pre_cursor());
+
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Word,
+        LineDirection::Right,
+    ));
+    let result = input.handle(InputAction::Delete(Magnitude::Line, LineDirection::Right));
+    assert_eq!(InputActionResult::ContentChanged, result);
+    assert_eq!("first\nsecond\nthird", input.content());
+
+    let result = input.handle(InputAction::Delete(Magnitude::Line, LineDirection::Left));
+    assert_eq!(InputActionResult::ContentChanged, result);
+    assert_eq!("first\n\nthird", input.content());
+    assert_eq!("first\n", input.pre_cursor());
+
+    let result = input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Left));
+    assert_eq!(InputActionResult::ContentChanged, result);
+    assert_eq!("first\nthird", input.content());
+}
+
+fn type_text(input: &mut Input, text: &str) {
+    for c in text.chars() {
+        input.handle(InputAction::Write(c));
+    }
+}
+
+#[test]
+fn undo_reverts_typed_text_a_word_at_a_time() {
+    let mut input = Input::new();
+    type_text(&mut input, "hello big world");
+
+    assert_eq!(
+        InputActionResult::ContentChanged,
+        input.handle(InputAction::Undo)
+    );
+    assert_eq!("hello big", input.content());
+    input.handle(InputAction::Undo);
+    assert_eq!("hello", input.content());
+    input.handle(InputAction::Undo);
+    assert_eq!("", input.content());
+    assert_eq!(InputActionResult::Clean, input.handle(InputAction::Undo));
+
+    input.handle(InputAction::Redo);
+    input.handle(InputAction::Redo);
+    assert_eq!("hello big", input.content());
+    assert_eq!(9, input.cursor());
+}
+
+#[test]
+fn cursor_moves_split_undo_steps() {
+    let mut input = Input::new();
+    type_text(&mut input, "ac");
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Left,
+    ));
+    type_text(&mut input, "b");
+
+    input.handle(InputAction::Undo);
+    assert_eq!("ac", input.content());
+    assert_eq!(1, input.cursor());
+}
+
+#[test]
+fn consecutive_char_deletes_are_undone_at_once() {
+    let mut input = Input::new_with("hello world");
+
+    for _ in 0..5 {
+        input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Left));
+    }
+    input.handle(InputAction::Delete(Magnitude::Word, LineDirection::Left));
+    assert_eq!("", input.content());
+
+    input.handle(InputAction::Undo);
+    assert_eq!("hello ", input.content());
+    input.handle(InputAction::Undo);
+    assert_eq!("hello world", input.content());
+}
+
+#[test]
+fn new_edits_discard_the_redo_history() {
+    let mut input = Input::new();
+    type_text(&mut input, "one");
+    input.handle(InputAction::Undo);
+    type_text(&mut input, "two");
+
+    assert_eq!(InputActionResult::Clean, input.handle(InputAction::Redo));
+    assert_eq!("two", input.content());
+}
+
+#[test]
+fn killed_text_is_yanked() {
+    let mut input = Input::new_with("hello world").with_cursor(5);
+
+    input.handle(InputAction::Delete(Magnitude::Line, LineDirection::Right));
+    assert_eq!("hello", input.content());
+
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Line,
+        LineDirection::Left,
+    ));
+    input.handle(InputAction::Yank);
+    assert_eq!(" worldhello", input.content());
+    assert_eq!(6, input.cursor());
+
+    input.handle(InputAction::Undo);
+    assert_eq!("hello", input.content());
+}
+
+#[test]
+fn consecutive_kills_are_joined() {
+    let mut input = Input::new_with("one two three");
+
+    input.handle(InputAction::Delete(Magnitude::Word, LineDirection::Left));
+    input.handle(InputAction::Delete(Magnitude::Word, LineDirection::Left));
+    assert_eq!("one ", input.content());
+
+    input.handle(InputAction::Yank);
+    assert_eq!("one two three", input.content());
+}
+
+#[test]
+fn yank_pop_rotates_the_kill_ring() {
+    let mut input = Input::new_with("first second");
+
+    input.handle(InputAction::Delete(Magnitude::Word, LineDirection::Left));
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Left,
+    ));
+    input.handle(InputAction::Delete(Magnitude::Line, LineDirection::Left));
+    assert_eq!(" ", input.content());
+
+    input.handle(InputAction::Yank);
+    assert_eq!("first ", input.content());
+    input.handle(InputAction::YankPop);
+    assert_eq!("second ", input.content());
+    input.handle(InputAction::YankPop);
+    assert_eq!("first ", input.content());
+
+    // only right after a yank
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Right,
+    ));
+    assert_eq!(InputActionResult::Clean, input.handle(InputAction::YankPop));
+}
+
+#[test]
+fn yank_without_kills_does_nothing() {
+    let mut input = Input::new_with("text");
+
+    assert_eq!(InputActionResult::Clean, input.handle(InputAction::Yank));
+    assert_eq!(InputActionResult::Clean, input.handle(InputAction::Undo));
+}
+
+/// Input in vim normal mode, after typing `keys` in it.
+fn vim_normal(content: &str, cursor: usize, keys: &str) -> (Vim, Input) {
+    let mut vim = Vim::new();
+    let mut input = Input::new_with(content).with_cursor(cursor);
+
+    vim.escape(&mut input);
+    input.cursor = cursor;
+    for c in keys.chars() {
+        vim.handle(InputAction::Write(c), &mut input);
+    }
+
+    (vim, input)
+}
+
+#[test]
+fn vim_escape_switches_to_normal_mode() {
+    let mut vim = Vim::new();
+    let mut input = Input::new_with("abc");
+
+    assert!(vim.escape(&mut input));
+    assert!(vim.is_normal());
+    assert_eq!(2, input.cursor());
+
+    // nothing left to escape, the prompt is canceled
+    assert!(!vim.escape(&mut input));
+}
+
+#[test]
+fn vim_motions() {
+    let content = "one two.three four";
+
+    let assert = |expected, initial, keys| {
+        let (_, input) = vim_normal(content, initial, keys);
+        assert_eq!(expected, input.cursor(), "{keys} from {initial}");
+    };
+
+    assert(1, 0, "l");
+    assert(0, 0, "h");
+    assert(4, 0, "w");
+    assert(7, 4, "w");
+    assert(8, 7, "w");
+    assert(4, 7, "b");
+    assert(2, 0, "e");
+    assert(6, 2, "e");
+    assert(17, 5, "$");
+    assert(0, 5, "0");
+    // the cursor stays on the last character
+    assert(17, 17, "l");
+}
+
+#[test]
+fn vim_delete_commands() {
+    let assert = |expected, cursor, keys| {
+        let (vim, input) = vim_normal("one two three", cursor, keys);
+        assert_eq!(expected, input.content(), "{keys} from {cursor}");
+        assert!(vim.is_normal());
+    };
+
+    assert("ne two three", 0, "x");
+    assert("two three", 0, "dw");
+    assert("one three", 4, "dw");
+    assert("one two ", 8, "dw");
+    assert("one  three", 4, "de");
+    assert("one three", 8, "db");
+    assert("one ", 4, "d$");
+    assert("", 4, "dd");
+    assert("one ", 4, "D");
+    assert("one two three", 4, "d<");
+}
+
+#[test]
+fn vim_delete_line_in_multiline_input() {
+    let (_, input) = vim_normal("one\ntwo\nthree", 5, "dd");
+    assert_eq!("one\nthree", input.content());
+    assert_eq!(4, input.cursor());
+
+    let (_, input) = vim_normal("one\ntwo", 5, "dd");
+    assert_eq!("one", input.content());
+    assert_eq!(0, input.cursor());
+}
+
+#[test]
+fn vim_change_commands_switch_to_insert_mode() {
+    let (vim, input) = vim_normal("one two three", 4, "cw");
+    assert_eq!("one  three", input.content());
+    assert_eq!(4, input.cursor());
+    assert!(!vim.is_normal());
+
+    let (mut vim, mut input) = vim_normal("one two three", 4, "cc");
+    assert_eq!("", input.content());
+    vim.handle(InputAction::Write('x'), &mut input);
+    assert_eq!("x", input.content());
+}
+
+#[test]
+fn vim_insert_commands() {
+    let assert = |expected, keys| {
+        let (mut vim, mut input) = vim_normal("abc", 1, keys);
+        assert!(!vim.is_normal());
+        vim.handle(InputAction::Write('_'), &mut input);
+        assert_eq!(expected, input.content(), "{keys}");
+    };
+
+    assert("a_bc", "i");
+    assert("ab_c", "a");
+    assert("_abc", "I");
+    assert("abc_", "A");
+}
+
+#[test]
+fn vim_paste_and_undo() {
+    let (mut vim, mut input) = vim_normal("one two", 0, "dw");
+    assert_eq!("two", input.content());
+
+    vim.handle(InputAction::Write('$'), &mut input);
+    vim.handle(InputAction::Write('p'), &mut input);
+    assert_eq!("twoone ", input.content());
+    assert_eq!(6, input.cursor());
+
+    vim.handle(InputAction::Write('0'), &mut input);
+    vim.handle(InputAction::Write('P'), &mut input);
+    assert_eq!("one twoone ", input.content());
+
+    vim.handle(InputAction::Write('u'), &mut input);
+    assert_eq!("twoone ", input.content());
+    vim.handle(InputAction::Write('u'), &mut input);
+    assert_eq!("two", input.content());
+    vim.handle(InputAction::Write('u'), &mut input);
+    assert_eq!("one two", input.content());
+}
@@ -0,0 +1,343 @@
+//! Vim-style modal editing of text inputs.
+
+use std::cmp::{max, min};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::InputAction;
+
+use super::{is_alphanumeric, Input, InputActionResult, LineDirection, Magnitude};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum VimMode {
+    /// Keys are typed into the input, as when vim mode is disabled.
+    Insert,
+    /// Keys are commands moving the cursor and editing the input.
+    Normal,
+}
+
+/// Kind of the grapheme under a motion, words being made of graphemes of the
+/// same kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CharClass {
+    Whitespace,
+    Word,
+    Punctuation,
+}
+
+fn char_class(grapheme: &str) -> CharClass {
+    if grapheme.chars().all(char::is_whitespace) {
+        CharClass::Whitespace
+    } else if grapheme == "_" || is_alphanumeric(grapheme) {
+        CharClass::Word
+    } else {
+        CharClass::Punctuation
+    }
+}
+
+/// Modal editing state of a text input, starting in insert mode.
+///
+/// In normal mode, typed characters are interpreted as vim commands:
+///
+/// - `h`, `l`, `j`, `k`, `w`, `b`, `e`, `0` and `$` move the cursor.
+/// - `x` deletes the character under the cursor.
+/// - `d` and `c` followed by a motion delete the text it moves over, `c`
+///   switching to insert mode afterwards. `dd` and `cc` apply to the whole
+///   line, `D` and `C` to the end of the line.
+/// - `p` and `P` paste the last deleted text after or before the cursor.
+/// - `u` undoes the last edit.
+/// - `i`, `a`, `I` and `A` switch to insert mode.
+///
+/// Other key presses, such as the arrow keys, keep editing the input as in
+/// insert mode.
+#[derive(Clone, Debug)]
+pub(crate) struct Vim {
+    mode: VimMode,
+    pending_operator: Option<char>,
+    register: String,
+}
+
+impl Vim {
+    pub fn new() -> Self {
+        Self {
+            mode: VimMode::Insert,
+            pending_operator: None,
+            register: String::new(),
+        }
+    }
+
+    pub fn is_normal(&self) -> bool {
+        self.mode == VimMode::Normal
+    }
+
+    pub fn handle(&mut self, action: InputAction, input: &mut Input) -> InputActionResult {
+        match action {
+            InputAction::Write(c) if self.is_normal() => {
+                let result = self.command(c, input);
+                if self.is_normal() {
+                    clamp_cursor(input);
+                }
+                result
+            }
+            action => input.handle(action),
+        }
+    }
+
+    /// Handles the escape key, switching from insert to normal mode or
+    /// discarding a pending operator. Returns whether the key was consumed,
+    /// that is, it should not cancel the prompt.
+    pub fn escape(&mut self, input: &mut Input) -> bool {
+        match self.mode {
+            VimMode::Insert => {
+                self.mode = VimMode::Normal;
+                if input.cursor > input.line_start(input.cursor) {
+                    input.cursor -= 1;
+                }
+                input.last_edit = None;
+                true
+            }
+            VimMode::Normal => self.pending_operator.take().is_some(),
+        }
+    }
+
+    fn command(&mut self, c: char, input: &mut Input) -> InputActionResult {
+        if let Some(operator) = self.pending_operator.take() {
+            return self.operate(operator, c, input);
+        }
+
+        let line_start = input.line_start(input.cursor);
+        let line_end = input.line_end(input.cursor);
+
+        match c {
+            'd' | 'c' => {
+                self.pending_operator = Some(c);
+                InputActionResult::Clean
+            }
+            'D' => self.delete(input.cursor, line_end, input),
+            'C' => {
+                let result = self.delete(input.cursor, line_end, input);
+                self.insert_at(input.cursor, input);
+                or_redraw(result)
+            }
+            'x' => self.delete(input.cursor, min(input.cursor + 1, line_end), input),
+            'p' => self.paste(min(input.cursor + 1, line_end), input),
+            'P' => self.paste(input.cursor, input),
+            'u' => input.handle(InputAction::Undo),
+            'i' => self.insert_at(input.cursor, input),
+            'a' => self.insert_at(min(input.cursor + 1, line_end), input),
+            'I' => self.insert_at(line_start, input),
+            'A' => self.insert_at(line_end, input),
+            'j' => input.handle(InputAction::MoveCursor(
+                Magnitude::Char,
+                LineDirection::Down,
+            )),
+            'k' => input.handle(InputAction::MoveCursor(Magnitude::Char, LineDirection::Up)),
+            c => match motion(c, input) {
+                Some(target) => move_cursor(target, input),
+                None => InputActionResult::Clean,
+            },
+        }
+    }
+
+    /// Applies the operator `d` or `c` to the text covered by the motion `c`.
+    fn operate(&mut self, operator: char, c: char, input: &mut Input) -> InputActionResult {
+        let cursor = input.cursor;
+        let line_start = input.line_start(cursor);
+        let line_end = input.line_end(cursor);
+
+        let (start, end) = if c == operator {
+            // whole line, along with one of its line breaks when deleted.
+            match operator {
+                'c' => (line_start, line_end),
+                _ if line_end < input.length => (line_start, line_end + 1),
+                _ => (line_start.saturating_sub(1), line_end),
+            }
+        } else {
+            match c {
+                // as in vim, `cw` changes up to the end of the word.
+                'w' if operator == 'c' && !on_whitespace(input) => {
+                    (cursor, end_of_current_word(input) + 1)
+                }
+                'w' => (cursor, min(next_word(input), line_end)),
+                'e' => (cursor, end_of_word(input) + 1),
+                c => match motion(c, input) {
+                    Some(target) => (min(target, cursor), max(target, cursor)),
+                    None => return InputActionResult::Clean,
+                },
+            }
+        };
+
+        let result = self.delete(start, min(end, input.length), input);
+
+        if operator == 'c' {
+            self.insert_at(input.cursor, input);
+            return or_redraw(result);
+        }
+
+        if c == operator {
+            input.cursor = input.line_start(input.cursor);
+        }
+
+        result
+    }
+
+    fn delete(&mut self, start: usize, end: usize, input: &mut Input) -> InputActionResult {
+        if start >= end {
+            return InputActionResult::Clean;
+        }
+
+        self.register = input.delete_range(start, end);
+        InputActionResult::ContentChanged
+    }
+
+    /// Pastes the register at the given index, leaving the cursor on its last
+    /// character.
+    fn paste(&mut self, at: usize, input: &mut Input) -> InputActionResult {
+        if self.register.is_empty() {
+            return InputActionResult::Clean;
+        }
+
+        input.cursor = at;
+        let result = input.handle(InputAction::Insert(self.register.clone()));
+        input.cursor = input.cursor.saturating_sub(1);
+
+        result
+    }
+
+    fn insert_at(&mut self, at: usize, input: &mut Input) -> InputActionResult {
+        self.mode = VimMode::Insert;
+        input.cursor = at;
+        input.last_edit = None;
+
+        // the mode change alone needs a redraw, since it is shown by the prompt.
+        InputActionResult::PositionChanged
+    }
+}
+
+/// Index the cursor is moved to by the motion `c`, if it is one.
+fn motion(c: char, input: &Input) -> Option<usize> {
+    let cursor = input.cursor;
+    let line_start = input.line_start(cursor);
+
+    let target = match c {
+        'h' => max(cursor.saturating_sub(1), line_start),
+        'l' => min(cursor + 1, input.line_end(cursor)),
+        'w' => next_word(input),
+        'b' => previous_word(input),
+        'e' => end_of_word(input),
+        '0' => line_start,
+        '$' => input.line_end(cursor),
+        _ => return None,
+    };
+
+    Some(target)
+}
+
+fn move_cursor(target: usize, input: &mut Input) -> InputActionResult {
+    input.last_edit = None;
+
+    if target == input.cursor {
+        return InputActionResult::Clean;
+    }
+
+    input.cursor = target;
+    InputActionResult::PositionChanged
+}
+
+/// Result of an edit that also changed the mode, which is shown by the prompt.
+fn or_redraw(result: InputActionResult) -> InputActionResult {
+    match result {
+        InputActionResult::Clean => InputActionResult::PositionChanged,
+        result => result,
+    }
+}
+
+fn classes(input: &Input) -> Vec<CharClass> {
+    input.content.graphemes(true).map(char_class).collect()
+}
+
+fn on_whitespace(input: &Input) -> bool {
+    classes(input).get(input.cursor) == Some(&CharClass::Whitespace)
+}
+
+/// Last character of the word under the cursor.
+fn end_of_current_word(input: &Input) -> usize {
+    let classes = classes(input);
+    let mut index = input.cursor;
+
+    while index + 1 < classes.len() && classes[index + 1] == classes[input.cursor] {
+        index += 1;
+    }
+
+    index
+}
+
+/// Start of the next word, or the end of the input.
+fn next_word(input: &Input) -> usize {
+    let classes = classes(input);
+    let mut index = input.cursor;
+
+    if let Some(&class) = classes.get(index) {
+        while classes.get(index) == Some(&class) && class != CharClass::Whitespace {
+            index += 1;
+        }
+    }
+    while classes.get(index) == Some(&CharClass::Whitespace) {
+        index += 1;
+    }
+
+    index
+}
+
+/// Start of the word before the cursor, or of the word under it when the
+/// cursor is not on its first character.
+fn previous_word(input: &Input) -> usize {
+    let classes = classes(input);
+    let mut index = input.cursor;
+
+    while index > 0 && classes[index - 1] == CharClass::Whitespace {
+        index -= 1;
+    }
+    if index > 0 {
+        let class = classes[index - 1];
+        while index > 0 && classes[index - 1] == class {
+            index -= 1;
+        }
+    }
+
+    index
+}
+
+/// Last character of the word after the cursor, or of the word under it when
+/// the cursor is not on its last character.
+fn end_of_word(input: &Input) -> usize {
+    let classes = classes(input);
+    if classes.is_empty() {
+        return 0;
+    }
+
+    let mut index = input.cursor + 1;
+    while classes.get(index) == Some(&CharClass::Whitespace) {
+        index += 1;
+    }
+    if index >= classes.len() {
+        return classes.len() - 1;
+    }
+
+    while classes.get(index + 1) == Some(&classes[index]) {
+        index += 1;
+    }
+
+    index
+}
+
+/// Keeps the cursor on a character of the line, as it can not be after the
+/// last one in normal mode.
+fn clamp_cursor(input: &mut Input) {
+    let line_start = input.line_start(input.cursor);
+    let line_end = input.line_end(input.cursor);
+
+    if input.cursor >= line_end && line_end > line_start {
+        input.cursor = line_end - 1;
+    }
+}
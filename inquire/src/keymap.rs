@@ -0,0 +1,1280 @@
+//! Configurable bindings between key presses and prompt commands.
+//!
+//! Every prompt derives its actions from a [`KeyMap`], a table of key
+//! patterns bound to [`Command`]s within a [`KeyScope`]. The default key map
+//! mirrors the bindings listed in `KEY_BINDINGS.md`, which is generated from
+//! it.
+//!
+//! Key maps can be changed globally with
+//! [`set_global_key_map`](crate::set_global_key_map) or per prompt with their
+//! `with_key_map` method, either programmatically or by loading a
+//! configuration string:
+//!
+//! ```
+//! use inquire::{keymap::KeyMap, Text};
+//!
+//! // Ctrl+D no longer cancels the prompt, only Esc and Ctrl+G do.
+//! let key_map: KeyMap = "
+//!     [all]
+//!     cancel = esc, ctrl+g
+//! "
+//! .parse()
+//! .unwrap();
+//!
+//! let prompt = Text::new("What's your name?").with_key_map(key_map);
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use once_cell::sync::Lazy;
+
+use crate::{
+    error::{InquireError, InquireResult},
+    ui::{Key, KeyModifiers},
+};
+
+pub(crate) static DEFAULT_KEY_MAP: Lazy<KeyMap> = Lazy::new(|| {
+    let bindings = DEFAULT_BINDINGS
+        .iter()
+        .flat_map(|(scope, command, keys)| {
+            keys.iter().map(move |key| Binding {
+                scope: *scope,
+                pattern: key.parse().expect("default key bindings must be valid"),
+                command: *command,
+            })
+        })
+        .collect();
+
+    KeyMap { bindings }
+});
+
+/// Default bindings, in order of precedence.
+const DEFAULT_BINDINGS: &[(KeyScope, Command, &[&str])] = &[
+    (KeyScope::All, Command::ForceSubmit, &["ctrl+enter"]),
+    (KeyScope::Multiline, Command::Submit, &["ctrl+d"]),
+    (
+        KeyScope::Multiline,
+        Command::InsertNewline,
+        &["*+enter", "newline"],
+    ),
+    (KeyScope::Multiline, Command::MoveUp, &["up", "ctrl+p"]),
+    (KeyScope::Multiline, Command::MoveDown, &["down", "ctrl+n"]),
+    (
+        KeyScope::All,
+        Command::Submit,
+        &["*+enter", "newline", "ctrl+j"],
+    ),
+    (
+        KeyScope::All,
+        Command::Cancel,
+        &["*+esc", "ctrl+g", "ctrl+d"],
+    ),
+    (KeyScope::All, Command::Interrupt, &["ctrl+c"]),
+    (KeyScope::All, Command::Help, &["*+f1"]),
+    (
+        KeyScope::History,
+        Command::PreviousHistory,
+        &["up", "ctrl+p"],
+    ),
+    (KeyScope::History, Command::NextHistory, &["down", "ctrl+n"]),
+    (
+        KeyScope::History,
+        Command::ReverseSearchHistory,
+        &["ctrl+r"],
+    ),
+    (KeyScope::Vim, Command::ClearSelections, &["h"]),
+    (KeyScope::Vim, Command::MoveUp, &["k"]),
+    (KeyScope::Vim, Command::MoveDown, &["j"]),
+    (KeyScope::Vim, Command::SelectAll, &["l"]),
+    (
+        KeyScope::Text,
+        Command::MoveUp,
+        &["up", "ctrl+p", "*+backtab"],
+    ),
+    (KeyScope::Text, Command::PageUp, &["*+pageup"]),
+    (KeyScope::Text, Command::MoveDown, &["down", "ctrl+n"]),
+    (KeyScope::Text, Command::PageDown, &["*+pagedown"]),
+    (KeyScope::Text, Command::UseSuggestion, &["*+tab"]),
+    (
+        KeyScope::Text,
+        Command::AcceptCompletionWord,
+        &["alt+right"],
+    ),
+    (
+        KeyScope::Password,
+        Command::ToggleDisplayMode,
+        &["ctrl+*+r", "ctrl+*+R"],
+    ),
+    (
+        KeyScope::Select,
+        Command::MoveUp,
+        &["up", "ctrl+p", "*+backtab"],
+    ),
+    (KeyScope::Select, Command::PageUp, &["*+pageup"]),
+    (KeyScope::Select, Command::MoveToStart, &["*+home"]),
+    (KeyScope::Select, Command::MoveDown, &["down", "ctrl+n"]),
+    (KeyScope::Select, Command::PageDown, &["*+pagedown"]),
+    (KeyScope::Select, Command::MoveToEnd, &["*+end"]),
+    (KeyScope::PathSelect, Command::ParentDirectory, &["alt+up"]),
+    (
+        KeyScope::MultiSelect,
+        Command::MoveUp,
+        &["up", "ctrl+p", "*+backtab"],
+    ),
+    (KeyScope::MultiSelect, Command::PageUp, &["*+pageup"]),
+    (KeyScope::MultiSelect, Command::MoveToStart, &["*+home"]),
+    (
+        KeyScope::MultiSelect,
+        Command::MoveDown,
+        &["down", "ctrl+n"],
+    ),
+    (KeyScope::MultiSelect, Command::PageDown, &["*+pagedown"]),
+    (KeyScope::MultiSelect, Command::MoveToEnd, &["*+end"]),
+    (
+        KeyScope::MultiSelect,
+        Command::ToggleCurrentOption,
+        &["space"],
+    ),
+    (KeyScope::MultiSelect, Command::SelectAll, &["right"]),
+    (KeyScope::MultiSelect, Command::ClearSelections, &["left"]),
+    (
+        KeyScope::DateSelect,
+        Command::PrevDay,
+        &["left", "ctrl+b", "h"],
+    ),
+    (
+        KeyScope::DateSelect,
+        Command::NextDay,
+        &["right", "ctrl+f", "l"],
+    ),
+    (
+        KeyScope::DateSelect,
+        Command::PrevWeek,
+        &["up", "ctrl+p", "k", "*+backtab"],
+    ),
+    (
+        KeyScope::DateSelect,
+        Command::NextWeek,
+        &["down", "ctrl+n", "j", "*+tab"],
+    ),
+    (
+        KeyScope::DateSelect,
+        Command::PrevMonth,
+        &[
+            "pageup", "[", "*+left", "alt+v", "meta+v", "alt+V", "meta+V", "*+b", "*+B",
+        ],
+    ),
+    (
+        KeyScope::DateSelect,
+        Command::NextMonth,
+        &["pagedown", "]", "*+right", "ctrl+v", "ctrl+V", "*+f", "*+F"],
+    ),
+    (
+        KeyScope::DateSelect,
+        Command::PrevYear,
+        &["*+pageup", "*+{", "*+[", "*+up"],
+    ),
+    (
+        KeyScope::DateSelect,
+        Command::NextYear,
+        &["*+pagedown", "*+}", "*+]", "*+down"],
+    ),
+    (KeyScope::Editor, Command::OpenEditor, &["*+e"]),
+    (KeyScope::Form, Command::Back, &["*+backtab"]),
+    (
+        KeyScope::Input,
+        Command::DeleteWordLeft,
+        &["ctrl+*+backspace"],
+    ),
+    (KeyScope::Input, Command::DeleteCharLeft, &["*+backspace"]),
+    // Ctrl+Backspace is reported as Ctrl+H by some terminals, which should
+    // neither delete a word nor write an h.
+    (KeyScope::Input, Command::Ignore, &["ctrl+*+h"]),
+    (
+        KeyScope::Input,
+        Command::DeleteWordRight,
+        &["ctrl+*+delete"],
+    ),
+    (KeyScope::Input, Command::DeleteCharRight, &["*+delete"]),
+    (KeyScope::Input, Command::MoveLineStart, &["*+home"]),
+    (KeyScope::Input, Command::MoveWordLeft, &["ctrl+*+left"]),
+    (KeyScope::Input, Command::MoveCharLeft, &["*+left"]),
+    (KeyScope::Input, Command::MoveLineEnd, &["*+end"]),
+    (KeyScope::Input, Command::MoveWordRight, &["ctrl+*+right"]),
+    (KeyScope::Input, Command::MoveCharRight, &["*+right"]),
+    (KeyScope::Input, Command::DeleteWordLeft, &["ctrl+w"]),
+    (KeyScope::Input, Command::DeleteLineLeft, &["ctrl+u"]),
+    (KeyScope::Input, Command::DeleteLineRight, &["ctrl+k"]),
+    (KeyScope::Input, Command::Yank, &["ctrl+y"]),
+    (KeyScope::Input, Command::YankPop, &["alt+y"]),
+    // Ctrl+_ is reported as Ctrl+7 by some terminals.
+    (
+        KeyScope::Input,
+        Command::Undo,
+        &["ctrl+z", "ctrl+*+_", "ctrl+7"],
+    ),
+    (
+        KeyScope::Input,
+        Command::Redo,
+        &["ctrl+shift+z", "ctrl+*+Z"],
+    ),
+];
+
+/// Group of prompts to which a key binding applies.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KeyScope {
+    /// All prompts.
+    All,
+    /// [`Select`](crate::Select) and [`MultiSelect`](crate::MultiSelect)
+    /// prompts with vim mode enabled.
+    Vim,
+    /// [`Text`](crate::Text) prompts.
+    Text,
+    /// [`Password`](crate::Password) prompts.
+    Password,
+    /// [`Select`](crate::Select) and [`PathSelect`](crate::PathSelect)
+    /// prompts.
+    Select,
+    /// [`PathSelect`](crate::PathSelect) prompts, taking precedence over the
+    /// bindings of [`Select`](crate::Select) prompts.
+    PathSelect,
+    /// [`MultiSelect`](crate::MultiSelect) prompts.
+    MultiSelect,
+    /// [`DateSelect`](crate::DateSelect) prompts.
+    DateSelect,
+    /// [`Editor`](crate::Editor) prompts.
+    Editor,
+    /// Prompts run as steps of a [`Form`](crate::Form), taking precedence
+    /// over the bindings of the prompts.
+    Form,
+    /// Prompts with a text input, including the filter of list prompts.
+    Input,
+    /// [`Text`](crate::Text) and [`CustomType`](crate::CustomType) prompts
+    /// in multi-line mode, taking precedence over the bindings of all
+    /// prompts.
+    Multiline,
+    /// [`Text`](crate::Text) and [`CustomType`](crate::CustomType) prompts
+    /// with a [`History`](crate::history::History), taking precedence over
+    /// the bindings of the prompts.
+    History,
+}
+
+impl KeyScope {
+    const VALUES: [KeyScope; 13] = [
+        KeyScope::All,
+        KeyScope::Vim,
+        KeyScope::Text,
+        KeyScope::Password,
+        KeyScope::Select,
+        KeyScope::PathSelect,
+        KeyScope::MultiSelect,
+        KeyScope::DateSelect,
+        KeyScope::Editor,
+        KeyScope::Form,
+        KeyScope::Input,
+        KeyScope::Multiline,
+        KeyScope::History,
+    ];
+
+    /// Name of the scope in key map configurations.
+    pub fn name(self) -> &'static str {
+        match self {
+            KeyScope::All => "all",
+            KeyScope::Vim => "vim",
+            KeyScope::Text => "text",
+            KeyScope::Password => "password",
+            KeyScope::Select => "select",
+            KeyScope::PathSelect => "path_select",
+            KeyScope::MultiSelect => "multi_select",
+            KeyScope::DateSelect => "date_select",
+            KeyScope::Editor => "editor",
+            KeyScope::Form => "form",
+            KeyScope::Input => "input",
+            KeyScope::Multiline => "multiline",
+            KeyScope::History => "history",
+        }
+    }
+
+    fn markdown_title(self) -> &'static str {
+        match self {
+            KeyScope::All => "All prompts",
+            KeyScope::Vim => "Vim mode",
+            KeyScope::Text => "Text Prompts",
+            KeyScope::Password => "Password Prompts",
+            KeyScope::Select => "Select Prompts",
+            KeyScope::PathSelect => "PathSelect Prompts",
+            KeyScope::MultiSelect => "MultiSelect Prompts",
+            KeyScope::DateSelect => "DateSelect Prompts",
+            KeyScope::Editor => "Editor Prompts",
+            KeyScope::Form => "Forms",
+            KeyScope::Input => "Text Input",
+            KeyScope::Multiline => "Multi-line Input",
+            KeyScope::History => "History",
+        }
+    }
+
+    fn markdown_description(self) -> &'static str {
+        match self {
+            KeyScope::All => "These key bindings may be used with all prompts.",
+            KeyScope::Vim => "These key bindings may be used in [`Select`] and [`MultiSelect`] prompts when vim mode is enabled, taking precedence over the bindings of the prompts.",
+            KeyScope::Text => "These key bindings may be used in [`Text`] prompts, moving the cursor through the suggestions when they are displayed. <kbd>right</kbd> and <kbd>end</kbd> also accept the inline completion when the cursor is at the end of the input.",
+            KeyScope::Password => "These key bindings may be used in [`Password`] prompts.",
+            KeyScope::Select => "These key bindings may be used in [`Select`] and [`PathSelect`] prompts.",
+            KeyScope::PathSelect => "These key bindings may be used in [`PathSelect`] prompts, taking precedence over the bindings of [`Select`] prompts. <kbd>backspace</kbd> also moves to the parent directory when the filter is empty.",
+            KeyScope::MultiSelect => "These key bindings may be used in [`MultiSelect`] prompts.",
+            KeyScope::DateSelect => "These key bindings may be used in the interactive calendar of the [`DateSelect`] prompt.",
+            KeyScope::Editor => "These key bindings may be used in [`Editor`] prompts.",
+            KeyScope::Form => "These key bindings may be used in all prompts run as steps of a [`Form`], taking precedence over the bindings of the prompts.",
+            KeyScope::Input => "These key bindings may be used with all prompts that ask the user for text input: [`Text`], [`Select`], [`MultiSelect`], [`Confirm`], [`CustomType`] and [`Password`]. Any other character typed is inserted into the input.",
+            KeyScope::Multiline => "These key bindings may be used in [`Text`] and [`CustomType`] prompts in multi-line mode, taking precedence over the bindings of all prompts.",
+            KeyScope::History => "These key bindings may be used in [`Text`] and [`CustomType`] prompts with a history, taking precedence over the bindings of the prompts. When suggestions are displayed, the arrows move through the suggestions instead.",
+        }
+    }
+}
+
+impl FromStr for KeyScope {
+    type Err = InquireError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        KeyScope::VALUES
+            .iter()
+            .copied()
+            .find(|scope| scope.name() == s)
+            .ok_or_else(|| InquireError::InvalidConfiguration(format!("unknown key scope `{}`", s)))
+    }
+}
+
+/// Prompt-agnostic directive that can be bound to a key.
+///
+/// Commands not supported by a prompt are ignored by it, letting the next
+/// binding of the key apply.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Command {
+    /// Submits the current answer.
+    Submit,
+    /// Submits the answer as typed, ignoring any highlighted suggestion.
+    ForceSubmit,
+    /// Cancels the prompt.
+    Cancel,
+    /// Interrupts the prompt.
+    Interrupt,
+    /// Toggles the list of key bindings of the prompt.
+    Help,
+    /// Returns to the previous step of a form.
+    Back,
+    /// Consumes the key press without doing anything.
+    Ignore,
+    /// Moves the input cursor back one character.
+    MoveCharLeft,
+    /// Moves the input cursor forward one character.
+    MoveCharRight,
+    /// Moves the input cursor back one word.
+    MoveWordLeft,
+    /// Moves the input cursor forward one word.
+    MoveWordRight,
+    /// Moves the input cursor to the start of the line.
+    MoveLineStart,
+    /// Moves the input cursor to the end of the line.
+    MoveLineEnd,
+    /// Deletes the character to the left of the input cursor.
+    DeleteCharLeft,
+    /// Deletes the character at the input cursor.
+    DeleteCharRight,
+    /// Deletes the word to the left of the input cursor.
+    DeleteWordLeft,
+    /// Deletes the word to the right of the input cursor.
+    DeleteWordRight,
+    /// Deletes everything to the left of the input cursor.
+    DeleteLineLeft,
+    /// Deletes everything to the right of the input cursor.
+    DeleteLineRight,
+    /// Reverts the last edit of the input.
+    Undo,
+    /// Reapplies the last edit of the input reverted by [`Command::Undo`].
+    Redo,
+    /// Inserts the most recently deleted word or line at the input cursor.
+    Yank,
+    /// Replaces the text inserted by the previous yank by the text deleted
+    /// before it.
+    YankPop,
+    /// Inserts a line break at the input cursor.
+    InsertNewline,
+    /// Moves the cursor one row up.
+    MoveUp,
+    /// Moves the cursor one row down.
+    MoveDown,
+    /// Moves the cursor one page up.
+    PageUp,
+    /// Moves the cursor one page down.
+    PageDown,
+    /// Moves the cursor to the first option.
+    MoveToStart,
+    /// Moves the cursor to the last option.
+    MoveToEnd,
+    /// Toggles the selection of the highlighted option.
+    ToggleCurrentOption,
+    /// Selects all options.
+    SelectAll,
+    /// Deselects all options.
+    ClearSelections,
+    /// Replaces the input with the highlighted suggestion.
+    UseSuggestion,
+    /// Accepts the next word of the inline completion.
+    AcceptCompletionWord,
+    /// Replaces the input with the previous entry of the history.
+    PreviousHistory,
+    /// Replaces the input with the next entry of the history.
+    NextHistory,
+    /// Searches the history backwards for entries containing a query.
+    ReverseSearchHistory,
+    /// Toggles whether the password is displayed.
+    ToggleDisplayMode,
+    /// Moves the calendar cursor one day back.
+    PrevDay,
+    /// Moves the calendar cursor one day forward.
+    NextDay,
+    /// Moves the calendar cursor one week back.
+    PrevWeek,
+    /// Moves the calendar cursor one week forward.
+    NextWeek,
+    /// Moves the calendar one month back.
+    PrevMonth,
+    /// Moves the calendar one month forward.
+    NextMonth,
+    /// Moves the calendar one year back.
+    PrevYear,
+    /// Moves the calendar one year forward.
+    NextYear,
+    /// Opens the external editor.
+    OpenEditor,
+    /// Moves to the parent directory.
+    ParentDirectory,
+}
+
+impl Command {
+    const VALUES: [Command; 49] = [
+        Command::Submit,
+        Command::ForceSubmit,
+        Command::Cancel,
+        Command::Interrupt,
+        Command::Help,
+        Command::Back,
+        Command::Ignore,
+        Command::MoveCharLeft,
+        Command::MoveCharRight,
+        Command::MoveWordLeft,
+        Command::MoveWordRight,
+        Command::MoveLineStart,
+        Command::MoveLineEnd,
+        Command::DeleteCharLeft,
+        Command::DeleteCharRight,
+        Command::DeleteWordLeft,
+        Command::DeleteWordRight,
+        Command::DeleteLineLeft,
+        Command::DeleteLineRight,
+        Command::Undo,
+        Command::Redo,
+        Command::Yank,
+        Command::YankPop,
+        Command::InsertNewline,
+        Command::MoveUp,
+        Command::MoveDown,
+        Command::PageUp,
+        Command::PageDown,
+        Command::MoveToStart,
+        Command::MoveToEnd,
+        Command::ToggleCurrentOption,
+        Command::SelectAll,
+        Command::ClearSelections,
+        Command::UseSuggestion,
+        Command::AcceptCompletionWord,
+        Command::PreviousHistory,
+        Command::NextHistory,
+        Command::ReverseSearchHistory,
+        Command::ToggleDisplayMode,
+        Command::PrevDay,
+        Command::NextDay,
+        Command::PrevWeek,
+        Command::NextWeek,
+        Command::PrevMonth,
+        Command::NextMonth,
+        Command::PrevYear,
+        Command::NextYear,
+        Command::OpenEditor,
+        Command::ParentDirectory,
+    ];
+
+    /// Name of the command in key map configurations.
+    pub fn name(self) -> &'static str {
+        match self {
+            Command::Submit => "submit",
+            Command::ForceSubmit => "force_submit",
+            Command::Cancel => "cancel",
+            Command::Interrupt => "interrupt",
+            Command::Help => "help",
+            Command::Back => "back",
+            Command::Ignore => "ignore",
+            Command::MoveCharLeft => "move_char_left",
+            Command::MoveCharRight => "move_char_right",
+            Command::MoveWordLeft => "move_word_left",
+            Command::MoveWordRight => "move_word_right",
+            Command::MoveLineStart => "move_line_start",
+            Command::MoveLineEnd => "move_line_end",
+            Command::DeleteCharLeft => "delete_char_left",
+            Command::DeleteCharRight => "delete_char_right",
+            Command::DeleteWordLeft => "delete_word_left",
+            Command::DeleteWordRight => "delete_word_right",
+            Command::DeleteLineLeft => "delete_line_left",
+            Command::DeleteLineRight => "delete_line_right",
+            Command::Undo => "undo",
+            Command::Redo => "redo",
+            Command::Yank => "yank",
+            Command::YankPop => "yank_pop",
+            Command::InsertNewline => "insert_newline",
+            Command::MoveUp => "move_up",
+            Command::MoveDown => "move_down",
+            Command::PageUp => "page_up",
+            Command::PageDown => "page_down",
+            Command::MoveToStart => "move_to_start",
+            Command::MoveToEnd => "move_to_end",
+            Command::ToggleCurrentOption => "toggle_current_option",
+            Command::SelectAll => "select_all",
+            Command::ClearSelections => "clear_selections",
+            Command::UseSuggestion => "use_suggestion",
+            Command::AcceptCompletionWord => "accept_completion_word",
+            Command::PreviousHistory => "previous_history",
+            Command::NextHistory => "next_history",
+            Command::ReverseSearchHistory => "reverse_search_history",
+            Command::ToggleDisplayMode => "toggle_display_mode",
+            Command::PrevDay => "prev_day",
+            Command::NextDay => "next_day",
+            Command::PrevWeek => "prev_week",
+            Command::NextWeek => "next_week",
+            Command::PrevMonth => "prev_month",
+            Command::NextMonth => "next_month",
+            Command::PrevYear => "prev_year",
+            Command::NextYear => "next_year",
+            Command::OpenEditor => "open_editor",
+            Command::ParentDirectory => "parent_directory",
+        }
+    }
+
+    /// Short description of the command, as displayed in the help of prompts.
+    pub fn description(self) -> &'static str {
+        match self {
+            Command::Submit => "Submit the answer.",
+            Command::ForceSubmit => {
+                "Submit the answer as typed, ignoring the highlighted suggestion."
+            }
+            Command::Cancel => "Cancel the prompt.",
+            Command::Interrupt => "Interrupt the prompt.",
+            Command::Help => "Show or hide the key bindings of the prompt.",
+            Command::Back => "Return to the previous step of the form.",
+            Command::Ignore => "Do nothing.",
+            Command::MoveCharLeft => "Move the cursor back one character.",
+            Command::MoveCharRight => "Move the cursor forward one character.",
+            Command::MoveWordLeft => "Move one word to the left of the cursor.",
+            Command::MoveWordRight => "Move one word to the right of the cursor.",
+            Command::MoveLineStart => "Move the cursor to the start of the line.",
+            Command::MoveLineEnd => "Move the cursor to the end of the line.",
+            Command::DeleteCharLeft => "Delete one character to the left of the cursor.",
+            Command::DeleteCharRight => "Delete the character at the cursor.",
+            Command::DeleteWordLeft => "Delete one word to the left of the cursor.",
+            Command::DeleteWordRight => "Delete one word to the right of the cursor.",
+            Command::DeleteLineLeft => "Delete everything to the left of the cursor.",
+            Command::DeleteLineRight => "Delete everything to the right of the cursor.",
+            Command::Undo => "Undo the last edit.",
+            Command::Redo => "Redo the last undone edit.",
+            Command::Yank => "Insert the last deleted word or line.",
+            Command::YankPop => "Replace the inserted text by the one deleted before it.",
+            Command::InsertNewline => "Insert a line break at the cursor.",
+            Command::MoveUp => "Move the cursor one row up.",
+            Command::MoveDown => "Move the cursor one row down.",
+            Command::PageUp => "Move the cursor one page up.",
+            Command::PageDown => "Move the cursor one page down.",
+            Command::MoveToStart => "Move the cursor to the first option.",
+            Command::MoveToEnd => "Move the cursor to the last option.",
+            Command::ToggleCurrentOption => "Toggle the selection of the highlighted option.",
+            Command::SelectAll => "Select all options.",
+            Command::ClearSelections => "Unselect all options.",
+            Command::UseSuggestion => "Replace the input with the highlighted suggestion, if any.",
+            Command::AcceptCompletionWord => {
+                "Accept the next word of the inline completion, or move one word to the right without one."
+            }
+            Command::PreviousHistory => "Replace the input with the previous entry of the history.",
+            Command::NextHistory => "Replace the input with the next entry of the history.",
+            Command::ReverseSearchHistory => {
+                "Search the history backwards, or move to the next older match."
+            }
+            Command::ToggleDisplayMode => "Toggle whether the password is displayed, when enabled.",
+            Command::PrevDay => "Move the cursor one day back.",
+            Command::NextDay => "Move the cursor one day forward.",
+            Command::PrevWeek => "Move the cursor one week back.",
+            Command::NextWeek => "Move the cursor one week forward.",
+            Command::PrevMonth => "Move the calendar back by one month.",
+            Command::NextMonth => "Move the calendar forward by one month.",
+            Command::PrevYear => "Move the calendar back by one year.",
+            Command::NextYear => "Move the calendar forward by one year.",
+            Command::OpenEditor => "Open the editor.",
+            Command::ParentDirectory => "Move to the parent directory.",
+        }
+    }
+}
+
+impl FromStr for Command {
+    type Err = InquireError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Command::VALUES
+            .iter()
+            .copied()
+            .find(|command| command.name() == s)
+            .ok_or_else(|| InquireError::InvalidConfiguration(format!("unknown command `{}`", s)))
+    }
+}
+
+/// Key without its modifiers, as matched by a [`KeyPattern`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KeyCode {
+    /// Escape key.
+    Escape,
+    /// Enter key.
+    Enter,
+    /// Backspace key.
+    Backspace,
+    /// Tab key.
+    Tab,
+    /// Shift+Tab.
+    BackTab,
+    /// Insert key.
+    Insert,
+    /// Delete key.
+    Delete,
+    /// Home key.
+    Home,
+    /// End key.
+    End,
+    /// Page up key.
+    PageUp,
+    /// Page down key.
+    PageDown,
+    /// Up arrow key.
+    Up,
+    /// Down arrow key.
+    Down,
+    /// Left arrow key.
+    Left,
+    /// Right arrow key.
+    Right,
+    /// Function key with the given number.
+    F(u8),
+    /// Key typing the given character.
+    Char(char),
+}
+
+impl KeyCode {
+    const NAMED: [(KeyCode, &'static str); 19] = [
+        (KeyCode::Escape, "esc"),
+        (KeyCode::Enter, "enter"),
+        (KeyCode::Backspace, "backspace"),
+        (KeyCode::Tab, "tab"),
+        (KeyCode::BackTab, "backtab"),
+        (KeyCode::Insert, "insert"),
+        (KeyCode::Delete, "delete"),
+        (KeyCode::Home, "home"),
+        (KeyCode::End, "end"),
+        (KeyCode::PageUp, "pageup"),
+        (KeyCode::PageDown, "pagedown"),
+        (KeyCode::Up, "up"),
+        (KeyCode::Down, "down"),
+        (KeyCode::Left, "left"),
+        (KeyCode::Right, "right"),
+        (KeyCode::Char(' '), "space"),
+        (KeyCode::Char('+'), "plus"),
+        (KeyCode::Char(','), "comma"),
+        (KeyCode::Char('\n'), "newline"),
+    ];
+
+    fn from_key(key: &Key) -> Option<(KeyCode, KeyModifiers)> {
+        let key = match key {
+            Key::Escape(m) => (KeyCode::Escape, *m),
+            Key::Enter(m) => (KeyCode::Enter, *m),
+            Key::Backspace(m) => (KeyCode::Backspace, *m),
+            Key::Tab(m) => (KeyCode::Tab, *m),
+            Key::BackTab(m) => (KeyCode::BackTab, *m),
+            Key::Insert(m) => (KeyCode::Insert, *m),
+            Key::Delete(m) => (KeyCode::Delete, *m),
+            Key::Home(m) => (KeyCode::Home, *m),
+            Key::End(m) => (KeyCode::End, *m),
+            Key::PageUp(m) => (KeyCode::PageUp, *m),
+            Key::PageDown(m) => (KeyCode::PageDown, *m),
+            Key::Up(m) => (KeyCode::Up, *m),
+            Key::Down(m) => (KeyCode::Down, *m),
+            Key::Left(m) => (KeyCode::Left, *m),
+            Key::Right(m) => (KeyCode::Right, *m),
+            Key::F(n, m) => (KeyCode::F(*n), *m),
+            Key::Char(c, m) => (KeyCode::Char(*c), *m),
+            Key::Resize(..) | Key::Mouse(_) | Key::Paste(_) => return None,
+        };
+
+        Some(key)
+    }
+}
+
+impl fmt::Display for KeyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match KeyCode::NAMED.iter().find(|(code, _)| code == self) {
+            Some((_, name)) => f.write_str(name),
+            None => match self {
+                KeyCode::F(n) => write!(f, "f{}", n),
+                KeyCode::Char(c) => write!(f, "{}", c),
+                _ => unreachable!("all key codes but characters and function keys are named"),
+            },
+        }
+    }
+}
+
+impl FromStr for KeyCode {
+    type Err = InquireError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((code, _)) = KeyCode::NAMED.iter().find(|(_, name)| *name == s) {
+            return Ok(*code);
+        }
+
+        if let Some(n) = s.strip_prefix('f').and_then(|n| n.parse().ok()) {
+            return Ok(KeyCode::F(n));
+        }
+
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(KeyCode::Char(c)),
+            _ => Err(InquireError::InvalidConfiguration(format!(
+                "unknown key `{}`",
+                s
+            ))),
+        }
+    }
+}
+
+const MODIFIER_NAMES: [(KeyModifiers, &str); 6] = [
+    (KeyModifiers::CONTROL, "ctrl"),
+    (KeyModifiers::ALT, "alt"),
+    (KeyModifiers::META, "meta"),
+    (KeyModifiers::SUPER, "super"),
+    (KeyModifiers::HYPER, "hyper"),
+    (KeyModifiers::SHIFT, "shift"),
+];
+
+/// Key press matched by a binding of a [`KeyMap`].
+///
+/// Patterns are written as the modifiers followed by the key, joined by
+/// `+`, e.g. `ctrl+d` or `alt+shift+left`. A `*` in place of a modifier
+/// makes the pattern also match when other modifiers are held, e.g.
+/// `ctrl+*+left` matches both Ctrl+Left and Ctrl+Shift+Left.
+///
+/// Keys are named `esc`, `enter`, `backspace`, `tab`, `backtab`, `insert`,
+/// `delete`, `home`, `end`, `pageup`, `pagedown`, `up`, `down`, `left`,
+/// `right` and `f1` to `f24`, while
+/// characters are written as themselves, except for `space`, `plus`,
+/// `comma` and `newline`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyPattern {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    exact: bool,
+}
+
+impl KeyPattern {
+    /// Creates a pattern matching the key pressed with exactly the given
+    /// modifiers.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            code,
+            modifiers,
+            exact: true,
+        }
+    }
+
+    /// Makes the pattern also match when modifiers other than its own are
+    /// held.
+    pub fn with_any_modifiers(mut self) -> Self {
+        self.exact = false;
+        self
+    }
+
+    /// Returns whether the key press matches the pattern.
+    pub(crate) fn matches(&self, key: &Key) -> bool {
+        match KeyCode::from_key(key) {
+            Some((code, modifiers)) if code == self.code => match self.exact {
+                true => modifiers == self.modifiers,
+                false => modifiers.contains(self.modifiers),
+            },
+            _ => false,
+        }
+    }
+
+    fn to_markdown(self) -> String {
+        let mut keys: Vec<String> = MODIFIER_NAMES
+            .iter()
+            .filter(|(modifier, _)| self.modifiers.contains(*modifier))
+            .map(|(_, name)| (*name).to_string())
+            .collect();
+
+        if !self.exact {
+            keys.push(String::from("\\*"));
+        }
+
+        keys.push(match self.code {
+            KeyCode::Char(c @ ('|' | '`' | '*' | '[' | ']' | '\\' | '<' | '_')) => {
+                format!("\\{}", c)
+            }
+            code => code.to_string(),
+        });
+
+        keys.iter()
+            .map(|key| format!("<kbd>{}</kbd>", key))
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+}
+
+impl fmt::Display for KeyPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in MODIFIER_NAMES {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{}+", name)?;
+            }
+        }
+
+        if !self.exact {
+            f.write_str("*+")?;
+        }
+
+        write!(f, "{}", self.code)
+    }
+}
+
+impl FromStr for KeyPattern {
+    type Err = InquireError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (modifiers, key) = match s.rsplit_once('+') {
+            Some((modifiers, key)) => (Some(modifiers), key),
+            None => (None, s),
+        };
+
+        let mut pattern = KeyPattern::new(key.parse()?, KeyModifiers::NONE);
+
+        for modifier in modifiers.into_iter().flat_map(|m| m.split('+')) {
+            if modifier == "*" {
+                pattern.exact = false;
+                continue;
+            }
+
+            match MODIFIER_NAMES.iter().find(|(_, name)| *name == modifier) {
+                Some((flag, _)) => pattern.modifiers |= *flag,
+                None => {
+                    return Err(InquireError::InvalidConfiguration(format!(
+                        "unknown modifier `{}` in key `{}`",
+                        modifier, s
+                    )))
+                }
+            }
+        }
+
+        Ok(pattern)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Binding {
+    scope: KeyScope,
+    pattern: KeyPattern,
+    command: Command,
+}
+
+/// Table of key bindings used by prompts to derive their actions from key
+/// presses.
+///
+/// When a key is pressed, the bindings of the scopes applicable to the
+/// prompt are checked in order of precedence, and the first one whose
+/// command is supported by the prompt is applied. Bindings added to a key map
+/// take precedence over the existing ones. Keys not bound to any command
+/// are handled by the prompt itself, e.g. typed characters are inserted
+/// into text inputs.
+///
+/// The default key map contains the bindings listed in `KEY_BINDINGS.md`.
+///
+/// A key map can be loaded from a configuration string, where each line
+/// binds a command to a comma-separated list of [`KeyPattern`]s under a
+/// `[scope]` header, replacing the previous keys of the command in that
+/// scope. Lines before any header apply to the `all` scope, lines starting
+/// with `#` are comments and an empty list of keys unbinds the command.
+///
+/// ```
+/// use inquire::keymap::{Command, KeyMap, KeyScope};
+///
+/// // Ctrl+D writes a 'd' instead of canceling the prompt.
+/// let key_map = KeyMap::default()
+///     .with_config(
+///         "
+///         cancel = esc, ctrl+g
+///
+///         [select]
+///         move_up = up, ctrl+p, ctrl+k
+///         move_down = down, ctrl+n, ctrl+j
+///         ",
+///     )
+///     .unwrap()
+///     .bind(KeyScope::MultiSelect, "ctrl+a".parse().unwrap(), Command::SelectAll);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyMap {
+    bindings: Vec<Binding>,
+}
+
+impl KeyMap {
+    /// Binds the key pattern to the command in the given scope, taking
+    /// precedence over existing bindings.
+    pub fn bind(mut self, scope: KeyScope, pattern: KeyPattern, command: Command) -> Self {
+        self.bindings
+            .retain(|b| !(b.scope == scope && b.pattern == pattern));
+        self.bindings.insert(
+            0,
+            Binding {
+                scope,
+                pattern,
+                command,
+            },
+        );
+        self
+    }
+
+    /// Removes the bindings of the key pattern in the given scope.
+    pub fn unbind(mut self, scope: KeyScope, pattern: KeyPattern) -> Self {
+        self.bindings
+            .retain(|b| !(b.scope == scope && b.pattern == pattern));
+        self
+    }
+
+    /// Replaces the key patterns bound to the command in the given scope,
+    /// with the new ones taking precedence over existing bindings.
+    pub fn rebind(mut self, scope: KeyScope, command: Command, patterns: &[KeyPattern]) -> Self {
+        self.bindings
+            .retain(|b| !(b.scope == scope && b.command == command));
+
+        for pattern in patterns.iter().rev() {
+            self = self.bind(scope, *pattern, command);
+        }
+
+        self
+    }
+
+    /// Applies the bindings of a configuration string to the key map, as
+    /// described in the [`KeyMap`] documentation.
+    pub fn with_config(mut self, config: &str) -> InquireResult<Self> {
+        let mut scope = KeyScope::All;
+
+        for (index, line) in config.lines().enumerate() {
+            let in_line = |err: InquireError| match err {
+                InquireError::InvalidConfiguration(msg) => InquireError::InvalidConfiguration(
+                    format!("key map line {}: {}", index + 1, msg),
+                ),
+                err => err,
+            };
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                scope = name.trim().parse().map_err(in_line)?;
+                continue;
+            }
+
+            let (command, keys) = line.split_once('=').ok_or_else(|| {
+                in_line(InquireError::InvalidConfiguration(String::from(
+                    "expected `command = keys`",
+                )))
+            })?;
+
+            let command = command.trim().parse().map_err(in_line)?;
+            let patterns = keys
+                .split(',')
+                .map(str::trim)
+                .filter(|key| !key.is_empty())
+                .map(str::parse)
+                .collect::<InquireResult<Vec<KeyPattern>>>()
+                .map_err(in_line)?;
+
+            self = self.rebind(scope, command, &patterns);
+        }
+
+        Ok(self)
+    }
+
+    /// Commands bound to the key press in the given scopes, in order of
+    /// precedence.
+    pub(crate) fn commands<'a>(
+        &'a self,
+        key: &'a Key,
+        scopes: &'a [KeyScope],
+    ) -> impl Iterator<Item = Command> + 'a {
+        self.bindings
+            .iter()
+            .filter(move |b| scopes.contains(&b.scope) && b.pattern.matches(key))
+            .map(|b| b.command)
+    }
+
+    /// Key patterns bound to each command in the given scopes, with the
+    /// commands in order of precedence.
+    pub(crate) fn bindings_by_command(
+        &self,
+        scopes: &[KeyScope],
+    ) -> Vec<(Command, Vec<KeyPattern>)> {
+        let mut commands: Vec<(Command, Vec<KeyPattern>)> = vec![];
+
+        for binding in self.bindings.iter().filter(|b| scopes.contains(&b.scope)) {
+            match commands.iter_mut().find(|(c, _)| *c == binding.command) {
+                Some((_, patterns)) => patterns.push(binding.pattern),
+                None => commands.push((binding.command, vec![binding.pattern])),
+            }
+        }
+
+        commands
+    }
+
+    /// Renders the key map as the markdown document found in
+    /// `KEY_BINDINGS.md`.
+    pub fn to_markdown(&self) -> String {
+        let mut doc = String::from(MARKDOWN_HEADER);
+
+        for scope in KeyScope::VALUES {
+            let rows = self.bindings_by_command(&[scope]);
+            if rows.is_empty() {
+                continue;
+            }
+
+            doc.push_str(&format!(
+                "\n## {}\n\n{}\n\n",
+                scope.markdown_title(),
+                scope.markdown_description()
+            ));
+
+            let header = ["**keys**", "**command**", "**description**"].map(String::from);
+            let rows: Vec<[String; 3]> = std::iter::once(header)
+                .chain(rows.into_iter().map(|(command, patterns)| {
+                    [
+                        patterns
+                            .into_iter()
+                            .map(KeyPattern::to_markdown)
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        format!("`{}`", command.name()),
+                        match command {
+                            Command::Cancel | Command::Interrupt => {
+                                command.description().replace('.', "\\*.")
+                            }
+                            command => command.description().to_string(),
+                        },
+                    ]
+                }))
+                .collect();
+            push_markdown_table(&mut doc, &rows);
+
+            if scope == KeyScope::All {
+                doc.push_str(CANCEL_FOOTNOTE);
+            }
+        }
+
+        doc.push_str(MARKDOWN_LINKS);
+        doc
+    }
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        DEFAULT_KEY_MAP.clone()
+    }
+}
+
+impl FromStr for KeyMap {
+    type Err = InquireError;
+
+    /// Loads a configuration string on top of the default key map.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        KeyMap::default().with_config(s)
+    }
+}
+
+fn push_markdown_table(doc: &mut String, rows: &[[String; 3]]) {
+    let mut widths = [0; 3];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    for (index, row) in rows.iter().enumerate() {
+        for (cell, width) in row.iter().zip(widths) {
+            let padding = width - cell.chars().count();
+            doc.push_str(&format!("| {}{} ", cell, " ".repeat(padding)));
+        }
+        doc.push_str("|\n");
+
+        if index == 0 {
+            for width in widths {
+                doc.push_str(&format!("| {} ", "-".repeat(width)));
+            }
+            doc.push_str("|\n");
+        }
+    }
+}
+
+const MARKDOWN_HEADER: &str = "# Key Bindings
+
+<!-- Generated from the default `KeyMap`, run `UPDATE_KEY_BINDINGS=1 cargo test -p inquire key_bindings_doc` to update it. -->
+
+This file lists all of the key bindings registered by default in prompts.
+
+The bindings can be changed globally with [`set_global_key_map`] or for a single prompt with its `with_key_map` method, using a [`KeyMap`] that can be loaded from a configuration string, e.g.:
+
+```toml
+[all]
+cancel = esc, ctrl+g
+
+[select]
+move_up = up, ctrl+p, ctrl+k
+```
+
+Bindings are listed in order of precedence. Keys containing <kbd>\\*</kbd> also match when any other modifiers are held.
+";
+
+const CANCEL_FOOTNOTE: &str = "
+\\* Canceling and interrupting a prompt have two different meanings. Canceling is defined specially for when the end user is allowed to skip a prompt, the library user can then use `prompt_skippable` which wraps the return type into an `Option` and catches the `CanceledOperation` error transforming it into a `Ok(None)` result. Interrupted operations are closer to \"stop-the-world\" operations, where the library user should treat them as termination commands.
+";
+
+const MARKDOWN_LINKS: &str = "
+[`KeyMap`]: https://docs.rs/inquire/*/inquire/keymap/struct.KeyMap.html
+[`set_global_key_map`]: https://docs.rs/inquire/*/inquire/fn.set_global_key_map.html
+[`Text`]: https://docs.rs/inquire/*/inquire/prompts/text/struct.Text.html
+[`DateSelect`]: https://docs.rs/inquire/*/inquire/prompts/dateselect/struct.DateSelect.html
+[`Select`]: https://docs.rs/inquire/*/inquire/prompts/select/struct.Select.html
+[`PathSelect`]: https://docs.rs/inquire/*/inquire/struct.PathSelect.html
+[`MultiSelect`]: https://docs.rs/inquire/*/inquire/prompts/multiselect/struct.MultiSelect.html
+[`Confirm`]: https://docs.rs/inquire/*/inquire/prompts/confirm/struct.Confirm.html
+[`Editor`]: https://docs.rs/inquire/*/inquire/prompts/editor/struct.Editor.html
+[`CustomType`]: https://docs.rs/inquire/*/inquire/struct.CustomType.html
+[`Password`]: https://docs.rs/inquire/*/inquire/prompts/password/struct.Password.html
+[`Form`]: https://docs.rs/inquire/*/inquire/struct.Form.html
+";
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Action, InputAction};
+
+    #[test]
+    fn key_bindings_doc_is_up_to_date() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../KEY_BINDINGS.md");
+        let expected = KeyMap::default().to_markdown();
+
+        if std::env::var_os("UPDATE_KEY_BINDINGS").is_some() {
+            std::fs::write(path, &expected).unwrap();
+        }
+
+        let current = std::fs::read_to_string(path).unwrap();
+        assert!(
+            current == expected,
+            "KEY_BINDINGS.md is outdated, run `UPDATE_KEY_BINDINGS=1 cargo test -p inquire key_bindings_doc` to update it"
+        );
+    }
+
+    #[test]
+    fn key_patterns_round_trip() {
+        for pattern in [
+            "ctrl+d",
+            "alt+shift+left",
+            "ctrl+*+r",
+            "*+pageup",
+            "plus",
+            "ctrl+comma",
+            "V",
+            "shift+f12",
+            "*+backtab",
+        ] {
+            assert_eq!(pattern, pattern.parse::<KeyPattern>().unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn loose_patterns_match_extra_modifiers() {
+        let pattern: KeyPattern = "ctrl+*+left".parse().unwrap();
+
+        assert!(pattern.matches(&Key::Left(KeyModifiers::CONTROL)));
+        assert!(pattern.matches(&Key::Left(KeyModifiers::CONTROL | KeyModifiers::SHIFT)));
+        assert!(!pattern.matches(&Key::Left(KeyModifiers::SHIFT)));
+        assert!(!"ctrl+left"
+            .parse::<KeyPattern>()
+            .unwrap()
+            .matches(&Key::Left(KeyModifiers::CONTROL | KeyModifiers::SHIFT)));
+    }
+
+    #[test]
+    fn config_replaces_keys_of_command() {
+        let key_map: KeyMap = "cancel = esc, ctrl+g".parse().unwrap();
+        let ctrl_d = Key::Char('d', KeyModifiers::CONTROL);
+
+        assert_eq!(
+            Some(Action::Cancel),
+            Action::<InputAction>::from_key_with_map(
+                Key::Escape(KeyModifiers::NONE),
+                &(),
+                &key_map
+            )
+        );
+        assert_eq!(
+            Some(Action::Inner(InputAction::Write('d'))),
+            Action::<InputAction>::from_key_with_map(ctrl_d, &(), &key_map)
+        );
+    }
+
+    #[test]
+    fn bindings_added_take_precedence() {
+        let key_map = KeyMap::default().bind(
+            KeyScope::Input,
+            "ctrl+d".parse().unwrap(),
+            Command::DeleteCharRight,
+        );
+
+        assert_eq!(
+            Some(Action::Inner(InputAction::Delete(
+                crate::input::Magnitude::Char,
+                crate::input::LineDirection::Right
+            ))),
+            Action::<InputAction>::from_key_with_map(
+                Key::Char('d', KeyModifiers::CONTROL),
+                &(),
+                &key_map
+            )
+        );
+    }
+
+    #[test]
+    fn invalid_config_reports_line() {
+        let err = KeyMap::default()
+            .with_config("\n[select]\nmove_up = up, ctrl+x+p")
+            .unwrap_err();
+
+        assert_eq!(
+            "The prompt configuration is invalid: key map line 3: unknown modifier `x` in key `ctrl+x+p`",
+            err.to_string()
+        );
+        assert!("[selec]".parse::<KeyMap>().is_err());
+        assert!("teleport = t".parse::<KeyMap>().is_err());
+        assert!("move_up up".parse::<KeyMap>().is_err());
+    }
+}
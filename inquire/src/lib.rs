@@ -8,6 +8,7 @@
 //! - [`DateSelect`]* to get a date input from the user, selected via an _interactive calendar_;
 //! - [`Select`] to ask the user to select one option from a given list;
 //! - [`MultiSelect`] to ask the user to select an arbitrary number of options from a given list;
+//! - [`PathSelect`] to ask the user to select a file or directory by browsing the filesystem;
 //! - [`Confirm`] for simple yes/no confirmation prompts;
 //! - [`CustomType`] for text prompts that you would like to parse to a custom type, such as numbers or UUIDs;
 //! - [`Password`] for secretive text prompts.
@@ -57,6 +58,7 @@
 //! [`DateSelect`]: crate::DateSelect
 //! [`Select`]: crate::Select
 //! [`MultiSelect`]: crate::MultiSelect
+//! [`PathSelect`]: crate::PathSelect
 //! [`Confirm`]: crate::Confirm
 //! [`CustomType`]: crate::CustomType
 //! [`Password`]: crate::Password
@@ -70,13 +72,18 @@ pub mod autocompletion;
 mod config;
 #[cfg(feature = "date")]
 mod date_utils;
+mod enum_support;
 #[cfg(feature = "date")]
 pub use date_utils::DateFromStr;
 pub mod error;
 pub mod formatter;
+pub mod history;
 mod input;
+pub mod keymap;
 pub mod list_option;
+pub mod mask;
 pub mod parser;
+mod path_utils;
 mod prompts;
 mod terminal;
 pub mod type_aliases;
@@ -85,7 +92,12 @@ mod utils;
 pub mod validator;
 
 pub use crate::autocompletion::Autocomplete;
-pub use crate::config::set_global_render_config;
+pub use crate::config::{
+    set_global_accessible_mode, set_global_key_map, set_global_render_config, set_global_vim_mode,
+};
+pub use crate::enum_support::InquireEnumVariants;
 pub use crate::error::{CustomUserError, InquireError};
 pub use crate::input::action::*;
 pub use crate::prompts::*;
+#[cfg(feature = "macros")]
+pub use inquire_derive::{Inquire, InquireSelect};
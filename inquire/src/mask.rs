@@ -0,0 +1,333 @@
+//! Input masks for structured text entry, such as phone numbers or dates.
+//!
+//! A mask is defined by a spec in which each slot character accepts a class
+//! of characters, all other characters being literals inserted automatically
+//! while the user types:
+//!
+//! - `9`: a digit.
+//! - `a`: a letter.
+//! - `*`: a letter or a digit.
+//! - `h`: a hexadecimal digit.
+//! - `\`: escapes the next character, making it a literal.
+//!
+//! For example, `(999) 999-9999` is a US phone number and `99/99/9999` a
+//! date. Masks are applied to [`CustomType`](crate::CustomType) prompts with
+//! [`with_mask`](crate::CustomType::with_mask).
+
+use crate::{
+    error::{InquireError, InquireResult},
+    input::Input,
+};
+
+/// Class of characters accepted by a slot of a mask.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Slot {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Hex,
+}
+
+impl Slot {
+    fn from_spec(c: char) -> Option<Self> {
+        match c {
+            '9' => Some(Self::Digit),
+            'a' => Some(Self::Letter),
+            '*' => Some(Self::Alphanumeric),
+            'h' => Some(Self::Hex),
+            _ => None,
+        }
+    }
+
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Alphanumeric => c.is_alphanumeric(),
+            Self::Hex => c.is_ascii_hexdigit(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Slot(Slot),
+    Literal(char),
+}
+
+/// Mask constraining each character of a text input to a class of
+/// characters, with literals such as separators inserted automatically.
+///
+/// The user only types the characters of the slots: literals are displayed
+/// as soon as the slots before them are filled, backspace skips them, and the
+/// slots left to fill are displayed after the input, like a placeholder.
+///
+/// # Example
+///
+/// ```
+/// use inquire::{mask::InputMask, CustomType};
+///
+/// let mask = InputMask::new("(999) 999-9999").unwrap();
+/// assert_eq!("(555) 123-4567", mask.format("5551234567"));
+///
+/// let phone = CustomType::<String>::new("Phone number:").with_mask(mask);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputMask {
+    tokens: Vec<Token>,
+    placeholder: char,
+    raw_value: bool,
+}
+
+impl InputMask {
+    /// Default character displayed in the slots left to fill.
+    pub const DEFAULT_PLACEHOLDER: char = '_';
+
+    /// Creates a mask from its spec, see the [module documentation](self)
+    /// for its syntax.
+    ///
+    /// Returns [`InquireError::InvalidConfiguration`] if the spec has no
+    /// slots or ends with an escape character.
+    pub fn new(spec: &str) -> InquireResult<Self> {
+        let mut tokens = vec![];
+        let mut chars = spec.chars();
+
+        while let Some(c) = chars.next() {
+            let token = match c {
+                '\\' => match chars.next() {
+                    Some(c) => Token::Literal(c),
+                    None => {
+                        return Err(InquireError::InvalidConfiguration(format!(
+                            "mask `{}` ends with an escape character",
+                            spec
+                        )))
+                    }
+                },
+                c => match Slot::from_spec(c) {
+                    Some(slot) => Token::Slot(slot),
+                    None => Token::Literal(c),
+                },
+            };
+            tokens.push(token);
+        }
+
+        if !tokens.iter().any(|t| matches!(t, Token::Slot(_))) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "mask `{}` has no slots",
+                spec
+            )));
+        }
+
+        Ok(Self {
+            tokens,
+            placeholder: Self::DEFAULT_PLACEHOLDER,
+            raw_value: false,
+        })
+    }
+
+    /// Sets the character displayed in the slots left to fill.
+    pub fn with_placeholder(mut self, placeholder: char) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Sets whether the parser receives only the characters typed in the
+    /// slots, e.g. `5551234567`, instead of the formatted value,
+    /// `(555) 123-4567`. Defaults to the formatted value.
+    pub fn with_raw_value(mut self, raw_value: bool) -> Self {
+        self.raw_value = raw_value;
+        self
+    }
+
+    /// Number of characters to type to fill the mask.
+    pub fn slots(&self) -> usize {
+        self.slot_kinds().count()
+    }
+
+    /// Whether the characters typed by the user fit in the slots of the
+    /// mask, which may not all be filled.
+    pub fn accepts(&self, raw: &str) -> bool {
+        let mut slots = self.slot_kinds();
+        raw.chars()
+            .all(|c| matches!(slots.next(), Some(slot) if slot.accepts(c)))
+    }
+
+    /// Whether the characters typed by the user fill all the slots of the
+    /// mask.
+    pub fn is_complete(&self, raw: &str) -> bool {
+        self.accepts(raw) && raw.chars().count() == self.slots()
+    }
+
+    /// Formats the characters typed by the user, inserting the literals up to
+    /// the first slot left to fill.
+    pub fn format(&self, raw: &str) -> String {
+        let mut raw = raw.chars();
+        let mut formatted = String::new();
+
+        for token in &self.tokens {
+            match token {
+                Token::Literal(c) => formatted.push(*c),
+                Token::Slot(_) => match raw.next() {
+                    Some(c) => formatted.push(c),
+                    None => break,
+                },
+            }
+        }
+
+        formatted
+    }
+
+    /// Value handed to the parser for the characters typed by the user,
+    /// formatted unless the mask is set to use raw values.
+    pub fn value(&self, raw: &str) -> String {
+        if self.raw_value {
+            raw.to_owned()
+        } else {
+            self.format(raw)
+        }
+    }
+
+    /// Extracts the characters of the slots from `first_slot` on from a text,
+    /// such as a formatted value or pasted text, skipping the characters that
+    /// do not fit in the next slot.
+    pub(crate) fn raw_from(&self, text: &str, first_slot: usize) -> String {
+        let mut slots = self.slot_kinds().skip(first_slot).peekable();
+
+        text.chars()
+            .filter(|&c| match slots.peek() {
+                Some(slot) if slot.accepts(c) => {
+                    slots.next();
+                    true
+                }
+                _ => false,
+            })
+            .collect()
+    }
+
+    /// Display of the characters typed by the user: their formatted value
+    /// with the cursor on the slot it is on in the raw characters, and the
+    /// remainder of the mask, with placeholders in the slots left to fill.
+    pub(crate) fn display(&self, raw: &Input) -> (Input, String) {
+        let formatted = self.format(raw.content());
+
+        let cursor = self
+            .tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| matches!(t, Token::Slot(_)))
+            .nth(raw.cursor())
+            .map(|(index, _)| index)
+            .filter(|&index| index < formatted.chars().count())
+            .unwrap_or_else(|| formatted.chars().count());
+
+        let remainder = self.tokens[formatted.chars().count()..]
+            .iter()
+            .map(|t| match t {
+                Token::Literal(c) => *c,
+                Token::Slot(_) => self.placeholder,
+            })
+            .collect();
+
+        (Input::new_with(formatted).with_cursor(cursor), remainder)
+    }
+
+    /// Whole mask as displayed before anything is typed, e.g. `(___) ___-____`.
+    pub(crate) fn template(&self) -> String {
+        let (input, remainder) = self.display(&Input::new());
+        format!("{}{}", input.content(), remainder)
+    }
+
+    fn slot_kinds(&self) -> impl Iterator<Item = Slot> + '_ {
+        self.tokens.iter().filter_map(|t| match t {
+            Token::Slot(slot) => Some(*slot),
+            Token::Literal(_) => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::InputMask;
+    use crate::{error::InquireError, input::Input};
+
+    #[test]
+    fn literals_are_inserted_up_to_the_next_slot() {
+        let mask = InputMask::new("(999) 999-9999").unwrap();
+
+        assert_eq!("(", mask.format(""));
+        assert_eq!("(55", mask.format("55"));
+        assert_eq!("(555) ", mask.format("555"));
+        assert_eq!("(555) 123-4567", mask.format("5551234567"));
+    }
+
+    #[test]
+    fn slots_constrain_characters() {
+        let mask = InputMask::new("aa99 hh*").unwrap();
+
+        assert!(mask.accepts("GB"));
+        assert!(mask.accepts("GB12fF"));
+        assert!(mask.accepts("GB12fFz"));
+        assert!(!mask.accepts("G1"));
+        assert!(!mask.accepts("GB12fg"));
+        assert!(!mask.accepts("GB12fFzz"));
+
+        assert!(!mask.is_complete("GB12fF"));
+        assert!(mask.is_complete("GB12fF0"));
+    }
+
+    #[test]
+    fn escaped_slot_characters_are_literals() {
+        let mask = InputMask::new("\\9-99").unwrap();
+
+        assert_eq!(2, mask.slots());
+        assert_eq!("9-12", mask.format("12"));
+    }
+
+    #[test]
+    fn invalid_specs_are_rejected() {
+        assert!(matches!(
+            InputMask::new("--"),
+            Err(InquireError::InvalidConfiguration(_))
+        ));
+        assert!(matches!(
+            InputMask::new("99\\"),
+            Err(InquireError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[test]
+    fn value_is_formatted_or_raw() {
+        let mask = InputMask::new("99/99/9999").unwrap();
+
+        assert_eq!("25/12/2024", mask.value("25122024"));
+        assert_eq!("25122024", mask.with_raw_value(true).value("25122024"));
+    }
+
+    #[test]
+    fn raw_characters_are_extracted_from_formatted_text() {
+        let mask = InputMask::new("(999) 999-9999").unwrap();
+
+        assert_eq!("5551234567", mask.raw_from("(555) 123-4567", 0));
+        assert_eq!("5551234567", mask.raw_from("555.123.4567.89", 0));
+        assert_eq!("4567", mask.raw_from("4567.89", 6));
+    }
+
+    #[test]
+    fn display_maps_the_cursor_to_the_formatted_value() {
+        let mask = InputMask::new("(999) 999-9999").unwrap();
+
+        let (display, remainder) = mask.display(&Input::new_with("5551"));
+        assert_eq!("(555) 1", display.content());
+        assert_eq!(7, display.cursor());
+        assert_eq!("__-____", remainder);
+
+        let (display, _) = mask.display(&Input::new_with("5551").with_cursor(3));
+        assert_eq!(6, display.cursor());
+
+        let (display, remainder) = mask.display(&Input::new_with(""));
+        assert_eq!("(", display.content());
+        assert_eq!("___) ___-____", remainder);
+
+        assert_eq!("(___) ___-____", mask.template());
+    }
+}
@@ -0,0 +1,220 @@
+//! Filesystem helpers shared by [`PathCompleter`](crate::autocompletion::PathCompleter)
+//! and [`PathSelect`](crate::PathSelect).
+
+use std::{
+    ffi::OsStr,
+    fmt, fs, io,
+    path::{Component, Path, PathBuf, MAIN_SEPARATOR},
+};
+
+/// Entry of a directory, as listed to the user.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PathEntry {
+    pub name: String,
+    pub is_dir: bool,
+}
+
+impl fmt::Display for PathEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.is_dir {
+            true => write!(f, "{}{}", self.name, MAIN_SEPARATOR),
+            false => f.write_str(&self.name),
+        }
+    }
+}
+
+/// Criteria of the entries listed to the user.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct PathFilter {
+    pub show_hidden: bool,
+    pub directories_only: bool,
+    /// Extensions of the files listed, without their leading dot, all files
+    /// being listed if empty.
+    pub extensions: Vec<String>,
+}
+
+impl PathFilter {
+    pub fn with_extensions(mut self, extensions: &[&str]) -> Self {
+        self.extensions = extensions
+            .iter()
+            .map(|ext| ext.trim_start_matches('.').to_owned())
+            .collect();
+        self
+    }
+
+    /// Whether the entry is listed. Directories are listed regardless of the
+    /// extensions, as the files may be in them.
+    pub fn accepts(&self, name: &str, is_dir: bool) -> bool {
+        if !self.show_hidden && name.starts_with('.') {
+            return false;
+        }
+        if is_dir {
+            return true;
+        }
+        if self.directories_only {
+            return false;
+        }
+
+        self.extensions.is_empty()
+            || Path::new(name)
+                .extension()
+                .and_then(OsStr::to_str)
+                .map(|ext| self.extensions.iter().any(|e| e.eq_ignore_ascii_case(ext)))
+                .unwrap_or(false)
+    }
+}
+
+/// Entries of a directory accepted by the filter, directories first, then
+/// sorted by name ignoring case. Entries whose name is not valid UTF-8 are
+/// skipped.
+pub(crate) fn read_entries(dir: &Path, filter: &PathFilter) -> io::Result<Vec<PathEntry>> {
+    let mut entries = vec![];
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let Ok(name) = entry.file_name().into_string() else {
+            continue;
+        };
+        // symbolic links are followed, listing links to directories as such.
+        let is_dir = entry.path().is_dir();
+
+        if filter.accepts(&name, is_dir) {
+            entries.push(PathEntry { name, is_dir });
+        }
+    }
+
+    entries.sort_by_cached_key(|entry| (!entry.is_dir, entry.name.to_lowercase()));
+
+    Ok(entries)
+}
+
+/// Home directory of the current user, if known.
+pub(crate) fn home_dir() -> Option<PathBuf> {
+    #[cfg(windows)]
+    let var = "USERPROFILE";
+    #[cfg(not(windows))]
+    let var = "HOME";
+
+    std::env::var_os(var)
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Replaces a leading `~` by the home directory of the current user.
+pub(crate) fn expand_tilde(path: &str) -> PathBuf {
+    let rest = match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(std::path::is_separator) => rest,
+        _ => return PathBuf::from(path),
+    };
+
+    match home_dir() {
+        Some(home) => home.join(rest.trim_start_matches(std::path::is_separator)),
+        None => PathBuf::from(path),
+    }
+}
+
+/// Absolute version of the path, relative to the current directory, with its
+/// `.` and `..` components resolved without following symbolic links.
+pub(crate) fn absolute(path: &Path) -> io::Result<PathBuf> {
+    let path = match path.is_absolute() {
+        true => path.to_path_buf(),
+        false => std::env::current_dir()?.join(path),
+    };
+
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use super::{absolute, expand_tilde, home_dir, read_entries, PathEntry, PathFilter};
+
+    fn entry(name: &str, is_dir: bool) -> PathEntry {
+        PathEntry {
+            name: name.to_owned(),
+            is_dir,
+        }
+    }
+
+    #[test]
+    fn entries_are_filtered_and_sorted() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::create_dir(dir.path().join(".git")).unwrap();
+        fs::write(dir.path().join("README.md"), "").unwrap();
+        fs::write(dir.path().join("logo.PNG"), "").unwrap();
+        fs::write(dir.path().join(".env"), "").unwrap();
+
+        let entries = read_entries(dir.path(), &PathFilter::default()).unwrap();
+        assert_eq!(
+            vec![
+                entry("src", true),
+                entry("logo.PNG", false),
+                entry("README.md", false)
+            ],
+            entries
+        );
+
+        let filter = PathFilter {
+            show_hidden: true,
+            ..PathFilter::default()
+        }
+        .with_extensions(&[".png"]);
+        let entries = read_entries(dir.path(), &filter).unwrap();
+        assert_eq!(
+            vec![
+                entry(".git", true),
+                entry("src", true),
+                entry("logo.PNG", false)
+            ],
+            entries
+        );
+
+        let filter = PathFilter {
+            directories_only: true,
+            ..PathFilter::default()
+        };
+        let entries = read_entries(dir.path(), &filter).unwrap();
+        assert_eq!(vec![entry("src", true)], entries);
+    }
+
+    #[test]
+    fn tilde_is_expanded_to_the_home_directory() {
+        assert_eq!(PathBuf::from("~user/a"), expand_tilde("~user/a"));
+        assert_eq!(PathBuf::from("a/~"), expand_tilde("a/~"));
+
+        if let Some(home) = home_dir() {
+            assert_eq!(home, expand_tilde("~"));
+            assert_eq!(home.join("a"), expand_tilde("~/a"));
+        }
+    }
+
+    #[test]
+    fn absolute_paths_are_normalized() {
+        let root = Path::new(if cfg!(windows) { "C:\\" } else { "/" });
+
+        assert_eq!(
+            root.join("a").join("c"),
+            absolute(&root.join("a").join(".").join("b").join("..").join("c")).unwrap()
+        );
+        assert_eq!(
+            std::env::current_dir().unwrap().join("a"),
+            absolute(Path::new("a")).unwrap()
+        );
+    }
+}
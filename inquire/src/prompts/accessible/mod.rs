@@ -0,0 +1,676 @@
+//! Line-based flows of the prompts in the accessible mode, see
+//! [`set_global_accessible_mode`](crate::set_global_accessible_mode).
+
+#[cfg(test)]
+mod test;
+
+use std::{fmt::Display, io::Write, path::PathBuf};
+
+use crate::{
+    error::{InquireError, InquireResult},
+    list_option::ListOption,
+    path_utils::{absolute, expand_tilde},
+    prompts::path_select::validate_selection,
+    type_aliases::OptionHelp,
+    ui::{AccessibleBackend, LineReader, Output, RenderConfig},
+    validator::{ErrorMessage, Validation},
+    Confirm, CustomType, MultiSelect, Password, PasswordDisplayMode, PathSelect, Select, Text,
+};
+
+/// Prompt that can be answered in the accessible mode.
+pub(crate) trait AccessiblePrompt: Sized {
+    type Output;
+
+    fn output(&self) -> &Output;
+
+    fn prompt_accessible<R, W>(
+        self,
+        backend: &mut AccessibleBackend<R, W>,
+    ) -> InquireResult<Self::Output>
+    where
+        R: LineReader,
+        W: Write;
+}
+
+/// Prompts `prompt` in the accessible mode, reading answers from the standard
+/// input and printing to the output of the prompt.
+pub(crate) fn prompt<P>(prompt: P) -> InquireResult<P::Output>
+where
+    P: AccessiblePrompt,
+{
+    let mut backend = AccessibleBackend::new(prompt.output())?;
+    prompt.prompt_accessible(&mut backend)
+}
+
+/// Prints the question of a prompt, with hints on how to answer it between
+/// parentheses, followed by its help message, if any.
+fn print_question<R, W>(
+    backend: &mut AccessibleBackend<R, W>,
+    message: &str,
+    hints: &[String],
+    help_message: Option<&str>,
+) -> InquireResult<()>
+where
+    R: LineReader,
+    W: Write,
+{
+    let question = match hints.is_empty() {
+        true => message.to_owned(),
+        false => format!("{} ({})", message, hints.join(", ")),
+    };
+    backend.print_line(&question)?;
+
+    if let Some(help_message) = help_message {
+        backend.print_line(help_message)?;
+    }
+
+    Ok(())
+}
+
+/// Prints the options of a list, numbered from 1.
+fn print_options<R, W, T>(
+    backend: &mut AccessibleBackend<R, W>,
+    options: &[T],
+    option_help: Option<OptionHelp<'_, T>>,
+) -> InquireResult<()>
+where
+    R: LineReader,
+    W: Write,
+    T: Display,
+{
+    for (index, option) in options.iter().enumerate() {
+        let help = option_help.and_then(|option_help| option_help(option));
+        let line = match help {
+            Some(help) => format!("{}. {} ({})", index + 1, option, help),
+            None => format!("{}. {}", index + 1, option),
+        };
+        backend.print_line(&line)?;
+    }
+
+    Ok(())
+}
+
+/// Help message of a prompt, unless it is the default one, which describes
+/// the key bindings of the interactive mode.
+fn custom_help_message<'a>(
+    help_message: Option<&'a str>,
+    default: Option<&str>,
+) -> Option<&'a str> {
+    help_message.filter(|help| Some(*help) != default)
+}
+
+/// Finds the option typed by the user, either by its number, starting from
+/// 1, or by its value, ignoring case.
+fn find_option<T>(options: &[T], input: &str) -> Option<usize>
+where
+    T: Display,
+{
+    let input = input.trim();
+
+    if let Ok(number) = input.parse::<usize>() {
+        return number.checked_sub(1).filter(|index| *index < options.len());
+    }
+
+    options
+        .iter()
+        .position(|option| option.to_string().to_lowercase() == input.to_lowercase())
+}
+
+/// Hint of text inputs in multi-line mode, whose answers end with an empty
+/// line.
+fn multiline_hint(multiline: bool) -> impl Iterator<Item = String> {
+    multiline
+        .then(|| String::from("end with an empty line"))
+        .into_iter()
+}
+
+/// Reads the answer of a text input, spanning multiple lines in multi-line
+/// mode.
+fn read_text_answer<R, W>(
+    backend: &mut AccessibleBackend<R, W>,
+    multiline: bool,
+) -> InquireResult<String>
+where
+    R: LineReader,
+    W: Write,
+{
+    match multiline {
+        true => backend.read_multiline_answer(),
+        false => backend.read_answer(),
+    }
+}
+
+fn error_message(message: ErrorMessage, render_config: &RenderConfig<'_>) -> String {
+    match message {
+        ErrorMessage::Default => render_config.error_message.default_message.to_owned(),
+        ErrorMessage::Custom(message) => message,
+    }
+}
+
+/// Runs validators until one of them rejects the answer.
+fn validate<V, F>(validators: &[V], mut validate: F) -> InquireResult<Validation>
+where
+    F: FnMut(&V) -> Result<Validation, crate::CustomUserError>,
+{
+    for validator in validators {
+        match validate(validator) {
+            Ok(Validation::Valid) => {}
+            Ok(invalid) => return Ok(invalid),
+            Err(err) => return Err(InquireError::Custom(err)),
+        }
+    }
+
+    Ok(Validation::Valid)
+}
+
+impl<'a> AccessiblePrompt for Text<'a> {
+    type Output = String;
+
+    fn output(&self) -> &Output {
+        &self.output
+    }
+
+    fn prompt_accessible<R, W>(self, backend: &mut AccessibleBackend<R, W>) -> InquireResult<String>
+    where
+        R: LineReader,
+        W: Write,
+    {
+        let default = self.default.or(self.initial_value);
+        let hints: Vec<String> = multiline_hint(self.multiline)
+            .chain(default.map(|default| format!("default: {}", default)))
+            .collect();
+        print_question(backend, self.message, &hints, self.help_message)?;
+
+        let answer = loop {
+            let input = read_text_answer(backend, self.multiline)?;
+
+            // as in the interactive mode, defaults skip the validators
+            if let (Some(default), true) = (self.default, input.is_empty()) {
+                break default.to_owned();
+            }
+            let answer = match self.initial_value {
+                Some(initial_value) if input.is_empty() => initial_value.to_owned(),
+                _ => input,
+            };
+
+            match validate(&self.validators, |v| v.validate(&answer))? {
+                Validation::Valid => break answer,
+                Validation::Invalid(msg) => {
+                    backend.print_error(&error_message(msg, &self.render_config))?;
+                }
+            }
+        };
+
+        if let Some(history) = &self.history {
+            history.push(&answer)?;
+        }
+
+        backend.print_answer(&(self.formatter)(&answer))?;
+        Ok(answer)
+    }
+}
+
+impl<'a> AccessiblePrompt for Password<'a> {
+    type Output = String;
+
+    fn output(&self) -> &Output {
+        &self.output
+    }
+
+    fn prompt_accessible<R, W>(self, backend: &mut AccessibleBackend<R, W>) -> InquireResult<String>
+    where
+        R: LineReader,
+        W: Write,
+    {
+        let read = |backend: &mut AccessibleBackend<R, W>| match self.display_mode {
+            PasswordDisplayMode::Full => backend.read_answer(),
+            PasswordDisplayMode::Hidden | PasswordDisplayMode::Masked => {
+                backend.read_hidden_answer()
+            }
+        };
+
+        let hints = match self.display_mode {
+            PasswordDisplayMode::Full => vec![],
+            PasswordDisplayMode::Hidden | PasswordDisplayMode::Masked => {
+                vec![String::from("input is hidden")]
+            }
+        };
+        print_question(backend, self.message, &hints, self.help_message)?;
+
+        let answer = loop {
+            let answer = read(backend)?;
+
+            if let Validation::Invalid(msg) = validate(&self.validators, |v| v.validate(&answer))? {
+                backend.print_error(&error_message(msg, &self.render_config))?;
+                continue;
+            }
+
+            if !self.enable_confirmation {
+                break answer;
+            }
+
+            backend.print_line(self.custom_confirmation_message.unwrap_or("Confirmation:"))?;
+            if read(backend)? == answer {
+                break answer;
+            }
+
+            backend.print_error(
+                self.custom_confirmation_error_message
+                    .unwrap_or("The answers don't match."),
+            )?;
+            // the password is asked again, followed by its confirmation
+            print_question(backend, self.message, &hints, None)?;
+        };
+
+        backend.print_answer(&(self.formatter)(&answer))?;
+        Ok(answer)
+    }
+}
+
+impl<'a> AccessiblePrompt for Confirm<'a> {
+    type Output = bool;
+
+    fn output(&self) -> &Output {
+        &self.output
+    }
+
+    fn prompt_accessible<R, W>(self, backend: &mut AccessibleBackend<R, W>) -> InquireResult<bool>
+    where
+        R: LineReader,
+        W: Write,
+    {
+        let hint = match self.default {
+            Some(default) => (self.default_value_formatter)(default),
+            None => String::from("y/n"),
+        };
+        print_question(backend, self.message, &[hint], self.help_message)?;
+
+        let answer = loop {
+            let input = backend.read_answer()?;
+
+            let parsed = match (self.default, self.starting_input) {
+                (Some(default), _) if input.is_empty() => Ok(default),
+                (None, Some(starting_input)) if input.is_empty() => (self.parser)(starting_input),
+                _ => (self.parser)(input.trim()),
+            };
+
+            match parsed {
+                Ok(answer) => break answer,
+                Err(()) => backend.print_error(&self.error_message)?,
+            }
+        };
+
+        backend.print_answer(&(self.formatter)(answer))?;
+        Ok(answer)
+    }
+}
+
+impl<'a, T> AccessiblePrompt for CustomType<'a, T>
+where
+    T: Clone,
+{
+    type Output = T;
+
+    fn output(&self) -> &Output {
+        &self.output
+    }
+
+    fn prompt_accessible<R, W>(self, backend: &mut AccessibleBackend<R, W>) -> InquireResult<T>
+    where
+        R: LineReader,
+        W: Write,
+    {
+        let default = self.default.as_ref().map(|default| {
+            format!(
+                "default: {}",
+                (self.default_value_formatter)(default.clone())
+            )
+        });
+        let format = self
+            .mask
+            .as_ref()
+            .map(|mask| format!("format: {}", mask.template()));
+        let hints: Vec<String> = multiline_hint(self.multiline)
+            .chain(format)
+            .chain(default)
+            .collect();
+        print_question(backend, self.message, &hints, self.help_message)?;
+
+        let (answer, input) = loop {
+            let input = read_text_answer(backend, self.multiline)?;
+            let input = match self.starting_input {
+                Some(starting_input) if input.is_empty() => starting_input.to_owned(),
+                _ => input,
+            };
+            // masked inputs are recorded in the history as typed in the slots.
+            let input = match &self.mask {
+                Some(mask) => mask.raw_from(input.trim(), 0),
+                None => input,
+            };
+
+            let parsed = match (&self.default, &self.mask) {
+                (Some(default), _) if input.is_empty() => Ok(default.clone()),
+                (_, Some(mask)) if !mask.is_complete(&input) => Err(()),
+                (_, Some(mask)) => (self.parser)(&mask.value(&input)),
+                (_, None) => (self.parser)(input.trim()),
+            };
+            let Ok(answer) = parsed else {
+                backend.print_error(&self.error_message)?;
+                continue;
+            };
+
+            match validate(&self.validators, |v| v.validate(&answer))? {
+                Validation::Valid => break (answer, input),
+                Validation::Invalid(msg) => {
+                    backend.print_error(&error_message(msg, &self.render_config))?;
+                }
+            }
+        };
+
+        if let Some(history) = &self.history {
+            history.push(&input)?;
+        }
+
+        backend.print_answer(&(self.formatter)(answer.clone()))?;
+        Ok(answer)
+    }
+}
+
+impl<'a, T> AccessiblePrompt for Select<'a, T>
+where
+    T: Display,
+{
+    type Output = ListOption<T>;
+
+    fn output(&self) -> &Output {
+        &self.output
+    }
+
+    fn prompt_accessible<R, W>(
+        mut self,
+        backend: &mut AccessibleBackend<R, W>,
+    ) -> InquireResult<ListOption<T>>
+    where
+        R: LineReader,
+        W: Write,
+    {
+        if self.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+        if self.starting_cursor >= self.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor index {} is out-of-bounds for length {} of options",
+                self.starting_cursor,
+                self.options.len()
+            )));
+        }
+
+        let hints = [
+            String::from("type the number or the value of an option"),
+            format!("default: {}", self.starting_cursor + 1),
+        ];
+        let help_message =
+            custom_help_message(self.help_message, Select::<T>::DEFAULT_HELP_MESSAGE);
+        print_question(backend, self.message, &hints, help_message)?;
+        print_options(backend, &self.options, self.option_help)?;
+
+        let index = loop {
+            let input = backend.read_answer()?;
+            if input.trim().is_empty() {
+                break self.starting_cursor;
+            }
+
+            match find_option(&self.options, &input) {
+                Some(index) => break index,
+                None => backend.print_error(&format!("No option matches \"{}\".", input.trim()))?,
+            }
+        };
+
+        let value = self.options.swap_remove(index);
+        let answer = ListOption::new(index, value);
+
+        backend.print_answer(&(self.formatter)(answer.as_ref()))?;
+        Ok(answer)
+    }
+}
+
+impl<'a, T> AccessiblePrompt for MultiSelect<'a, T>
+where
+    T: Display,
+{
+    type Output = Vec<ListOption<T>>;
+
+    fn output(&self) -> &Output {
+        &self.output
+    }
+
+    fn prompt_accessible<R, W>(
+        self,
+        backend: &mut AccessibleBackend<R, W>,
+    ) -> InquireResult<Vec<ListOption<T>>>
+    where
+        R: LineReader,
+        W: Write,
+    {
+        if self.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+        let default = self.default.clone().unwrap_or_default();
+        if let Some(index) = default.iter().find(|index| **index >= self.options.len()) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Index {} is out-of-bounds for length {} of options",
+                index,
+                self.options.len()
+            )));
+        }
+
+        let mut hints = vec![String::from(
+            "type the numbers or the values of the options, separated by commas",
+        )];
+        if !default.is_empty() {
+            let numbers: Vec<String> = default
+                .iter()
+                .map(|index| (index + 1).to_string())
+                .collect();
+            hints.push(format!("default: {}", numbers.join(", ")));
+        }
+        let help_message =
+            custom_help_message(self.help_message, MultiSelect::<T>::DEFAULT_HELP_MESSAGE);
+        print_question(backend, self.message, &hints, help_message)?;
+        print_options(backend, &self.options, self.option_help)?;
+
+        let indexes = loop {
+            let input = backend.read_answer()?;
+
+            let mut indexes = match parse_selection(&self.options, &input) {
+                _ if input.trim().is_empty() => default.clone(),
+                Ok(indexes) => indexes,
+                Err(unknown) => {
+                    backend.print_error(&format!("No option matches \"{}\".", unknown))?;
+                    continue;
+                }
+            };
+            indexes.sort_unstable();
+            indexes.dedup();
+
+            let Some(validator) = &self.validator else {
+                break indexes;
+            };
+            let selected: Vec<ListOption<&T>> = indexes
+                .iter()
+                .map(|index| ListOption::new(*index, &self.options[*index]))
+                .collect();
+            match validator.validate(&selected) {
+                Ok(Validation::Valid) => break indexes,
+                Ok(Validation::Invalid(msg)) => {
+                    backend.print_error(&error_message(msg, &self.render_config))?;
+                }
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        };
+
+        let selected: Vec<ListOption<&T>> = indexes
+            .iter()
+            .map(|index| ListOption::new(*index, &self.options[*index]))
+            .collect();
+        backend.print_answer(&(self.formatter)(&selected))?;
+
+        let answer = self
+            .options
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| indexes.contains(index))
+            .map(|(index, value)| ListOption::new(index, value))
+            .collect();
+        Ok(answer)
+    }
+}
+
+impl<'a> AccessiblePrompt for PathSelect<'a> {
+    type Output = PathBuf;
+
+    fn output(&self) -> &Output {
+        &self.output
+    }
+
+    fn prompt_accessible<R, W>(
+        self,
+        backend: &mut AccessibleBackend<R, W>,
+    ) -> InquireResult<PathBuf>
+    where
+        R: LineReader,
+        W: Write,
+    {
+        let directory = self.resolve_starting_directory()?;
+        let filter = self.path_filter();
+
+        let hints = [format!("relative to {}", directory.display())];
+        let help_message = custom_help_message(self.help_message, PathSelect::DEFAULT_HELP_MESSAGE);
+        print_question(backend, self.message, &hints, help_message)?;
+
+        let answer = loop {
+            let input = backend.read_answer()?;
+            let path = absolute(&directory.join(expand_tilde(input.trim())))?;
+
+            match validate_selection(&path, self.selection_mode, &filter, self.writable_only) {
+                Ok(()) => break path,
+                Err(message) => backend.print_error(&message)?,
+            }
+        };
+
+        backend.print_answer(&(self.formatter)(&answer))?;
+        Ok(answer)
+    }
+}
+
+/// Indexes of the options typed by the user, separated by commas, or the
+/// first one not matching any option.
+fn parse_selection<'i, T>(options: &[T], input: &'i str) -> Result<Vec<usize>, &'i str>
+where
+    T: Display,
+{
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|option| !option.is_empty())
+        .map(|option| find_option(options, option).ok_or(option))
+        .collect()
+}
+
+#[cfg(feature = "date")]
+mod date {
+    use std::io::Write;
+
+    use time::{macros::format_description, Date};
+
+    use crate::{
+        error::InquireResult,
+        ui::{AccessibleBackend, LineReader, Output},
+        validator::Validation,
+        DateSelect,
+    };
+
+    use super::{custom_help_message, error_message, print_question, validate, AccessiblePrompt};
+
+    fn iso(date: Date) -> String {
+        date.format(format_description!("[year]-[month]-[day]"))
+            .unwrap_or_else(|_| date.to_string())
+    }
+
+    fn parse_iso(input: &str) -> Option<Date> {
+        Date::parse(input, format_description!("[year]-[month]-[day]")).ok()
+    }
+
+    impl<'a> AccessiblePrompt for DateSelect<'a> {
+        type Output = Date;
+
+        fn output(&self) -> &Output {
+            &self.output
+        }
+
+        /// Dates are typed in the `YYYY-MM-DD` format, the calendar not being
+        /// displayed.
+        fn prompt_accessible<R, W>(
+            self,
+            backend: &mut AccessibleBackend<R, W>,
+        ) -> InquireResult<Date>
+        where
+            R: LineReader,
+            W: Write,
+        {
+            let mut hints = vec![
+                String::from("YYYY-MM-DD"),
+                format!("default: {}", iso(self.starting_date)),
+            ];
+            match (self.min_date, self.max_date) {
+                (Some(min), Some(max)) => {
+                    hints.push(format!("from {} to {}", iso(min), iso(max)));
+                }
+                (Some(min), None) => hints.push(format!("from {}", iso(min))),
+                (None, Some(max)) => hints.push(format!("until {}", iso(max))),
+                (None, None) => {}
+            }
+            let help_message =
+                custom_help_message(self.help_message, DateSelect::DEFAULT_HELP_MESSAGE);
+            print_question(backend, self.message, &hints, help_message)?;
+
+            let answer = loop {
+                let input = backend.read_answer()?;
+
+                let date = match input.trim() {
+                    "" => self.starting_date,
+                    input => {
+                        let Some(date) = parse_iso(input) else {
+                            backend.print_error("Invalid date, type it as YYYY-MM-DD.")?;
+                            continue;
+                        };
+                        date
+                    }
+                };
+
+                if let Some(min) = self.min_date.filter(|min| date < *min) {
+                    backend.print_error(&format!("The date must be {} or later.", iso(min)))?;
+                    continue;
+                }
+                if let Some(max) = self.max_date.filter(|max| date > *max) {
+                    backend.print_error(&format!("The date must be {} or earlier.", iso(max)))?;
+                    continue;
+                }
+
+                match validate(&self.validators, |v| v.validate(date))? {
+                    Validation::Valid => break date,
+                    Validation::Invalid(msg) => {
+                        backend.print_error(&error_message(msg, &self.render_config))?;
+                    }
+                }
+            };
+
+            backend.print_answer(&(self.formatter)(answer))?;
+            Ok(answer)
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::VecDeque;
+
+use crate::{
+    error::{InquireError, InquireResult},
+    list_option::ListOption,
+    mask::InputMask,
+    ui::AccessibleBackend,
+    validator::{Validation, ValueRequiredValidator},
+    Confirm, CustomType, MultiSelect, Password, PasswordDisplayMode, PathSelect, Select, Text,
+};
+
+use super::AccessiblePrompt;
+
+/// Answers `prompt` with the given lines, returning its result along with
+/// everything it printed.
+fn run<P>(prompt: P, lines: &[&str]) -> (InquireResult<P::Output>, String)
+where
+    P: AccessiblePrompt,
+{
+    let lines: VecDeque<String> = lines.iter().map(|line| (*line).to_string()).collect();
+    let mut backend = AccessibleBackend::with_reader_and_writer(lines, Vec::new());
+
+    let result = prompt.prompt_accessible(&mut backend);
+    let printed = String::from_utf8(backend.writer().clone()).unwrap();
+
+    (result, printed)
+}
+
+#[test]
+fn text_errors_are_printed_as_lines() {
+    let prompt = Text::new("What's your name?").with_validator(ValueRequiredValidator::default());
+
+    let (result, printed) = run(prompt, &["", "Ada"]);
+
+    assert_eq!("Ada", result.unwrap());
+    assert_eq!(
+        "What's your name?\n\
+         Error: A response is required.\n\
+         Answer: Ada\n",
+        printed
+    );
+}
+
+#[test]
+fn text_default_is_submitted_with_empty_line() {
+    let prompt = Text::new("Where?")
+        .with_default("home")
+        .with_help_message("Your location");
+
+    let (result, printed) = run(prompt, &[""]);
+
+    assert_eq!("home", result.unwrap());
+    assert_eq!(
+        "Where? (default: home)\nYour location\nAnswer: home\n",
+        printed
+    );
+}
+
+#[test]
+fn escape_line_cancels_prompt() {
+    let (result, _) = run(Text::new("Name?"), &["\x1b"]);
+    assert!(matches!(result, Err(InquireError::OperationCanceled)));
+
+    let (result, _) = run(Text::new("Name?"), &[]);
+    assert!(matches!(result, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn select_options_are_numbered_and_picked_by_number_or_value() {
+    let options = vec!["Banana", "Apple", "Cherry"];
+
+    let (result, printed) = run(Select::new("Fruit?", options.clone()), &["4", "apple"]);
+    assert_eq!(ListOption::new(1, "Apple"), result.unwrap());
+    assert_eq!(
+        "Fruit? (type the number or the value of an option, default: 1)\n\
+         1. Banana\n\
+         2. Apple\n\
+         3. Cherry\n\
+         Error: No option matches \"4\".\n\
+         Answer: Apple\n",
+        printed
+    );
+
+    let (result, _) = run(Select::new("Fruit?", options.clone()), &["3"]);
+    assert_eq!(ListOption::new(2, "Cherry"), result.unwrap());
+
+    let prompt = Select::new("Fruit?", options).with_starting_cursor(1);
+    let (result, _) = run(prompt, &[""]);
+    assert_eq!(ListOption::new(1, "Apple"), result.unwrap());
+}
+
+#[test]
+fn select_with_no_options_is_rejected() {
+    let (result, printed) = run(Select::<&str>::new("Fruit?", vec![]), &[]);
+
+    assert!(matches!(result, Err(InquireError::InvalidConfiguration(_))));
+    assert_eq!("", printed);
+}
+
+#[test]
+fn multiselect_options_are_separated_by_commas() {
+    let options = vec!["Banana", "Apple", "Cherry"];
+
+    let (result, printed) = run(
+        MultiSelect::new("Fruits?", options.clone()),
+        &["1, kiwi", "cherry, 1"],
+    );
+    assert_eq!(
+        vec![ListOption::new(0, "Banana"), ListOption::new(2, "Cherry")],
+        result.unwrap()
+    );
+    assert!(printed.contains("Error: No option matches \"kiwi\".\n"));
+    assert!(printed.ends_with("Answer: Banana, Cherry\n"));
+
+    let prompt = MultiSelect::new("Fruits?", options).with_default(&[1]);
+    let (result, printed) = run(prompt, &[""]);
+    assert_eq!(vec![ListOption::new(1, "Apple")], result.unwrap());
+    assert!(printed.starts_with(
+        "Fruits? (type the numbers or the values of the options, separated by commas, default: 2)\n"
+    ));
+}
+
+#[test]
+fn multiselect_validator_errors_are_printed() {
+    let prompt = MultiSelect::new("Fruits?", vec!["Banana", "Apple", "Cherry"]).with_validator(
+        |answer: &[ListOption<&&str>]| match answer.len() {
+            2 => Ok(Validation::Valid),
+            _ => Ok(Validation::Invalid("Pick two fruits.".into())),
+        },
+    );
+
+    let (result, printed) = run(prompt, &["1", "1,2"]);
+
+    assert_eq!(2, result.unwrap().len());
+    assert!(printed.contains("Error: Pick two fruits.\n"));
+}
+
+#[test]
+fn confirm_answers_are_parsed() {
+    let (result, printed) = run(Confirm::new("Continue?"), &["maybe", "y"]);
+
+    assert!(result.unwrap());
+    assert_eq!(
+        format!(
+            "Continue? (y/n)\nError: {}\nAnswer: Yes\n",
+            Confirm::DEFAULT_ERROR_MESSAGE
+        ),
+        printed
+    );
+
+    let (result, _) = run(Confirm::new("Continue?").with_default(false), &[""]);
+    assert!(!result.unwrap());
+}
+
+#[test]
+fn custom_type_answers_are_parsed() {
+    let prompt = CustomType::<u8>::new("Age?").with_error_message("Type a number.");
+
+    let (result, printed) = run(prompt, &["old", "42"]);
+
+    assert_eq!(42, result.unwrap());
+    assert_eq!("Age?\nError: Type a number.\nAnswer: 42\n", printed);
+}
+
+#[test]
+fn custom_type_masks_are_applied_to_answers() {
+    let prompt = CustomType::<String>::new("Phone?")
+        .with_mask(InputMask::new("(999) 999-9999").unwrap())
+        .with_error_message("Type a full number.");
+
+    let (result, printed) = run(prompt, &["555 123", "555.123.4567"]);
+
+    assert_eq!("(555) 123-4567", result.unwrap());
+    assert_eq!(
+        "Phone? (format: (___) ___-____)\n\
+         Error: Type a full number.\n\
+         Answer: (555) 123-4567\n",
+        printed
+    );
+}
+
+#[test]
+fn password_is_asked_again_when_confirmation_differs() {
+    let prompt = Password::new("Password:").with_display_mode(PasswordDisplayMode::Hidden);
+
+    let (result, printed) = run(prompt, &["secret", "typo", "secret", "secret"]);
+
+    assert_eq!("secret", result.unwrap());
+    assert_eq!(
+        "Password: (input is hidden)\n\
+         \n\
+         Confirmation:\n\
+         \n\
+         Error: The answers don't match.\n\
+         Password: (input is hidden)\n\
+         \n\
+         Confirmation:\n\
+         \n\
+         Answer: ********\n",
+        printed
+    );
+}
+
+#[cfg(feature = "date")]
+#[test]
+fn dates_are_typed() {
+    use time::macros::date;
+
+    let prompt = crate::DateSelect::new("Date?")
+        .with_starting_date(date!(2024 - 03 - 10))
+        .with_min_date(date!(2024 - 01 - 01));
+
+    let (result, printed) = run(prompt, &["10/03/2024", "2023-12-31", "2024-02-29"]);
+
+    assert_eq!(date!(2024 - 02 - 29), result.unwrap());
+    assert_eq!(
+        "Date? (YYYY-MM-DD, default: 2024-03-10, from 2024-01-01)\n\
+         Error: Invalid date, type it as YYYY-MM-DD.\n\
+         Error: The date must be 2024-01-01 or later.\n\
+         Answer: 29-02-2024\n",
+        printed
+    );
+}
+
+#[test]
+fn multiline_answers_end_with_an_empty_line() {
+    let prompt = Text::new("Bio?").with_multiline();
+
+    let (result, printed) = run(prompt, &["Rust developer.", "Likes cats.", ""]);
+
+    assert_eq!("Rust developer.\nLikes cats.", result.unwrap());
+    assert_eq!(
+        "Bio? (end with an empty line)\nAnswer: Rust developer.\nLikes cats.\n",
+        printed
+    );
+}
+
+#[test]
+fn path_select_reads_paths_relative_to_starting_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("docs")).unwrap();
+    std::fs::write(dir.path().join("notes.txt"), "").unwrap();
+
+    let prompt = PathSelect::new("File:").with_starting_directory(dir.path());
+
+    let (result, printed) = run(prompt, &["missing.txt", "docs", "docs/../notes.txt"]);
+
+    let directory = dir.path().display();
+    assert_eq!(dir.path().join("notes.txt"), result.unwrap());
+    assert_eq!(
+        format!(
+            "File: (relative to {0})\n\
+             Error: {0}/missing.txt does not exist.\n\
+             Error: {0}/docs is a directory.\n\
+             Answer: {0}/notes.txt\n",
+            directory
+        ),
+        printed
+    );
+}
@@ -3,7 +3,10 @@
 
 use std::fmt::Debug;
 
-use crate::ui::{Key, KeyModifiers};
+use crate::{
+    keymap::{Command, KeyMap, KeyScope, DEFAULT_KEY_MAP},
+    ui::Key,
+};
 
 /// Top-level type to describe the directives a prompt
 /// receives.
@@ -11,70 +14,132 @@ use crate::ui::{Key, KeyModifiers};
 /// Each prompt should implement its own custom InnerAction type
 /// which is parsed and stored in the Inner variant, if applicable,
 /// on the normal execution flow of a prompt.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Action<I>
 where
-    I: Copy + Clone + PartialEq + Eq,
+    I: Clone + PartialEq + Eq,
 {
     /// Submits the current prompt answer, finishing the prompt if valid.
     Submit,
+    /// Submits the current prompt answer as typed, ignoring any highlighted
+    /// suggestion, finishing the prompt if valid.
+    ForceSubmit,
     /// Cancels the prompt execution with a graceful shutdown.
     Cancel,
     /// Interrupts the prompt execution without a graceful shutdown.
     Interrupt,
+    /// Shows or hides the key bindings of the prompt.
+    ToggleHelp,
+    /// Leaves the prompt to return to the previous step of a form.
+    Back,
     /// Specialized actions according to the prompt type.
     Inner(I),
 }
 
 impl<I> Action<I>
 where
-    I: Copy + Clone + PartialEq + Eq,
+    I: Clone + PartialEq + Eq,
 {
-    /// Derives a prompt action from a Key event.
+    /// Derives a prompt action from a Key event, using the default key map.
     pub fn from_key<C>(key: Key, config: &C) -> Option<Action<I>>
     where
         I: InnerAction<Config = C>,
     {
-        match key {
-            Key::Enter
-            | Key::Char('\n', KeyModifiers::NONE)
-            | Key::Char('j', KeyModifiers::CONTROL) => Some(Action::Submit),
-            Key::Escape
-            | Key::Char('g', KeyModifiers::CONTROL)
-            | Key::Char('d', KeyModifiers::CONTROL) => Some(Action::Cancel),
-            Key::Char('c', KeyModifiers::CONTROL) => Some(Action::Interrupt),
-            key => I::from_key(key, config).map(Action::Inner),
+        Self::from_key_with_map(key, config, &DEFAULT_KEY_MAP)
+    }
+
+    /// Derives a prompt action from a Key event, according to the bindings
+    /// of the key map in the [`KeyScope::All`] scope and in the scopes of
+    /// the inner action.
+    pub fn from_key_with_map<C>(key: Key, config: &C, key_map: &KeyMap) -> Option<Action<I>>
+    where
+        I: InnerAction<Config = C>,
+    {
+        let mut scopes = vec![KeyScope::All];
+        scopes.extend_from_slice(I::key_scopes(config));
+
+        for command in key_map.commands(&key, &scopes) {
+            let action = match command {
+                Command::Submit => Action::Submit,
+                Command::ForceSubmit => Action::ForceSubmit,
+                Command::Cancel => Action::Cancel,
+                Command::Interrupt => Action::Interrupt,
+                Command::Help => Action::ToggleHelp,
+                Command::Back => Action::Back,
+                Command::Ignore => return None,
+                command => match I::from_command(command, config) {
+                    Some(action) => Action::Inner(action),
+                    None => continue,
+                },
+            };
+
+            return Some(action);
         }
+
+        I::from_unbound_key(key, config).map(Action::Inner)
     }
 }
 
 /// InnerActions are specialized prompt actions.
 ///
 /// They must provide an implementation to optionally derive an action
-/// from a key event.
+/// from a command bound to a key in a [`KeyMap`], and from keys not bound
+/// to any command.
 pub trait InnerAction
 where
-    Self: Sized + Copy + Clone + PartialEq + Eq,
+    Self: Sized + Clone + PartialEq + Eq,
 {
     /// Configuration type for the prompt.
     ///
     /// This is used to derive the action from a key event.
     type Config;
 
-    /// Derives a prompt action from a Key event and the prompt configuration.
-    fn from_key(key: Key, config: &Self::Config) -> Option<Self>
-    where
-        Self: Sized;
+    /// Scopes of the key map whose bindings apply to the prompt, besides
+    /// [`KeyScope::All`], in order of precedence.
+    fn key_scopes(config: &Self::Config) -> &'static [KeyScope];
+
+    /// Derives a prompt action from a command bound to the pressed key,
+    /// returning `None` if the command is not supported by the prompt.
+    fn from_command(command: Command, config: &Self::Config) -> Option<Self>;
+
+    /// Derives a prompt action from a Key event not bound to any command
+    /// supported by the prompt, such as a typed character.
+    fn from_unbound_key(_key: Key, _config: &Self::Config) -> Option<Self> {
+        None
+    }
+
+    /// Derives a prompt action from a Key event and the prompt configuration,
+    /// using the default key map.
+    fn from_key(key: Key, config: &Self::Config) -> Option<Self> {
+        Self::from_key_with_map(key, config, &DEFAULT_KEY_MAP)
+    }
+
+    /// Derives a prompt action from a Key event and the prompt configuration,
+    /// according to the bindings of the key map in the scopes of the action.
+    fn from_key_with_map(key: Key, config: &Self::Config, key_map: &KeyMap) -> Option<Self> {
+        for command in key_map.commands(&key, Self::key_scopes(config)) {
+            if command == Command::Ignore {
+                return None;
+            }
+
+            if let Some(action) = Self::from_command(command, config) {
+                return Some(action);
+            }
+        }
+
+        Self::from_unbound_key(key, config)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::{
+        keymap::{Command, KeyScope},
         ui::{Key, KeyModifiers},
         Action, InnerAction,
     };
 
-    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[derive(Clone, Debug, PartialEq, Eq)]
     pub enum MockInnerAction {
         Action(Key),
     }
@@ -82,17 +147,22 @@ mod test {
     impl InnerAction for MockInnerAction {
         type Config = ();
 
-        fn from_key(key: Key, _config: &()) -> Option<Self>
-        where
-            Self: Sized,
-        {
+        fn key_scopes(_config: &()) -> &'static [KeyScope] {
+            &[]
+        }
+
+        fn from_command(_command: Command, _config: &()) -> Option<Self> {
+            None
+        }
+
+        fn from_unbound_key(key: Key, _config: &()) -> Option<Self> {
             Some(Self::Action(key))
         }
     }
 
     #[test]
     fn standard_keybindings_for_submit() {
-        let key = Key::Enter;
+        let key = Key::Enter(KeyModifiers::NONE);
         assert_eq!(
             Some(Action::<MockInnerAction>::Submit),
             Action::from_key(key, &())
@@ -101,7 +171,7 @@ mod test {
 
     #[test]
     fn standard_keybindings_for_cancel() {
-        let key = Key::Escape;
+        let key = Key::Escape(KeyModifiers::NONE);
         assert_eq!(
             Some(Action::<MockInnerAction>::Cancel),
             Action::from_key(key, &())
@@ -127,9 +197,9 @@ mod test {
         );
         assert_eq!(
             Some(Action::<MockInnerAction>::Inner(MockInnerAction::Action(
-                Key::Home
+                Key::Home(KeyModifiers::NONE)
             ))),
-            Action::from_key(Key::Home, &())
+            Action::from_key(Key::Home(KeyModifiers::NONE), &())
         );
         assert_eq!(
             Some(Action::<MockInnerAction>::Inner(MockInnerAction::Action(
@@ -5,13 +5,16 @@ mod test;
 pub use action::*;
 
 use crate::{
-    config::get_configuration,
+    config::{get_accessible_mode, get_configuration, get_key_map},
     error::{InquireError, InquireResult},
     formatter::{BoolFormatter, DEFAULT_BOOL_FORMATTER},
+    keymap::KeyMap,
     parser::{BoolParser, DEFAULT_BOOL_PARSER},
+    prompts::accessible,
+    prompts::Timeout,
     terminal::get_default_terminal,
-    ui::{Backend, CustomTypeBackend, RenderConfig},
-    CustomType,
+    ui::{Backend, CustomTypeBackend, Output, RenderConfig},
+    CustomType, NewlinePolicy,
 };
 
 /// Prompt to ask the user for simple yes/no questions, commonly known by asking the user displaying the `(y/n)` text.
@@ -91,6 +94,11 @@ pub struct Confirm<'a> {
     /// Error message displayed when a value could not be parsed from input.
     pub error_message: String,
 
+    /// Key bindings used to parse the user's key presses into actions.
+    ///
+    /// Defaults to the global key map, see [set_global_key_map](crate::set_global_key_map).
+    pub key_map: KeyMap,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -100,6 +108,14 @@ pub struct Confirm<'a> {
     /// config is treated as the only source of truth. If you want to customize colors
     /// and still support NO_COLOR, you will have to do this on your end.
     pub render_config: RenderConfig<'a>,
+
+    /// Destination where the prompt is rendered, stderr by default.
+    ///
+    /// Keys are read from the terminal regardless of the output.
+    pub output: Output,
+
+    /// Time limit for the user to answer the prompt, if any.
+    pub timeout: Option<Timeout>,
 }
 
 impl<'a> Confirm<'a> {
@@ -132,7 +148,10 @@ impl<'a> Confirm<'a> {
             parser: Self::DEFAULT_PARSER,
             default_value_formatter: Self::DEFAULT_DEFAULT_VALUE_FORMATTER,
             error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            key_map: get_key_map(),
             render_config: get_configuration(),
+            output: Output::default(),
+            timeout: None,
         }
     }
 
@@ -188,6 +207,26 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Sets a time limit for the user to answer the prompt.
+    ///
+    /// Accepts either a [`Duration`](std::time::Duration), in which case the
+    /// prompt returns `Err(InquireError::Timeout)` on expiry, or a [`Timeout`]
+    /// describing what happens on expiry and whether a countdown is displayed.
+    pub fn with_timeout<D>(mut self, timeout: D) -> Self
+    where
+        D: Into<Timeout>,
+    {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// Sets the key bindings used to parse the user's key presses into actions,
+    /// overriding the global key map.
+    pub fn with_key_map(mut self, key_map: KeyMap) -> Self {
+        self.key_map = key_map;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -201,6 +240,13 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Sets the destination where the prompt is rendered, such as
+    /// [`Output::Stderr`] to keep stdout free for the program's output.
+    pub fn with_output(mut self, output: Output) -> Self {
+        self.output = output;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -221,11 +267,41 @@ impl<'a> Confirm<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<bool> {
-        let (input_reader, terminal) = get_default_terminal()?;
+        if get_accessible_mode() {
+            return accessible::prompt(self);
+        }
+
+        let (input_reader, terminal) = get_default_terminal(&self.output)?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Asynchronous version of [`prompt`](Self::prompt), which does not block
+    /// the executor while waiting for the user's input.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<bool> {
+        self.prompt_async_with_cancellation(std::future::pending())
+            .await
+    }
+
+    /// Asynchronous version of [`prompt`](Self::prompt) that can be aborted
+    /// by the application, e.g. by passing `token.cancelled()` of a tokio
+    /// `CancellationToken`.
+    ///
+    /// When `cancellation` resolves before the user submits an answer, the
+    /// prompt is torn down and `Err(InquireError::OperationAborted)` is returned.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async_with_cancellation<C>(self, cancellation: C) -> InquireResult<bool>
+    where
+        C: std::future::Future<Output = ()>,
+    {
+        CustomType::from(self)
+            .prompt_async_with_cancellation(cancellation)
+            .await
+    }
+
     pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
         self,
         backend: &mut B,
@@ -253,7 +329,15 @@ impl<'a> From<Confirm<'a>> for CustomType<'a, bool> {
             parser: co.parser,
             validators: vec![],
             error_message: co.error_message,
+            paste_newline_policy: NewlinePolicy::DEFAULT,
+            multiline: false,
+            history: None,
+            vim_mode: false,
+            mask: None,
+            key_map: co.key_map,
             render_config: co.render_config,
+            output: co.output,
+            timeout: co.timeout,
         }
     }
 }
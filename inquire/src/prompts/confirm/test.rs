@@ -1,4 +1,4 @@
-use std::vec;
+use std::{time::Duration, vec};
 
 use rstest::rstest;
 
@@ -8,12 +8,12 @@ use crate::{
         test::{FakeBackend, Token},
         Key, KeyModifiers,
     },
-    Confirm, InquireError,
+    Confirm, InquireError, Timeout, TimeoutBehavior,
 };
 
 #[test]
 fn prompt_can_be_initialized_from_str() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let result = Confirm::from("Question")
         .with_default(true)
@@ -37,7 +37,7 @@ fn prompt_without_default_correctly_parses_input(
     #[case] expected_result: bool,
 ) -> InquireResult<()> {
     let mut keys = Key::char_keys_from_str(input);
-    keys.push(Key::Enter);
+    keys.push(Key::Enter(KeyModifiers::NONE));
 
     let mut backend = FakeBackend::new(keys);
 
@@ -52,8 +52,8 @@ fn prompt_without_default_correctly_parses_input(
 #[rstest]
 fn escape_after_successful_submit_has_no_effect() -> InquireResult<()> {
     let mut keys = Key::char_keys_from_str("yes");
-    keys.push(Key::Enter);
-    keys.push(Key::Escape);
+    keys.push(Key::Enter(KeyModifiers::NONE));
+    keys.push(Key::Escape(KeyModifiers::NONE));
 
     let mut backend = FakeBackend::new(keys);
 
@@ -74,8 +74,8 @@ fn escape_after_successful_submit_has_no_effect() -> InquireResult<()> {
 #[case("0")]
 fn invalid_inputs_are_properly_rejected(#[case] input: &str) -> InquireResult<()> {
     let mut keys = Key::char_keys_from_str(input);
-    keys.push(Key::Enter);
-    keys.push(Key::Escape);
+    keys.push(Key::Enter(KeyModifiers::NONE));
+    keys.push(Key::Escape(KeyModifiers::NONE));
 
     let mut backend = FakeBackend::new(keys);
 
@@ -96,7 +96,7 @@ fn invalid_inputs_are_properly_rejected(#[case] input: &str) -> InquireResult<()
 #[case(true)]
 #[case(false)]
 fn prompt_with_default_can_be_readily_submitted(#[case] default_value: bool) -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let result = Confirm::from("Question")
         .with_default(default_value)
@@ -113,7 +113,7 @@ fn prompt_with_valid_starting_input_can_be_readily_submitted(
     #[case] input: &str,
     #[case] expected_result: bool,
 ) -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let result = Confirm::from("Question")
         .with_starting_input(input)
@@ -125,7 +125,7 @@ fn prompt_with_valid_starting_input_can_be_readily_submitted(
 
 #[rstest]
 fn placeholder_is_rendered() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let _ = Confirm::new("Question")
         .with_placeholder("Placeholder")
@@ -165,8 +165,8 @@ fn custom_parser_for_spanish_works_as_expected(
     #[case] expected_result: Option<bool>,
 ) -> InquireResult<()> {
     let mut keys = Key::char_keys_from_str(input);
-    keys.push(Key::Enter);
-    keys.push(Key::Escape);
+    keys.push(Key::Enter(KeyModifiers::NONE));
+    keys.push(Key::Escape(KeyModifiers::NONE));
 
     let mut backend = FakeBackend::new(keys);
 
@@ -203,8 +203,8 @@ fn custom_parser_for_spanish_works_as_expected(
 fn default_error_message_is_rendered_on_invalid_input() -> InquireResult<()> {
     let mut backend = FakeBackend::new(vec![
         Key::Char('c', KeyModifiers::NONE),
-        Key::Enter,
-        Key::Escape,
+        Key::Enter(KeyModifiers::NONE),
+        Key::Escape(KeyModifiers::NONE),
     ]);
 
     let result = Confirm::new("Question").prompt_with_backend(&mut backend);
@@ -245,8 +245,8 @@ fn default_error_message_is_rendered_on_invalid_input() -> InquireResult<()> {
 fn custom_error_message_is_rendered_on_invalid_input() -> InquireResult<()> {
     let mut backend = FakeBackend::new(vec![
         Key::Char('c', KeyModifiers::NONE),
-        Key::Enter,
-        Key::Escape,
+        Key::Enter(KeyModifiers::NONE),
+        Key::Escape(KeyModifiers::NONE),
     ]);
 
     let result = Confirm::new("Question")
@@ -293,7 +293,7 @@ fn default_formatter_for_default_values_follows_convention(
     #[case] default_value: bool,
     #[case] expected_output: &str,
 ) -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let _ = Confirm::new("Question")
         .with_default(default_value)
@@ -332,7 +332,7 @@ fn custom_formatter_for_default_values_is_used(
     #[case] default_value: bool,
     #[case] expected_output: &str,
 ) -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let _ = Confirm::new("Question")
         .with_default(default_value)
@@ -370,7 +370,7 @@ fn custom_formatter_for_default_values_is_used(
 
 #[test]
 fn default_help_message_does_not_exist_and_is_not_rendered() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let _ = Confirm::new("Question")
         .with_default(true)
@@ -394,7 +394,7 @@ fn default_help_message_does_not_exist_and_is_not_rendered() -> InquireResult<()
 
 #[test]
 fn custom_help_message_is_rendered() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let _ = Confirm::new("Question")
         .with_help_message("Custom help message")
@@ -427,9 +427,38 @@ fn custom_help_message_is_rendered() -> InquireResult<()> {
     Ok(())
 }
 
+#[test]
+fn f1_toggles_key_bindings_help() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::F(1, KeyModifiers::NONE),
+        Key::F(1, KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let _ = Confirm::new("Question")
+        .with_default(true)
+        .prompt_with_backend(&mut backend)?;
+
+    let rendered_frames = backend.frames();
+    let lists_bindings = |idx: usize| {
+        rendered_frames[idx].has_token(&Token::HelpMessage(
+            "*+f1: Show or hide the key bindings of the prompt".into(),
+        ))
+    };
+
+    assert!(!lists_bindings(0), "Key bindings were listed before F1");
+    assert!(lists_bindings(1), "Key bindings were not listed after F1");
+    assert!(
+        !lists_bindings(2),
+        "Key bindings were listed after the second F1"
+    );
+
+    Ok(())
+}
+
 #[test]
 fn custom_formatter_affects_final_output() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let custom_formatter = |d: bool| format!("WOW! {}", d);
     let result = Confirm::new("Question")
@@ -457,7 +486,7 @@ fn custom_formatter_affects_final_output() -> InquireResult<()> {
 
 #[test]
 fn default_formatter_outputs_true_answer_as_yes() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let result = Confirm::new("Question")
         .with_default(true)
@@ -480,7 +509,7 @@ fn default_formatter_outputs_true_answer_as_yes() -> InquireResult<()> {
 
 #[test]
 fn default_formatter_outputs_true_answer_as_no() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let result = Confirm::new("Question")
         .with_default(false)
@@ -503,7 +532,7 @@ fn default_formatter_outputs_true_answer_as_no() -> InquireResult<()> {
 
 #[test]
 fn escape_raises_error() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Escape]);
+    let mut backend = FakeBackend::new(vec![Key::Escape(KeyModifiers::NONE)]);
 
     let result = Confirm::new("Question").prompt_with_backend(&mut backend);
 
@@ -548,3 +577,78 @@ fn ctrl_c_interrupts_prompt() -> InquireResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn expired_timeout_submits_default_when_configured() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![]);
+
+    let result = Confirm::new("Question")
+        .with_default(true)
+        .with_timeout(Timeout::new(Duration::ZERO).with_behavior(TimeoutBehavior::SubmitDefault))
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(result, "Answer was not the default one");
+
+    Ok(())
+}
+
+#[test]
+fn expired_timeout_raises_error_by_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![]);
+
+    let result = Confirm::new("Question")
+        .with_default(true)
+        .with_timeout(Duration::ZERO)
+        .prompt_with_backend(&mut backend);
+
+    assert!(
+        matches!(result, Err(InquireError::Timeout)),
+        "Error was not the 'Timeout' expected"
+    );
+
+    let final_frame = backend.frames().last().unwrap();
+    assert!(
+        final_frame.has_token(&Token::CanceledPrompt("Question".into())),
+        "Final frame did not contain the correct canceled prompt token"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn expired_timeout_without_default_raises_error() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![]);
+
+    let result = Confirm::new("Question")
+        .with_timeout(Timeout::new(Duration::ZERO).with_behavior(TimeoutBehavior::SubmitDefault))
+        .prompt_with_backend(&mut backend);
+
+    assert!(
+        matches!(result, Err(InquireError::Timeout)),
+        "Error was not the 'Timeout' expected"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn timeout_countdown_is_rendered() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('y', KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let result = Confirm::new("Question")
+        .with_timeout(Timeout::new(Duration::from_secs(3600)).with_countdown(true))
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(result, "Answer was not the expected one");
+
+    let first_frame = backend.frames().first().unwrap();
+    assert!(
+        first_frame.has_token(&Token::HelpMessage("3600s remaining".into())),
+        "First frame did not contain the countdown"
+    );
+
+    Ok(())
+}
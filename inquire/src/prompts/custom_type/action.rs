@@ -1,23 +1,58 @@
-use crate::{ui::Key, InnerAction, InputAction};
+use crate::{
+    input::action::normalize_newlines,
+    keymap::{Command, KeyScope},
+    ui::Key,
+    InnerAction, InputAction,
+};
 
 use super::config::CustomTypeConfig;
 
 /// Set of actions for a CustomTypePrompt.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CustomTypePromptAction {
     /// Action on the value text input handler.
     ValueInput(InputAction),
+    /// Replaces the input with the previous entry of the history.
+    PreviousHistoryEntry,
+    /// Replaces the input with the next entry of the history.
+    NextHistoryEntry,
+    /// Starts or continues a reverse search through the history.
+    SearchHistory,
 }
 
 impl InnerAction for CustomTypePromptAction {
     type Config = CustomTypeConfig;
 
-    fn from_key(key: Key, _config: &CustomTypeConfig) -> Option<Self> {
-        let action = match InputAction::from_key(key, &()) {
-            Some(action) => Self::ValueInput(action),
-            None => return None,
+    fn key_scopes(config: &CustomTypeConfig) -> &'static [KeyScope] {
+        match (config.multiline, config.history) {
+            (true, true) => &[KeyScope::Multiline, KeyScope::History, KeyScope::Input],
+            (true, false) => &[KeyScope::Multiline, KeyScope::Input],
+            (false, true) => &[KeyScope::History, KeyScope::Input],
+            (false, false) => &[KeyScope::Input],
+        }
+    }
+
+    fn from_command(command: Command, config: &CustomTypeConfig) -> Option<Self> {
+        let action = match command {
+            Command::PreviousHistory if config.history => Self::PreviousHistoryEntry,
+            Command::NextHistory if config.history => Self::NextHistoryEntry,
+            Command::ReverseSearchHistory if config.history => Self::SearchHistory,
+            command if config.multiline => {
+                Self::ValueInput(InputAction::from_multiline_command(command)?)
+            }
+            command => Self::ValueInput(InputAction::from_command(command, &())?),
         };
 
         Some(action)
     }
+
+    fn from_unbound_key(key: Key, config: &CustomTypeConfig) -> Option<Self> {
+        let action = match key {
+            Key::Paste(text) if config.multiline => InputAction::Insert(normalize_newlines(&text)),
+            Key::Paste(text) => InputAction::Insert(config.paste_newline_policy.apply(&text)?),
+            key => InputAction::from_unbound_key(key, &())?,
+        };
+
+        Some(Self::ValueInput(action))
+    }
 }
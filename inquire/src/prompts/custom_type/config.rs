@@ -1,10 +1,21 @@
-use crate::CustomType;
+use crate::{CustomType, NewlinePolicy};
 
 /// Configuration settings used in the execution of a CustomTypePrompt.
-pub struct CustomTypeConfig {}
+pub struct CustomTypeConfig {
+    /// How line breaks in pasted text are handled.
+    pub paste_newline_policy: NewlinePolicy,
+    /// Whether the input spans multiple lines.
+    pub multiline: bool,
+    /// Whether the prompt has a history.
+    pub history: bool,
+}
 
 impl<T> From<&CustomType<'_, T>> for CustomTypeConfig {
-    fn from(_value: &CustomType<'_, T>) -> Self {
-        Self {}
+    fn from(value: &CustomType<'_, T>) -> Self {
+        Self {
+            paste_newline_policy: value.paste_newline_policy,
+            multiline: value.multiline,
+            history: value.history.is_some(),
+        }
     }
 }
@@ -1,21 +1,32 @@
 mod action;
 mod config;
 mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
 
 pub use action::*;
 
 use std::str::FromStr;
 
 use crate::{
-    config::get_configuration,
+    config::{get_accessible_mode, get_configuration, get_key_map, get_vim_mode},
     error::{InquireError, InquireResult},
     formatter::CustomTypeFormatter,
+    history::History,
+    keymap::KeyMap,
+    mask::InputMask,
     parser::CustomTypeParser,
+    prompts::accessible,
     prompts::prompt::Prompt,
+    prompts::Timeout,
     terminal::get_default_terminal,
-    ui::{Backend, CustomTypeBackend, RenderConfig},
+    ui::{Backend, CustomTypeBackend, Output, RenderConfig},
     validator::CustomTypeValidator,
+    NewlinePolicy,
 };
+#[cfg(feature = "async")]
+use crate::{prompts::prompt::prompt_async, terminal::get_default_async_terminal};
 
 use self::prompt::CustomTypePrompt;
 
@@ -38,7 +49,7 @@ use self::prompt::CustomTypePrompt;
 /// If your type `T` does not satisfy these constraints, you can always manually instantiate the entire struct yourself like this:
 ///
 /// ```no_run
-/// use inquire::{CustomType, ui::RenderConfig};
+/// use inquire::{keymap::KeyMap, CustomType, NewlinePolicy, ui::{Output, RenderConfig}};
 ///
 /// let amount_prompt: CustomType<f64> = CustomType {
 ///     message: "How much is your travel going to cost?",
@@ -54,7 +65,15 @@ use self::prompt::CustomTypePrompt;
 ///         Ok(val) => Ok(val),
 ///         Err(_) => Err(()),
 ///     },
+///     paste_newline_policy: NewlinePolicy::Strip,
+///     multiline: false,
+///     history: None,
+///     vim_mode: false,
+///     mask: None,
+///     key_map: KeyMap::default(),
 ///     render_config: RenderConfig::default(),
+///     output: Output::default(),
+///     timeout: None,
 /// };
 /// ```
 ///
@@ -117,6 +136,38 @@ pub struct CustomType<'a, T> {
     /// Error message displayed when value could not be parsed from input.
     pub error_message: String,
 
+    /// How line breaks in text pasted by the user are handled, as typing
+    /// one would submit the prompt.
+    ///
+    /// Ignored in multi-line mode, where pasted line breaks are kept.
+    pub paste_newline_policy: NewlinePolicy,
+
+    /// Whether the input spans multiple lines, in which case Enter inserts a
+    /// line break and Ctrl+D submits the prompt.
+    pub multiline: bool,
+
+    /// History of previous inputs, recalled with the arrow keys or searched
+    /// with Ctrl+R. The input is added to it when the prompt is submitted.
+    pub history: Option<History>,
+
+    /// Whether the text input has vim-style modal editing. It starts in
+    /// insert mode, ESC switching to normal mode, where keys are vim commands
+    /// and ESC cancels the prompt.
+    ///
+    /// Defaults to the global vim mode, see [set_global_vim_mode](crate::set_global_vim_mode).
+    pub vim_mode: bool,
+
+    /// Mask constraining each character of the input, whose literals are
+    /// inserted automatically. The parser receives the formatted or the raw
+    /// value of the mask, and incomplete inputs are rejected with the error
+    /// message.
+    pub mask: Option<InputMask>,
+
+    /// Key bindings used to parse the user's key presses into actions.
+    ///
+    /// Defaults to the global key map, see [set_global_key_map](crate::set_global_key_map).
+    pub key_map: KeyMap,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -126,6 +177,14 @@ pub struct CustomType<'a, T> {
     /// config is treated as the only source of truth. If you want to customize colors
     /// and still support NO_COLOR, you will have to do this on your end.
     pub render_config: RenderConfig<'a>,
+
+    /// Destination where the prompt is rendered, stderr by default.
+    ///
+    /// Keys are read from the terminal regardless of the output.
+    pub output: Output,
+
+    /// Time limit for the user to answer the prompt, if any.
+    pub timeout: Option<Timeout>,
 }
 
 impl<'a, T> CustomType<'a, T>
@@ -151,7 +210,15 @@ where
             parser: &|a| a.parse::<T>().map_err(|_e| ()),
             validators: Self::DEFAULT_VALIDATORS,
             error_message: "Invalid input".into(),
+            paste_newline_policy: NewlinePolicy::DEFAULT,
+            multiline: false,
+            history: None,
+            vim_mode: get_vim_mode(),
+            mask: None,
+            key_map: get_key_map(),
             render_config: get_configuration(),
+            output: Output::default(),
+            timeout: None,
         }
     }
 
@@ -241,6 +308,87 @@ where
         self
     }
 
+    /// Sets how line breaks in text pasted by the user are handled.
+    ///
+    /// Defaults to [`NewlinePolicy::Strip`].
+    pub fn with_paste_newline_policy(mut self, paste_newline_policy: NewlinePolicy) -> Self {
+        self.paste_newline_policy = paste_newline_policy;
+        self
+    }
+
+    /// Lets the user type an input spanning multiple lines, passed to the
+    /// parser as is.
+    ///
+    /// Enter and Alt+Enter insert a line break, the arrow keys move the
+    /// cursor across lines and Ctrl+D submits the prompt. These bindings can
+    /// be changed in the [`KeyScope::Multiline`](crate::keymap::KeyScope::Multiline)
+    /// scope of the key map.
+    pub fn with_multiline(mut self) -> Self {
+        self.multiline = true;
+        self
+    }
+
+    /// Sets the history of previous inputs, which the user can go through
+    /// with the up and down arrows, or search with Ctrl+R. The input is added
+    /// to the history when the prompt is successfully submitted.
+    ///
+    /// In multi-line mode, the arrows move the cursor across lines and only
+    /// the search is available. These bindings can be changed in the
+    /// [`KeyScope::History`](crate::keymap::KeyScope::History) scope of the
+    /// key map.
+    pub fn with_history(mut self, history: History) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// Enables or disables vim-style modal editing of the text input.
+    ///
+    /// The input starts in insert mode, where keys are typed as usual. ESC
+    /// switches to normal mode, shown by the
+    /// [`vim_normal_prompt_prefix`](crate::ui::RenderConfig::vim_normal_prompt_prefix),
+    /// where `h`, `l`, `w`, `b`, `e`, `0` and `$` move the cursor, `x` deletes
+    /// a character, `d` and `c` followed by a motion delete or change text,
+    /// `dd` and `cc` the whole line, `p` pastes the deleted text and `u`
+    /// undoes the last edit. `i`, `a`, `I` and `A` go back to insert mode. A
+    /// second ESC in normal mode cancels the prompt.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets a mask for structured inputs such as phone numbers or dates, see
+    /// [`InputMask`].
+    ///
+    /// The user only types the characters of the slots of the mask, its
+    /// literals being inserted automatically, and the slots left to fill are
+    /// displayed after the input. The parser receives the formatted value,
+    /// or the raw one with [`InputMask::with_raw_value`], once all the slots
+    /// are filled.
+    pub fn with_mask(mut self, mask: InputMask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Sets a time limit for the user to answer the prompt.
+    ///
+    /// Accepts either a [`Duration`](std::time::Duration), in which case the
+    /// prompt returns `Err(InquireError::Timeout)` on expiry, or a [`Timeout`]
+    /// describing what happens on expiry and whether a countdown is displayed.
+    pub fn with_timeout<D>(mut self, timeout: D) -> Self
+    where
+        D: Into<Timeout>,
+    {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// Sets the key bindings used to parse the user's key presses into actions,
+    /// overriding the global key map.
+    pub fn with_key_map(mut self, key_map: KeyMap) -> Self {
+        self.key_map = key_map;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -254,6 +402,13 @@ where
         self
     }
 
+    /// Sets the destination where the prompt is rendered, such as
+    /// [`Output::Stderr`] to keep stdout free for the program's output.
+    pub fn with_output(mut self, output: Output) -> Self {
+        self.output = output;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -274,11 +429,41 @@ where
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<T> {
-        let (input_reader, terminal) = get_default_terminal()?;
+        if get_accessible_mode() {
+            return accessible::prompt(self);
+        }
+
+        let (input_reader, terminal) = get_default_terminal(&self.output)?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Asynchronous version of [`prompt`](Self::prompt), which does not block
+    /// the executor while waiting for the user's input.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<T> {
+        self.prompt_async_with_cancellation(std::future::pending())
+            .await
+    }
+
+    /// Asynchronous version of [`prompt`](Self::prompt) that can be aborted
+    /// by the application, e.g. by passing `token.cancelled()` of a tokio
+    /// `CancellationToken`.
+    ///
+    /// When `cancellation` resolves before the user submits an answer, the
+    /// prompt is torn down and `Err(InquireError::OperationAborted)` is returned.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async_with_cancellation<C>(self, cancellation: C) -> InquireResult<T>
+    where
+        C: std::future::Future<Output = ()>,
+    {
+        let (input_reader, terminal) = get_default_async_terminal(&self.output)?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        prompt_async(CustomTypePrompt::from(self), &mut backend, cancellation).await
+    }
+
     pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
         self,
         backend: &mut B,
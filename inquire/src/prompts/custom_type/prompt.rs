@@ -1,12 +1,19 @@
 use crate::{
     error::InquireResult,
     formatter::CustomTypeFormatter,
-    input::Input,
+    history::HistoryNavigator,
+    input::{Input, InputActionResult, Vim},
+    keymap::KeyMap,
+    mask::InputMask,
     parser::CustomTypeParser,
-    prompts::prompt::{ActionResult, Prompt},
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        text::DEFAULT_HELP_MESSAGE_MULTILINE,
+        Timeout,
+    },
     ui::CustomTypeBackend,
     validator::{CustomTypeValidator, ErrorMessage, Validation},
-    CustomType, InquireError,
+    CustomType, InputAction, InquireError,
 };
 
 use super::{action::CustomTypePromptAction, config::CustomTypeConfig};
@@ -14,6 +21,7 @@ use super::{action::CustomTypePromptAction, config::CustomTypeConfig};
 pub struct CustomTypePrompt<'a, T> {
     message: &'a str,
     config: CustomTypeConfig,
+    key_map: KeyMap,
     error: Option<ErrorMessage>,
     help_message: Option<&'a str>,
     default: Option<T>,
@@ -23,6 +31,10 @@ pub struct CustomTypePrompt<'a, T> {
     validators: Vec<Box<dyn CustomTypeValidator<T>>>,
     parser: CustomTypeParser<'a, T>,
     error_message: String,
+    history: Option<HistoryNavigator>,
+    vim: Option<Vim>,
+    mask: Option<InputMask>,
+    timeout: Option<Timeout>,
 }
 
 impl<'a, T> From<CustomType<'a, T>> for CustomTypePrompt<'a, T>
@@ -30,7 +42,11 @@ where
     T: Clone,
 {
     fn from(co: CustomType<'a, T>) -> Self {
-        let input = Input::new_with(co.starting_input.unwrap_or_default());
+        let starting_input = co.starting_input.unwrap_or_default();
+        let input = match &co.mask {
+            Some(mask) => Input::new_with(mask.raw_from(starting_input, 0)),
+            None => Input::new_with(starting_input),
+        };
         let input = if let Some(placeholder) = co.placeholder {
             input.with_placeholder(placeholder)
         } else {
@@ -40,6 +56,7 @@ where
         Self {
             message: co.message,
             config: (&co).into(),
+            key_map: co.key_map,
             error: None,
             default: co.default,
             help_message: co.help_message,
@@ -49,6 +66,10 @@ where
             parser: co.parser,
             input,
             error_message: co.error_message,
+            history: co.history.map(HistoryNavigator::new),
+            vim: co.vim_mode.then(Vim::new),
+            mask: co.mask,
+            timeout: co.timeout,
         }
     }
 }
@@ -75,11 +96,62 @@ where
             _ => {}
         }
 
-        match (self.parser)(self.input.content()) {
+        let value = match &self.mask {
+            Some(mask) if !mask.is_complete(self.input.content()) => {
+                return Err(self.error_message.clone())
+            }
+            Some(mask) => mask.value(self.input.content()),
+            None => self.input.content().to_owned(),
+        };
+
+        match (self.parser)(&value) {
             Ok(val) => Ok(val),
             Err(_) => Err(self.error_message.clone()),
         }
     }
+
+    /// Applies an action to the input, reverting it when the result does not
+    /// fit in the mask.
+    fn edit_input(&mut self, action: InputAction) -> ActionResult {
+        let Some(mask) = &self.mask else {
+            return self.apply_input_action(action).into();
+        };
+
+        let action = match action {
+            InputAction::Insert(text) => {
+                InputAction::Insert(mask.raw_from(&text, self.input.cursor()))
+            }
+            action => action,
+        };
+
+        let before = self.input.clone();
+        let result = self.apply_input_action(action);
+
+        match &self.mask {
+            Some(mask) if !mask.accepts(self.input.content()) => {
+                self.input = before;
+                ActionResult::Clean
+            }
+            _ => result.into(),
+        }
+    }
+
+    fn apply_input_action(&mut self, action: InputAction) -> InputActionResult {
+        match self.vim.as_mut() {
+            Some(vim) => vim.handle(action, &mut self.input),
+            None => self.input.handle(action),
+        }
+    }
+
+    fn navigate_history(
+        &mut self,
+        navigate: fn(&mut HistoryNavigator, &mut Input) -> ActionResult,
+    ) -> ActionResult {
+        match self.history.as_mut() {
+            Some(history) => navigate(history, &mut self.input),
+            None => ActionResult::Clean,
+        }
+    }
 }
 
 impl<'a, Backend, T> Prompt<Backend> for CustomTypePrompt<'a, T>
@@ -99,14 +171,42 @@ where
         &self.config
     }
 
+    fn key_map(&self) -> &KeyMap {
+        &self.key_map
+    }
+
     fn format_answer(&self, answer: &T) -> String {
         (self.formatter)((*answer).clone())
     }
 
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        let canceled_search = match self.history.as_mut() {
+            Some(history) => history.cancel_search(&mut self.input),
+            None => false,
+        };
+
+        let consumed = canceled_search
+            || match self.vim.as_mut() {
+                Some(vim) => vim.escape(&mut self.input),
+                None => false,
+            };
+
+        Ok(!consumed)
+    }
+
     fn submit(&mut self) -> InquireResult<Option<T>> {
+        if let Some(history) = self.history.as_mut() {
+            history.accept_search();
+        }
+
         let answer = match self.get_final_answer() {
             Ok(answer) => match self.validate_current_answer(&answer)? {
-                Validation::Valid => Some(answer),
+                Validation::Valid => {
+                    if let Some(history) = &self.history {
+                        history.record(self.input.content());
+                    }
+                    Some(answer)
+                }
                 Validation::Invalid(msg) => {
                     self.error = Some(msg);
                     None
@@ -122,15 +222,43 @@ where
     }
 
     fn handle(&mut self, action: CustomTypePromptAction) -> InquireResult<ActionResult> {
+        if let Some(history) = self.history.as_mut() {
+            match &action {
+                CustomTypePromptAction::ValueInput(input_action) => {
+                    if let Some(result) = history.handle_search_input(input_action, &mut self.input)
+                    {
+                        return Ok(result);
+                    }
+                }
+                CustomTypePromptAction::SearchHistory => {}
+                _ => history.accept_search(),
+            }
+        }
+
         let result = match action {
-            CustomTypePromptAction::ValueInput(input_action) => {
-                self.input.handle(input_action).into()
+            CustomTypePromptAction::ValueInput(input_action) => self.edit_input(input_action),
+            CustomTypePromptAction::PreviousHistoryEntry => {
+                self.navigate_history(HistoryNavigator::previous)
+            }
+            CustomTypePromptAction::NextHistoryEntry => {
+                self.navigate_history(HistoryNavigator::next)
+            }
+            CustomTypePromptAction::SearchHistory => {
+                self.navigate_history(HistoryNavigator::search)
             }
         };
 
         Ok(result)
     }
 
+    fn timeout(&self) -> Option<Timeout> {
+        self.timeout
+    }
+
+    fn default_answer(&mut self) -> Option<T> {
+        self.default.take()
+    }
+
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
@@ -144,10 +272,28 @@ where
             .as_ref()
             .map(|val| default_value_formatter(val.clone()));
 
-        backend.render_prompt(prompt, default_message.as_deref(), &self.input)?;
+        backend.set_vim_normal_mode(matches!(&self.vim, Some(vim) if vim.is_normal()));
+        match &self.mask {
+            Some(mask) => {
+                let (input, remainder) = mask.display(&self.input);
+                backend.render_prompt_with_mask(
+                    prompt,
+                    default_message.as_deref(),
+                    &input,
+                    &remainder,
+                )?;
+            }
+            None => backend.render_prompt(prompt, default_message.as_deref(), &self.input)?,
+        }
+
+        if let Some((query, found)) = self.history.as_ref().and_then(|h| h.search_query()) {
+            backend.render_history_search(query, found)?;
+        }
 
         if let Some(message) = self.help_message {
             backend.render_help_message(message)?;
+        } else if self.config.multiline {
+            backend.render_help_message(DEFAULT_HELP_MESSAGE_MULTILINE)?;
         }
 
         Ok(())
@@ -0,0 +1,81 @@
+use super::CustomType;
+use crate::mask::InputMask;
+use crate::ui::{Key, KeyModifiers};
+
+macro_rules! text_to_events {
+    ($text:expr) => {{
+        $text
+            .chars()
+            .map(|c| Key::Char(c, KeyModifiers::NONE))
+            .collect::<Vec<Key>>()
+    }};
+}
+
+fn phone_number<'a>() -> CustomType<'a, String> {
+    CustomType::new("Phone number:").with_mask(InputMask::new("(999) 999-9999").unwrap())
+}
+
+#[test]
+fn mask_formats_the_parsed_value() {
+    let mut backend = crate::prompts::test::fake_backend(text_to_events!("555-123-4567\n"));
+
+    let ans = phone_number().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("(555) 123-4567", ans);
+}
+
+#[test]
+fn mask_can_hand_the_raw_value_to_the_parser() {
+    let mut backend = crate::prompts::test::fake_backend(text_to_events!("5551234567\n"));
+
+    let ans = CustomType::<u64>::new("Phone number:")
+        .with_mask(
+            InputMask::new("(999) 999-9999")
+                .unwrap()
+                .with_raw_value(true),
+        )
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(5551234567, ans);
+}
+
+#[test]
+fn backspace_skips_mask_literals() {
+    let mut events = text_to_events!("555123");
+    events.push(Key::Backspace(KeyModifiers::NONE));
+    events.push(Key::Backspace(KeyModifiers::NONE));
+    events.append(&mut text_to_events!("991234\n"));
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let ans = phone_number().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("(555) 199-1234", ans);
+}
+
+#[test]
+fn incomplete_masks_are_rejected() {
+    let mut events = text_to_events!("555\n");
+    events.append(&mut text_to_events!("1234567\n"));
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let ans = phone_number()
+        .with_error_message("Incomplete number")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("(555) 123-4567", ans);
+}
+
+#[test]
+fn pasted_text_is_fitted_in_the_mask() {
+    let events = vec![
+        Key::Paste(String::from("(555) 123-4567")),
+        Key::Enter(KeyModifiers::NONE),
+    ];
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let ans = phone_number().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("(555) 123-4567", ans);
+}
@@ -13,6 +13,9 @@ pub struct DateSelectConfig {
 
     /// Weekday to start the week on.
     pub week_start: Weekday,
+
+    /// Whether mouse events are captured.
+    pub mouse_capture: bool,
 }
 
 impl From<&DateSelect<'_>> for DateSelectConfig {
@@ -21,6 +24,7 @@ impl From<&DateSelect<'_>> for DateSelectConfig {
             min_date: value.min_date,
             max_date: value.max_date,
             week_start: value.week_start,
+            mouse_capture: value.mouse_capture,
         }
     }
 }
@@ -10,15 +10,20 @@ pub use action::*;
 use time::Date;
 
 use crate::{
-    config::get_configuration,
+    config::{get_accessible_mode, get_configuration, get_key_map},
     date_utils::get_current_date,
     error::{InquireError, InquireResult},
     formatter::{self, DateFormatter},
+    keymap::KeyMap,
+    prompts::accessible,
     prompts::prompt::Prompt,
+    prompts::Timeout,
     terminal::get_default_terminal,
-    ui::{date::DateSelectBackend, Backend, RenderConfig},
+    ui::{date::DateSelectBackend, Backend, Output, RenderConfig},
     validator::DateValidator,
 };
+#[cfg(feature = "async")]
+use crate::{prompts::prompt::prompt_async, terminal::get_default_async_terminal};
 
 use self::prompt::DateSelectPrompt;
 
@@ -94,6 +99,17 @@ pub struct DateSelect<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn DateValidator>>,
 
+    /// Whether mouse events are captured, allowing the user to click on
+    /// days and to switch months with the mouse wheel.
+    ///
+    /// Not supported when using `console` as the terminal back-end.
+    pub mouse_capture: bool,
+
+    /// Key bindings used to parse the user's key presses into actions.
+    ///
+    /// Defaults to the global key map, see [set_global_key_map](crate::set_global_key_map).
+    pub key_map: KeyMap,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -103,6 +119,14 @@ pub struct DateSelect<'a> {
     /// config is treated as the only source of truth. If you want to customize colors
     /// and still support NO_COLOR, you will have to do this on your end.
     pub render_config: RenderConfig<'a>,
+
+    /// Destination where the prompt is rendered, stderr by default.
+    ///
+    /// Keys are read from the terminal regardless of the output.
+    pub output: Output,
+
+    /// Time limit for the user to answer the prompt, if any.
+    pub timeout: Option<Timeout>,
 }
 
 impl<'a> DateSelect<'a> {
@@ -139,7 +163,11 @@ impl<'a> DateSelect<'a> {
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
             week_start: Self::DEFAULT_WEEK_START,
+            mouse_capture: false,
+            key_map: get_key_map(),
             render_config: get_configuration(),
+            output: Output::default(),
+            timeout: None,
         }
     }
 
@@ -166,6 +194,16 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Enables mouse capture, allowing the user to click on days and to
+    /// switch months with the mouse wheel.
+    ///
+    /// The first click on a day moves the cursor to it, clicking on the
+    /// selected day submits it.
+    pub fn with_mouse_capture(mut self) -> Self {
+        self.mouse_capture = true;
+        self
+    }
+
     /// Sets the min date.
     pub fn with_min_date(mut self, min_date: Date) -> Self {
         self.min_date = Some(min_date);
@@ -220,6 +258,26 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Sets a time limit for the user to answer the prompt.
+    ///
+    /// Accepts either a [`Duration`](std::time::Duration), in which case the
+    /// prompt returns `Err(InquireError::Timeout)` on expiry, or a [`Timeout`]
+    /// describing what happens on expiry and whether a countdown is displayed.
+    pub fn with_timeout<D>(mut self, timeout: D) -> Self
+    where
+        D: Into<Timeout>,
+    {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// Sets the key bindings used to parse the user's key presses into actions,
+    /// overriding the global key map.
+    pub fn with_key_map(mut self, key_map: KeyMap) -> Self {
+        self.key_map = key_map;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -233,6 +291,13 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Sets the destination where the prompt is rendered, such as
+    /// [`Output::Stderr`] to keep stdout free for the program's output.
+    pub fn with_output(mut self, output: Output) -> Self {
+        self.output = output;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -253,11 +318,41 @@ impl<'a> DateSelect<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<Date> {
-        let (input_reader, terminal) = get_default_terminal()?;
+        if get_accessible_mode() {
+            return accessible::prompt(self);
+        }
+
+        let (input_reader, terminal) = get_default_terminal(&self.output)?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Asynchronous version of [`prompt`](Self::prompt), which does not block
+    /// the executor while waiting for the user's input.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<Date> {
+        self.prompt_async_with_cancellation(std::future::pending())
+            .await
+    }
+
+    /// Asynchronous version of [`prompt`](Self::prompt) that can be aborted
+    /// by the application, e.g. by passing `token.cancelled()` of a tokio
+    /// `CancellationToken`.
+    ///
+    /// When `cancellation` resolves before the user submits an answer, the
+    /// prompt is torn down and `Err(InquireError::OperationAborted)` is returned.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async_with_cancellation<C>(self, cancellation: C) -> InquireResult<Date>
+    where
+        C: std::future::Future<Output = ()>,
+    {
+        let (input_reader, terminal) = get_default_async_terminal(&self.output)?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        prompt_async(DateSelectPrompt::new(self)?, &mut backend, cancellation).await
+    }
+
     pub(crate) fn prompt_with_backend<B: DateSelectBackend>(
         self,
         backend: &mut B,
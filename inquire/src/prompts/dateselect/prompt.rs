@@ -7,10 +7,14 @@ use crate::{
     date_utils::get_current_date,
     error::InquireResult,
     formatter::DateFormatter,
-    prompts::prompt::{ActionResult, Prompt},
-    ui::date::DateSelectBackend,
+    keymap::KeyMap,
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        Timeout,
+    },
+    ui::{date::DateSelectBackend, HitTarget, MouseEvent, MouseEventKind},
     validator::{DateValidator, ErrorMessage, Validation},
-    DateSelect, InquireError,
+    Action, DateSelect, InquireError,
 };
 use time::util::days_in_year_month;
 use time::{Date, Duration, Month};
@@ -20,11 +24,14 @@ use super::{action::DateSelectPromptAction, config::DateSelectConfig};
 pub struct DateSelectPrompt<'a> {
     message: &'a str,
     config: DateSelectConfig,
+    key_map: KeyMap,
     current_date: Date,
     help_message: Option<&'a str>,
     formatter: DateFormatter<'a>,
     validators: Vec<Box<dyn DateValidator>>,
     error: Option<ErrorMessage>,
+    starting_date: Date,
+    timeout: Option<Timeout>,
 }
 
 impl<'a> DateSelectPrompt<'a> {
@@ -48,10 +55,13 @@ impl<'a> DateSelectPrompt<'a> {
             message: so.message,
             current_date: so.starting_date,
             config: (&so).into(),
+            key_map: so.key_map,
             help_message: so.help_message,
             formatter: so.formatter,
             validators: so.validators,
             error: None,
+            starting_date: so.starting_date,
+            timeout: so.timeout,
         })
     }
 
@@ -153,6 +163,10 @@ where
         &self.config
     }
 
+    fn key_map(&self) -> &KeyMap {
+        &self.key_map
+    }
+
     fn submit(&mut self) -> InquireResult<Option<Date>> {
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.cur_answer()),
@@ -187,11 +201,47 @@ where
             DateSelectPromptAction::GoToNextYear => self.shift_months(12),
             DateSelectPromptAction::GoToPrevMonth => self.shift_months(-1),
             DateSelectPromptAction::GoToNextMonth => self.shift_months(1),
+            DateSelectPromptAction::GoToDate(date) => self.update_date(date),
         };
 
         Ok(result)
     }
 
+    fn timeout(&self) -> Option<Timeout> {
+        self.timeout
+    }
+
+    fn default_answer(&mut self) -> Option<Date> {
+        Some(self.starting_date)
+    }
+
+    fn mouse_capture(&self) -> bool {
+        self.config.mouse_capture
+    }
+
+    fn mouse_action(
+        &self,
+        event: MouseEvent,
+        target: Option<HitTarget>,
+    ) -> Option<Action<DateSelectPromptAction>> {
+        match (event.kind, target) {
+            // a first click moves the cursor to the date, a second one submits it
+            (MouseEventKind::LeftClick, Some(HitTarget::Date(date))) => {
+                match date == self.current_date {
+                    true => Some(Action::Submit),
+                    false => Some(Action::Inner(DateSelectPromptAction::GoToDate(date))),
+                }
+            }
+            (MouseEventKind::ScrollUp, _) => {
+                Some(Action::Inner(DateSelectPromptAction::GoToPrevMonth))
+            }
+            (MouseEventKind::ScrollDown, _) => {
+                Some(Action::Inner(DateSelectPromptAction::GoToNextMonth))
+            }
+            _ => None,
+        }
+    }
+
     fn render(&self, backend: &mut B) -> InquireResult<()> {
         let prompt = &self.message;
 
@@ -43,11 +43,15 @@ macro_rules! date_test {
     };
 }
 
-date_test!(today_date, vec![Key::Enter], get_current_date());
+date_test!(
+    today_date,
+    vec![Key::Enter(KeyModifiers::NONE)],
+    get_current_date()
+);
 
 date_test!(
     custom_default_date,
-    vec![Key::Enter],
+    vec![Key::Enter(KeyModifiers::NONE)],
     Date::from_calendar_date(2021, time::Month::January, 9).unwrap(),
     DateSelect::new("Date")
         .with_default(Date::from_calendar_date(2021, time::Month::January, 9).unwrap())
@@ -57,7 +61,11 @@ date_test!(
 /// Tests that a closure that actually closes on a variable can be used
 /// as a DateSelect validator.
 fn closure_validator() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Left(KeyModifiers::NONE), Key::Enter]);
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
 
     let today_date = get_current_date();
 
@@ -107,7 +115,7 @@ fn daily_navigation_checks() -> InquireResult<()> {
         Key::Right(KeyModifiers::NONE),
         Key::Right(KeyModifiers::NONE),
         Key::Right(KeyModifiers::NONE),
-        Key::Enter,
+        Key::Enter(KeyModifiers::NONE),
     ];
     let mut backend = FakeBackend::new(input);
 
@@ -137,7 +145,7 @@ fn weekly_navigation_checks() -> InquireResult<()> {
         Key::Down(KeyModifiers::NONE),
         Key::Down(KeyModifiers::NONE),
         Key::Down(KeyModifiers::NONE),
-        Key::Enter,
+        Key::Enter(KeyModifiers::NONE),
     ];
     let mut backend = FakeBackend::new(input);
 
@@ -162,7 +170,7 @@ fn monthly_navigation_checks() -> InquireResult<()> {
         Key::Char(']', KeyModifiers::NONE),
         Key::Char('[', KeyModifiers::NONE),
         Key::Char('[', KeyModifiers::NONE),
-        Key::Enter,
+        Key::Enter(KeyModifiers::NONE),
     ];
     let mut backend = FakeBackend::new(input);
 
@@ -187,7 +195,7 @@ fn yearly_navigation_checks() -> InquireResult<()> {
         Key::Char('{', KeyModifiers::NONE),
         Key::Char('}', KeyModifiers::NONE),
         Key::Char('}', KeyModifiers::NONE),
-        Key::Enter,
+        Key::Enter(KeyModifiers::NONE),
     ];
     let mut backend = FakeBackend::new(input);
 
@@ -241,7 +249,7 @@ fn naive_navigation_combination() -> InquireResult<()> {
         Key::Right(KeyModifiers::NONE),
         Key::Char('}', KeyModifiers::NONE),
         Key::Up(KeyModifiers::NONE),
-        Key::Enter,
+        Key::Enter(KeyModifiers::NONE),
     ];
     let mut backend = FakeBackend::new(input);
 
@@ -295,7 +303,7 @@ fn emacs_naive_navigation_combination() -> InquireResult<()> {
         Key::Char('f', KeyModifiers::CONTROL),
         Key::Char('}', KeyModifiers::NONE),
         Key::Char('p', KeyModifiers::CONTROL),
-        Key::Enter,
+        Key::Enter(KeyModifiers::NONE),
     ];
     let mut backend = FakeBackend::new(input);
 
@@ -349,7 +357,7 @@ fn vim_naive_navigation_combination() -> InquireResult<()> {
         Key::Char('l', KeyModifiers::NONE),
         Key::Char('}', KeyModifiers::NONE),
         Key::Char('k', KeyModifiers::NONE),
-        Key::Enter,
+        Key::Enter(KeyModifiers::NONE),
     ];
     let mut backend = FakeBackend::new(input);
 
@@ -366,7 +374,7 @@ fn vim_naive_navigation_combination() -> InquireResult<()> {
 
 #[test]
 fn default_help_message_exists_and_is_rendered() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let _ = DateSelect::new("Question").prompt_with_backend(&mut backend)?;
 
@@ -400,7 +408,7 @@ fn default_help_message_exists_and_is_rendered() -> InquireResult<()> {
 
 #[test]
 fn custom_help_message_is_rendered() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let _ = DateSelect::new("Question")
         .with_help_message("Custom help message")
@@ -434,7 +442,7 @@ fn custom_help_message_is_rendered() -> InquireResult<()> {
 
 #[test]
 fn removing_help_message_results_in_no_help_message_rendered() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let _ = DateSelect::new("Question")
         .without_help_message()
@@ -458,7 +466,7 @@ fn removing_help_message_results_in_no_help_message_rendered() -> InquireResult<
 
 #[test]
 fn backend_receives_correct_default_week_start() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let _ = DateSelect::new("Question").prompt_with_backend(&mut backend)?;
 
@@ -485,7 +493,7 @@ fn backend_receives_correct_default_week_start() -> InquireResult<()> {
 
 #[test]
 fn backend_receives_correct_custom_week_start() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let _ = DateSelect::new("Question")
         .with_week_start(chrono::Weekday::Wed)
@@ -515,7 +523,7 @@ fn backend_receives_correct_custom_week_start() -> InquireResult<()> {
 #[test]
 fn set_min_date_is_respected() -> InquireResult<()> {
     let mut moves = vec![Key::Left(KeyModifiers::NONE); 200];
-    moves.push(Key::Enter);
+    moves.push(Key::Enter(KeyModifiers::NONE));
     let mut backend = FakeBackend::new(moves);
 
     let custom_min_date = NaiveDate::from_ymd_opt(2022, 12, 25).unwrap();
@@ -549,7 +557,7 @@ fn set_min_date_is_respected() -> InquireResult<()> {
 #[test]
 fn set_max_date_is_respected() -> InquireResult<()> {
     let mut moves = vec![Key::Right(KeyModifiers::NONE); 200];
-    moves.push(Key::Enter);
+    moves.push(Key::Enter(KeyModifiers::NONE));
     let mut backend = FakeBackend::new(moves);
 
     let custom_max_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
@@ -583,7 +591,7 @@ fn set_max_date_is_respected() -> InquireResult<()> {
 #[test]
 fn no_min_date_means_you_can_go_very_far() -> InquireResult<()> {
     let mut moves = vec![Key::Char('{', KeyModifiers::NONE); 2000]; // 2000 years back!
-    moves.push(Key::Enter);
+    moves.push(Key::Enter(KeyModifiers::NONE));
     let mut backend = FakeBackend::new(moves);
 
     let answer = DateSelect::new("Question")
@@ -620,7 +628,7 @@ fn no_min_date_means_you_can_go_very_far() -> InquireResult<()> {
 #[test]
 fn no_max_date_means_you_can_go_very_far() -> InquireResult<()> {
     let mut moves = vec![Key::Char('}', KeyModifiers::NONE); 2000]; // 2000 years forward!
-    moves.push(Key::Enter);
+    moves.push(Key::Enter(KeyModifiers::NONE));
     let mut backend = FakeBackend::new(moves);
 
     let answer = DateSelect::new("Question")
@@ -658,7 +666,7 @@ fn no_max_date_means_you_can_go_very_far() -> InquireResult<()> {
 // this test might fail if `today` is set to A and the prompt is initialized
 // right after the day turns, becoming A+1, but it's unlikely to happen
 fn starting_date_is_today_by_default() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let today = chrono::Local::now().date_naive();
     let prompt = DateSelect::new("Question");
@@ -696,7 +704,7 @@ fn starting_date_is_today_by_default() -> InquireResult<()> {
 
 #[test]
 fn custom_starting_date_is_respected_and_selected_by_default() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let custom_starting_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
     let prompt = DateSelect::new("Question").with_starting_date(custom_starting_date);
@@ -734,7 +742,7 @@ fn custom_starting_date_is_respected_and_selected_by_default() -> InquireResult<
 
 #[test]
 fn custom_formatter_affects_final_output() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let starting_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
     let custom_formatter = |d: NaiveDate| d.format("WOW! %Y hmm %m xd %d").to_string();
@@ -763,7 +771,7 @@ fn custom_formatter_affects_final_output() -> InquireResult<()> {
 
 #[test]
 fn default_formatter_outputs_answer_as_extensive_locale() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let mut backend = FakeBackend::new(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let starting_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
     let expected_output = starting_date.format("%B %-e, %Y").to_string();
@@ -788,7 +796,7 @@ fn default_formatter_outputs_answer_as_extensive_locale() -> InquireResult<()> {
 
 #[test]
 fn escape_raises_error() -> InquireResult<()> {
-    let mut backend = FakeBackend::new(vec![Key::Escape]);
+    let mut backend = FakeBackend::new(vec![Key::Escape(KeyModifiers::NONE)]);
 
     let result = DateSelect::new("Question").prompt_with_backend(&mut backend);
 
@@ -836,8 +844,11 @@ fn ctrl_c_interrupts_prompt() -> InquireResult<()> {
 
 #[test]
 fn validator_is_respected() -> InquireResult<()> {
-    let mut backend =
-        FakeBackend::new(vec![Key::Enter, Key::Right(KeyModifiers::NONE), Key::Enter]);
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
 
     let result = DateSelect::new("Question")
         .with_validator(|d: NaiveDate| {
@@ -904,15 +915,15 @@ fn validator_is_respected() -> InquireResult<()> {
 #[test]
 fn multiple_validators_are_respected() -> InquireResult<()> {
     let mut backend = FakeBackend::new(vec![
-        Key::Enter, // 01-01
+        Key::Enter(KeyModifiers::NONE), // 01-01
         Key::Right(KeyModifiers::NONE),
-        Key::Enter, // 01-02
+        Key::Enter(KeyModifiers::NONE), // 01-02
         Key::Right(KeyModifiers::NONE),
-        Key::Enter, // 01-03
+        Key::Enter(KeyModifiers::NONE), // 01-03
         Key::Right(KeyModifiers::NONE),
-        Key::Enter, // 01-04
+        Key::Enter(KeyModifiers::NONE), // 01-04
         Key::Right(KeyModifiers::NONE),
-        Key::Enter, // 01-05
+        Key::Enter(KeyModifiers::NONE), // 01-05
     ]);
 
     let result = DateSelect::new("Question")
@@ -0,0 +1,220 @@
+mod shape;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+mod value;
+
+use std::{marker::PhantomData, str::FromStr};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    ui::{Output, RenderConfig},
+    Confirm, CustomType, Select, Text,
+};
+
+use self::{shape::Shape, value::Value};
+
+use super::form::{Step, StepBackend};
+
+/// Prompts the value of a type implementing serde's `Deserialize`, asking for
+/// each of its fields with the prompt matching its type.
+///
+/// The shape of the type is traced from its `Deserialize` implementation, and
+/// the answers are deserialized back into it. Fields are prompted in their
+/// declaration order:
+///
+/// - `bool`: [`Confirm`]
+/// - Strings: [`Text`]
+/// - Numbers and `char`: [`CustomType`]
+/// - Enums whose variants have no fields: [`Select`], listing the variants
+/// - Structs: their fields are prompted in turn
+/// - `Option` fields are skippable, being `None` when the user presses ESC, as
+///   with `prompt_skippable`. Optional structs are prompted only if the user
+///   confirms setting them.
+///
+/// The message of each prompt is built from the names of the field and of its
+/// parents, e.g. `Database host:` for the `host` field of a `database` field.
+///
+/// Sequences, maps, tuples and enum variants with fields are not supported,
+/// nor are types whose `Deserialize` implementation rejects the placeholder
+/// values used to trace them, such as `false`, `0` or an empty string. In
+/// those cases, [`InquireError::InvalidConfiguration`] is returned before any
+/// prompt.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::DeserializePrompt;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     name: String,
+///     port: u16,
+///     verbose: bool,
+///     proxy: Option<String>,
+/// }
+///
+/// let config: Config = DeserializePrompt::new().prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+pub struct DeserializePrompt<'a, T> {
+    render_config: RenderConfig<'a>,
+    output: Output,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> DeserializePrompt<'a, T>
+where
+    T: DeserializeOwned,
+{
+    /// Creates a prompt for the fields of `T`.
+    pub fn new() -> Self {
+        Self {
+            render_config: get_configuration(),
+            output: Output::default(),
+            _type: PhantomData,
+        }
+    }
+
+    /// Sets the render configuration of all prompts.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Sets the destination where all prompts are rendered.
+    pub fn with_output(mut self, output: Output) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Prompts the fields of `T`, returning the value deserialized from the
+    /// answers.
+    pub fn prompt(self) -> InquireResult<T> {
+        let output = self.output.clone();
+        self.run(StepBackend::Terminal(&output))
+    }
+
+    fn run(self, mut backend: StepBackend<'_>) -> InquireResult<T> {
+        let shape = Shape::trace::<T>()?;
+        let value = self.ask(&shape, &mut vec![], &mut backend)?;
+
+        T::deserialize(value).map_err(|err| InquireError::Custom(err.0.into()))
+    }
+
+    fn ask(
+        &self,
+        shape: &Shape,
+        path: &mut Vec<&'static str>,
+        backend: &mut StepBackend<'_>,
+    ) -> InquireResult<Value> {
+        let message = message(path);
+
+        let value = match shape {
+            Shape::Bool => Value::Bool(
+                Confirm::new(&message)
+                    .with_render_config(self.render_config)
+                    .run(None, &[], backend.reborrow())?,
+            ),
+            Shape::I8 => Value::I64(self.custom_type::<i8>(&message, backend)?.into()),
+            Shape::I16 => Value::I64(self.custom_type::<i16>(&message, backend)?.into()),
+            Shape::I32 => Value::I64(self.custom_type::<i32>(&message, backend)?.into()),
+            Shape::I64 => Value::I64(self.custom_type(&message, backend)?),
+            Shape::I128 => Value::I128(self.custom_type(&message, backend)?),
+            Shape::U8 => Value::U64(self.custom_type::<u8>(&message, backend)?.into()),
+            Shape::U16 => Value::U64(self.custom_type::<u16>(&message, backend)?.into()),
+            Shape::U32 => Value::U64(self.custom_type::<u32>(&message, backend)?.into()),
+            Shape::U64 => Value::U64(self.custom_type(&message, backend)?),
+            Shape::U128 => Value::U128(self.custom_type(&message, backend)?),
+            Shape::F32 => Value::F64(self.custom_type::<f32>(&message, backend)?.into()),
+            Shape::F64 => Value::F64(self.custom_type(&message, backend)?),
+            Shape::Char => Value::Char(self.custom_type(&message, backend)?),
+            Shape::Str => Value::Str(
+                Text::new(&message)
+                    .with_render_config(self.render_config)
+                    .run(None, &[], backend.reborrow())?,
+            ),
+            Shape::Unit => Value::Unit,
+            Shape::Enum(variants) => Value::UnitVariant(
+                Select::new(&message, variants.to_vec())
+                    .with_render_config(self.render_config)
+                    .run(None, &[], backend.reborrow())?
+                    .value,
+            ),
+            Shape::Newtype(inner) => Value::Newtype(Box::new(self.ask(inner, path, backend)?)),
+            Shape::Option(inner) if inner.is_single_prompt() => {
+                match self.ask(inner, path, backend) {
+                    Ok(value) => Value::Some(Box::new(value)),
+                    Err(InquireError::OperationCanceled) => Value::None,
+                    Err(err) => return Err(err),
+                }
+            }
+            Shape::Option(inner) => {
+                let set = Confirm::new(&format!("Set {}?", describe(path)))
+                    .with_default(false)
+                    .with_render_config(self.render_config)
+                    .run(None, &[], backend.reborrow())?;
+
+                if set {
+                    Value::Some(Box::new(self.ask(inner, path, backend)?))
+                } else {
+                    Value::None
+                }
+            }
+            Shape::Struct(fields) => {
+                let mut values = Vec::with_capacity(fields.len());
+                for (name, field) in fields {
+                    path.push(name);
+                    let value = self.ask(field, path, backend);
+                    path.pop();
+                    values.push((*name, value?));
+                }
+                Value::Struct(values)
+            }
+        };
+
+        Ok(value)
+    }
+
+    fn custom_type<N>(&self, message: &str, backend: &mut StepBackend<'_>) -> InquireResult<N>
+    where
+        N: FromStr + ToString + Clone + 'static,
+    {
+        CustomType::<N>::new(message)
+            .with_render_config(self.render_config)
+            .run(None, &[], backend.reborrow())
+    }
+}
+
+impl<'a, T> Default for DeserializePrompt<'a, T>
+where
+    T: DeserializeOwned,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Describes the field at `path`, e.g. `database host` for `database.host`.
+fn describe(path: &[&str]) -> String {
+    if path.is_empty() {
+        return String::from("value");
+    }
+
+    path.join(" ").replace('_', " ")
+}
+
+/// Message of the prompt of the field at `path`, e.g. `Database host:`, or
+/// `Value:` for a type that is not a struct.
+fn message(path: &[&str]) -> String {
+    let description = describe(path);
+    let mut chars = description.chars();
+    match chars.next() {
+        Some(first) => format!("{}{}:", first.to_uppercase(), chars.as_str()),
+        None => String::from(":"),
+    }
+}
@@ -0,0 +1,379 @@
+use std::fmt::{self, Display};
+
+use serde::de::{
+    self, value::StrDeserializer, DeserializeOwned, DeserializeSeed, Deserializer, EnumAccess,
+    MapAccess, VariantAccess, Visitor,
+};
+
+use crate::error::{InquireError, InquireResult};
+
+/// Maximum nesting of traced types, guarding against recursive types.
+const MAX_DEPTH: usize = 32;
+
+/// Shape of a type, as requested from its `Deserialize` implementation.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Shape {
+    Bool,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    F32,
+    F64,
+    Char,
+    Str,
+    Unit,
+    Option(Box<Shape>),
+    Newtype(Box<Shape>),
+    Struct(Vec<(&'static str, Shape)>),
+    /// Enum whose variants have no fields, by name.
+    Enum(&'static [&'static str]),
+}
+
+impl Shape {
+    /// Traces the shape of `T` by deserializing it from placeholder values.
+    pub(super) fn trace<T>() -> InquireResult<Shape>
+    where
+        T: DeserializeOwned,
+    {
+        let result = trace_with_variant::<T>(None).and_then(|shape| {
+            // only one variant of each enum is visited per trace, so the
+            // others are checked for fields by tracing the type again.
+            let mut enums = vec![];
+            shape.collect_enums(&mut enums);
+            for variants in enums {
+                for index in 1..variants.len() {
+                    trace_with_variant::<T>(Some((variants, index)))?;
+                }
+            }
+
+            Ok(shape)
+        });
+
+        result.map_err(|err| {
+            InquireError::InvalidConfiguration(format!("Type can not be prompted: {}", err))
+        })
+    }
+
+    fn collect_enums(&self, enums: &mut Vec<&'static [&'static str]>) {
+        match self {
+            Shape::Option(inner) | Shape::Newtype(inner) => inner.collect_enums(enums),
+            Shape::Struct(fields) => {
+                for (_, shape) in fields {
+                    shape.collect_enums(enums);
+                }
+            }
+            Shape::Enum(variants) => enums.push(variants),
+            _ => {}
+        }
+    }
+
+    /// Whether the shape is answered with a single prompt.
+    pub(super) fn is_single_prompt(&self) -> bool {
+        match self {
+            Shape::Unit | Shape::Option(_) | Shape::Struct(_) => false,
+            Shape::Newtype(inner) => inner.is_single_prompt(),
+            _ => true,
+        }
+    }
+}
+
+/// Error of the tracing and of the deserialization of answers.
+#[derive(Debug)]
+pub(super) struct DeError(pub(super) String);
+
+impl Display for DeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for DeError {}
+
+impl de::Error for DeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        DeError(msg.to_string())
+    }
+}
+
+/// Variant visited when tracing enums with the given variants, identified by
+/// its index. Enums not matching it visit their first variant.
+type VariantChoice = Option<(&'static [&'static str], usize)>;
+
+fn trace_with_variant<T>(variant: VariantChoice) -> Result<Shape, DeError>
+where
+    T: DeserializeOwned,
+{
+    let mut shape = None;
+    T::deserialize(Tracer {
+        shape: &mut shape,
+        depth: 0,
+        variant,
+    })?;
+
+    traced(shape)
+}
+
+fn traced(shape: Option<Shape>) -> Result<Shape, DeError> {
+    shape.ok_or_else(|| DeError(String::from("the type did not request a value")))
+}
+
+fn unsupported(what: &str) -> DeError {
+    DeError(format!("{} are not supported", what))
+}
+
+/// Deserializer recording the shape requested by the type.
+struct Tracer<'s> {
+    shape: &'s mut Option<Shape>,
+    depth: usize,
+    variant: VariantChoice,
+}
+
+impl<'s> Tracer<'s> {
+    fn check_depth(&self) -> Result<(), DeError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(DeError(String::from(
+                "the type is recursive or too deeply nested",
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn nested<'n>(&self, shape: &'n mut Option<Shape>) -> Result<Tracer<'n>, DeError> {
+        self.check_depth()?;
+
+        Ok(Tracer {
+            shape,
+            depth: self.depth + 1,
+            variant: self.variant,
+        })
+    }
+}
+
+macro_rules! trace_scalar {
+    ($($method:ident => $shape:ident, $visit:ident($($value:expr)?);)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+                *self.shape = Some(Shape::$shape);
+                visitor.$visit($($value)?)
+            }
+        )*
+    };
+}
+
+macro_rules! trace_unsupported {
+    ($($method:ident => $what:expr;)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, DeError> {
+                Err(unsupported($what))
+            }
+        )*
+    };
+}
+
+impl<'de, 's> Deserializer<'de> for Tracer<'s> {
+    type Error = DeError;
+
+    trace_scalar! {
+        deserialize_bool => Bool, visit_bool(false);
+        deserialize_i8 => I8, visit_i8(0);
+        deserialize_i16 => I16, visit_i16(0);
+        deserialize_i32 => I32, visit_i32(0);
+        deserialize_i64 => I64, visit_i64(0);
+        deserialize_i128 => I128, visit_i128(0);
+        deserialize_u8 => U8, visit_u8(0);
+        deserialize_u16 => U16, visit_u16(0);
+        deserialize_u32 => U32, visit_u32(0);
+        deserialize_u64 => U64, visit_u64(0);
+        deserialize_u128 => U128, visit_u128(0);
+        deserialize_f32 => F32, visit_f32(0.0);
+        deserialize_f64 => F64, visit_f64(0.0);
+        deserialize_char => Char, visit_char(' ');
+        deserialize_str => Str, visit_str("");
+        deserialize_string => Str, visit_string(String::new());
+        deserialize_unit => Unit, visit_unit();
+    }
+
+    trace_unsupported! {
+        deserialize_any => "Self-describing types";
+        deserialize_bytes => "Bytes";
+        deserialize_byte_buf => "Bytes";
+        deserialize_seq => "Sequences";
+        deserialize_map => "Maps";
+        deserialize_identifier => "Identifiers";
+        deserialize_ignored_any => "Ignored values";
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        let mut inner = None;
+        let value = visitor.visit_some(self.nested(&mut inner)?)?;
+        *self.shape = Some(Shape::Option(Box::new(traced(inner)?)));
+
+        Ok(value)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        *self.shape = Some(Shape::Unit);
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        let mut inner = None;
+        let value = visitor.visit_newtype_struct(self.nested(&mut inner)?)?;
+        *self.shape = Some(Shape::Newtype(Box::new(traced(inner)?)));
+
+        Ok(value)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        _visitor: V,
+    ) -> Result<V::Value, DeError> {
+        Err(unsupported("Tuples"))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        _visitor: V,
+    ) -> Result<V::Value, DeError> {
+        Err(unsupported("Tuple structs"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.check_depth()?;
+
+        let mut shapes = Vec::with_capacity(fields.len());
+        let value = visitor.visit_map(TraceFields {
+            fields,
+            shapes: &mut shapes,
+            depth: self.depth + 1,
+            variant: self.variant,
+        })?;
+        *self.shape = Some(Shape::Struct(shapes));
+
+        Ok(value)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        let index = match self.variant {
+            Some((chosen, index)) if chosen == variants => index,
+            _ => 0,
+        };
+        let variant = match variants.get(index) {
+            Some(variant) => *variant,
+            None => return Err(unsupported("Enums without variants")),
+        };
+
+        *self.shape = Some(Shape::Enum(variants));
+        visitor.visit_enum(TraceVariant(variant))
+    }
+}
+
+/// Fields of a traced struct, recording the shape of each one.
+struct TraceFields<'s> {
+    fields: &'static [&'static str],
+    shapes: &'s mut Vec<(&'static str, Shape)>,
+    depth: usize,
+    variant: VariantChoice,
+}
+
+impl<'de, 's> MapAccess<'de> for TraceFields<'s> {
+    type Error = DeError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, DeError>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.fields.get(self.shapes.len()) {
+            Some(field) => seed.deserialize(StrDeserializer::new(field)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, DeError>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let field = self.fields[self.shapes.len()];
+        let mut shape = None;
+        let value = seed.deserialize(Tracer {
+            shape: &mut shape,
+            depth: self.depth,
+            variant: self.variant,
+        })?;
+        self.shapes.push((field, traced(shape)?));
+
+        Ok(value)
+    }
+}
+
+/// Variant of a traced enum, which must not have fields.
+struct TraceVariant(&'static str);
+
+impl<'de> EnumAccess<'de> for TraceVariant {
+    type Error = DeError;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self), DeError>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(StrDeserializer::new(self.0))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for TraceVariant {
+    type Error = DeError;
+
+    fn unit_variant(self) -> Result<(), DeError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, _seed: T) -> Result<T::Value, DeError>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        Err(unsupported("Enum variants with fields"))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, _visitor: V) -> Result<V::Value, DeError> {
+        Err(unsupported("Enum variants with fields"))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, DeError> {
+        Err(unsupported("Enum variants with fields"))
+    }
+}
@@ -0,0 +1,238 @@
+use serde::Deserialize;
+
+use crate::{
+    error::{InquireError, InquireResult},
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    DeserializePrompt,
+};
+
+use super::{shape::Shape, StepBackend};
+
+fn type_str(keys: &mut Vec<Key>, input: &str) {
+    keys.extend(input.chars().map(|c| Key::Char(c, KeyModifiers::NONE)));
+}
+
+fn enter(keys: &mut Vec<Key>) {
+    keys.push(Key::Enter(KeyModifiers::NONE));
+}
+
+fn escape(keys: &mut Vec<Key>) {
+    keys.push(Key::Escape(KeyModifiers::NONE));
+}
+
+fn run<T>(keys: Vec<Key>) -> InquireResult<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut backend = fake_backend(keys);
+    DeserializePrompt::new().run(StepBackend::Fake(&mut backend))
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Config {
+    name: String,
+    workers: u8,
+    ratio: f32,
+    verbose: bool,
+    level: Level,
+    database: Database,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Database {
+    host: String,
+    port: Port,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Port(u16);
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Level {
+    Error,
+    Warn,
+    Info,
+}
+
+#[test]
+fn shape_is_traced_from_deserialize() {
+    let shape = Shape::trace::<Config>().unwrap();
+
+    let expected = Shape::Struct(vec![
+        ("name", Shape::Str),
+        ("workers", Shape::U8),
+        ("ratio", Shape::F32),
+        ("verbose", Shape::Bool),
+        ("level", Shape::Enum(&["error", "warn", "info"])),
+        (
+            "database",
+            Shape::Struct(vec![
+                ("host", Shape::Str),
+                ("port", Shape::Newtype(Box::new(Shape::U16))),
+            ]),
+        ),
+    ]);
+    assert_eq!(expected, shape);
+}
+
+#[test]
+fn fields_are_prompted_and_deserialized() {
+    let mut keys = vec![];
+    type_str(&mut keys, "api");
+    enter(&mut keys);
+    type_str(&mut keys, "4");
+    enter(&mut keys);
+    type_str(&mut keys, "0.5");
+    enter(&mut keys);
+    type_str(&mut keys, "y");
+    enter(&mut keys);
+    keys.push(Key::Down(KeyModifiers::NONE));
+    enter(&mut keys);
+    type_str(&mut keys, "localhost");
+    enter(&mut keys);
+    type_str(&mut keys, "5432");
+    enter(&mut keys);
+
+    let config: Config = run(keys).unwrap();
+
+    let expected = Config {
+        name: String::from("api"),
+        workers: 4,
+        ratio: 0.5,
+        verbose: true,
+        level: Level::Warn,
+        database: Database {
+            host: String::from("localhost"),
+            port: Port(5432),
+        },
+    };
+    assert_eq!(expected, config);
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Optional {
+    proxy: Option<String>,
+    retries: Option<u32>,
+    database: Option<Database>,
+}
+
+#[test]
+fn optional_fields_are_skipped_with_escape() {
+    let mut keys = vec![];
+    escape(&mut keys);
+    type_str(&mut keys, "3");
+    enter(&mut keys);
+    // does not set the database
+    enter(&mut keys);
+
+    let optional: Optional = run(keys).unwrap();
+
+    let expected = Optional {
+        proxy: None,
+        retries: Some(3),
+        database: None,
+    };
+    assert_eq!(expected, optional);
+}
+
+#[test]
+fn optional_structs_are_prompted_when_confirmed() {
+    let mut keys = vec![];
+    escape(&mut keys);
+    escape(&mut keys);
+    type_str(&mut keys, "y");
+    enter(&mut keys);
+    type_str(&mut keys, "db");
+    enter(&mut keys);
+    type_str(&mut keys, "1");
+    enter(&mut keys);
+
+    let optional: Optional = run(keys).unwrap();
+
+    let expected = Optional {
+        proxy: None,
+        retries: None,
+        database: Some(Database {
+            host: String::from("db"),
+            port: Port(1),
+        }),
+    };
+    assert_eq!(expected, optional);
+}
+
+#[test]
+fn escape_on_required_field_cancels() {
+    let mut keys = vec![];
+    escape(&mut keys);
+
+    let result: InquireResult<Database> = run(keys);
+
+    assert!(matches!(result, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn type_that_is_not_a_struct_is_prompted_as_value() {
+    let mut keys = vec![];
+    type_str(&mut keys, "42");
+    enter(&mut keys);
+
+    let value: i64 = run(keys).unwrap();
+
+    assert_eq!(42, value);
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct Unsupported {
+    tags: Vec<String>,
+}
+
+#[test]
+fn unsupported_types_are_rejected_before_prompting() {
+    let result: InquireResult<Unsupported> = run(vec![]);
+
+    assert!(matches!(
+        result,
+        Err(InquireError::InvalidConfiguration(msg)) if msg.contains("Sequences")
+    ));
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+enum Payload {
+    A,
+    B(u8),
+}
+
+#[test]
+fn enum_variants_with_fields_are_rejected_before_prompting() {
+    let result: InquireResult<Payload> = run(vec![]);
+
+    assert!(matches!(
+        result,
+        Err(InquireError::InvalidConfiguration(msg)) if msg.contains("Enum variants with fields")
+    ));
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct Node {
+    value: u8,
+    next: Option<Box<Node>>,
+}
+
+#[test]
+fn recursive_types_are_rejected() {
+    let result: InquireResult<Node> = run(vec![]);
+
+    assert!(matches!(result, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn message_is_built_from_field_path() {
+    assert_eq!("Database host:", super::message(&["database", "host"]));
+    assert_eq!("Log level:", super::message(&["log_level"]));
+    assert_eq!("Value:", super::message(&[]));
+}
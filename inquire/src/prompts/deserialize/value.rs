@@ -0,0 +1,70 @@
+use serde::{
+    de::{
+        value::{MapDeserializer, StrDeserializer},
+        Deserializer, IntoDeserializer, Visitor,
+    },
+    forward_to_deserialize_any,
+};
+
+use super::shape::DeError;
+
+/// Answers to the prompts of a type, deserialized into it.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Value {
+    Bool(bool),
+    I64(i64),
+    I128(i128),
+    U64(u64),
+    U128(u128),
+    F64(f64),
+    Char(char),
+    Str(String),
+    Unit,
+    None,
+    Some(Box<Value>),
+    Newtype(Box<Value>),
+    Struct(Vec<(&'static str, Value)>),
+    UnitVariant(&'static str),
+}
+
+impl<'de> Deserializer<'de> for Value {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self {
+            Value::Bool(value) => visitor.visit_bool(value),
+            Value::I64(value) => visitor.visit_i64(value),
+            Value::I128(value) => visitor.visit_i128(value),
+            Value::U64(value) => visitor.visit_u64(value),
+            Value::U128(value) => visitor.visit_u128(value),
+            Value::F64(value) => visitor.visit_f64(value),
+            Value::Char(value) => visitor.visit_char(value),
+            Value::Str(value) => visitor.visit_string(value),
+            Value::Unit => visitor.visit_unit(),
+            Value::None => visitor.visit_none(),
+            Value::Some(value) => visitor.visit_some(*value),
+            Value::Newtype(value) => visitor.visit_newtype_struct(*value),
+            Value::Struct(fields) => {
+                let mut map = MapDeserializer::new(fields.into_iter());
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            Value::UnitVariant(variant) => visitor.visit_enum(StrDeserializer::new(variant)),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, DeError> for Value {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
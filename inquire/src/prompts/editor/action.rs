@@ -1,4 +1,7 @@
-use crate::{ui::Key, InnerAction};
+use crate::{
+    keymap::{Command, KeyScope},
+    InnerAction,
+};
 
 use super::config::EditorConfig;
 
@@ -12,9 +15,13 @@ pub enum EditorPromptAction {
 impl InnerAction for EditorPromptAction {
     type Config = EditorConfig;
 
-    fn from_key(key: Key, _config: &EditorConfig) -> Option<Self> {
-        let action = match key {
-            Key::Char('e', _) => Self::OpenEditor,
+    fn key_scopes(_config: &EditorConfig) -> &'static [KeyScope] {
+        &[KeyScope::Editor]
+    }
+
+    fn from_command(command: Command, _config: &EditorConfig) -> Option<Self> {
+        let action = match command {
+            Command::OpenEditor => Self::OpenEditor,
             _ => return None,
         };
 
@@ -12,13 +12,18 @@ use std::{
 use once_cell::sync::Lazy;
 
 use crate::{
+    config::get_key_map,
     error::{InquireError, InquireResult},
     formatter::StringFormatter,
+    keymap::KeyMap,
     prompts::prompt::Prompt,
+    prompts::Timeout,
     terminal::get_default_terminal,
-    ui::{Backend, EditorBackend, RenderConfig},
+    ui::{Backend, EditorBackend, Output, RenderConfig},
     validator::StringValidator,
 };
+#[cfg(feature = "async")]
+use crate::{prompts::prompt::prompt_async, terminal::get_default_async_terminal};
 
 use self::prompt::EditorPrompt;
 
@@ -79,6 +84,11 @@ pub struct Editor<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Key bindings used to parse the user's key presses into actions.
+    ///
+    /// Defaults to the global key map, see [set_global_key_map](crate::set_global_key_map).
+    pub key_map: KeyMap,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -88,6 +98,14 @@ pub struct Editor<'a> {
     /// config is treated as the only source of truth. If you want to customize colors
     /// and still support NO_COLOR, you will have to do this on your end.
     pub render_config: RenderConfig<'a>,
+
+    /// Destination where the prompt is rendered, stderr by default.
+    ///
+    /// Keys are read from the terminal regardless of the output.
+    pub output: Output,
+
+    /// Time limit for the user to answer the prompt, if any.
+    pub timeout: Option<Timeout>,
 }
 
 impl<'a> Editor<'a> {
@@ -111,7 +129,10 @@ impl<'a> Editor<'a> {
             help_message: Self::DEFAULT_HELP_MESSAGE,
             validators: Self::DEFAULT_VALIDATORS,
             formatter: Self::DEFAULT_FORMATTER,
+            key_map: get_key_map(),
             render_config: RenderConfig::default(),
+            output: Output::default(),
+            timeout: None,
         }
     }
 
@@ -183,6 +204,26 @@ impl<'a> Editor<'a> {
         self
     }
 
+    /// Sets a time limit for the user to answer the prompt.
+    ///
+    /// Accepts either a [`Duration`](std::time::Duration), in which case the
+    /// prompt returns `Err(InquireError::Timeout)` on expiry, or a [`Timeout`]
+    /// describing what happens on expiry and whether a countdown is displayed.
+    pub fn with_timeout<D>(mut self, timeout: D) -> Self
+    where
+        D: Into<Timeout>,
+    {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// Sets the key bindings used to parse the user's key presses into actions,
+    /// overriding the global key map.
+    pub fn with_key_map(mut self, key_map: KeyMap) -> Self {
+        self.key_map = key_map;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -196,6 +237,13 @@ impl<'a> Editor<'a> {
         self
     }
 
+    /// Sets the destination where the prompt is rendered, such as
+    /// [`Output::Stderr`] to keep stdout free for the program's output.
+    pub fn with_output(mut self, output: Output) -> Self {
+        self.output = output;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -216,11 +264,37 @@ impl<'a> Editor<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<String> {
-        let (input_reader, terminal) = get_default_terminal()?;
+        let (input_reader, terminal) = get_default_terminal(&self.output)?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Asynchronous version of [`prompt`](Self::prompt), which does not block
+    /// the executor while waiting for the user's input.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<String> {
+        self.prompt_async_with_cancellation(std::future::pending())
+            .await
+    }
+
+    /// Asynchronous version of [`prompt`](Self::prompt) that can be aborted
+    /// by the application, e.g. by passing `token.cancelled()` of a tokio
+    /// `CancellationToken`.
+    ///
+    /// When `cancellation` resolves before the user submits an answer, the
+    /// prompt is torn down and `Err(InquireError::OperationAborted)` is returned.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async_with_cancellation<C>(self, cancellation: C) -> InquireResult<String>
+    where
+        C: std::future::Future<Output = ()>,
+    {
+        let (input_reader, terminal) = get_default_async_terminal(&self.output)?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        prompt_async(EditorPrompt::new(self)?, &mut backend, cancellation).await
+    }
+
     pub(crate) fn prompt_with_backend<B: EditorBackend>(
         self,
         backend: &mut B,
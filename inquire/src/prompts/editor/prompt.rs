@@ -5,7 +5,11 @@ use tempfile::NamedTempFile;
 use crate::{
     error::InquireResult,
     formatter::StringFormatter,
-    prompts::prompt::{ActionResult, Prompt},
+    keymap::KeyMap,
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        Timeout,
+    },
     ui::EditorBackend,
     validator::{ErrorMessage, StringValidator, Validation},
     Editor, InquireError,
@@ -16,11 +20,13 @@ use super::{action::EditorPromptAction, config::EditorConfig};
 pub struct EditorPrompt<'a> {
     message: &'a str,
     config: EditorConfig,
+    key_map: KeyMap,
     help_message: Option<&'a str>,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
     error: Option<ErrorMessage>,
     tmp_file: NamedTempFile,
+    timeout: Option<Timeout>,
 }
 
 impl<'a> From<&'a str> for Editor<'a> {
@@ -34,11 +40,13 @@ impl<'a> EditorPrompt<'a> {
         Ok(Self {
             message: so.message,
             config: (&so).into(),
+            key_map: so.key_map,
             help_message: so.help_message,
             formatter: so.formatter,
             validators: so.validators,
             error: None,
             tmp_file: Self::create_file(so.file_extension, so.predefined_text)?,
+            timeout: so.timeout,
         })
     }
 
@@ -112,6 +120,10 @@ where
         &self.config
     }
 
+    fn key_map(&self) -> &KeyMap {
+        &self.key_map
+    }
+
     fn format_answer(&self, answer: &String) -> String {
         (self.formatter)(answer)
     }
@@ -137,6 +149,10 @@ where
         }
     }
 
+    fn timeout(&self) -> Option<Timeout> {
+        self.timeout
+    }
+
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
@@ -0,0 +1,137 @@
+use std::any::Any;
+
+use crate::error::{InquireError, InquireResult};
+
+/// Answers to the steps of a [`Form`](crate::Form), by step name.
+///
+/// Answers are stored with the type returned by the prompt of their step,
+/// e.g. `String` for [`Text`](crate::Text) prompts or `ListOption<T>` for
+/// [`Select`](crate::Select) prompts. Steps skipped because of their
+/// condition have no answer.
+#[derive(Debug, Default)]
+pub struct FormAnswers {
+    answers: Vec<(String, Box<dyn Any>)>,
+}
+
+impl FormAnswers {
+    /// Returns the answer to the step, if it was answered and its type is `T`.
+    pub fn get<T>(&self, name: &str) -> Option<&T>
+    where
+        T: 'static,
+    {
+        self.answers
+            .iter()
+            .find(|(n, _)| n == name)
+            .and_then(|(_, answer)| answer.downcast_ref())
+    }
+
+    /// Removes and returns the answer to the step, if it was answered and
+    /// its type is `T`.
+    pub fn remove<T>(&mut self, name: &str) -> Option<T>
+    where
+        T: 'static,
+    {
+        let position = self
+            .answers
+            .iter()
+            .position(|(n, answer)| n == name && answer.is::<T>())?;
+        let (_, answer) = self.answers.remove(position);
+
+        answer.downcast().ok().map(|answer| *answer)
+    }
+
+    /// Removes and returns the answer to the step, failing if it was not
+    /// answered or if its type is not `T`.
+    ///
+    /// This is meant to be used in [`FromFormAnswers`] implementations.
+    pub fn take<T>(&mut self, name: &str) -> InquireResult<T>
+    where
+        T: 'static,
+    {
+        match self.remove(name) {
+            Some(answer) => Ok(answer),
+            None if self.contains(name) => Err(InquireError::InvalidConfiguration(format!(
+                "The answer to form step `{}` is not of type `{}`",
+                name,
+                std::any::type_name::<T>()
+            ))),
+            None => Err(InquireError::InvalidConfiguration(format!(
+                "Form step `{}` was not answered",
+                name
+            ))),
+        }
+    }
+
+    /// Returns whether the step was answered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.answers.iter().any(|(n, _)| n == name)
+    }
+
+    /// Names of the answered steps, in the order they were answered.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.answers.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Number of answered steps.
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    /// Returns whether no step was answered.
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+
+    pub(super) fn push(&mut self, name: &str, answer: Box<dyn Any>) {
+        self.answers.push((name.to_string(), answer));
+    }
+
+    pub(super) fn pop(&mut self) -> Option<(String, Box<dyn Any>)> {
+        self.answers.pop()
+    }
+
+    pub(super) fn iter(&self) -> impl Iterator<Item = (&str, &dyn Any)> {
+        self.answers
+            .iter()
+            .map(|(name, answer)| (name.as_str(), answer.as_ref()))
+    }
+}
+
+/// Conversion from the answers of a [`Form`](crate::Form), used by
+/// [`Form::prompt_as`](crate::Form::prompt_as).
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{error::InquireResult, CustomType, Form, FormAnswers, FromFormAnswers, Text};
+///
+/// struct User {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// impl FromFormAnswers for User {
+///     fn from_form_answers(mut answers: FormAnswers) -> InquireResult<Self> {
+///         Ok(Self {
+///             name: answers.take("name")?,
+///             age: answers.take("age")?,
+///         })
+///     }
+/// }
+///
+/// let user: User = Form::new()
+///     .step("name", Text::new("What's your name?"))
+///     .step("age", CustomType::<u32>::new("How old are you?"))
+///     .prompt_as()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+pub trait FromFormAnswers: Sized {
+    /// Builds the value from the answers of the form.
+    fn from_form_answers(answers: FormAnswers) -> InquireResult<Self>;
+}
+
+impl FromFormAnswers for FormAnswers {
+    fn from_form_answers(answers: FormAnswers) -> InquireResult<Self> {
+        Ok(answers)
+    }
+}
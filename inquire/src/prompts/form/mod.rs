@@ -0,0 +1,311 @@
+mod answers;
+mod step;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use answers::*;
+pub use step::FormStep;
+
+pub(crate) use self::step::sealed::{Step, StepBackend};
+
+use std::{any::Any, fmt::Display};
+
+use crate::{
+    config::get_key_map,
+    error::{InquireError, InquireResult},
+    keymap::{Command, KeyMap, KeyPattern, KeyScope},
+    ui::Output,
+    Select,
+};
+
+use self::step::AnyStep;
+
+/// Sequence of named prompts, asked one after the other, whose answers are
+/// returned together.
+///
+/// Any prompt of this crate can be a step of a form. The user can return to
+/// the previous step by pressing `Shift+Tab`, in which case the step is asked
+/// again, pre-filled with its previous answer, see [`FormStep`]. Steps can be
+/// asked only when a condition on the previous answers holds.
+///
+/// Once all steps are answered, a review screen lists the answers, letting the
+/// user submit them or go back to any step to edit its answer. The answers
+/// are returned as [`FormAnswers`], or as any type implementing
+/// [`FromFormAnswers`].
+///
+/// The key returning to the previous step is bound to [`Command::Back`] in the
+/// [`KeyScope::Form`] scope of the form's key map, and takes precedence over
+/// the bindings of the prompts.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{Confirm, Form, Select, Text};
+///
+/// let answers = Form::new()
+///     .step("name", Text::new("What's your name?"))
+///     .step("pizza", Confirm::new("Do you eat pizza?"))
+///     .step_if(
+///         "topping",
+///         Select::new("Favorite topping?", vec!["Pineapple", "Mushrooms"]),
+///         |answers| answers.get::<bool>("pizza") == Some(&true),
+///     )
+///     .prompt()?;
+///
+/// println!("Hello, {}!", answers.get::<String>("name").unwrap());
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+pub struct Form<'a> {
+    steps: Vec<FormEntry<'a>>,
+    summary_message: Option<&'a str>,
+    key_map: KeyMap,
+    output: Output,
+}
+
+/// Condition on the previous answers for a step to be asked.
+type StepCondition<'a> = Box<dyn Fn(&FormAnswers) -> bool + 'a>;
+
+struct FormEntry<'a> {
+    name: &'a str,
+    condition: Option<StepCondition<'a>>,
+    step: Box<dyn AnyStep<'a> + 'a>,
+}
+
+impl<'a> Form<'a> {
+    /// Default message of the review screen.
+    pub const DEFAULT_SUMMARY_MESSAGE: &'static str = "Review your answers";
+
+    /// Default help message of the review screen.
+    pub const DEFAULT_SUMMARY_HELP_MESSAGE: &'static str =
+        "↑↓ to move, enter to submit or to edit the selected answer";
+
+    /// Creates a form with no steps.
+    pub fn new() -> Self {
+        Self {
+            steps: vec![],
+            summary_message: Some(Self::DEFAULT_SUMMARY_MESSAGE),
+            key_map: get_key_map(),
+            output: Output::default(),
+        }
+    }
+
+    /// Adds a step to the form, whose answer is stored under `name`.
+    pub fn step<P>(mut self, name: &'a str, prompt: P) -> Self
+    where
+        P: FormStep<'a> + 'a,
+    {
+        self.steps.push(FormEntry {
+            name,
+            condition: None,
+            step: Box::new(prompt),
+        });
+        self
+    }
+
+    /// Adds a step to the form, asked only if `condition` holds for the
+    /// answers to the previous steps.
+    pub fn step_if<P, F>(mut self, name: &'a str, prompt: P, condition: F) -> Self
+    where
+        P: FormStep<'a> + 'a,
+        F: Fn(&FormAnswers) -> bool + 'a,
+    {
+        self.steps.push(FormEntry {
+            name,
+            condition: Some(Box::new(condition)),
+            step: Box::new(prompt),
+        });
+        self
+    }
+
+    /// Sets the message of the review screen displayed after the last step.
+    pub fn with_summary_message(mut self, message: &'a str) -> Self {
+        self.summary_message = Some(message);
+        self
+    }
+
+    /// Removes the review screen, returning the answers as soon as the last
+    /// step is answered.
+    pub fn without_summary(mut self) -> Self {
+        self.summary_message = None;
+        self
+    }
+
+    /// Sets the key bindings of the form and its review screen, overriding
+    /// the global key map.
+    pub fn with_key_map(mut self, key_map: KeyMap) -> Self {
+        self.key_map = key_map;
+        self
+    }
+
+    /// Sets the destination where all steps are rendered, overriding the
+    /// output of each prompt.
+    pub fn with_output(mut self, output: Output) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Prompts the steps of the form, returning their answers by name.
+    pub fn prompt(self) -> InquireResult<FormAnswers> {
+        let output = self.output.clone();
+        self.run(StepBackend::Terminal(&output))
+    }
+
+    /// Prompts the steps of the form, converting their answers to `T`.
+    pub fn prompt_as<T>(self) -> InquireResult<T>
+    where
+        T: FromFormAnswers,
+    {
+        T::from_form_answers(self.prompt()?)
+    }
+
+    fn run(self, mut backend: StepBackend<'_>) -> InquireResult<FormAnswers> {
+        for (i, entry) in self.steps.iter().enumerate() {
+            if self.steps[..i].iter().any(|e| e.name == entry.name) {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Form step `{}` is defined more than once",
+                    entry.name
+                )));
+            }
+        }
+
+        let back_keys: Vec<KeyPattern> = self
+            .key_map
+            .bindings_by_command(&[KeyScope::Form])
+            .into_iter()
+            .filter(|(command, _)| *command == Command::Back)
+            .flat_map(|(_, patterns)| patterns)
+            .collect();
+
+        let mut answers = FormAnswers::default();
+        // Indexes of the steps answered, in the same order as `answers`.
+        let mut answered: Vec<usize> = vec![];
+        // Answers given before going back, used to pre-fill the steps.
+        let mut previous: Vec<Option<Box<dyn Any>>> = self.steps.iter().map(|_| None).collect();
+        let mut current = 0;
+
+        loop {
+            let result = match self.steps.get(current) {
+                Some(entry) => {
+                    let skipped = match &entry.condition {
+                        Some(condition) => !condition(&answers),
+                        None => false,
+                    };
+                    if skipped {
+                        current += 1;
+                        continue;
+                    }
+
+                    entry
+                        .step
+                        .run_any(previous[current].as_deref(), &back_keys, backend.reborrow())
+                        .map(|answer| {
+                            answers.push(entry.name, answer);
+                            answered.push(current);
+                            current += 1;
+                        })
+                }
+                None => match self.summary_message {
+                    None => return Ok(answers),
+                    Some(message) => {
+                        let review = self.review(
+                            message,
+                            &answers,
+                            &answered,
+                            &back_keys,
+                            backend.reborrow(),
+                        );
+
+                        match review {
+                            Ok(ReviewOption::Submit) => return Ok(answers),
+                            Ok(ReviewOption::Edit { step, .. }) => {
+                                while answered.last().map(|&i| i >= step) == Some(true) {
+                                    Self::rewind(&mut answers, &mut answered, &mut previous);
+                                }
+                                current = step;
+                                Ok(())
+                            }
+                            Err(err) => Err(err),
+                        }
+                    }
+                },
+            };
+
+            match result {
+                Ok(()) => {}
+                Err(InquireError::NavigatedBack) => {
+                    // Going back from the first step asks it again.
+                    if let Some(step) = Self::rewind(&mut answers, &mut answered, &mut previous) {
+                        current = step;
+                    }
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Removes the last answer, keeping it to pre-fill its step, and returns
+    /// the index of the step.
+    fn rewind(
+        answers: &mut FormAnswers,
+        answered: &mut Vec<usize>,
+        previous: &mut [Option<Box<dyn Any>>],
+    ) -> Option<usize> {
+        let step = answered.pop()?;
+        previous[step] = answers.pop().map(|(_, answer)| answer);
+
+        Some(step)
+    }
+
+    fn review(
+        &self,
+        message: &str,
+        answers: &FormAnswers,
+        answered: &[usize],
+        back_keys: &[KeyPattern],
+        backend: StepBackend<'_>,
+    ) -> InquireResult<ReviewOption> {
+        let mut options = vec![ReviewOption::Submit];
+        for (&step, (_, answer)) in answered.iter().zip(answers.iter()) {
+            let step_prompt = &self.steps[step].step;
+            options.push(ReviewOption::Edit {
+                step,
+                label: format!(
+                    "{} {}",
+                    step_prompt.message(),
+                    step_prompt.format_any_answer(answer)
+                ),
+            });
+        }
+
+        let review = Select::new(message, options)
+            .with_help_message(Self::DEFAULT_SUMMARY_HELP_MESSAGE)
+            .with_key_map(self.key_map.clone());
+
+        review
+            .run(None, back_keys, backend)
+            .map(|option| option.value)
+    }
+}
+
+impl<'a> Default for Form<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Option of the review screen of a form.
+#[derive(Clone)]
+enum ReviewOption {
+    Submit,
+    Edit { step: usize, label: String },
+}
+
+impl Display for ReviewOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Submit => f.write_str("Submit"),
+            Self::Edit { label, .. } => f.write_str(label),
+        }
+    }
+}
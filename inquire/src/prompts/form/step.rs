@@ -0,0 +1,348 @@
+use std::{any::Any, fmt::Display};
+
+#[cfg(feature = "date")]
+use crate::DateSelect;
+#[cfg(feature = "editor")]
+use crate::Editor;
+use crate::{
+    error::InquireResult,
+    keymap::{Command, KeyMap, KeyPattern, KeyScope},
+    list_option::ListOption,
+    Confirm, CustomType, MultiSelect, Password, Select, Text,
+};
+
+use self::sealed::{Step, StepBackend};
+
+pub(crate) mod sealed {
+    use crate::{error::InquireResult, keymap::KeyPattern, ui::Output};
+
+    /// Where the steps of a form are prompted.
+    pub enum StepBackend<'b> {
+        /// A new terminal for each step, rendering to the given output.
+        Terminal(&'b Output),
+        #[cfg(test)]
+        Fake(&'b mut crate::prompts::test::TestBackend),
+    }
+
+    impl<'b> StepBackend<'b> {
+        pub fn reborrow(&mut self) -> StepBackend<'_> {
+            match self {
+                StepBackend::Terminal(output) => StepBackend::Terminal(output),
+                #[cfg(test)]
+                StepBackend::Fake(backend) => StepBackend::Fake(backend),
+            }
+        }
+    }
+
+    pub trait Step<'a>: Clone {
+        /// Type of the answers to the step.
+        type Answer: 'static;
+
+        fn message(&self) -> &str;
+
+        fn format_answer(&self, answer: &Self::Answer) -> String;
+
+        /// Prompts the step, pre-filled with the previous answer to it, if any,
+        /// and returning to the previous step when one of `back_keys` is pressed.
+        fn run(
+            self,
+            previous: Option<&Self::Answer>,
+            back_keys: &[KeyPattern],
+            backend: StepBackend<'_>,
+        ) -> InquireResult<Self::Answer>;
+    }
+}
+
+/// Prompt that can be used as a step of a [`Form`](crate::Form).
+///
+/// This trait is implemented by all prompts of this crate. When the user
+/// returns to a step, the prompt is pre-filled with their previous answer:
+/// as the initial value of [`Text`] and [`Editor`] prompts, as the default
+/// of [`Confirm`] and [`CustomType`] prompts, as the starting cursor of
+/// [`Select`] prompts, as the default selection of [`MultiSelect`] prompts
+/// and as the starting date of [`DateSelect`] prompts. [`Password`] prompts
+/// are never pre-filled.
+pub trait FormStep<'a>: Step<'a> {}
+
+impl<'a, P> FormStep<'a> for P where P: Step<'a> {}
+
+/// Type-erased [`FormStep`], as stored by forms.
+pub(super) trait AnyStep<'a> {
+    fn message(&self) -> &str;
+
+    fn format_any_answer(&self, answer: &dyn Any) -> String;
+
+    fn run_any(
+        &self,
+        previous: Option<&dyn Any>,
+        back_keys: &[KeyPattern],
+        backend: StepBackend<'_>,
+    ) -> InquireResult<Box<dyn Any>>;
+}
+
+impl<'a, P> AnyStep<'a> for P
+where
+    P: FormStep<'a>,
+{
+    fn message(&self) -> &str {
+        Step::message(self)
+    }
+
+    fn format_any_answer(&self, answer: &dyn Any) -> String {
+        match answer.downcast_ref::<P::Answer>() {
+            Some(answer) => self.format_answer(answer),
+            None => String::new(),
+        }
+    }
+
+    fn run_any(
+        &self,
+        previous: Option<&dyn Any>,
+        back_keys: &[KeyPattern],
+        backend: StepBackend<'_>,
+    ) -> InquireResult<Box<dyn Any>> {
+        let previous = previous.and_then(<dyn Any>::downcast_ref::<P::Answer>);
+        let answer = self.clone().run(previous, back_keys, backend)?;
+
+        Ok(Box::new(answer))
+    }
+}
+
+/// Binds the keys returning to the previous step, before any other binding.
+fn with_back_keys(key_map: KeyMap, back_keys: &[KeyPattern]) -> KeyMap {
+    back_keys.iter().fold(key_map, |key_map, pattern| {
+        key_map.bind(KeyScope::All, *pattern, Command::Back)
+    })
+}
+
+macro_rules! prompt_step {
+    ($prompt:expr, $backend:expr) => {
+        prompt_step!($prompt, $backend, prompt)
+    };
+    ($prompt:expr, $backend:expr, $prompt_fn:ident) => {
+        match $backend {
+            StepBackend::Terminal(output) => $prompt.with_output(output.clone()).$prompt_fn(),
+            #[cfg(test)]
+            StepBackend::Fake(backend) => $prompt.prompt_with_backend(backend),
+        }
+    };
+}
+
+impl<'a> Step<'a> for Text<'a> {
+    type Answer = String;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &String) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn run(
+        self,
+        previous: Option<&String>,
+        back_keys: &[KeyPattern],
+        backend: StepBackend<'_>,
+    ) -> InquireResult<String> {
+        let mut prompt: Text<'_> = self;
+        prompt.key_map = with_back_keys(prompt.key_map, back_keys);
+        if let Some(previous) = previous {
+            prompt.initial_value = Some(previous);
+        }
+
+        prompt_step!(prompt, backend)
+    }
+}
+
+impl<'a> Step<'a> for Password<'a> {
+    type Answer = String;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &String) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn run(
+        mut self,
+        _previous: Option<&String>,
+        back_keys: &[KeyPattern],
+        backend: StepBackend<'_>,
+    ) -> InquireResult<String> {
+        self.key_map = with_back_keys(self.key_map, back_keys);
+
+        prompt_step!(self, backend)
+    }
+}
+
+impl<'a> Step<'a> for Confirm<'a> {
+    type Answer = bool;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &bool) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn run(
+        mut self,
+        previous: Option<&bool>,
+        back_keys: &[KeyPattern],
+        backend: StepBackend<'_>,
+    ) -> InquireResult<bool> {
+        self.key_map = with_back_keys(self.key_map, back_keys);
+        if let Some(previous) = previous {
+            self.default = Some(*previous);
+        }
+
+        prompt_step!(self, backend)
+    }
+}
+
+impl<'a, T> Step<'a> for CustomType<'a, T>
+where
+    T: Clone + 'static,
+{
+    type Answer = T;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &T) -> String {
+        (self.formatter)(answer.clone())
+    }
+
+    fn run(
+        mut self,
+        previous: Option<&T>,
+        back_keys: &[KeyPattern],
+        backend: StepBackend<'_>,
+    ) -> InquireResult<T> {
+        self.key_map = with_back_keys(self.key_map, back_keys);
+        if let Some(previous) = previous {
+            self.default = Some(previous.clone());
+        }
+
+        prompt_step!(self, backend)
+    }
+}
+
+impl<'a, T> Step<'a> for Select<'a, T>
+where
+    T: Display + Clone + 'static,
+{
+    type Answer = ListOption<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &ListOption<T>) -> String {
+        (self.formatter)(answer.as_ref())
+    }
+
+    fn run(
+        mut self,
+        previous: Option<&ListOption<T>>,
+        back_keys: &[KeyPattern],
+        backend: StepBackend<'_>,
+    ) -> InquireResult<ListOption<T>> {
+        self.key_map = with_back_keys(self.key_map, back_keys);
+        if let Some(previous) = previous {
+            self.starting_cursor = previous.index;
+        }
+
+        prompt_step!(self, backend, raw_prompt)
+    }
+}
+
+impl<'a, T> Step<'a> for MultiSelect<'a, T>
+where
+    T: Display + Clone + 'static,
+{
+    type Answer = Vec<ListOption<T>>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &Vec<ListOption<T>>) -> String {
+        let options: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
+        (self.formatter)(&options)
+    }
+
+    fn run(
+        mut self,
+        previous: Option<&Vec<ListOption<T>>>,
+        back_keys: &[KeyPattern],
+        backend: StepBackend<'_>,
+    ) -> InquireResult<Vec<ListOption<T>>> {
+        self.key_map = with_back_keys(self.key_map, back_keys);
+        if let Some(previous) = previous {
+            self.default = Some(previous.iter().map(|option| option.index).collect());
+        }
+
+        prompt_step!(self, backend, raw_prompt)
+    }
+}
+
+#[cfg(feature = "date")]
+impl<'a> Step<'a> for DateSelect<'a> {
+    type Answer = time::Date;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &time::Date) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn run(
+        mut self,
+        previous: Option<&time::Date>,
+        back_keys: &[KeyPattern],
+        backend: StepBackend<'_>,
+    ) -> InquireResult<time::Date> {
+        self.key_map = with_back_keys(self.key_map, back_keys);
+        if let Some(previous) = previous {
+            self.starting_date = *previous;
+        }
+
+        prompt_step!(self, backend)
+    }
+}
+
+#[cfg(feature = "editor")]
+impl<'a> Step<'a> for Editor<'a> {
+    type Answer = String;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &String) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn run(
+        self,
+        previous: Option<&String>,
+        back_keys: &[KeyPattern],
+        backend: StepBackend<'_>,
+    ) -> InquireResult<String> {
+        let mut prompt: Editor<'_> = self;
+        prompt.key_map = with_back_keys(prompt.key_map, back_keys);
+        if let Some(previous) = previous {
+            prompt.predefined_text = Some(previous);
+        }
+
+        prompt_step!(prompt, backend)
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{
+    error::{InquireError, InquireResult},
+    list_option::ListOption,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    Confirm, Form, FormAnswers, FromFormAnswers, Select, Text,
+};
+
+use super::step::sealed::StepBackend;
+
+fn type_str(keys: &mut Vec<Key>, input: &str) {
+    keys.extend(input.chars().map(|c| Key::Char(c, KeyModifiers::NONE)));
+}
+
+fn enter(keys: &mut Vec<Key>) {
+    keys.push(Key::Enter(KeyModifiers::NONE));
+}
+
+fn back(keys: &mut Vec<Key>) {
+    keys.push(Key::BackTab(KeyModifiers::NONE));
+}
+
+fn run(form: Form<'_>, keys: Vec<Key>) -> InquireResult<FormAnswers> {
+    let mut backend = fake_backend(keys);
+    form.run(StepBackend::Fake(&mut backend))
+}
+
+#[test]
+fn answers_are_returned_by_name() {
+    let mut keys = vec![];
+    type_str(&mut keys, "Ana");
+    enter(&mut keys);
+    type_str(&mut keys, "y");
+    enter(&mut keys);
+    // submit on the review screen
+    enter(&mut keys);
+
+    let form = Form::new()
+        .step("name", Text::new("Name?"))
+        .step("pizza", Confirm::new("Pizza?"));
+    let answers = run(form, keys).unwrap();
+
+    assert_eq!(Some(&String::from("Ana")), answers.get("name"));
+    assert_eq!(Some(&true), answers.get("pizza"));
+    assert_eq!(vec!["name", "pizza"], answers.names().collect::<Vec<_>>());
+}
+
+#[test]
+fn shift_tab_returns_to_previous_step_with_its_answer() {
+    let mut keys = vec![];
+    type_str(&mut keys, "Ana");
+    enter(&mut keys);
+    back(&mut keys);
+    type_str(&mut keys, "is");
+    enter(&mut keys);
+    type_str(&mut keys, "n");
+    enter(&mut keys);
+    enter(&mut keys);
+
+    let form = Form::new()
+        .step("name", Text::new("Name?"))
+        .step("pizza", Confirm::new("Pizza?"));
+    let answers = run(form, keys).unwrap();
+
+    assert_eq!(Some(&String::from("Anais")), answers.get("name"));
+    assert_eq!(Some(&false), answers.get("pizza"));
+}
+
+#[test]
+fn shift_tab_on_first_step_asks_it_again() {
+    let mut keys = vec![];
+    back(&mut keys);
+    type_str(&mut keys, "Ana");
+    enter(&mut keys);
+    enter(&mut keys);
+
+    let form = Form::new().step("name", Text::new("Name?"));
+    let answers = run(form, keys).unwrap();
+
+    assert_eq!(Some(&String::from("Ana")), answers.get("name"));
+}
+
+#[test]
+fn back_keys_take_precedence_over_prompt_bindings() {
+    let mut keys = vec![];
+    type_str(&mut keys, "Ana");
+    enter(&mut keys);
+    keys.push(Key::Down(KeyModifiers::NONE));
+    // moves the cursor up on a standalone select prompt
+    back(&mut keys);
+    type_str(&mut keys, "!");
+    enter(&mut keys);
+    // the previous selection is the starting cursor
+    enter(&mut keys);
+    enter(&mut keys);
+
+    let form = Form::new().step("name", Text::new("Name?")).step(
+        "size",
+        Select::new("Size?", vec!["S", "M", "L"]).with_starting_cursor(1),
+    );
+    let answers = run(form, keys).unwrap();
+
+    assert_eq!(Some(&String::from("Ana!")), answers.get("name"));
+    assert_eq!(Some(&ListOption::new(1, "M")), answers.get("size"));
+}
+
+#[test]
+fn steps_whose_condition_does_not_hold_are_skipped() {
+    let mut keys = vec![];
+    type_str(&mut keys, "n");
+    enter(&mut keys);
+    type_str(&mut keys, "Ana");
+    enter(&mut keys);
+    // goes back over the skipped step
+    back(&mut keys);
+    enter(&mut keys);
+    enter(&mut keys);
+
+    let form = Form::new()
+        .step("pizza", Confirm::new("Pizza?"))
+        .step_if(
+            "topping",
+            Select::new("Topping?", vec!["Pineapple", "Mushrooms"]),
+            |answers| answers.get::<bool>("pizza") == Some(&true),
+        )
+        .step("name", Text::new("Name?"));
+    let answers = run(form, keys).unwrap();
+
+    assert!(!answers.contains("topping"));
+    assert_eq!(Some(&String::from("Ana")), answers.get("name"));
+    assert_eq!(2, answers.len());
+}
+
+#[test]
+fn answers_can_be_edited_from_the_review_screen() {
+    let mut keys = vec![];
+    type_str(&mut keys, "a");
+    enter(&mut keys);
+    type_str(&mut keys, "b");
+    enter(&mut keys);
+    // edits the first answer
+    keys.push(Key::Down(KeyModifiers::NONE));
+    enter(&mut keys);
+    type_str(&mut keys, "x");
+    enter(&mut keys);
+    // the following answers are kept
+    enter(&mut keys);
+    enter(&mut keys);
+
+    let form = Form::new()
+        .step("first", Text::new("First?"))
+        .step("second", Text::new("Second?"));
+    let answers = run(form, keys).unwrap();
+
+    assert_eq!(Some(&String::from("ax")), answers.get("first"));
+    assert_eq!(Some(&String::from("b")), answers.get("second"));
+}
+
+#[test]
+fn shift_tab_on_review_screen_returns_to_last_step() {
+    let mut keys = vec![];
+    type_str(&mut keys, "a");
+    enter(&mut keys);
+    back(&mut keys);
+    type_str(&mut keys, "b");
+    enter(&mut keys);
+    enter(&mut keys);
+
+    let form = Form::new().step("name", Text::new("Name?"));
+    let answers = run(form, keys).unwrap();
+
+    assert_eq!(Some(&String::from("ab")), answers.get("name"));
+}
+
+#[test]
+fn answers_are_returned_after_last_step_without_summary() {
+    let mut keys = vec![];
+    type_str(&mut keys, "a");
+    enter(&mut keys);
+
+    let form = Form::new()
+        .step("name", Text::new("Name?"))
+        .without_summary();
+    let answers = run(form, keys).unwrap();
+
+    assert_eq!(Some(&String::from("a")), answers.get("name"));
+}
+
+#[test]
+fn answers_can_be_converted_to_struct() {
+    #[derive(Debug, PartialEq)]
+    struct User {
+        name: String,
+        pizza: bool,
+    }
+
+    impl FromFormAnswers for User {
+        fn from_form_answers(mut answers: FormAnswers) -> InquireResult<Self> {
+            Ok(Self {
+                name: answers.take("name")?,
+                pizza: answers.take("pizza")?,
+            })
+        }
+    }
+
+    let mut keys = vec![];
+    type_str(&mut keys, "Ana");
+    enter(&mut keys);
+    type_str(&mut keys, "y");
+    enter(&mut keys);
+    enter(&mut keys);
+
+    let form = Form::new()
+        .step("name", Text::new("Name?"))
+        .step("pizza", Confirm::new("Pizza?"));
+    let user = User::from_form_answers(run(form, keys).unwrap()).unwrap();
+
+    assert_eq!(
+        User {
+            name: String::from("Ana"),
+            pizza: true
+        },
+        user
+    );
+}
+
+#[test]
+fn taking_answer_of_wrong_type_fails() {
+    let mut answers = FormAnswers::default();
+    answers.push("name", Box::new(String::from("Ana")));
+
+    assert!(matches!(
+        answers.take::<bool>("name"),
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+    assert!(matches!(
+        answers.take::<String>("age"),
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+    assert_eq!(String::from("Ana"), answers.take::<String>("name").unwrap());
+}
+
+#[test]
+fn duplicate_step_names_are_rejected() {
+    let form = Form::new()
+        .step("name", Text::new("Name?"))
+        .step("name", Text::new("Name again?"));
+
+    assert!(matches!(
+        run(form, vec![]),
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+}
@@ -1,29 +1,40 @@
+mod accessible;
 mod action;
 mod confirm;
 mod custom_type;
 #[cfg(feature = "date")]
 mod dateselect;
+#[cfg(feature = "serde")]
+mod deserialize;
 #[cfg(feature = "editor")]
 mod editor;
+mod form;
 mod multiselect;
 mod one_liners;
 mod password;
-mod prompt;
+mod path_select;
+pub(crate) mod prompt;
 mod select;
 #[cfg(test)]
 pub(crate) mod test;
 mod text;
+mod timeout;
 
 pub use action::*;
 pub use confirm::*;
 pub use custom_type::*;
 #[cfg(feature = "date")]
 pub use dateselect::*;
+#[cfg(feature = "serde")]
+pub use deserialize::*;
 #[cfg(feature = "editor")]
 pub use editor::*;
+pub use form::*;
 pub use multiselect::*;
 #[cfg(feature = "one-liners")]
 pub use one_liners::*;
 pub use password::*;
+pub use path_select::*;
 pub use select::*;
 pub use text::*;
+pub use timeout::*;
@@ -1,12 +1,13 @@
 use crate::{
-    ui::{Key, KeyModifiers},
+    keymap::{Command, KeyScope},
+    ui::Key,
     InnerAction, InputAction,
 };
 
 use super::config::MultiSelectConfig;
 
 /// Set of actions for a MultiSelectPrompt.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum MultiSelectPromptAction {
     /// Action on the value text input handler.
     FilterInput(InputAction),
@@ -28,44 +29,41 @@ pub enum MultiSelectPromptAction {
     SelectAll,
     /// Deselects all options.
     ClearSelections,
+    /// Moves the cursor to the option with the given index in the original
+    /// list and toggles its selection.
+    ToggleOption(usize),
 }
 
 impl InnerAction for MultiSelectPromptAction {
     type Config = MultiSelectConfig;
 
-    fn from_key(key: Key, config: &MultiSelectConfig) -> Option<Self> {
-        if config.vim_mode {
-            let action = match key {
-                Key::Char('h', KeyModifiers::NONE) => Some(Self::ClearSelections),
-                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
-                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
-                Key::Char('l', KeyModifiers::NONE) => Some(Self::SelectAll),
-                _ => None,
-            };
-
-            if action.is_some() {
-                return action;
-            }
+    fn key_scopes(config: &MultiSelectConfig) -> &'static [KeyScope] {
+        match config.vim_mode {
+            true => &[KeyScope::Vim, KeyScope::MultiSelect, KeyScope::Input],
+            false => &[KeyScope::MultiSelect, KeyScope::Input],
         }
+    }
 
-        let action = match key {
-            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
-            Key::PageUp(_) => Self::PageUp,
-            Key::Home => Self::MoveToStart,
+    fn from_command(command: Command, _config: &MultiSelectConfig) -> Option<Self> {
+        let action = match command {
+            Command::MoveUp => Self::MoveUp,
+            Command::PageUp => Self::PageUp,
+            Command::MoveToStart => Self::MoveToStart,
 
-            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
-            Key::PageDown(_) => Self::PageDown,
-            Key::End => Self::MoveToEnd,
+            Command::MoveDown => Self::MoveDown,
+            Command::PageDown => Self::PageDown,
+            Command::MoveToEnd => Self::MoveToEnd,
 
-            Key::Char(' ', KeyModifiers::NONE) => Self::ToggleCurrentOption,
-            Key::Right(KeyModifiers::NONE) => Self::SelectAll,
-            Key::Left(KeyModifiers::NONE) => Self::ClearSelections,
-            key => match InputAction::from_key(key, &()) {
-                Some(action) => Self::FilterInput(action),
-                None => return None,
-            },
+            Command::ToggleCurrentOption => Self::ToggleCurrentOption,
+            Command::SelectAll => Self::SelectAll,
+            Command::ClearSelections => Self::ClearSelections,
+            command => Self::FilterInput(InputAction::from_command(command, &())?),
         };
 
         Some(action)
     }
+
+    fn from_unbound_key(key: Key, _config: &MultiSelectConfig) -> Option<Self> {
+        InputAction::from_unbound_key(key, &()).map(Self::FilterInput)
+    }
 }
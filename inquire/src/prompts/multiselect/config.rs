@@ -7,10 +7,14 @@ pub struct MultiSelectConfig {
     pub vim_mode: bool,
     /// Page size of the list of options.
     pub page_size: usize,
+    /// Whether the page size is computed from the terminal height.
+    pub auto_page_size: bool,
     /// Whether to keep the filter text when an option is selected.
     pub keep_filter: bool,
     /// Whether to reset the cursor to the first option on filter input change.
     pub reset_cursor: bool,
+    /// Whether mouse events are captured.
+    pub mouse_capture: bool,
 }
 
 impl<T> From<&MultiSelect<'_, T>> for MultiSelectConfig {
@@ -18,8 +22,10 @@ impl<T> From<&MultiSelect<'_, T>> for MultiSelectConfig {
         Self {
             vim_mode: value.vim_mode,
             page_size: value.page_size,
+            auto_page_size: value.auto_page_size,
             keep_filter: value.keep_filter,
             reset_cursor: value.reset_cursor,
+            mouse_capture: value.mouse_capture,
         }
     }
 }
@@ -1,16 +1,22 @@
 use std::{cmp::Reverse, collections::BTreeSet, fmt::Display};
 
 use crate::{
+    ansi::AnsiStrippable,
     error::InquireResult,
     formatter::MultiOptionFormatter,
     input::{Input, InputActionResult},
+    keymap::KeyMap,
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
-    type_aliases::Scorer,
-    ui::MultiSelectBackend,
-    utils::paginate,
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        Timeout,
+    },
+    terminal::TerminalSize,
+    type_aliases::{MatchScorer, OptionHelp, Scorer},
+    ui::{HitTarget, MouseEvent, MouseEventKind, MultiSelectBackend},
+    utils::{auto_page_size, paginate},
     validator::{ErrorMessage, MultiOptionValidator, Validation},
-    InquireError, MultiSelect,
+    Action, InquireError, MultiSelect,
 };
 
 use super::{action::MultiSelectPromptAction, config::MultiSelectConfig};
@@ -18,17 +24,25 @@ use super::{action::MultiSelectPromptAction, config::MultiSelectConfig};
 pub struct MultiSelectPrompt<'a, T> {
     message: &'a str,
     config: MultiSelectConfig,
+    key_map: KeyMap,
     options: Vec<T>,
     string_options: Vec<String>,
     help_message: Option<&'a str>,
+    option_help: Option<OptionHelp<'a, T>>,
     cursor_index: usize,
     checked: BTreeSet<usize>,
     input: Option<Input>,
     scored_options: Vec<usize>,
     scorer: Scorer<'a, T>,
+    match_scorer: Option<MatchScorer<'a, T>>,
+    /// Indices of the characters of each option matched by the filter input,
+    /// empty unless a match scorer is set.
+    matches: Vec<Vec<usize>>,
     formatter: MultiOptionFormatter<'a, T>,
     validator: Option<Box<dyn MultiOptionValidator<T>>>,
     error: Option<ErrorMessage>,
+    default: BTreeSet<usize>,
+    timeout: Option<Timeout>,
 }
 
 impl<'a, T> MultiSelectPrompt<'a, T>
@@ -55,7 +69,7 @@ where
 
         let string_options = mso.options.iter().map(T::to_string).collect();
         let scored_options = (0..mso.options.len()).collect();
-        let checked_options = mso
+        let checked_options: BTreeSet<usize> = mso
             .default
             .as_ref()
             .map(|d| {
@@ -76,17 +90,23 @@ where
         Ok(Self {
             message: mso.message,
             config: (&mso).into(),
+            key_map: mso.key_map,
             options: mso.options,
             string_options,
             scored_options,
             help_message: mso.help_message,
+            option_help: mso.option_help,
             cursor_index: mso.starting_cursor,
             input,
             scorer: mso.scorer,
+            match_scorer: mso.match_scorer,
+            matches: vec![],
             formatter: mso.formatter,
             validator: mso.validator,
             error: None,
+            default: checked_options.clone(),
             checked: checked_options,
+            timeout: mso.timeout,
         })
     }
 
@@ -119,6 +139,17 @@ where
         self.update_cursor_position(new_position)
     }
 
+    fn highlight_option(&mut self, option_index: usize) -> ActionResult {
+        match self
+            .scored_options
+            .iter()
+            .position(|idx| *idx == option_index)
+        {
+            Some(position) => self.update_cursor_position(position),
+            None => ActionResult::Clean,
+        }
+    }
+
     fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
         if new_position != self.cursor_index {
             self.cursor_index = new_position;
@@ -150,6 +181,7 @@ where
 
         match action {
             MultiSelectPromptAction::ToggleCurrentOption
+            | MultiSelectPromptAction::ToggleOption(_)
             | MultiSelectPromptAction::SelectAll
             | MultiSelectPromptAction::ClearSelections => {
                 self.input.as_mut().map(Input::clear);
@@ -195,6 +227,22 @@ where
         answer
     }
 
+    /// Scores the option at the given index against the filter input, along
+    /// with the indices of its matched characters if a match scorer is set.
+    fn score(&self, content: &str, option: &T, index: usize) -> Option<(i64, Vec<usize>)> {
+        let string_value = &self.string_options[index];
+
+        match self.match_scorer {
+            Some(match_scorer) => {
+                let stripped = string_value.ansi_stripped_chars().collect::<String>();
+                match_scorer(content, option, &stripped, index)
+            }
+            None => {
+                (self.scorer)(content, option, string_value, index).map(|score| (score, vec![]))
+            }
+        }
+    }
+
     fn run_scorer(&mut self) {
         let content = match &self.input {
             Some(input) => input.content(),
@@ -205,16 +253,18 @@ where
             .options
             .iter()
             .enumerate()
-            .filter_map(|(i, opt)| {
-                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
-                    .map(|score| (i, score))
-            })
-            .collect::<Vec<(usize, i64)>>();
+            .filter_map(|(i, opt)| self.score(content, opt, i).map(|score| (i, score)))
+            .collect::<Vec<(usize, (i64, Vec<usize>))>>();
 
-        options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+        options.sort_unstable_by_key(|(_idx, (score, _))| Reverse(*score));
 
         let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
 
+        self.matches = vec![vec![]; self.options.len()];
+        for (idx, (_, matched)) in options {
+            self.matches[idx] = matched;
+        }
+
         if self.scored_options == new_scored_options {
             return;
         }
@@ -246,6 +296,10 @@ where
         &self.config
     }
 
+    fn key_map(&self) -> &KeyMap {
+        &self.key_map
+    }
+
     fn format_answer(&self, answer: &Vec<ListOption<T>>) -> String {
         let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
         (self.formatter)(&refs)
@@ -269,7 +323,7 @@ where
     }
 
     fn handle(&mut self, action: MultiSelectPromptAction) -> InquireResult<ActionResult> {
-        let result = match action {
+        let result = match &action {
             MultiSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
             MultiSelectPromptAction::MoveDown => self.move_cursor_down(1, true),
             MultiSelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
@@ -279,6 +333,10 @@ where
             MultiSelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             MultiSelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
             MultiSelectPromptAction::ToggleCurrentOption => self.toggle_cursor_selection(),
+            MultiSelectPromptAction::ToggleOption(index) => {
+                self.highlight_option(*index);
+                self.toggle_cursor_selection()
+            }
             MultiSelectPromptAction::SelectAll => {
                 self.checked.clear();
                 for idx in &self.scored_options {
@@ -292,7 +350,7 @@ where
             }
             MultiSelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
                 Some(input) => {
-                    let result = input.handle(input_action);
+                    let result = input.handle(input_action.clone());
 
                     if let InputActionResult::ContentChanged = result {
                         self.run_scorer();
@@ -309,6 +367,48 @@ where
         Ok(result)
     }
 
+    fn timeout(&self) -> Option<Timeout> {
+        self.timeout
+    }
+
+    fn default_answer(&mut self) -> Option<Vec<ListOption<T>>> {
+        let answer = std::mem::take(&mut self.options)
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| self.default.contains(idx))
+            .map(|(idx, value)| ListOption::new(idx, value))
+            .collect();
+
+        Some(answer)
+    }
+
+    fn resize(&mut self, terminal_size: TerminalSize) {
+        if self.config.auto_page_size {
+            self.config.page_size = auto_page_size(terminal_size.height());
+        }
+    }
+
+    fn mouse_capture(&self) -> bool {
+        self.config.mouse_capture
+    }
+
+    fn mouse_action(
+        &self,
+        event: MouseEvent,
+        target: Option<HitTarget>,
+    ) -> Option<Action<MultiSelectPromptAction>> {
+        let action = match (event.kind, target) {
+            (MouseEventKind::LeftClick, Some(HitTarget::ListOption(index))) => {
+                MultiSelectPromptAction::ToggleOption(index)
+            }
+            (MouseEventKind::ScrollUp, _) => MultiSelectPromptAction::PageUp,
+            (MouseEventKind::ScrollDown, _) => MultiSelectPromptAction::PageDown,
+            _ => return None,
+        };
+
+        Some(Action::Inner(action))
+    }
+
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
@@ -327,9 +427,13 @@ where
 
         let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
 
-        backend.render_options(page, &self.checked)?;
+        backend.render_options(page, &self.checked, &self.matches)?;
 
-        if let Some(help_message) = self.help_message {
+        let option_help = match (self.option_help, self.scored_options.get(self.cursor_index)) {
+            (Some(option_help), Some(&index)) => option_help(&self.options[index]),
+            _ => None,
+        };
+        if let Some(help_message) = option_help.or(self.help_message) {
             backend.render_help_message(help_message)?;
         }
 
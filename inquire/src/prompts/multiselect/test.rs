@@ -2,15 +2,18 @@ use crate::{
     formatter::MultiOptionFormatter,
     list_option::ListOption,
     test::fake_backend,
-    ui::{Key, KeyModifiers},
-    MultiSelect,
+    ui::{Key, KeyModifiers, MouseEvent, MouseEventKind},
+    InquireEnumVariants, MultiSelect,
 };
 
 #[test]
 /// Tests that a closure that actually closes on a variable can be used
 /// as a Select formatter.
 fn closure_formatter() {
-    let mut backend = fake_backend(vec![Key::Char(' ', KeyModifiers::NONE), Key::Enter]);
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
 
     let formatted = String::from("Thanks!");
     let formatter: MultiOptionFormatter<'_, i32> = &|_| formatted.clone();
@@ -31,11 +34,11 @@ fn down_arrow_on_empty_list_does_not_panic() {
     let mut backend = fake_backend(vec![
         Key::Char('9', KeyModifiers::NONE),
         Key::Down(KeyModifiers::NONE),
-        Key::Backspace,
+        Key::Backspace(KeyModifiers::NONE),
         Key::Char('3', KeyModifiers::NONE),
         Key::Down(KeyModifiers::NONE),
-        Key::Backspace,
-        Key::Enter,
+        Key::Backspace(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
     ]);
 
     let options = vec![1, 2, 3];
@@ -49,7 +52,10 @@ fn down_arrow_on_empty_list_does_not_panic() {
 
 #[test]
 fn selecting_all_by_default_behavior() {
-    let mut backend = fake_backend(vec![Key::Enter, Key::Enter]);
+    let mut backend = fake_backend(vec![
+        Key::Enter(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
     let options = vec![1, 2, 3];
 
     let answer_with_all_selected_by_default = MultiSelect::new("Question", options.clone())
@@ -82,7 +88,7 @@ fn list_option_indexes_are_relative_to_input_vec() {
         Key::Char(' ', KeyModifiers::NONE),
         Key::Down(KeyModifiers::NONE),
         Key::Char(' ', KeyModifiers::NONE),
-        Key::Enter,
+        Key::Enter(KeyModifiers::NONE),
     ]);
 
     let options = vec![1, 2, 3];
@@ -97,7 +103,10 @@ fn list_option_indexes_are_relative_to_input_vec() {
 #[test]
 // Anti-regression test: https://github.com/mikaelmello/inquire/issues/195
 fn starting_cursor_is_respected() {
-    let mut backend = fake_backend(vec![Key::Char(' ', KeyModifiers::NONE), Key::Enter]);
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
     let options = vec![1, 2, 3];
 
     let ans = MultiSelect::new("Question", options)
@@ -116,7 +125,7 @@ fn naive_assert_fuzzy_match_as_default_scorer() {
         Key::Char('r', KeyModifiers::NONE),
         Key::Char('y', KeyModifiers::NONE),
         Key::Char(' ', KeyModifiers::NONE),
-        Key::Enter,
+        Key::Enter(KeyModifiers::NONE),
     ]);
 
     let options = vec![
@@ -140,6 +149,24 @@ fn naive_assert_fuzzy_match_as_default_scorer() {
     assert_eq!(vec![ListOption::new(2, "Strawberry")], ans);
 }
 
+#[test]
+fn scorer_replaces_default_match_scorer() {
+    let mut backend = fake_backend(vec![
+        Key::Char('o', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let options = vec!["Avocado", "Banana"];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_scorer(&|_, _, string_value, _| (string_value == "Banana").then_some(0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, "Banana")], ans);
+}
+
 #[test]
 fn chars_do_not_affect_prompt_without_filtering() {
     let mut backend = fake_backend(vec![
@@ -148,7 +175,7 @@ fn chars_do_not_affect_prompt_without_filtering() {
         Key::Char('r', KeyModifiers::NONE),
         Key::Char('y', KeyModifiers::NONE),
         Key::Char(' ', KeyModifiers::NONE),
-        Key::Enter,
+        Key::Enter(KeyModifiers::NONE),
     ]);
 
     let options = vec![
@@ -172,3 +199,74 @@ fn chars_do_not_affect_prompt_without_filtering() {
 
     assert_eq!(vec![ListOption::new(0, "Banana")], ans);
 }
+
+#[test]
+fn clicking_options_toggles_them() {
+    let click = |row| {
+        Key::Mouse(MouseEvent {
+            kind: MouseEventKind::LeftClick,
+            column: 10,
+            row,
+        })
+    };
+
+    // the prompt is rendered on the first row, followed by the options
+    let mut backend = fake_backend(vec![
+        click(1),
+        click(3),
+        click(4),
+        click(4),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let options = vec![1, 2, 3, 4];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_mouse_capture()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, 1), ListOption::new(2, 3)], ans);
+}
+
+#[test]
+fn from_enum_returns_selected_variants() {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Topping {
+        Cheese,
+        Olives,
+        Pineapple,
+    }
+
+    impl InquireEnumVariants for Topping {
+        fn variants() -> Vec<Self> {
+            vec![Topping::Cheese, Topping::Olives, Topping::Pineapple]
+        }
+    }
+
+    impl std::fmt::Display for Topping {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self)
+        }
+    }
+
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let ans = MultiSelect::<Topping>::from_enum("Toppings")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(0, Topping::Cheese),
+            ListOption::new(2, Topping::Pineapple)
+        ],
+        ans
+    );
+}
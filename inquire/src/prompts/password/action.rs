@@ -1,12 +1,13 @@
 use crate::{
-    ui::{Key, KeyModifiers},
+    keymap::{Command, KeyScope},
+    ui::Key,
     InnerAction, InputAction,
 };
 
 use super::config::PasswordConfig;
 
 /// Set of actions for a PasswordPrompt.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum PasswordPromptAction {
     /// Action on the value text input handler.
     ValueInput(InputAction),
@@ -17,19 +18,25 @@ pub enum PasswordPromptAction {
 impl InnerAction for PasswordPromptAction {
     type Config = PasswordConfig;
 
-    fn from_key(key: Key, config: &PasswordConfig) -> Option<Self> {
-        let action = match key {
-            Key::Char('r' | 'R', m)
-                if m.contains(KeyModifiers::CONTROL) && config.enable_display_toggle =>
-            {
-                Self::ToggleDisplayMode
-            }
-            key => match InputAction::from_key(key, &()) {
-                Some(action) => Self::ValueInput(action),
-                None => return None,
-            },
+    fn key_scopes(_config: &PasswordConfig) -> &'static [KeyScope] {
+        &[KeyScope::Password, KeyScope::Input]
+    }
+
+    fn from_command(command: Command, config: &PasswordConfig) -> Option<Self> {
+        let action = match command {
+            Command::ToggleDisplayMode if config.enable_display_toggle => Self::ToggleDisplayMode,
+            command => Self::ValueInput(InputAction::from_command(command, &())?),
         };
 
         Some(action)
     }
+
+    fn from_unbound_key(key: Key, config: &PasswordConfig) -> Option<Self> {
+        let action = match key {
+            Key::Paste(text) => InputAction::Insert(config.paste_newline_policy.apply(&text)?),
+            key => InputAction::from_unbound_key(key, &())?,
+        };
+
+        Some(Self::ValueInput(action))
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{Password, PasswordDisplayMode};
+use crate::{NewlinePolicy, Password, PasswordDisplayMode};
 
 /// Configuration settings used in the execution of a PasswordPrompt.
 #[derive(Copy, Clone, Debug)]
@@ -7,6 +7,8 @@ pub struct PasswordConfig {
     pub enable_display_toggle: bool,
     /// The initial display mode of the password.
     pub display_mode: PasswordDisplayMode,
+    /// How line breaks in pasted text are handled.
+    pub paste_newline_policy: NewlinePolicy,
 }
 
 impl From<&Password<'_>> for PasswordConfig {
@@ -14,6 +16,7 @@ impl From<&Password<'_>> for PasswordConfig {
         Self {
             enable_display_toggle: value.enable_display_toggle,
             display_mode: value.display_mode,
+            paste_newline_policy: value.paste_newline_policy,
         }
     }
 }
@@ -8,14 +8,20 @@ mod test;
 pub use action::*;
 
 use crate::{
-    config::get_configuration,
+    config::{get_accessible_mode, get_configuration, get_key_map, get_vim_mode},
     error::{InquireError, InquireResult},
     formatter::StringFormatter,
+    keymap::KeyMap,
+    prompts::accessible,
     prompts::prompt::Prompt,
+    prompts::Timeout,
     terminal::get_default_terminal,
-    ui::{Backend, PasswordBackend, RenderConfig},
+    ui::{Backend, Output, PasswordBackend, RenderConfig},
     validator::StringValidator,
+    NewlinePolicy,
 };
+#[cfg(feature = "async")]
+use crate::{prompts::prompt::prompt_async, terminal::get_default_async_terminal};
 
 use self::prompt::PasswordPrompt;
 
@@ -118,6 +124,22 @@ pub struct Password<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// How line breaks in text pasted by the user are handled, as typing
+    /// one would submit the prompt.
+    pub paste_newline_policy: NewlinePolicy,
+
+    /// Whether the password input has vim-style modal editing. Each input
+    /// starts in insert mode, ESC switching to normal mode, where keys are vim
+    /// commands and ESC cancels the prompt or goes back from the confirmation.
+    ///
+    /// Defaults to the global vim mode, see [set_global_vim_mode](crate::set_global_vim_mode).
+    pub vim_mode: bool,
+
+    /// Key bindings used to parse the user's key presses into actions.
+    ///
+    /// Defaults to the global key map, see [set_global_key_map](crate::set_global_key_map).
+    pub key_map: KeyMap,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -127,6 +149,14 @@ pub struct Password<'a> {
     /// config is treated as the only source of truth. If you want to customize colors
     /// and still support NO_COLOR, you will have to do this on your end.
     pub render_config: RenderConfig<'a>,
+
+    /// Destination where the prompt is rendered, stderr by default.
+    ///
+    /// Keys are read from the terminal regardless of the output.
+    pub output: Output,
+
+    /// Time limit for the user to answer the prompt, if any.
+    pub timeout: Option<Timeout>,
 }
 
 impl<'a> Password<'a> {
@@ -160,7 +190,12 @@ impl<'a> Password<'a> {
             help_message: Self::DEFAULT_HELP_MESSAGE,
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
+            paste_newline_policy: NewlinePolicy::DEFAULT,
+            vim_mode: get_vim_mode(),
+            key_map: get_key_map(),
             render_config: get_configuration(),
+            output: Output::default(),
+            timeout: None,
         }
     }
 
@@ -236,6 +271,43 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Sets how line breaks in text pasted by the user are handled.
+    ///
+    /// Defaults to [`NewlinePolicy::Strip`].
+    pub fn with_paste_newline_policy(mut self, paste_newline_policy: NewlinePolicy) -> Self {
+        self.paste_newline_policy = paste_newline_policy;
+        self
+    }
+
+    /// Enables or disables vim-style modal editing of the password input.
+    ///
+    /// It works as in [`Text::with_vim_mode`](crate::Text::with_vim_mode),
+    /// the confirmation input starting again in insert mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets a time limit for the user to answer the prompt.
+    ///
+    /// Accepts either a [`Duration`](std::time::Duration), in which case the
+    /// prompt returns `Err(InquireError::Timeout)` on expiry, or a [`Timeout`]
+    /// describing what happens on expiry and whether a countdown is displayed.
+    pub fn with_timeout<D>(mut self, timeout: D) -> Self
+    where
+        D: Into<Timeout>,
+    {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// Sets the key bindings used to parse the user's key presses into actions,
+    /// overriding the global key map.
+    pub fn with_key_map(mut self, key_map: KeyMap) -> Self {
+        self.key_map = key_map;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -249,6 +321,13 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Sets the destination where the prompt is rendered, such as
+    /// [`Output::Stderr`] to keep stdout free for the program's output.
+    pub fn with_output(mut self, output: Output) -> Self {
+        self.output = output;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -269,11 +348,41 @@ impl<'a> Password<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<String> {
-        let (input_reader, terminal) = get_default_terminal()?;
+        if get_accessible_mode() {
+            return accessible::prompt(self);
+        }
+
+        let (input_reader, terminal) = get_default_terminal(&self.output)?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Asynchronous version of [`prompt`](Self::prompt), which does not block
+    /// the executor while waiting for the user's input.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<String> {
+        self.prompt_async_with_cancellation(std::future::pending())
+            .await
+    }
+
+    /// Asynchronous version of [`prompt`](Self::prompt) that can be aborted
+    /// by the application, e.g. by passing `token.cancelled()` of a tokio
+    /// `CancellationToken`.
+    ///
+    /// When `cancellation` resolves before the user submits an answer, the
+    /// prompt is torn down and `Err(InquireError::OperationAborted)` is returned.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async_with_cancellation<C>(self, cancellation: C) -> InquireResult<String>
+    where
+        C: std::future::Future<Output = ()>,
+    {
+        let (input_reader, terminal) = get_default_async_terminal(&self.output)?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        prompt_async(PasswordPrompt::from(self), &mut backend, cancellation).await
+    }
+
     pub(crate) fn prompt_with_backend<B: PasswordBackend>(
         self,
         backend: &mut B,
@@ -1,11 +1,15 @@
 use crate::{
     error::InquireResult,
     formatter::StringFormatter,
-    input::Input,
-    prompts::prompt::{ActionResult, Prompt},
+    input::{Input, InputActionResult, Vim},
+    keymap::KeyMap,
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        Timeout,
+    },
     ui::PasswordBackend,
     validator::{ErrorMessage, StringValidator, Validation},
-    InquireError, Password, PasswordDisplayMode,
+    InputAction, InquireError, Password, PasswordDisplayMode,
 };
 
 use super::{action::PasswordPromptAction, config::PasswordConfig};
@@ -25,6 +29,7 @@ struct PasswordConfirmation<'a> {
 pub struct PasswordPrompt<'a> {
     message: &'a str,
     config: PasswordConfig,
+    key_map: KeyMap,
     help_message: Option<&'a str>,
     input: Input,
     current_mode: PasswordDisplayMode,
@@ -33,6 +38,8 @@ pub struct PasswordPrompt<'a> {
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
     error: Option<ErrorMessage>,
+    vim: Option<Vim>,
+    timeout: Option<Timeout>,
 }
 
 impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
@@ -51,6 +58,7 @@ impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
         Self {
             message: so.message,
             config: (&so).into(),
+            key_map: so.key_map,
             help_message: so.help_message,
             current_mode: so.display_mode,
             confirmation,
@@ -59,6 +67,8 @@ impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
             validators: so.validators,
             input: Input::new(),
             error: None,
+            vim: so.vim_mode.then(Vim::new),
+            timeout: so.timeout,
         }
     }
 }
@@ -70,14 +80,37 @@ impl<'a> From<&'a str> for Password<'a> {
 }
 
 impl<'a> PasswordPrompt<'a> {
-    fn active_input_mut(&mut self) -> &mut Input {
-        if let Some(c) = &mut self.confirmation {
-            if self.confirmation_stage {
-                return &mut c.input;
-            }
+    fn apply_input_action(&mut self, action: InputAction) -> InputActionResult {
+        let input = match &mut self.confirmation {
+            Some(confirmation) if self.confirmation_stage => &mut confirmation.input,
+            _ => &mut self.input,
+        };
+
+        match self.vim.as_mut() {
+            Some(vim) => vim.handle(action, input),
+            None => input.handle(action),
+        }
+    }
+
+    /// Handles the escape key in vim mode, returning whether it was consumed.
+    fn vim_escape(&mut self) -> bool {
+        let input = match &mut self.confirmation {
+            Some(confirmation) if self.confirmation_stage => &mut confirmation.input,
+            _ => &mut self.input,
+        };
+
+        match self.vim.as_mut() {
+            Some(vim) => vim.escape(input),
+            None => false,
         }
+    }
 
-        &mut self.input
+    /// Starts the vim mode over when moving between the password and its
+    /// confirmation, so that no state is carried from one input to the other.
+    fn reset_vim(&mut self) {
+        if let Some(vim) = &mut self.vim {
+            *vim = Vim::new();
+        }
     }
 
     fn toggle_display_mode(&mut self) -> ActionResult {
@@ -152,15 +185,24 @@ where
         &self.config
     }
 
+    fn key_map(&self) -> &KeyMap {
+        &self.key_map
+    }
+
     fn format_answer(&self, answer: &String) -> String {
         (self.formatter)(answer)
     }
 
     fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.vim_escape() {
+            return Ok(false);
+        }
+
         if let Some(confirmation) = &mut self.confirmation {
             if self.confirmation_stage {
                 confirmation.input.clear();
                 self.confirmation_stage = false;
+                self.reset_vim();
                 return Ok(false);
             }
         }
@@ -184,10 +226,14 @@ where
         let result = match confirmation {
             ConfirmationStepResult::NoConfirmationRequired
             | ConfirmationStepResult::ConfirmationValidated => Some(cur_answer),
-            ConfirmationStepResult::ConfirmationPending => None,
+            ConfirmationStepResult::ConfirmationPending => {
+                self.reset_vim();
+                None
+            }
             ConfirmationStepResult::ConfirmationInvalidated(message) => {
                 self.error = Some(message);
                 self.input.clear();
+                self.reset_vim();
                 None
             }
         };
@@ -198,7 +244,7 @@ where
     fn handle(&mut self, action: PasswordPromptAction) -> InquireResult<ActionResult> {
         let result = match action {
             PasswordPromptAction::ValueInput(input_action) => {
-                self.active_input_mut().handle(input_action).into()
+                self.apply_input_action(input_action).into()
             }
             PasswordPromptAction::ToggleDisplayMode => self.toggle_display_mode(),
         };
@@ -206,17 +252,26 @@ where
         Ok(result)
     }
 
+    fn timeout(&self) -> Option<Timeout> {
+        self.timeout
+    }
+
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         if let Some(err) = &self.error {
             backend.render_error_message(err)?;
         }
 
+        // only the input being typed shows the vim normal mode
+        let vim_normal = matches!(&self.vim, Some(vim) if vim.is_normal());
+        backend.set_vim_normal_mode(vim_normal && !self.confirmation_stage);
+
         match self.current_mode {
             PasswordDisplayMode::Hidden => {
                 backend.render_prompt(self.message)?;
 
                 match &self.confirmation {
                     Some(confirmation) if self.confirmation_stage => {
+                        backend.set_vim_normal_mode(vim_normal);
                         backend.render_prompt(confirmation.message)?;
                     }
                     _ => {}
@@ -227,6 +282,7 @@ where
 
                 match &self.confirmation {
                     Some(confirmation) if self.confirmation_stage => {
+                        backend.set_vim_normal_mode(vim_normal);
                         backend.render_prompt_with_masked_input(
                             confirmation.message,
                             &confirmation.input,
@@ -240,6 +296,7 @@ where
 
                 match &self.confirmation {
                     Some(confirmation) if self.confirmation_stage => {
+                        backend.set_vim_normal_mode(vim_normal);
                         backend.render_prompt_with_full_input(
                             confirmation.message,
                             &confirmation.input,
@@ -27,14 +27,17 @@ macro_rules! password_test {
 
 password_test!(
     empty,
-    vec![Key::Enter],
+    vec![Key::Enter(KeyModifiers::NONE)],
     "",
     Password::new("").without_confirmation()
 );
 
 password_test!(
     single_letter,
-    vec![Key::Char('b', KeyModifiers::NONE), Key::Enter],
+    vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE)
+    ],
     "b",
     Password::new("").without_confirmation()
 );
@@ -58,12 +61,12 @@ password_test!(
     {
         let mut events = vec![];
         events.append(&mut text_to_events!("anor"));
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
         events.append(&mut text_to_events!("normal input"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events
     },
     "normal input",
@@ -75,18 +78,18 @@ password_test!(
     {
         let mut events = vec![];
         events.append(&mut text_to_events!("anor"));
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
         events.append(&mut text_to_events!("normal input"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events
     },
     "normal input",
@@ -98,14 +101,14 @@ password_test!(
     {
         let mut events = vec![];
         events.append(&mut text_to_events!("1234567890"));
-        events.push(Key::Enter);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
         events.append(&mut text_to_events!("yes"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events
     },
     "12345yes",
@@ -123,14 +126,14 @@ password_test!(
     {
         let mut events = vec![];
         events.append(&mut text_to_events!("1234567890"));
-        events.push(Key::Enter);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
         events.append(&mut text_to_events!("yes"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events
     },
     "12345yes",
@@ -148,9 +151,9 @@ password_test!(
     {
         let mut events = vec![];
         events.append(&mut text_to_events!("1234567890"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events.append(&mut text_to_events!("yesyes"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events
     },
     "yesyes",
@@ -168,9 +171,9 @@ password_test!(
     {
         let mut events = vec![];
         events.append(&mut text_to_events!("1234567890"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events.append(&mut text_to_events!("1234567890"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events
     },
     "1234567890",
@@ -183,9 +186,9 @@ password_test!(
     {
         let mut events = vec![];
         events.append(&mut text_to_events!("1234567890"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events.append(&mut text_to_events!("abcdefghij"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events
     },
     "",
@@ -198,15 +201,15 @@ password_test!(
     {
         let mut events = vec![];
         events.append(&mut text_to_events!("anor"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events.append(&mut text_to_events!("anor2"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         // The problem is that the 1st input values were not cleared
         // and the lack of a change in the 1st prompt can be confusing.
         events.append(&mut text_to_events!("anor"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events.append(&mut text_to_events!("anor"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events
     },
     "anor",
@@ -219,15 +222,15 @@ password_test!(
     {
         let mut events = vec![];
         events.append(&mut text_to_events!("anor"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events.append(&mut text_to_events!("anor2"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         // The problem is that the 1st input values were not cleared
         // and the lack of a change in the 1st prompt can be confusing.
         events.append(&mut text_to_events!("anor"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events.append(&mut text_to_events!("anor"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events
     },
     "anor",
@@ -240,17 +243,43 @@ password_test!(
     {
         let mut events = vec![];
         events.append(&mut text_to_events!("anor"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events.append(&mut text_to_events!("anor2"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         // The problem is that the 1st input values were not cleared
         // and the lack of a change in the 1st prompt can be confusing.
         events.append(&mut text_to_events!("anor"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events.append(&mut text_to_events!("anor"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events
     },
     "anor",
     Password::new("").with_display_mode(crate::PasswordDisplayMode::Masked)
 );
+
+password_test!(
+    vim_mode_edits_in_normal_mode,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("hunter3"));
+        events.push(Key::Escape(KeyModifiers::NONE));
+        events.append(&mut text_to_events!("xa2\n"));
+        events
+    },
+    "hunter2",
+    Password::new("").without_confirmation().with_vim_mode(true)
+);
+
+password_test!(
+    vim_mode_starts_confirmation_in_insert_mode,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("abc"));
+        events.push(Key::Escape(KeyModifiers::NONE));
+        events.append(&mut text_to_events!("\nabc\n"));
+        events
+    },
+    "abc",
+    Password::new("").with_vim_mode(true)
+);
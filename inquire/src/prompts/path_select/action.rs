@@ -0,0 +1,67 @@
+use crate::{
+    keymap::{Command, KeyScope},
+    ui::Key,
+    InnerAction, InputAction,
+};
+
+use super::config::PathSelectConfig;
+
+/// Set of actions for a PathSelectPrompt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathSelectPromptAction {
+    /// Action on the filter text input handler. Deleting a character to the
+    /// left of the cursor of an empty filter moves to the parent directory.
+    FilterInput(InputAction),
+    /// Moves the cursor to the entry above.
+    MoveUp,
+    /// Moves the cursor to the entry below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the start of the list.
+    MoveToStart,
+    /// Moves the cursor to the end of the list.
+    MoveToEnd,
+    /// Lists the parent of the current directory.
+    ParentDirectory,
+}
+
+impl InnerAction for PathSelectPromptAction {
+    type Config = PathSelectConfig;
+
+    fn key_scopes(config: &PathSelectConfig) -> &'static [KeyScope] {
+        match config.vim_mode {
+            true => &[
+                KeyScope::Vim,
+                KeyScope::PathSelect,
+                KeyScope::Select,
+                KeyScope::Input,
+            ],
+            false => &[KeyScope::PathSelect, KeyScope::Select, KeyScope::Input],
+        }
+    }
+
+    fn from_command(command: Command, _config: &PathSelectConfig) -> Option<Self> {
+        let action = match command {
+            Command::MoveUp => Self::MoveUp,
+            Command::PageUp => Self::PageUp,
+            Command::MoveToStart => Self::MoveToStart,
+
+            Command::MoveDown => Self::MoveDown,
+            Command::PageDown => Self::PageDown,
+            Command::MoveToEnd => Self::MoveToEnd,
+
+            Command::ParentDirectory => Self::ParentDirectory,
+
+            command => Self::FilterInput(InputAction::from_command(command, &())?),
+        };
+
+        Some(action)
+    }
+
+    fn from_unbound_key(key: Key, _config: &PathSelectConfig) -> Option<Self> {
+        InputAction::from_unbound_key(key, &()).map(Self::FilterInput)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::PathSelect;
+
+/// Configuration settings used in the execution of a PathSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct PathSelectConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of the list of entries.
+    pub page_size: usize,
+    /// Whether the page size is computed from the terminal height.
+    pub auto_page_size: bool,
+}
+
+impl From<&PathSelect<'_>> for PathSelectConfig {
+    fn from(value: &PathSelect<'_>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+            auto_page_size: value.auto_page_size,
+        }
+    }
+}
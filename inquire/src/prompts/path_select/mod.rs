@@ -0,0 +1,462 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    config::{get_accessible_mode, get_configuration, get_key_map, get_vim_mode},
+    error::{InquireError, InquireResult},
+    formatter::{PathFormatter, DEFAULT_PATH_FORMATTER},
+    keymap::KeyMap,
+    path_utils::{absolute, expand_tilde, PathFilter},
+    prompts::accessible,
+    prompts::prompt::Prompt,
+    prompts::Timeout,
+    terminal::get_default_terminal,
+    ui::{Backend, Output, RenderConfig, SelectBackend},
+};
+#[cfg(feature = "async")]
+use crate::{prompts::prompt::prompt_async, terminal::get_default_async_terminal};
+
+use self::prompt::PathSelectPrompt;
+
+/// Kind of paths selectable in a [`PathSelect`] prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PathSelectionMode {
+    /// Files are selected, while directories are opened.
+    File,
+    /// Directories are selected through the `./` entry listed first, while
+    /// the other directories are opened. Files are not listed.
+    Directory,
+    /// Files are selected, as well as directories through the `./` entry
+    /// listed first.
+    Any,
+}
+
+/// Prompt browsing the filesystem for the user to select a file or a
+/// directory, returning its path.
+///
+/// The entries of the current directory are listed as in a [`Select`](crate::Select)
+/// prompt, directories first. Pressing enter on a directory opens it, while
+/// backspace, when the filter is empty, goes up to the parent directory.
+///
+/// The selected path must exist and be readable, and optionally writable,
+/// otherwise an error is displayed and the user keeps browsing.
+///
+/// Like all others, this prompt also allows you to customize several aspects of it:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting directory**: Directory listed first, which may start with `~`. Defaults to the current directory.
+/// - **Selection mode**: Whether files, directories or both are selected, files by default.
+/// - **Hidden files**: Whether files and directories whose name starts with a dot are listed, `false` by default.
+/// - **Extensions**: Extensions of the files listed, all files being listed by default.
+/// - **Writable only**: Whether the selected path must be writable, `false` by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the selected path before showing it as the final answer.
+///   - Prints the selected path by default.
+/// - **Page size**: Number of entries displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{PathSelect, PathSelectionMode};
+///
+/// let config = PathSelect::new("Configuration file:")
+///     .with_starting_directory("~/.config")
+///     .with_extensions(&["toml", "yaml"])
+///     .prompt();
+///
+/// let output = PathSelect::new("Output directory:")
+///     .with_selection_mode(PathSelectionMode::Directory)
+///     .with_writable_only()
+///     .prompt();
+/// ```
+#[derive(Clone)]
+pub struct PathSelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Directory listed when the prompt starts, the current directory if
+    /// `None`. A leading `~` stands for the home directory of the user.
+    pub starting_directory: Option<PathBuf>,
+
+    /// Kind of paths selectable by the user.
+    pub selection_mode: PathSelectionMode,
+
+    /// Whether hidden files and directories are listed.
+    pub show_hidden: bool,
+
+    /// Extensions of the files listed, without their leading dot, all files
+    /// being listed if empty.
+    pub extensions: Vec<String>,
+
+    /// Whether the selected path must be writable by the current user.
+    pub writable_only: bool,
+
+    /// Page size of the entries displayed to the user.
+    pub page_size: usize,
+
+    /// Whether the page size is computed from the terminal height, in which
+    /// case `page_size` is ignored and the pages are recomputed on resizes.
+    pub auto_page_size: bool,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the entries using j and k.
+    pub vim_mode: bool,
+
+    /// Function that formats the selected path and presents it to the user as the final rendering of the prompt.
+    pub formatter: PathFormatter<'a>,
+
+    /// Key bindings used to parse the user's key presses into actions.
+    ///
+    /// Defaults to the global key map, see [set_global_key_map](crate::set_global_key_map).
+    pub key_map: KeyMap,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+
+    /// Destination where the prompt is rendered, stderr by default.
+    ///
+    /// Keys are read from the terminal regardless of the output.
+    pub output: Output,
+
+    /// Time limit for the user to answer the prompt, if any.
+    pub timeout: Option<Timeout>,
+}
+
+impl<'a> PathSelect<'a> {
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default selection mode.
+    pub const DEFAULT_SELECTION_MODE: PathSelectionMode = PathSelectionMode::File;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, enter to select or open, backspace to go up, type to filter");
+
+    /// Creates a [PathSelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            starting_directory: None,
+            selection_mode: Self::DEFAULT_SELECTION_MODE,
+            show_hidden: false,
+            extensions: vec![],
+            writable_only: false,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            auto_page_size: false,
+            vim_mode: get_vim_mode(),
+            formatter: DEFAULT_PATH_FORMATTER,
+            key_map: get_key_map(),
+            render_config: get_configuration(),
+            output: Output::default(),
+            timeout: None,
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the directory listed when the prompt starts, which may start with
+    /// `~` for the home directory of the user.
+    pub fn with_starting_directory<P>(mut self, directory: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.starting_directory = Some(directory.into());
+        self
+    }
+
+    /// Sets the kind of paths selectable by the user.
+    pub fn with_selection_mode(mut self, selection_mode: PathSelectionMode) -> Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
+    /// Sets whether hidden files and directories are listed.
+    pub fn with_hidden_files(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+
+    /// Only lists the files with one of the given extensions, ignoring case.
+    /// Extensions may be given with or without their leading dot.
+    pub fn with_extensions(mut self, extensions: &[&str]) -> Self {
+        self.extensions = PathFilter::default().with_extensions(extensions).extensions;
+        self
+    }
+
+    /// Only accepts paths that are writable by the current user.
+    pub fn with_writable_only(mut self) -> Self {
+        self.writable_only = true;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets the page size to fill the available height of the terminal,
+    /// adapting whenever the terminal is resized.
+    pub fn with_auto_page_size(mut self) -> Self {
+        self.auto_page_size = true;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: PathFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets a time limit for the user to answer the prompt.
+    ///
+    /// Accepts either a [`Duration`](std::time::Duration), in which case the
+    /// prompt returns `Err(InquireError::Timeout)` on expiry, or a [`Timeout`]
+    /// describing what happens on expiry and whether a countdown is displayed.
+    pub fn with_timeout<D>(mut self, timeout: D) -> Self
+    where
+        D: Into<Timeout>,
+    {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// Sets the key bindings used to parse the user's key presses into actions,
+    /// overriding the global key map.
+    pub fn with_key_map(mut self, key_map: KeyMap) -> Self {
+        self.key_map = key_map;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Sets the destination where the prompt is rendered, such as
+    /// [`Output::Stderr`] to keep stdout free for the program's output.
+    pub fn with_output(mut self, output: Output) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the path selected by the user.
+    pub fn prompt(self) -> InquireResult<PathBuf> {
+        if get_accessible_mode() {
+            return accessible::prompt(self);
+        }
+
+        let (input_reader, terminal) = get_default_terminal(&self.output)?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<PathBuf>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Asynchronous version of [`prompt`](Self::prompt), which does not block
+    /// the executor while waiting for the user's input.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<PathBuf> {
+        self.prompt_async_with_cancellation(std::future::pending())
+            .await
+    }
+
+    /// Asynchronous version of [`prompt`](Self::prompt) that can be aborted
+    /// by the application, e.g. by passing `token.cancelled()` of a tokio
+    /// `CancellationToken`.
+    ///
+    /// When `cancellation` resolves before the user submits an answer, the
+    /// prompt is torn down and `Err(InquireError::OperationAborted)` is returned.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async_with_cancellation<C>(self, cancellation: C) -> InquireResult<PathBuf>
+    where
+        C: std::future::Future<Output = ()>,
+    {
+        let (input_reader, terminal) = get_default_async_terminal(&self.output)?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        prompt_async(PathSelectPrompt::new(self)?, &mut backend, cancellation).await
+    }
+
+    pub(crate) fn prompt_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<PathBuf> {
+        PathSelectPrompt::new(self)?.prompt(backend)
+    }
+
+    /// Absolute path of the starting directory, with `~` expanded.
+    pub(crate) fn resolve_starting_directory(&self) -> InquireResult<PathBuf> {
+        let directory = match &self.starting_directory {
+            Some(directory) => directory
+                .to_str()
+                .map(expand_tilde)
+                .unwrap_or_else(|| directory.clone()),
+            None => PathBuf::from("."),
+        };
+
+        Ok(absolute(&directory)?)
+    }
+
+    pub(crate) fn path_filter(&self) -> PathFilter {
+        PathFilter {
+            show_hidden: self.show_hidden,
+            directories_only: self.selection_mode == PathSelectionMode::Directory,
+            extensions: self.extensions.clone(),
+        }
+    }
+}
+
+/// Checks that the path can be selected, returning the error message
+/// displayed to the user otherwise.
+pub(crate) fn validate_selection(
+    path: &Path,
+    selection_mode: PathSelectionMode,
+    filter: &PathFilter,
+    writable_only: bool,
+) -> Result<(), String> {
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(format!("{} does not exist.", path.display()))
+        }
+        Err(err) => return Err(format!("{} can not be accessed: {}.", path.display(), err)),
+    };
+
+    match (selection_mode, metadata.is_dir()) {
+        (PathSelectionMode::File, true) => {
+            return Err(format!("{} is a directory.", path.display()))
+        }
+        (PathSelectionMode::Directory, false) => {
+            return Err(format!("{} is not a directory.", path.display()))
+        }
+        _ => {}
+    }
+
+    let name = path.file_name().and_then(|name| name.to_str());
+    if let (false, Some(name)) = (metadata.is_dir(), name) {
+        let filter = PathFilter {
+            show_hidden: true,
+            ..filter.clone()
+        };
+        if !filter.accepts(name, false) {
+            return Err(format!(
+                "{} does not have one of the extensions {}.",
+                path.display(),
+                filter.extensions.join(", ")
+            ));
+        }
+    }
+
+    // other kinds of files, such as pipes, could block when opened.
+    let readable = if metadata.is_dir() {
+        fs::read_dir(path).is_ok()
+    } else if metadata.is_file() {
+        fs::File::open(path).is_ok()
+    } else {
+        true
+    };
+    if !readable {
+        return Err(format!("{} is not readable.", path.display()));
+    }
+
+    if writable_only && !is_writable(path, &metadata) {
+        return Err(format!("{} is not writable.", path.display()));
+    }
+
+    Ok(())
+}
+
+/// Whether the current user can write to the path, taking its owner and
+/// group into account, unlike the permission bits alone.
+#[cfg(unix)]
+#[allow(unsafe_code)]
+fn is_writable(path: &Path, _metadata: &fs::Metadata) -> bool {
+    use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+    let Ok(path) = CString::new(path.as_os_str().as_bytes()) else {
+        return false;
+    };
+
+    // SAFETY: `path` is a valid pointer to a nul-terminated string, which is
+    // only read during the call.
+    unsafe { libc::access(path.as_ptr(), libc::W_OK) == 0 }
+}
+
+/// Whether the current user can write to the path. Files are opened for
+/// writing, which leaves them untouched, while directories can only be
+/// checked against their read-only attribute.
+#[cfg(not(unix))]
+fn is_writable(path: &Path, metadata: &fs::Metadata) -> bool {
+    if metadata.is_file() {
+        fs::OpenOptions::new().write(true).open(path).is_ok()
+    } else {
+        !metadata.permissions().readonly()
+    }
+}
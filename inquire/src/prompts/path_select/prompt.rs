@@ -0,0 +1,338 @@
+use std::{
+    cmp::Reverse,
+    path::{Path, PathBuf, MAIN_SEPARATOR},
+};
+
+use crate::{
+    error::InquireResult,
+    formatter::PathFormatter,
+    input::{Input, InputActionResult, LineDirection, Magnitude},
+    keymap::KeyMap,
+    list_option::ListOption,
+    path_utils::{home_dir, read_entries, PathEntry, PathFilter},
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        Timeout,
+    },
+    terminal::TerminalSize,
+    ui::SelectBackend,
+    utils::{auto_page_size, paginate},
+    validator::ErrorMessage,
+    InputAction, InquireError, PathSelect, Select,
+};
+
+use super::{
+    action::PathSelectPromptAction, config::PathSelectConfig, validate_selection, PathSelectionMode,
+};
+
+/// Name of the entry selecting the listed directory itself.
+const CURRENT_DIRECTORY: &str = ".";
+
+pub struct PathSelectPrompt<'a> {
+    message: &'a str,
+    config: PathSelectConfig,
+    key_map: KeyMap,
+    help_message: Option<&'a str>,
+    selection_mode: PathSelectionMode,
+    filter: PathFilter,
+    writable_only: bool,
+    directory: PathBuf,
+    entries: Vec<PathEntry>,
+    scored_entries: Vec<usize>,
+    cursor_index: usize,
+    input: Input,
+    error: Option<ErrorMessage>,
+    formatter: PathFormatter<'a>,
+    timeout: Option<Timeout>,
+}
+
+impl<'a> PathSelectPrompt<'a> {
+    pub fn new(ps: PathSelect<'a>) -> InquireResult<Self> {
+        let directory = ps.resolve_starting_directory()?;
+        let config = (&ps).into();
+        let filter = ps.path_filter();
+
+        let mut prompt = Self {
+            message: ps.message,
+            config,
+            key_map: ps.key_map,
+            help_message: ps.help_message,
+            selection_mode: ps.selection_mode,
+            filter,
+            writable_only: ps.writable_only,
+            directory: PathBuf::new(),
+            entries: vec![],
+            scored_entries: vec![],
+            cursor_index: 0,
+            input: Input::new(),
+            error: None,
+            formatter: ps.formatter,
+            timeout: ps.timeout,
+        };
+
+        let entries = prompt.list(&directory).map_err(|err| {
+            InquireError::InvalidConfiguration(format!(
+                "Starting directory {} can not be listed: {}",
+                directory.display(),
+                err
+            ))
+        })?;
+        prompt.open(directory, entries, None);
+
+        Ok(prompt)
+    }
+
+    /// Entries listed for the directory, preceded by the directory itself
+    /// when directories can be selected.
+    fn list(&self, directory: &Path) -> std::io::Result<Vec<PathEntry>> {
+        let entries = read_entries(directory, &self.filter)?;
+
+        let current = match self.selection_mode {
+            PathSelectionMode::File => None,
+            PathSelectionMode::Directory | PathSelectionMode::Any => Some(PathEntry {
+                name: CURRENT_DIRECTORY.to_owned(),
+                is_dir: true,
+            }),
+        };
+
+        Ok(current.into_iter().chain(entries).collect())
+    }
+
+    fn open(&mut self, directory: PathBuf, entries: Vec<PathEntry>, highlighted: Option<&str>) {
+        self.cursor_index = highlighted
+            .and_then(|name| entries.iter().position(|entry| entry.name == name))
+            .unwrap_or(0);
+        self.scored_entries = (0..entries.len()).collect();
+        self.entries = entries;
+        self.directory = directory;
+        self.input = Input::new();
+        self.error = None;
+    }
+
+    /// Lists the given directory, highlighting the entry with the given name,
+    /// or displays an error if it can not be listed.
+    fn change_directory(&mut self, directory: PathBuf, highlighted: Option<&str>) -> ActionResult {
+        match self.list(&directory) {
+            Ok(entries) => self.open(directory, entries, highlighted),
+            Err(err) => {
+                self.error = Some(ErrorMessage::Custom(format!(
+                    "{} can not be opened: {}.",
+                    directory.display(),
+                    err
+                )));
+            }
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn parent_directory(&mut self) -> ActionResult {
+        let Some(parent) = self.directory.parent().map(PathBuf::from) else {
+            return ActionResult::Clean;
+        };
+
+        let name = self
+            .directory
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(String::from);
+
+        self.change_directory(parent, name.as_deref())
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.scored_entries.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= self.scored_entries.len() {
+            new_position = if self.scored_entries.is_empty() {
+                0
+            } else if wrap {
+                new_position % self.scored_entries.len()
+            } else {
+                self.scored_entries.len().saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn run_scorer(&mut self) {
+        let filter = self.input.content();
+
+        let mut entries = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                let name = entry.to_string();
+                (Select::<String>::DEFAULT_SCORER)(filter, &name, &name, i).map(|score| (i, score))
+            })
+            .collect::<Vec<(usize, i64)>>();
+
+        // stable, keeping directories first among entries of equal scores.
+        entries.sort_by_key(|(_idx, score)| Reverse(*score));
+
+        self.scored_entries = entries.into_iter().map(|(idx, _)| idx).collect();
+        self.cursor_index = 0;
+    }
+
+    fn select(&mut self, path: PathBuf) -> Option<PathBuf> {
+        match validate_selection(&path, self.selection_mode, &self.filter, self.writable_only) {
+            Ok(()) => Some(path),
+            Err(message) => {
+                self.error = Some(ErrorMessage::Custom(message));
+                None
+            }
+        }
+    }
+
+    /// Listed directory, with the home directory of the user abbreviated
+    /// to `~`.
+    fn directory_display(&self) -> String {
+        let relative_to_home =
+            home_dir().and_then(|home| self.directory.strip_prefix(home).ok().map(PathBuf::from));
+
+        let directory = match relative_to_home {
+            Some(path) if path.as_os_str().is_empty() => String::from("~"),
+            Some(path) => format!("~{}{}", MAIN_SEPARATOR, path.display()),
+            None => self.directory.display().to_string(),
+        };
+
+        match directory.ends_with(MAIN_SEPARATOR) {
+            true => directory,
+            false => format!("{}{}", directory, MAIN_SEPARATOR),
+        }
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for PathSelectPrompt<'a>
+where
+    Backend: SelectBackend,
+{
+    type Config = PathSelectConfig;
+    type InnerAction = PathSelectPromptAction;
+    type Output = PathBuf;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &PathSelectConfig {
+        &self.config
+    }
+
+    fn key_map(&self) -> &KeyMap {
+        &self.key_map
+    }
+
+    fn format_answer(&self, answer: &PathBuf) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<PathBuf>> {
+        let Some(entry) = self
+            .scored_entries
+            .get(self.cursor_index)
+            .map(|&index| self.entries[index].clone())
+        else {
+            return Ok(None);
+        };
+
+        if entry.name == CURRENT_DIRECTORY {
+            return Ok(self.select(self.directory.clone()));
+        }
+
+        let path = self.directory.join(&entry.name);
+        if entry.is_dir {
+            self.change_directory(path, None);
+            return Ok(None);
+        }
+
+        Ok(self.select(path))
+    }
+
+    fn handle(&mut self, action: PathSelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            PathSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
+            PathSelectPromptAction::MoveDown => self.move_cursor_down(1, true),
+            PathSelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            PathSelectPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
+            PathSelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            PathSelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            PathSelectPromptAction::ParentDirectory => self.parent_directory(),
+
+            PathSelectPromptAction::FilterInput(InputAction::Delete(
+                Magnitude::Char,
+                LineDirection::Left,
+            )) if self.input.content().is_empty() => self.parent_directory(),
+            PathSelectPromptAction::FilterInput(input_action) => {
+                let result = self.input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    self.run_scorer();
+                }
+
+                result.into()
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn timeout(&self) -> Option<Timeout> {
+        self.timeout
+    }
+
+    fn resize(&mut self, terminal_size: TerminalSize) {
+        if self.config.auto_page_size {
+            self.config.page_size = auto_page_size(terminal_size.height());
+        }
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        let prompt = format!("{} {}", self.message, self.directory_display());
+        backend.render_select_prompt(&prompt, Some(&self.input))?;
+
+        let choices = self
+            .scored_entries
+            .iter()
+            .map(|&i| ListOption::new(i, &self.entries[i]))
+            .collect::<Vec<ListOption<&PathEntry>>>();
+
+        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+
+        backend.render_options(page, &[])?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
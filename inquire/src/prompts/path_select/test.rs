@@ -0,0 +1,189 @@
+use std::fs;
+
+use crate::{
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    PathSelect, PathSelectionMode,
+};
+
+/// Directory with the tree:
+///
+/// ```text
+/// docs/
+///   guide.md
+///   notes.txt
+/// src/
+/// .hidden
+/// README.md
+/// ```
+fn tree() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir(dir.path().join("docs")).unwrap();
+    fs::create_dir(dir.path().join("src")).unwrap();
+    fs::write(dir.path().join("docs").join("guide.md"), "").unwrap();
+    fs::write(dir.path().join("docs").join("notes.txt"), "").unwrap();
+    fs::write(dir.path().join(".hidden"), "").unwrap();
+    fs::write(dir.path().join("README.md"), "").unwrap();
+    dir
+}
+
+fn chars(text: &str) -> impl Iterator<Item = Key> + '_ {
+    text.chars().map(|c| Key::Char(c, KeyModifiers::NONE))
+}
+
+#[test]
+fn enter_opens_directories_and_selects_files() {
+    let dir = tree();
+    let mut backend = fake_backend(vec![
+        Key::Enter(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let ans = PathSelect::new("File:")
+        .with_starting_directory(dir.path())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(dir.path().join("docs").join("notes.txt"), ans);
+}
+
+#[test]
+fn backspace_on_empty_filter_goes_to_parent_directory() {
+    let dir = tree();
+    let mut backend = fake_backend(vec![
+        Key::Backspace(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    // the directory left is highlighted after going up, two entries above
+    // README.md.
+    let ans = PathSelect::new("File:")
+        .with_starting_directory(dir.path().join("docs"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(dir.path().join("README.md"), ans);
+}
+
+#[test]
+fn backspace_on_filter_deletes_chars() {
+    let dir = tree();
+    let mut keys: Vec<Key> = chars("srcx").collect();
+    keys.push(Key::Backspace(KeyModifiers::NONE));
+    keys.push(Key::Backspace(KeyModifiers::NONE));
+    keys.push(Key::Backspace(KeyModifiers::NONE));
+    keys.push(Key::Backspace(KeyModifiers::NONE));
+    keys.push(Key::Enter(KeyModifiers::NONE));
+    keys.extend(chars("guide"));
+    keys.push(Key::Enter(KeyModifiers::NONE));
+    let mut backend = fake_backend(keys);
+
+    let ans = PathSelect::new("File:")
+        .with_starting_directory(dir.path())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(dir.path().join("docs").join("guide.md"), ans);
+}
+
+#[test]
+fn alt_up_goes_to_parent_directory_while_filtering() {
+    let dir = tree();
+    let mut keys: Vec<Key> = chars("gu").collect();
+    keys.push(Key::Up(KeyModifiers::ALT));
+    keys.extend(chars("read"));
+    keys.push(Key::Enter(KeyModifiers::NONE));
+    let mut backend = fake_backend(keys);
+
+    let ans = PathSelect::new("File:")
+        .with_starting_directory(dir.path().join("docs"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(dir.path().join("README.md"), ans);
+}
+
+#[test]
+fn directory_mode_selects_the_current_directory() {
+    let dir = tree();
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let ans = PathSelect::new("Directory:")
+        .with_starting_directory(dir.path())
+        .with_selection_mode(PathSelectionMode::Directory)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(dir.path().join("docs"), ans);
+}
+
+#[test]
+fn hidden_files_and_extensions_are_filtered() {
+    let dir = tree();
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let ans = PathSelect::new("File:")
+        .with_starting_directory(dir.path())
+        .with_hidden_files(true)
+        .with_extensions(&["md"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // .hidden has no extension, leaving only README.md after the directories.
+    assert_eq!(dir.path().join("README.md"), ans);
+}
+
+#[test]
+fn writable_only_rejects_read_only_files() {
+    let dir = tree();
+    let guide = dir.path().join("docs").join("guide.md");
+    let mut permissions = fs::metadata(&guide).unwrap().permissions();
+    permissions.set_readonly(true);
+    fs::set_permissions(&guide, permissions).unwrap();
+
+    let mut backend = fake_backend(vec![
+        Key::Enter(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let ans = PathSelect::new("File:")
+        .with_starting_directory(dir.path())
+        .with_writable_only()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // permissions do not apply to privileged users, such as root
+    let expected = match fs::OpenOptions::new().write(true).open(&guide) {
+        Ok(_) => guide,
+        Err(_) => dir.path().join("docs").join("notes.txt"),
+    };
+    assert_eq!(expected, ans);
+}
+
+#[test]
+fn missing_starting_directory_is_a_configuration_error() {
+    let dir = tree();
+    let mut backend = fake_backend(vec![Key::Enter(KeyModifiers::NONE)]);
+
+    let ans = PathSelect::new("File:")
+        .with_starting_directory(dir.path().join("missing"))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(
+        ans,
+        Err(crate::InquireError::InvalidConfiguration(_))
+    ));
+}
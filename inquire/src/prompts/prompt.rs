@@ -1,8 +1,28 @@
 //! Definitions of common behavior shared amongst all different prompt types.
 
-use crate::{error::InquireResult, input::InputActionResult, ui::CommonBackend, InquireError};
+use std::time::{Duration, Instant};
+#[cfg(feature = "async")]
+use std::{
+    future::{poll_fn, Future},
+    sync::{Arc, Condvar, Mutex, PoisonError},
+    task::{Context, Poll, Waker},
+};
 
-use super::action::{Action, InnerAction};
+#[cfg(feature = "async")]
+use crate::ui::AsyncInputReader;
+use crate::{
+    error::InquireResult,
+    input::InputActionResult,
+    keymap::{Command, KeyMap, KeyScope},
+    terminal::TerminalSize,
+    ui::{CommonBackend, HitTarget, Key, MouseEvent},
+    InquireError,
+};
+
+use super::{
+    action::{Action, InnerAction},
+    Timeout, TimeoutBehavior,
+};
 
 /// Represents the result of an action on the prompt.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -40,6 +60,116 @@ impl From<InputActionResult> for ActionResult {
     }
 }
 
+/// Returns how long to wait for a key press before the loop should
+/// wake up again, either to expire the prompt or to refresh the countdown.
+fn next_wake_up(remaining: Duration, show_countdown: bool) -> Duration {
+    match (show_countdown, remaining.subsec_nanos()) {
+        (true, 0) => remaining.min(Duration::from_secs(1)),
+        (true, nanos) => Duration::from_nanos(nanos.into()),
+        (false, _) => remaining,
+    }
+}
+
+/// Wakes up the task of an asynchronous prompt at a given instant, so that
+/// its timeout expires and its countdown is refreshed without relying on the
+/// timers of a specific async runtime.
+///
+/// Wake-ups are scheduled by a background thread, which stops when the timer
+/// is dropped.
+#[cfg(feature = "async")]
+struct WakeUpTimer {
+    shared: Arc<(Mutex<WakeUpState>, Condvar)>,
+}
+
+#[cfg(feature = "async")]
+#[derive(Default)]
+struct WakeUpState {
+    wake_up_at: Option<Instant>,
+    waker: Option<Waker>,
+    stopped: bool,
+}
+
+#[cfg(feature = "async")]
+impl WakeUpTimer {
+    fn new() -> Self {
+        let shared = Arc::new((Mutex::new(WakeUpState::default()), Condvar::new()));
+        let thread_shared = Arc::clone(&shared);
+
+        std::thread::spawn(move || {
+            let (state, condvar) = &*thread_shared;
+            let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+
+            while !state.stopped {
+                state = match state.wake_up_at {
+                    Some(instant) if instant <= Instant::now() => {
+                        state.wake_up_at = None;
+                        if let Some(waker) = state.waker.take() {
+                            waker.wake();
+                        }
+                        state
+                    }
+                    Some(instant) => {
+                        let wait = instant.saturating_duration_since(Instant::now());
+                        condvar
+                            .wait_timeout(state, wait)
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .0
+                    }
+                    None => condvar.wait(state).unwrap_or_else(PoisonError::into_inner),
+                };
+            }
+        });
+
+        Self { shared }
+    }
+
+    /// Returns whether the instant passed, otherwise scheduling a wake-up of
+    /// the current task at that instant.
+    fn poll_elapsed(&self, instant: Instant, cx: &mut Context<'_>) -> Poll<()> {
+        if instant <= Instant::now() {
+            return Poll::Ready(());
+        }
+
+        let (state, condvar) = &*self.shared;
+        let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.wake_up_at = Some(instant);
+        state.waker = Some(cx.waker().clone());
+        condvar.notify_one();
+
+        Poll::Pending
+    }
+}
+
+#[cfg(feature = "async")]
+impl Drop for WakeUpTimer {
+    fn drop(&mut self) {
+        let (state, condvar) = &*self.shared;
+        state.lock().unwrap_or_else(PoisonError::into_inner).stopped = true;
+        condvar.notify_one();
+    }
+}
+
+/// Event awaited by an asynchronous prompt.
+#[cfg(feature = "async")]
+enum AsyncEvent {
+    Key(Key),
+    /// The prompt has to expire or refresh its countdown.
+    WakeUp,
+    Canceled,
+}
+
+/// State of a prompt after handling a single key press.
+pub enum PromptState<T> {
+    /// The prompt is still waiting for input.
+    Active(ActionResult),
+
+    /// The user submitted a valid answer.
+    Submitted(T),
+
+    /// The user asked to show or hide the key bindings of the prompt.
+    HelpToggled,
+}
+
 /// Shared behavior among all different prompt types.
 pub trait Prompt<Backend>
 where
@@ -61,6 +191,9 @@ where
     /// actions to the prompt.
     fn config(&self) -> &Self::Config;
 
+    /// Returns the key bindings used to parse key events into actions.
+    fn key_map(&self) -> &KeyMap;
+
     /// Hook called when a prompt is finished. Returns a string
     /// to be rendered to the user as the final submission to the prompt.
     ///
@@ -93,6 +226,15 @@ where
     /// On `Err(*)`, the prompt is teared down.
     fn submit(&mut self) -> InquireResult<Option<Self::Output>>;
 
+    /// Hook called when the user force-submits the answer to the prompt,
+    /// asking for their input to be submitted as typed, e.g. ignoring a
+    /// highlighted suggestion.
+    ///
+    /// Defaults to a regular submission.
+    fn force_submit(&mut self) -> InquireResult<Option<Self::Output>> {
+        self.submit()
+    }
+
     /// Entrypoint for any business logic for the prompt. Returns the result
     /// of the action. If the result is `Clean`, the prompt will
     /// not be re-rendered.
@@ -104,6 +246,38 @@ where
     /// to the prompt, which will then be submitted to this method just the same.
     fn handle(&mut self, action: Self::InnerAction) -> InquireResult<ActionResult>;
 
+    /// Time limit for the user to answer the prompt, if any.
+    fn timeout(&self) -> Option<Timeout> {
+        None
+    }
+
+    /// Hook called when the prompt times out with the
+    /// [`TimeoutBehavior::SubmitDefault`] behavior. Returns the default or
+    /// starting value of the prompt, if there is one.
+    fn default_answer(&mut self) -> Option<Self::Output> {
+        None
+    }
+
+    /// Hook called with the terminal size before the first draw and
+    /// whenever the terminal is resized.
+    fn resize(&mut self, _terminal_size: TerminalSize) {}
+
+    /// Whether the prompt reacts to mouse events, in which case the
+    /// terminal is asked to report them.
+    fn mouse_capture(&self) -> bool {
+        false
+    }
+
+    /// Derives an action from a mouse event, given the element of the
+    /// prompt found at the event's position, if any.
+    fn mouse_action(
+        &self,
+        _event: MouseEvent,
+        _target: Option<HitTarget>,
+    ) -> Option<Action<Self::InnerAction>> {
+        None
+    }
+
     /// Hook called for the rendering of the prompt UI.
     ///
     /// The implementation should **not** call neither `frame_setup` or
@@ -117,51 +291,303 @@ where
     /// unless the situation really warrants it.
     fn prompt(mut self, backend: &mut Backend) -> InquireResult<Self::Output> {
         self.setup()?;
+        self.resize(backend.terminal_size());
+        if self.mouse_capture() {
+            backend.enable_mouse_capture()?;
+        }
 
+        let timeout = self.timeout();
+        let deadline = timeout.map(|t| Instant::now() + t.duration);
+
+        let mut show_help = false;
         let mut last_handle = ActionResult::NeedsRedraw;
         let final_answer = loop {
+            let remaining = deadline.map(|d| d.saturating_duration_since(Instant::now()));
+
+            if let (Some(timeout), Some(remaining)) = (timeout, remaining) {
+                if remaining.is_zero() {
+                    break self.expire(timeout, backend)?;
+                }
+            }
+
             if last_handle.needs_redraw() {
-                backend.frame_setup()?;
-                self.render(backend)?;
-                backend.frame_finish()?;
-                last_handle = ActionResult::Clean;
+                let countdown = timeout.filter(|t| t.show_countdown).and(remaining);
+                self.redraw(backend, countdown, show_help)?;
             }
 
-            let key = backend.read_key()?;
-            let action = Action::from_key(key, self.config());
+            let key = match (timeout, remaining) {
+                (Some(timeout), Some(remaining)) => {
+                    let wait = next_wake_up(remaining, timeout.show_countdown);
 
-            if let Some(action) = action {
-                last_handle = match action {
-                    Action::Submit => {
-                        if let Some(answer) = self.submit()? {
-                            break answer;
-                        }
-                        ActionResult::NeedsRedraw
-                    }
-                    Action::Cancel => {
-                        let pre_cancel_result = self.pre_cancel()?;
-
-                        if pre_cancel_result {
-                            backend.frame_setup()?;
-                            backend.render_canceled_prompt(self.message())?;
-                            backend.frame_finish()?;
-                            return Err(InquireError::OperationCanceled);
-                        }
+                    let Some(key) = backend.read_key_timeout(wait)? else {
+                        last_handle = match timeout.show_countdown {
+                            true => ActionResult::NeedsRedraw,
+                            false => ActionResult::Clean,
+                        };
+                        continue;
+                    };
 
-                        ActionResult::NeedsRedraw
-                    }
-                    Action::Interrupt => return Err(InquireError::OperationInterrupted),
-                    Action::Inner(inner_action) => self.handle(inner_action)?,
-                };
+                    key
+                }
+                _ => backend.read_key()?,
+            };
+
+            last_handle = match self.handle_key(key, backend)? {
+                PromptState::Active(result) => result,
+                PromptState::Submitted(answer) => break answer,
+                PromptState::HelpToggled => {
+                    show_help = !show_help;
+                    ActionResult::NeedsRedraw
+                }
+            };
+        };
+
+        self.finish(&final_answer, backend)?;
+
+        Ok(final_answer)
+    }
+
+    /// Renders a full frame of the prompt, optionally followed by its key
+    /// bindings and the remaining time to answer it.
+    fn redraw(
+        &self,
+        backend: &mut Backend,
+        countdown: Option<Duration>,
+        show_help: bool,
+    ) -> InquireResult<()> {
+        backend.frame_setup()?;
+        self.render(backend)?;
+        if show_help {
+            self.render_key_bindings(backend)?;
+        }
+        if let Some(remaining) = countdown {
+            backend.render_countdown(remaining)?;
+        }
+        backend.frame_finish()?;
+
+        Ok(())
+    }
+
+    /// Renders the key bindings supported by the prompt, one per line.
+    fn render_key_bindings(&self, backend: &mut Backend) -> InquireResult<()> {
+        let mut scopes = vec![KeyScope::All];
+        scopes.extend_from_slice(Self::InnerAction::key_scopes(self.config()));
+
+        for (command, patterns) in self.key_map().bindings_by_command(&scopes) {
+            let supported = match command {
+                Command::Submit
+                | Command::ForceSubmit
+                | Command::Cancel
+                | Command::Interrupt
+                | Command::Help
+                | Command::Back => true,
+                Command::Ignore => false,
+                command => Self::InnerAction::from_command(command, self.config()).is_some(),
+            };
+
+            if supported {
+                let keys = patterns
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let description = command.description().trim_end_matches('.');
+                backend.render_help_message(&format!("{}: {}", keys, description))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finishes a prompt whose timeout expired, according to the
+    /// configured [`TimeoutBehavior`].
+    fn expire(&mut self, timeout: Timeout, backend: &mut Backend) -> InquireResult<Self::Output> {
+        if timeout.behavior == TimeoutBehavior::SubmitDefault {
+            if let Some(answer) = self.default_answer() {
+                return Ok(answer);
+            }
+        }
+
+        self.render_canceled(backend)?;
+        Err(InquireError::Timeout)
+    }
+
+    /// Parses a key press into an action and applies it to the prompt.
+    fn handle_key(
+        &mut self,
+        key: Key,
+        backend: &mut Backend,
+    ) -> InquireResult<PromptState<Self::Output>> {
+        if let Key::Resize(width, height) = key {
+            self.resize(TerminalSize::new(width, height));
+            return Ok(PromptState::Active(ActionResult::NeedsRedraw));
+        }
+
+        let action = match key {
+            Key::Mouse(event) => {
+                let target = backend.hit_test(event.column, event.row);
+                self.mouse_action(event, target)
             }
+            key => Action::from_key_with_map(key, self.config(), self.key_map()),
         };
 
-        let formatted = self.format_answer(&final_answer);
+        let result = match action {
+            Some(Action::Submit) => {
+                if let Some(answer) = self.submit()? {
+                    return Ok(PromptState::Submitted(answer));
+                }
+                ActionResult::NeedsRedraw
+            }
+            Some(Action::ForceSubmit) => {
+                if let Some(answer) = self.force_submit()? {
+                    return Ok(PromptState::Submitted(answer));
+                }
+                ActionResult::NeedsRedraw
+            }
+            Some(Action::Cancel) => {
+                let pre_cancel_result = self.pre_cancel()?;
+
+                if pre_cancel_result {
+                    self.render_canceled(backend)?;
+                    return Err(InquireError::OperationCanceled);
+                }
+
+                ActionResult::NeedsRedraw
+            }
+            Some(Action::Interrupt) => return Err(InquireError::OperationInterrupted),
+            Some(Action::ToggleHelp) => return Ok(PromptState::HelpToggled),
+            Some(Action::Back) => {
+                // the prompt is cleared, as it's going to be asked again
+                backend.frame_setup()?;
+                backend.frame_finish()?;
+                return Err(InquireError::NavigatedBack);
+            }
+            Some(Action::Inner(inner_action)) => self.handle(inner_action)?,
+            None => ActionResult::Clean,
+        };
+
+        Ok(PromptState::Active(result))
+    }
+
+    /// Renders the final frame of a prompt that was canceled.
+    fn render_canceled(&self, backend: &mut Backend) -> InquireResult<()> {
+        backend.frame_setup()?;
+        backend.render_canceled_prompt(self.message())?;
+        backend.frame_finish()?;
+
+        Ok(())
+    }
+
+    /// Renders the final frame of a prompt, containing the submitted answer.
+    fn finish(&self, answer: &Self::Output, backend: &mut Backend) -> InquireResult<()> {
+        let formatted = self.format_answer(answer);
 
         backend.frame_setup()?;
         backend.render_prompt_with_answer(self.message(), &formatted)?;
         backend.frame_finish()?;
 
-        Ok(final_answer)
+        Ok(())
     }
 }
+
+/// Asynchronous counterpart of [`Prompt::prompt`].
+///
+/// Keys are read without blocking the executor thread. When `cancellation`
+/// resolves before the prompt is finished, the prompt is rendered as canceled
+/// and `Err(InquireError::OperationAborted)` is returned.
+///
+/// The timeout of the prompt is applied as in [`Prompt::prompt`], the task
+/// being woken up by a background thread when it expires.
+#[cfg(feature = "async")]
+pub async fn prompt_async<P, Backend, C>(
+    mut prompt: P,
+    backend: &mut Backend,
+    cancellation: C,
+) -> InquireResult<P::Output>
+where
+    P: Prompt<Backend>,
+    Backend: CommonBackend + AsyncInputReader,
+    C: Future<Output = ()>,
+{
+    prompt.setup()?;
+    prompt.resize(backend.terminal_size());
+    if prompt.mouse_capture() {
+        backend.enable_mouse_capture()?;
+    }
+
+    let mut cancellation = Box::pin(cancellation);
+
+    let timeout = prompt.timeout();
+    let deadline = timeout.map(|t| Instant::now() + t.duration);
+    let timer = timeout.map(|_| WakeUpTimer::new());
+
+    let mut show_help = false;
+    let mut last_handle = ActionResult::NeedsRedraw;
+    let final_answer = loop {
+        let remaining = deadline.map(|d| d.saturating_duration_since(Instant::now()));
+
+        if let (Some(timeout), Some(remaining)) = (timeout, remaining) {
+            if remaining.is_zero() {
+                break prompt.expire(timeout, backend)?;
+            }
+        }
+
+        if last_handle.needs_redraw() {
+            let countdown = timeout.filter(|t| t.show_countdown).and(remaining);
+            prompt.redraw(backend, countdown, show_help)?;
+        }
+
+        let wake_up_at = match (timeout, remaining) {
+            (Some(timeout), Some(remaining)) => {
+                Some(Instant::now() + next_wake_up(remaining, timeout.show_countdown))
+            }
+            _ => None,
+        };
+
+        let event = poll_fn(|cx| {
+            if cancellation.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(Ok(AsyncEvent::Canceled));
+            }
+
+            if let Poll::Ready(key) = backend.poll_read_key(cx) {
+                return Poll::Ready(key.map(AsyncEvent::Key));
+            }
+
+            match (&timer, wake_up_at) {
+                (Some(timer), Some(instant)) => timer
+                    .poll_elapsed(instant, cx)
+                    .map(|_| Ok(AsyncEvent::WakeUp)),
+                _ => Poll::Pending,
+            }
+        })
+        .await?;
+
+        let key = match event {
+            AsyncEvent::Key(key) => key,
+            AsyncEvent::WakeUp => {
+                last_handle = match timeout.map(|t| t.show_countdown) {
+                    Some(true) => ActionResult::NeedsRedraw,
+                    _ => ActionResult::Clean,
+                };
+                continue;
+            }
+            AsyncEvent::Canceled => {
+                prompt.render_canceled(backend)?;
+                return Err(InquireError::OperationAborted);
+            }
+        };
+
+        last_handle = match prompt.handle_key(key, backend)? {
+            PromptState::Active(result) => result,
+            PromptState::Submitted(answer) => break answer,
+            PromptState::HelpToggled => {
+                show_help = !show_help;
+                ActionResult::NeedsRedraw
+            }
+        };
+    };
+
+    prompt.finish(&final_answer, backend)?;
+
+    Ok(final_answer)
+}
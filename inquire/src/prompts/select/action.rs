@@ -1,12 +1,13 @@
 use crate::{
-    ui::{Key, KeyModifiers},
+    keymap::{Command, KeyScope},
+    ui::Key,
     InnerAction, InputAction,
 };
 
 use super::config::SelectConfig;
 
 /// Set of actions for a SelectPrompt.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum SelectPromptAction {
     /// Action on the value text input handler.
     FilterInput(InputAction),
@@ -22,39 +23,37 @@ pub enum SelectPromptAction {
     MoveToStart,
     /// Moves the cursor to the end of the list.
     MoveToEnd,
+    /// Moves the cursor to the option with the given index in the original list.
+    HighlightOption(usize),
 }
 
 impl InnerAction for SelectPromptAction {
     type Config = SelectConfig;
 
-    fn from_key(key: Key, config: &SelectConfig) -> Option<Self> {
-        if config.vim_mode {
-            let action = match key {
-                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
-                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
-                _ => None,
-            };
-
-            if action.is_some() {
-                return action;
-            }
+    fn key_scopes(config: &SelectConfig) -> &'static [KeyScope] {
+        match config.vim_mode {
+            true => &[KeyScope::Vim, KeyScope::Select, KeyScope::Input],
+            false => &[KeyScope::Select, KeyScope::Input],
         }
+    }
 
-        let action = match key {
-            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
-            Key::PageUp(_) => Self::PageUp,
-            Key::Home => Self::MoveToStart,
+    fn from_command(command: Command, _config: &SelectConfig) -> Option<Self> {
+        let action = match command {
+            Command::MoveUp => Self::MoveUp,
+            Command::PageUp => Self::PageUp,
+            Command::MoveToStart => Self::MoveToStart,
 
-            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
-            Key::PageDown(_) => Self::PageDown,
-            Key::End => Self::MoveToEnd,
+            Command::MoveDown => Self::MoveDown,
+            Command::PageDown => Self::PageDown,
+            Command::MoveToEnd => Self::MoveToEnd,
 
-            key => match InputAction::from_key(key, &()) {
-                Some(action) => Self::FilterInput(action),
-                None => return None,
-            },
+            command => Self::FilterInput(InputAction::from_command(command, &())?),
         };
 
         Some(action)
     }
+
+    fn from_unbound_key(key: Key, _config: &SelectConfig) -> Option<Self> {
+        InputAction::from_unbound_key(key, &()).map(Self::FilterInput)
+    }
 }
@@ -7,8 +7,12 @@ pub struct SelectConfig {
     pub vim_mode: bool,
     /// Page size of the list of options.
     pub page_size: usize,
+    /// Whether the page size is computed from the terminal height.
+    pub auto_page_size: bool,
     /// Whether to reset the cursor to the first option on filter input change.
     pub reset_cursor: bool,
+    /// Whether mouse events are captured.
+    pub mouse_capture: bool,
 }
 
 impl<T> From<&Select<'_, T>> for SelectConfig {
@@ -16,7 +20,9 @@ impl<T> From<&Select<'_, T>> for SelectConfig {
         Self {
             vim_mode: value.vim_mode,
             page_size: value.page_size,
+            auto_page_size: value.auto_page_size,
             reset_cursor: value.reset_cursor,
+            mouse_capture: value.mouse_capture,
         }
     }
 }
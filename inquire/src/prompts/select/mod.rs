@@ -9,15 +9,21 @@ pub use action::*;
 use std::fmt::Display;
 
 use crate::{
-    config::get_configuration,
+    config::{get_accessible_mode, get_configuration, get_key_map, get_vim_mode},
+    enum_support::InquireEnumVariants,
     error::{InquireError, InquireResult},
     formatter::OptionFormatter,
+    keymap::KeyMap,
     list_option::ListOption,
+    prompts::accessible,
     prompts::prompt::Prompt,
+    prompts::Timeout,
     terminal::get_default_terminal,
-    type_aliases::Scorer,
-    ui::{Backend, RenderConfig, SelectBackend},
+    type_aliases::{MatchScorer, OptionHelp, Scorer},
+    ui::{Backend, Output, RenderConfig, SelectBackend},
 };
+#[cfg(feature = "async")]
+use crate::{prompts::prompt::prompt_async, terminal::get_default_async_terminal};
 
 use self::prompt::SelectPrompt;
 
@@ -50,6 +56,7 @@ static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::defaul
 /// - **Page size**: Number of options displayed at once, 7 by default.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
 /// - **Scorer function**: Function that defines the order of options and if displayed as all.
+/// - **Match scorer function**: Scorer function that also returns the matched characters of the options, highlighted with the `matched_text` style sheet of the `RenderConfig`. Takes precedence over the scorer function when set, which is the case by default with the `fuzzy` feature.
 ///
 /// # Example
 ///
@@ -80,9 +87,23 @@ pub struct Select<'a, T> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Function returning the help message of an option, displayed instead of
+    /// [`help_message`](Self::help_message) while the option is highlighted.
+    pub option_help: Option<OptionHelp<'a, T>>,
+
     /// Page size of the options displayed to the user.
     pub page_size: usize,
 
+    /// Whether the page size is computed from the terminal height, in which
+    /// case `page_size` is ignored and the pages are recomputed on resizes.
+    pub auto_page_size: bool,
+
+    /// Whether mouse events are captured, allowing the user to click on
+    /// options and to scroll through pages with the mouse wheel.
+    ///
+    /// Not supported when using `console` as the terminal back-end.
+    pub mouse_capture: bool,
+
     /// Whether vim mode is enabled. When enabled, the user can
     /// navigate through the options using hjkl.
     pub vim_mode: bool,
@@ -106,9 +127,22 @@ pub struct Select<'a, T> {
     /// options.
     pub scorer: Scorer<'a, T>,
 
+    /// Function called with the current user input to score the provided
+    /// options and to find the characters of each option to highlight.
+    ///
+    /// When set, it is used instead of [`scorer`](Self::scorer). Defaults to
+    /// [`DEFAULT_MATCH_SCORER`](Self::DEFAULT_MATCH_SCORER) with the `fuzzy`
+    /// feature, and is unset by [`with_scorer`](Self::with_scorer).
+    pub match_scorer: Option<MatchScorer<'a, T>>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: OptionFormatter<'a, T>,
 
+    /// Key bindings used to parse the user's key presses into actions.
+    ///
+    /// Defaults to the global key map, see [set_global_key_map](crate::set_global_key_map).
+    pub key_map: KeyMap,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -118,6 +152,14 @@ pub struct Select<'a, T> {
     /// config is treated as the only source of truth. If you want to customize colors
     /// and still support NO_COLOR, you will have to do this on your end.
     pub render_config: RenderConfig<'a>,
+
+    /// Destination where the prompt is rendered, stderr by default.
+    ///
+    /// Keys are read from the terminal regardless of the output.
+    pub output: Output,
+
+    /// Time limit for the user to answer the prompt, if any.
+    pub timeout: Option<Timeout>,
 }
 
 impl<'a, T> Select<'a, T>
@@ -178,6 +220,25 @@ where
             }
         };
 
+    /// Default scoring function returning the matched characters of the
+    /// options, scoring them as [`DEFAULT_SCORER`](Self::DEFAULT_SCORER) does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let scorer = Select::<&str>::DEFAULT_MATCH_SCORER;
+    /// assert_eq!(None,                  scorer("sa", &"New York",   "New York",   0));
+    /// assert_eq!(Some((49, vec![0, 1])), scorer("sa", &"Sacramento", "Sacramento", 1));
+    /// assert_eq!(Some((35, vec![2, 3])), scorer("sa", &"Mesa",       "Mesa",       3));
+    /// ```
+    #[cfg(feature = "fuzzy")]
+    pub const DEFAULT_MATCH_SCORER: MatchScorer<'a, T> =
+        &|input, _option, string_value, _idx| -> Option<(i64, Vec<usize>)> {
+            DEFAULT_MATCHER.fuzzy_indices(string_value, input)
+        };
+
     /// Default page size.
     pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
 
@@ -205,18 +266,38 @@ where
             message,
             options,
             help_message: Self::DEFAULT_HELP_MESSAGE,
+            option_help: None,
             page_size: Self::DEFAULT_PAGE_SIZE,
-            vim_mode: Self::DEFAULT_VIM_MODE,
+            auto_page_size: false,
+            mouse_capture: false,
+            vim_mode: get_vim_mode(),
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
             filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
             scorer: Self::DEFAULT_SCORER,
+            #[cfg(feature = "fuzzy")]
+            match_scorer: Some(Self::DEFAULT_MATCH_SCORER),
+            #[cfg(not(feature = "fuzzy"))]
+            match_scorer: None,
             formatter: Self::DEFAULT_FORMATTER,
+            key_map: get_key_map(),
             render_config: get_configuration(),
+            output: Output::default(),
+            timeout: None,
             starting_filter_input: None,
         }
     }
 
+    /// Creates a [Select] whose options are the variants of the enum `T`, along
+    /// with default configuration values. The help message of each variant is
+    /// displayed while it is highlighted.
+    pub fn from_enum(message: &'a str) -> Self
+    where
+        T: InquireEnumVariants,
+    {
+        Self::new(message, T::variants()).with_option_help(&|variant: &T| variant.help())
+    }
+
     /// Sets the help message of the prompt.
     pub fn with_help_message(mut self, message: &'a str) -> Self {
         self.help_message = Some(message);
@@ -229,21 +310,58 @@ where
         self
     }
 
+    /// Sets the function returning the help message of each option, displayed
+    /// instead of the help message of the prompt while the option is highlighted.
+    pub fn with_option_help(mut self, option_help: OptionHelp<'a, T>) -> Self {
+        self.option_help = Some(option_help);
+        self
+    }
+
     /// Sets the page size.
     pub fn with_page_size(mut self, page_size: usize) -> Self {
         self.page_size = page_size;
         self
     }
 
+    /// Sets the page size to fill the available height of the terminal,
+    /// adapting whenever the terminal is resized.
+    pub fn with_auto_page_size(mut self) -> Self {
+        self.auto_page_size = true;
+        self
+    }
+
+    /// Enables mouse capture, allowing the user to click on options and
+    /// to scroll through pages with the mouse wheel.
+    ///
+    /// The first click on an option highlights it, clicking on the
+    /// highlighted option selects it.
+    pub fn with_mouse_capture(mut self) -> Self {
+        self.mouse_capture = true;
+        self
+    }
+
     /// Enables or disables vim_mode.
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
         self
     }
 
-    /// Sets the scoring function.
+    /// Sets the scoring function, replacing the match scoring function used
+    /// by default to highlight the matched characters of the options.
     pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
         self.scorer = scorer;
+        self.match_scorer = None;
+        self
+    }
+
+    /// Sets the scoring function returning the matched characters of the
+    /// options, which are then highlighted. Takes precedence over the
+    /// function set by [`with_scorer`](Self::with_scorer).
+    ///
+    /// With the `fuzzy` feature, [`DEFAULT_MATCH_SCORER`](Self::DEFAULT_MATCH_SCORER)
+    /// is used unless this or [`with_scorer`](Self::with_scorer) is called.
+    pub fn with_match_scorer(mut self, match_scorer: MatchScorer<'a, T>) -> Self {
+        self.match_scorer = Some(match_scorer);
         self
     }
 
@@ -287,6 +405,26 @@ where
         self
     }
 
+    /// Sets a time limit for the user to answer the prompt.
+    ///
+    /// Accepts either a [`Duration`](std::time::Duration), in which case the
+    /// prompt returns `Err(InquireError::Timeout)` on expiry, or a [`Timeout`]
+    /// describing what happens on expiry and whether a countdown is displayed.
+    pub fn with_timeout<D>(mut self, timeout: D) -> Self
+    where
+        D: Into<Timeout>,
+    {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// Sets the key bindings used to parse the user's key presses into actions,
+    /// overriding the global key map.
+    pub fn with_key_map(mut self, key_map: KeyMap) -> Self {
+        self.key_map = key_map;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -300,6 +438,13 @@ where
         self
     }
 
+    /// Sets the destination where the prompt is rendered, such as
+    /// [`Output::Stderr`] to keep stdout free for the program's output.
+    pub fn with_output(mut self, output: Output) -> Self {
+        self.output = output;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -331,11 +476,43 @@ where
     /// Returns a [`ListOption`](crate::list_option::ListOption) containing
     /// the index of the selection and the owned object selected by the user.
     pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
-        let (input_reader, terminal) = get_default_terminal()?;
+        if get_accessible_mode() {
+            return accessible::prompt(self);
+        }
+
+        let (input_reader, terminal) = get_default_terminal(&self.output)?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Asynchronous version of [`prompt`](Self::prompt), which does not block
+    /// the executor while waiting for the user's input.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<T> {
+        self.prompt_async_with_cancellation(std::future::pending())
+            .await
+    }
+
+    /// Asynchronous version of [`prompt`](Self::prompt) that can be aborted
+    /// by the application, e.g. by passing `token.cancelled()` of a tokio
+    /// `CancellationToken`.
+    ///
+    /// When `cancellation` resolves before the user submits an answer, the
+    /// prompt is torn down and `Err(InquireError::OperationAborted)` is returned.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async_with_cancellation<C>(self, cancellation: C) -> InquireResult<T>
+    where
+        C: std::future::Future<Output = ()>,
+    {
+        let (input_reader, terminal) = get_default_async_terminal(&self.output)?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        prompt_async(SelectPrompt::new(self)?, &mut backend, cancellation)
+            .await
+            .map(|op| op.value)
+    }
+
     pub(crate) fn prompt_with_backend<B: SelectBackend>(
         self,
         backend: &mut B,
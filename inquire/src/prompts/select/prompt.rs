@@ -1,15 +1,21 @@
 use std::{cmp::Reverse, fmt::Display};
 
 use crate::{
+    ansi::AnsiStrippable,
     error::InquireResult,
     formatter::OptionFormatter,
     input::{Input, InputActionResult},
+    keymap::KeyMap,
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
-    type_aliases::Scorer,
-    ui::SelectBackend,
-    utils::paginate,
-    InquireError, Select,
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        Timeout,
+    },
+    terminal::TerminalSize,
+    type_aliases::{MatchScorer, OptionHelp, Scorer},
+    ui::{HitTarget, MouseEvent, MouseEventKind, SelectBackend},
+    utils::{auto_page_size, paginate},
+    Action, InquireError, Select,
 };
 
 use super::{action::SelectPromptAction, config::SelectConfig};
@@ -17,14 +23,22 @@ use super::{action::SelectPromptAction, config::SelectConfig};
 pub struct SelectPrompt<'a, T> {
     message: &'a str,
     config: SelectConfig,
+    key_map: KeyMap,
     options: Vec<T>,
     string_options: Vec<String>,
     scored_options: Vec<usize>,
     help_message: Option<&'a str>,
+    option_help: Option<OptionHelp<'a, T>>,
     cursor_index: usize,
     input: Option<Input>,
     scorer: Scorer<'a, T>,
+    match_scorer: Option<MatchScorer<'a, T>>,
+    /// Indices of the characters of each option matched by the filter input,
+    /// empty unless a match scorer is set.
+    matches: Vec<Vec<usize>>,
     formatter: OptionFormatter<'a, T>,
+    starting_cursor: usize,
+    timeout: Option<Timeout>,
 }
 
 impl<'a, T> SelectPrompt<'a, T>
@@ -59,14 +73,20 @@ where
         Ok(Self {
             message: so.message,
             config: (&so).into(),
+            key_map: so.key_map,
             options: so.options,
             string_options,
             scored_options,
             help_message: so.help_message,
+            option_help: so.option_help,
             cursor_index: so.starting_cursor,
             input,
             scorer: so.scorer,
+            match_scorer: so.match_scorer,
+            matches: vec![],
             formatter: so.formatter,
+            starting_cursor: so.starting_cursor,
+            timeout: so.timeout,
         })
     }
 
@@ -99,6 +119,17 @@ where
         self.update_cursor_position(new_position)
     }
 
+    fn highlight_option(&mut self, option_index: usize) -> ActionResult {
+        match self
+            .scored_options
+            .iter()
+            .position(|idx| *idx == option_index)
+        {
+            Some(position) => self.update_cursor_position(position),
+            None => ActionResult::Clean,
+        }
+    }
+
     fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
         if new_position != self.cursor_index {
             self.cursor_index = new_position;
@@ -122,6 +153,22 @@ where
         ListOption::new(index, value)
     }
 
+    /// Scores the option at the given index against the filter input, along
+    /// with the indices of its matched characters if a match scorer is set.
+    fn score(&self, content: &str, option: &T, index: usize) -> Option<(i64, Vec<usize>)> {
+        let string_value = &self.string_options[index];
+
+        match self.match_scorer {
+            Some(match_scorer) => {
+                let stripped = string_value.ansi_stripped_chars().collect::<String>();
+                match_scorer(content, option, &stripped, index)
+            }
+            None => {
+                (self.scorer)(content, option, string_value, index).map(|score| (score, vec![]))
+            }
+        }
+    }
+
     fn run_scorer(&mut self) {
         let content = match &self.input {
             Some(input) => input.content(),
@@ -132,16 +179,18 @@ where
             .options
             .iter()
             .enumerate()
-            .filter_map(|(i, opt)| {
-                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
-                    .map(|score| (i, score))
-            })
-            .collect::<Vec<(usize, i64)>>();
+            .filter_map(|(i, opt)| self.score(content, opt, i).map(|score| (i, score)))
+            .collect::<Vec<(usize, (i64, Vec<usize>))>>();
 
-        options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+        options.sort_unstable_by_key(|(_idx, (score, _))| Reverse(*score));
 
         let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
 
+        self.matches = vec![vec![]; self.options.len()];
+        for (idx, (_, matched)) in options {
+            self.matches[idx] = matched;
+        }
+
         if self.scored_options == new_scored_options {
             return;
         }
@@ -173,6 +222,10 @@ where
         &self.config
     }
 
+    fn key_map(&self) -> &KeyMap {
+        &self.key_map
+    }
+
     fn format_answer(&self, answer: &ListOption<T>) -> String {
         (self.formatter)(answer.as_ref())
     }
@@ -199,6 +252,7 @@ where
             SelectPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
             SelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             SelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            SelectPromptAction::HighlightOption(index) => self.highlight_option(index),
 
             SelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
                 Some(input) => {
@@ -217,6 +271,45 @@ where
         Ok(result)
     }
 
+    fn timeout(&self) -> Option<Timeout> {
+        self.timeout
+    }
+
+    fn default_answer(&mut self) -> Option<ListOption<T>> {
+        let value = self.options.swap_remove(self.starting_cursor);
+
+        Some(ListOption::new(self.starting_cursor, value))
+    }
+
+    fn resize(&mut self, terminal_size: TerminalSize) {
+        if self.config.auto_page_size {
+            self.config.page_size = auto_page_size(terminal_size.height());
+        }
+    }
+
+    fn mouse_capture(&self) -> bool {
+        self.config.mouse_capture
+    }
+
+    fn mouse_action(
+        &self,
+        event: MouseEvent,
+        target: Option<HitTarget>,
+    ) -> Option<Action<SelectPromptAction>> {
+        match (event.kind, target) {
+            // a first click highlights the option, a second one submits it
+            (MouseEventKind::LeftClick, Some(HitTarget::ListOption(index))) => {
+                match self.scored_options.get(self.cursor_index) == Some(&index) {
+                    true => Some(Action::Submit),
+                    false => Some(Action::Inner(SelectPromptAction::HighlightOption(index))),
+                }
+            }
+            (MouseEventKind::ScrollUp, _) => Some(Action::Inner(SelectPromptAction::PageUp)),
+            (MouseEventKind::ScrollDown, _) => Some(Action::Inner(SelectPromptAction::PageDown)),
+            _ => None,
+        }
+    }
+
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
@@ -231,9 +324,13 @@ where
 
         let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
 
-        backend.render_options(page)?;
+        backend.render_options(page, &self.matches)?;
 
-        if let Some(help_message) = self.help_message {
+        let option_help = match (self.option_help, self.scored_options.get(self.cursor_index)) {
+            (Some(option_help), Some(&index)) => option_help(&self.options[index]),
+            _ => None,
+        };
+        if let Some(help_message) = option_help.or(self.help_message) {
             backend.render_help_message(help_message)?;
         }
 
@@ -1,16 +1,21 @@
+use std::time::Duration;
+
 use crate::{
     formatter::OptionFormatter,
     list_option::ListOption,
     test::fake_backend,
-    ui::{Key, KeyModifiers},
-    Select,
+    ui::{Key, KeyModifiers, MouseEvent, MouseEventKind},
+    Select, Timeout, TimeoutBehavior,
 };
 
 #[test]
 /// Tests that a closure that actually closes on a variable can be used
 /// as a Select formatter.
 fn closure_formatter() {
-    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
 
     let formatter: OptionFormatter<'_, i32> = &|_| String::from("Thanks!");
     let options = vec![1, 2, 3];
@@ -23,15 +28,33 @@ fn closure_formatter() {
     assert_eq!(ListOption::new(1, 2), ans);
 }
 
+#[test]
+fn shift_tab_moves_cursor_up() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::BackTab(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let options = vec![1, 2, 3];
+
+    let ans = Select::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, 2), ans);
+}
+
 #[test]
 // Anti-regression test: https://github.com/mikaelmello/inquire/issues/29
 fn enter_arrow_on_empty_list_does_not_panic() {
     let mut backend = fake_backend(vec![
         Key::Char('9', KeyModifiers::NONE),
-        Key::Enter,
-        Key::Backspace,
+        Key::Enter(KeyModifiers::NONE),
+        Key::Backspace(KeyModifiers::NONE),
         Key::Char('3', KeyModifiers::NONE),
-        Key::Enter,
+        Key::Enter(KeyModifiers::NONE),
     ]);
 
     let options = vec![1, 2, 3];
@@ -49,11 +72,11 @@ fn down_arrow_on_empty_list_does_not_panic() {
     let mut backend = fake_backend(vec![
         Key::Char('9', KeyModifiers::NONE),
         Key::Down(KeyModifiers::NONE),
-        Key::Backspace,
+        Key::Backspace(KeyModifiers::NONE),
         Key::Char('3', KeyModifiers::NONE),
         Key::Down(KeyModifiers::NONE),
-        Key::Backspace,
-        Key::Enter,
+        Key::Backspace(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
     ]);
 
     let options = vec![1, 2, 3];
@@ -68,7 +91,7 @@ fn down_arrow_on_empty_list_does_not_panic() {
 #[test]
 // Anti-regression test: https://github.com/mikaelmello/inquire/issues/195
 fn starting_cursor_is_respected() {
-    let mut backend = fake_backend(vec![Key::Enter]);
+    let mut backend = fake_backend(vec![Key::Enter(KeyModifiers::NONE)]);
 
     let options = vec![1, 2, 3];
 
@@ -87,7 +110,7 @@ fn naive_assert_fuzzy_match_as_default_scorer() {
         Key::Char('r', KeyModifiers::NONE),
         Key::Char('r', KeyModifiers::NONE),
         Key::Char('y', KeyModifiers::NONE),
-        Key::Enter,
+        Key::Enter(KeyModifiers::NONE),
     ]);
 
     let options = vec![
@@ -111,6 +134,46 @@ fn naive_assert_fuzzy_match_as_default_scorer() {
     assert_eq!(ListOption::new(2, "Strawberry"), ans);
 }
 
+#[test]
+fn match_scorer_takes_precedence_and_receives_ansi_stripped_values() {
+    let mut backend = fake_backend(vec![
+        Key::Char('L', KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let options = vec!["\x1b[33mBanana\x1b[0m", "\x1b[33mLemon\x1b[0m"];
+
+    let ans = Select::new("Question", options)
+        .with_scorer(&|_, _, _, _| None)
+        .with_match_scorer(
+            &|input, _, string_value, _| match string_value.starts_with(input) {
+                true => Some((0, (0..input.chars().count()).collect())),
+                false => None,
+            },
+        )
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "\x1b[33mLemon\x1b[0m"), ans);
+}
+
+#[test]
+fn scorer_replaces_default_match_scorer() {
+    let mut backend = fake_backend(vec![
+        Key::Char('o', KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let options = vec!["Avocado", "Banana"];
+
+    let ans = Select::new("Question", options)
+        .with_scorer(&|_, _, string_value, _| (string_value == "Banana").then_some(0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Banana"), ans);
+}
+
 #[test]
 fn chars_do_not_affect_prompt_without_filtering() {
     let mut backend = fake_backend(vec![
@@ -118,7 +181,7 @@ fn chars_do_not_affect_prompt_without_filtering() {
         Key::Char('r', KeyModifiers::NONE),
         Key::Char('r', KeyModifiers::NONE),
         Key::Char('y', KeyModifiers::NONE),
-        Key::Enter,
+        Key::Enter(KeyModifiers::NONE),
     ]);
 
     let options = vec![
@@ -142,3 +205,183 @@ fn chars_do_not_affect_prompt_without_filtering() {
 
     assert_eq!(ListOption::new(0, "Banana"), ans);
 }
+
+#[test]
+fn expired_timeout_submits_starting_cursor_when_configured() {
+    let mut backend = fake_backend(vec![]);
+
+    let options = vec![1, 2, 3];
+
+    let ans = Select::new("Question", options)
+        .with_starting_cursor(2)
+        .with_timeout(Timeout::new(Duration::ZERO).with_behavior(TimeoutBehavior::SubmitDefault))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, 3), ans);
+}
+
+#[test]
+fn resize_recomputes_auto_page_size() {
+    let mut backend = fake_backend(vec![
+        Key::Resize(80, 6),
+        Key::PageDown(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let options = (1..=10).collect();
+
+    let ans = Select::new("Question", options)
+        .with_auto_page_size()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(3, 4), ans);
+}
+
+fn mouse(kind: MouseEventKind, row: u16) -> Key {
+    Key::Mouse(MouseEvent {
+        kind,
+        column: 4,
+        row,
+    })
+}
+
+#[test]
+fn clicking_highlighted_option_selects_it() {
+    // the prompt is rendered on the first row, followed by the options
+    let mut backend = fake_backend(vec![
+        mouse(MouseEventKind::LeftClick, 3),
+        mouse(MouseEventKind::LeftClick, 3),
+    ]);
+
+    let options = vec![1, 2, 3, 4];
+
+    let ans = Select::new("Question", options)
+        .with_mouse_capture()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, 3), ans);
+}
+
+#[test]
+fn clicks_outside_of_options_are_ignored() {
+    let mut backend = fake_backend(vec![
+        mouse(MouseEventKind::LeftClick, 0),
+        mouse(MouseEventKind::LeftClick, 20),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let options = vec![1, 2, 3, 4];
+
+    let ans = Select::new("Question", options)
+        .with_mouse_capture()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, 1), ans);
+}
+
+#[test]
+fn clicks_map_to_options_of_current_page() {
+    let mut backend = fake_backend(vec![
+        mouse(MouseEventKind::ScrollDown, 0),
+        mouse(MouseEventKind::LeftClick, 1),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let options = (1..=10).collect();
+
+    let ans = Select::new("Question", options)
+        .with_page_size(3)
+        .with_mouse_capture()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // scrolling moves the cursor to the 4th option, whose page starts at the 3rd one
+    assert_eq!(ListOption::new(2, 3), ans);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Plan {
+    Free,
+    Pro,
+}
+
+impl crate::InquireEnumVariants for Plan {
+    fn variants() -> Vec<Self> {
+        vec![Plan::Free, Plan::Pro]
+    }
+
+    fn help(&self) -> Option<&'static str> {
+        match self {
+            Plan::Free => None,
+            Plan::Pro => Some("Unlimited projects"),
+        }
+    }
+}
+
+impl std::fmt::Display for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[test]
+fn from_enum_returns_selected_variant() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let ans = Select::<Plan>::from_enum("Plan")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, Plan::Pro), ans);
+}
+
+#[test]
+fn from_enum_filters_variants_by_display_name() {
+    let mut backend = fake_backend(vec![
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let ans = Select::<Plan>::from_enum("Plan")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(Plan::Pro, ans.value);
+}
+
+#[test]
+fn from_enum_uses_variant_help_as_option_help() {
+    let prompt = Select::<Plan>::from_enum("Plan");
+    let option_help = prompt.option_help.unwrap();
+
+    assert_eq!(None, option_help(&Plan::Free));
+    assert_eq!(Some("Unlimited projects"), option_help(&Plan::Pro));
+}
+
+#[cfg(feature = "strum")]
+#[test]
+fn from_enum_supports_strum_variant_arrays() {
+    #[derive(Clone, Copy, Debug, PartialEq, strum::VariantArray, strum::Display)]
+    enum Size {
+        Small,
+        Large,
+    }
+
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ]);
+
+    let ans = Select::<Size>::from_enum("Size")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(Size::Large, ans.value);
+}
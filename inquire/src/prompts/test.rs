@@ -15,9 +15,36 @@ where
             None => panic!("EOF"),
         }
     }
+
+    /// Running out of keys is treated as the user not pressing anything.
+    fn read_key_timeout(
+        &mut self,
+        _timeout: std::time::Duration,
+    ) -> crate::error::InquireResult<Option<Key>> {
+        Ok(self.next())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T> crate::ui::AsyncInputReader for T
+where
+    T: Iterator<Item = Key>,
+{
+    fn poll_read_key(
+        &mut self,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<crate::error::InquireResult<Key>> {
+        match self.next() {
+            Some(key) => std::task::Poll::Ready(Ok(key)),
+            None => std::task::Poll::Pending,
+        }
+    }
 }
 
-pub fn fake_backend(input: Vec<Key>) -> Backend<'static, impl InputReader, CrosstermTerminal> {
+/// Backend reading keys from a list and rendering to memory.
+pub type TestBackend = Backend<'static, std::vec::IntoIter<Key>, CrosstermTerminal>;
+
+pub fn fake_backend(input: Vec<Key>) -> TestBackend {
     let output = CrosstermTerminal::new_in_memory_output();
     Backend::new(input.into_iter(), output, RenderConfig::default()).unwrap()
 }
@@ -1,12 +1,14 @@
 use crate::{
-    ui::{Key, KeyModifiers},
+    input::action::normalize_newlines,
+    keymap::{Command, KeyScope},
+    ui::Key,
     InnerAction, InputAction,
 };
 
 use super::config::TextConfig;
 
 /// Set of actions for a TextPrompt.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[allow(clippy::enum_variant_names)]
 pub enum TextPromptAction {
     /// Action on the value text input handler.
@@ -21,31 +23,63 @@ pub enum TextPromptAction {
     MoveToSuggestionPageDown,
     /// When a suggestion list exists, autocompletes the text input with the current suggestion.
     UseCurrentSuggestion,
+    /// Accepts the next word of the inline completion. Without one, moves
+    /// the cursor one word to the right.
+    AcceptCompletionWord,
+    /// Replaces the input with the previous entry of the history. When a
+    /// suggestion list exists, moves the cursor to the option above instead.
+    PreviousHistoryEntry,
+    /// Replaces the input with the next entry of the history. When a
+    /// suggestion list exists, moves the cursor to the option below instead.
+    NextHistoryEntry,
+    /// Starts or continues a reverse search through the history.
+    SearchHistory,
 }
 
 impl InnerAction for TextPromptAction {
     type Config = TextConfig;
 
-    fn from_key(key: Key, _config: &TextConfig) -> Option<Self> {
-        let action = match key {
-            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => {
-                Self::MoveToSuggestionAbove
-            }
-            Key::PageUp(_) => Self::MoveToSuggestionPageUp,
+    fn key_scopes(config: &TextConfig) -> &'static [KeyScope] {
+        match (config.multiline, config.history) {
+            (true, true) => &[KeyScope::Multiline, KeyScope::History, KeyScope::Input],
+            (true, false) => &[KeyScope::Multiline, KeyScope::Input],
+            (false, true) => &[KeyScope::History, KeyScope::Text, KeyScope::Input],
+            (false, false) => &[KeyScope::Text, KeyScope::Input],
+        }
+    }
 
-            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => {
-                Self::MoveToSuggestionBelow
+    fn from_command(command: Command, config: &TextConfig) -> Option<Self> {
+        let action = match command {
+            Command::PreviousHistory if config.history => Self::PreviousHistoryEntry,
+            Command::NextHistory if config.history => Self::NextHistoryEntry,
+            Command::ReverseSearchHistory if config.history => Self::SearchHistory,
+
+            command if config.multiline => {
+                Self::ValueInput(InputAction::from_multiline_command(command)?)
             }
-            Key::PageDown(_) => Self::MoveToSuggestionPageDown,
 
-            Key::Tab => Self::UseCurrentSuggestion,
+            Command::MoveUp => Self::MoveToSuggestionAbove,
+            Command::PageUp => Self::MoveToSuggestionPageUp,
+
+            Command::MoveDown => Self::MoveToSuggestionBelow,
+            Command::PageDown => Self::MoveToSuggestionPageDown,
+
+            Command::UseSuggestion => Self::UseCurrentSuggestion,
+            Command::AcceptCompletionWord => Self::AcceptCompletionWord,
 
-            key => match InputAction::from_key(key, &()) {
-                Some(action) => Self::ValueInput(action),
-                None => return None,
-            },
+            command => Self::ValueInput(InputAction::from_command(command, &())?),
         };
 
         Some(action)
     }
+
+    fn from_unbound_key(key: Key, config: &TextConfig) -> Option<Self> {
+        let action = match key {
+            Key::Paste(text) if config.multiline => InputAction::Insert(normalize_newlines(&text)),
+            Key::Paste(text) => InputAction::Insert(config.paste_newline_policy.apply(&text)?),
+            key => InputAction::from_unbound_key(key, &())?,
+        };
+
+        Some(Self::ValueInput(action))
+    }
 }
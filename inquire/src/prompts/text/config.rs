@@ -1,16 +1,28 @@
-use crate::Text;
+use crate::{NewlinePolicy, Text};
 
 /// Configuration settings used in the execution of a TextPrompt.
 #[derive(Copy, Clone, Debug)]
 pub struct TextConfig {
     /// Page size of the suggestion list, if it exists.
     pub page_size: usize,
+    /// Whether the page size is computed from the terminal height.
+    pub auto_page_size: bool,
+    /// How line breaks in pasted text are handled.
+    pub paste_newline_policy: NewlinePolicy,
+    /// Whether the input spans multiple lines.
+    pub multiline: bool,
+    /// Whether the prompt has a history.
+    pub history: bool,
 }
 
 impl From<&Text<'_>> for TextConfig {
     fn from(value: &Text<'_>) -> Self {
         Self {
             page_size: value.page_size,
+            auto_page_size: value.auto_page_size,
+            paste_newline_policy: value.paste_newline_policy,
+            multiline: value.multiline,
+            history: value.history.is_some(),
         }
     }
 }
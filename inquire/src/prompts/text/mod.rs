@@ -8,19 +8,27 @@ mod test;
 pub use action::*;
 
 use crate::{
-    autocompletion::Autocomplete,
-    config::get_configuration,
+    autocompletion::{Autocomplete, TokenAutocomplete},
+    config::{get_accessible_mode, get_configuration, get_key_map, get_vim_mode},
     error::{InquireError, InquireResult},
     formatter::{StringFormatter, DEFAULT_STRING_FORMATTER},
+    history::History,
+    keymap::KeyMap,
+    prompts::accessible,
     prompts::prompt::Prompt,
+    prompts::Timeout,
     terminal::get_default_terminal,
-    ui::{Backend, RenderConfig, TextBackend},
+    ui::{Backend, Output, RenderConfig, TextBackend},
     validator::StringValidator,
+    NewlinePolicy,
 };
+#[cfg(feature = "async")]
+use crate::{prompts::prompt::prompt_async, terminal::get_default_async_terminal};
 
 use self::prompt::TextPrompt;
 
 const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete, enter to submit";
+pub(crate) const DEFAULT_HELP_MESSAGE_MULTILINE: &str = "enter for a new line, ctrl+d to submit";
 
 /// Standard text prompt that returns the user string input.
 ///
@@ -61,6 +69,10 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 ///
 /// Every time `get_suggestions` is called, the method returns the list of paths that match the user input. When the user presses the autocompletion hotkey, the `FilePathCompleter` checks whether there is any path selected from the list, if there is, it decides to replace the current text input for it. The interesting piece of functionality is that if there isn't a path selected from the list, the `FilePathCompleter` calculates the longest common prefix amongst all scanned paths and updates the text input to an unambiguous new value. Similar to how terminals work when traversing paths.
 ///
+/// While the cursor is at the end of the input, the completion the autocompleter would make, or else the newest entry of the history starting with the input, is displayed inline after the cursor with the [`ghost_text`](crate::ui::RenderConfig::ghost_text) style sheet. The right arrow or End accepts it, and Alt+Right accepts its next word.
+///
+/// To complete only a part of the input, such as the word under the cursor, call `with_token_autocomplete()` with a value that implements the `TokenAutocomplete` trait instead. Its `suggest` method receives the input and the position of the cursor, and returns `Suggestion`s replacing a span of the input, which may have descriptions displayed in a second column and their own styles.
+///
 /// # Example
 ///
 /// ```no_run
@@ -100,6 +112,10 @@ pub struct Text<'a> {
     /// Autocompleter responsible for handling suggestions and input completions.
     pub autocompleter: Option<Box<dyn Autocomplete>>,
 
+    /// Autocompleter aware of the position of the cursor, used instead of
+    /// [`autocompleter`](Self::autocompleter) when set.
+    pub token_autocompleter: Option<Box<dyn TokenAutocomplete>>,
+
     /// Collection of validators to apply to the user input.
     ///
     /// Validators are executed in the order they are stored, stopping at and displaying to the user
@@ -111,6 +127,38 @@ pub struct Text<'a> {
     /// Page size of the suggestions displayed to the user, when applicable.
     pub page_size: usize,
 
+    /// Whether the page size is computed from the terminal height, in which
+    /// case `page_size` is ignored and the pages are recomputed on resizes.
+    pub auto_page_size: bool,
+
+    /// How line breaks in text pasted by the user are handled, as typing
+    /// one would submit the prompt.
+    ///
+    /// Ignored in multi-line mode, where pasted line breaks are kept.
+    pub paste_newline_policy: NewlinePolicy,
+
+    /// Whether the input spans multiple lines, in which case Enter inserts a
+    /// line break and Ctrl+D submits the prompt.
+    ///
+    /// Suggestions of the autocompleter are not displayed in multi-line mode.
+    pub multiline: bool,
+
+    /// History of previous answers, recalled with the arrow keys or searched
+    /// with Ctrl+R. The answer is added to it when the prompt is submitted.
+    pub history: Option<History>,
+
+    /// Whether the text input has vim-style modal editing. It starts in
+    /// insert mode, ESC switching to normal mode, where keys are vim commands
+    /// and ESC cancels the prompt.
+    ///
+    /// Defaults to the global vim mode, see [set_global_vim_mode](crate::set_global_vim_mode).
+    pub vim_mode: bool,
+
+    /// Key bindings used to parse the user's key presses into actions.
+    ///
+    /// Defaults to the global key map, see [set_global_key_map](crate::set_global_key_map).
+    pub key_map: KeyMap,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -120,6 +168,14 @@ pub struct Text<'a> {
     /// config is treated as the only source of truth. If you want to customize colors
     /// and still support NO_COLOR, you will have to do this on your end.
     pub render_config: RenderConfig<'a>,
+
+    /// Destination where the prompt is rendered, stderr by default.
+    ///
+    /// Keys are read from the terminal regardless of the output.
+    pub output: Output,
+
+    /// Time limit for the user to answer the prompt, if any.
+    pub timeout: Option<Timeout>,
 }
 
 impl<'a> Text<'a> {
@@ -146,8 +202,17 @@ impl<'a> Text<'a> {
             validators: Self::DEFAULT_VALIDATORS,
             formatter: Self::DEFAULT_FORMATTER,
             page_size: Self::DEFAULT_PAGE_SIZE,
+            auto_page_size: false,
             autocompleter: None,
+            token_autocompleter: None,
+            paste_newline_policy: NewlinePolicy::DEFAULT,
+            multiline: false,
+            history: None,
+            vim_mode: get_vim_mode(),
+            key_map: get_key_map(),
             render_config: get_configuration(),
+            output: Output::default(),
+            timeout: None,
         }
     }
 
@@ -185,6 +250,19 @@ impl<'a> Text<'a> {
         AC: Autocomplete + 'static,
     {
         self.autocompleter = Some(Box::new(ac));
+        self.token_autocompleter = None;
+        self
+    }
+
+    /// Sets a new autocompleter aware of the position of the cursor, whose
+    /// suggestions replace a part of the input, such as the word under the
+    /// cursor, and may have descriptions displayed next to them.
+    pub fn with_token_autocomplete<AC>(mut self, ac: AC) -> Self
+    where
+        AC: TokenAutocomplete + 'static,
+    {
+        self.token_autocompleter = Some(Box::new(ac));
+        self.autocompleter = None;
         self
     }
 
@@ -200,6 +278,13 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets the page size to fill the available height of the terminal,
+    /// adapting whenever the terminal is resized.
+    pub fn with_auto_page_size(mut self) -> Self {
+        self.auto_page_size = true;
+        self
+    }
+
     /// Adds a validator to the collection of validators. You might want to use this feature
     /// in case you need to require certain features from the user's answer, such as
     /// defining a limit of characters.
@@ -232,6 +317,76 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets how line breaks in text pasted by the user are handled.
+    ///
+    /// Defaults to [`NewlinePolicy::Strip`].
+    pub fn with_paste_newline_policy(mut self, paste_newline_policy: NewlinePolicy) -> Self {
+        self.paste_newline_policy = paste_newline_policy;
+        self
+    }
+
+    /// Lets the user type an answer spanning multiple lines.
+    ///
+    /// Enter and Alt+Enter insert a line break, the arrow keys move the
+    /// cursor across lines and Ctrl+D submits the prompt. These bindings can
+    /// be changed in the [`KeyScope::Multiline`](crate::keymap::KeyScope::Multiline)
+    /// scope of the key map.
+    ///
+    /// Suggestions of the autocompleter are not displayed in multi-line mode.
+    pub fn with_multiline(mut self) -> Self {
+        self.multiline = true;
+        self
+    }
+
+    /// Sets the history of previous answers, which the user can go through
+    /// with the up and down arrows, or search with Ctrl+R. The answer is
+    /// added to the history when the prompt is successfully submitted.
+    ///
+    /// When suggestions are displayed, the arrows move through them instead.
+    /// In multi-line mode, the arrows move the cursor across lines and only
+    /// the search is available. These bindings can be changed in the
+    /// [`KeyScope::History`](crate::keymap::KeyScope::History) scope of the
+    /// key map.
+    pub fn with_history(mut self, history: History) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// Enables or disables vim-style modal editing of the text input.
+    ///
+    /// The input starts in insert mode, where keys are typed as usual. ESC
+    /// switches to normal mode, shown by the
+    /// [`vim_normal_prompt_prefix`](crate::ui::RenderConfig::vim_normal_prompt_prefix),
+    /// where `h`, `l`, `w`, `b`, `e`, `0` and `$` move the cursor, `x` deletes
+    /// a character, `d` and `c` followed by a motion delete or change text,
+    /// `dd` and `cc` the whole line, `p` pastes the deleted text and `u`
+    /// undoes the last edit. `i`, `a`, `I` and `A` go back to insert mode. A
+    /// second ESC in normal mode cancels the prompt.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets a time limit for the user to answer the prompt.
+    ///
+    /// Accepts either a [`Duration`](std::time::Duration), in which case the
+    /// prompt returns `Err(InquireError::Timeout)` on expiry, or a [`Timeout`]
+    /// describing what happens on expiry and whether a countdown is displayed.
+    pub fn with_timeout<D>(mut self, timeout: D) -> Self
+    where
+        D: Into<Timeout>,
+    {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// Sets the key bindings used to parse the user's key presses into actions,
+    /// overriding the global key map.
+    pub fn with_key_map(mut self, key_map: KeyMap) -> Self {
+        self.key_map = key_map;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -245,6 +400,13 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets the destination where the prompt is rendered, such as
+    /// [`Output::Stderr`] to keep stdout free for the program's output.
+    pub fn with_output(mut self, output: Output) -> Self {
+        self.output = output;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -265,11 +427,41 @@ impl<'a> Text<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<String> {
-        let (input_reader, terminal) = get_default_terminal()?;
+        if get_accessible_mode() {
+            return accessible::prompt(self);
+        }
+
+        let (input_reader, terminal) = get_default_terminal(&self.output)?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Asynchronous version of [`prompt`](Self::prompt), which does not block
+    /// the executor while waiting for the user's input.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<String> {
+        self.prompt_async_with_cancellation(std::future::pending())
+            .await
+    }
+
+    /// Asynchronous version of [`prompt`](Self::prompt) that can be aborted
+    /// by the application, e.g. by passing `token.cancelled()` of a tokio
+    /// `CancellationToken`.
+    ///
+    /// When `cancellation` resolves before the user submits an answer, the
+    /// prompt is torn down and `Err(InquireError::OperationAborted)` is returned.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async_with_cancellation<C>(self, cancellation: C) -> InquireResult<String>
+    where
+        C: std::future::Future<Output = ()>,
+    {
+        let (input_reader, terminal) = get_default_async_terminal(&self.output)?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        prompt_async(TextPrompt::from(self), &mut backend, cancellation).await
+    }
+
     pub(crate) fn prompt_with_backend<B: TextBackend>(
         self,
         backend: &mut B,
@@ -1,32 +1,51 @@
-use std::cmp::min;
+use std::{borrow::Cow, cmp::min};
+
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    autocompletion::{NoAutoCompletion, Replacement},
+    autocompletion::{AutocompleteAdapter, NoAutoCompletion, Suggestion, TokenAutocomplete},
     error::InquireResult,
     formatter::StringFormatter,
-    input::{Input, InputActionResult},
+    history::HistoryNavigator,
+    input::{Input, InputActionResult, LineDirection, Magnitude, Vim},
+    keymap::KeyMap,
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        Timeout,
+    },
+    terminal::TerminalSize,
     ui::TextBackend,
-    utils::paginate,
+    utils::{auto_page_size, paginate},
     validator::{ErrorMessage, StringValidator, Validation},
-    Autocomplete, InquireError, Text,
+    InputAction, InquireError, Text,
 };
 
-use super::{action::TextPromptAction, config::TextConfig, DEFAULT_HELP_MESSAGE_WITH_AC};
+use super::{
+    action::TextPromptAction, config::TextConfig, DEFAULT_HELP_MESSAGE_MULTILINE,
+    DEFAULT_HELP_MESSAGE_WITH_AC,
+};
 
 pub struct TextPrompt<'a> {
     message: &'a str,
     config: TextConfig,
+    key_map: KeyMap,
     default: Option<&'a str>,
     help_message: Option<&'a str>,
     input: Input,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
     error: Option<ErrorMessage>,
-    autocompleter: Box<dyn Autocomplete>,
-    suggested_options: Vec<String>,
+    autocompleter: Box<dyn TokenAutocomplete>,
+    /// Whether suggestions depend on the position of the cursor, which is
+    /// only the case for token-aware autocompleters.
+    cursor_aware_suggestions: bool,
+    suggested_options: Vec<Suggestion>,
     suggestion_cursor_index: Option<usize>,
+    ghost: Option<String>,
+    history: Option<HistoryNavigator>,
+    vim: Option<Vim>,
+    timeout: Option<Timeout>,
 }
 
 impl<'a> From<Text<'a>> for TextPrompt<'a> {
@@ -38,20 +57,32 @@ impl<'a> From<Text<'a>> for TextPrompt<'a> {
             input
         };
 
+        let config = (&so).into();
+        let cursor_aware_suggestions = so.token_autocompleter.is_some();
+        let autocompleter = match (so.token_autocompleter, so.autocompleter) {
+            (Some(autocompleter), _) => autocompleter,
+            (None, Some(autocompleter)) => Box::new(AutocompleteAdapter::from(autocompleter)),
+            (None, None) => Box::new(AutocompleteAdapter::new(NoAutoCompletion)),
+        };
+
         Self {
             message: so.message,
-            config: (&so).into(),
+            config,
+            key_map: so.key_map,
             default: so.default,
             help_message: so.help_message,
             formatter: so.formatter,
-            autocompleter: so
-                .autocompleter
-                .unwrap_or_else(|| Box::<NoAutoCompletion>::default()),
+            autocompleter,
+            cursor_aware_suggestions,
             input,
             error: None,
             suggestion_cursor_index: None,
             suggested_options: vec![],
+            ghost: None,
+            history: so.history.map(HistoryNavigator::new),
+            vim: so.vim_mode.then(Vim::new),
             validators: so.validators,
+            timeout: so.timeout,
         }
     }
 }
@@ -64,19 +95,21 @@ impl<'a> From<&'a str> for Text<'a> {
 
 impl<'a> TextPrompt<'a> {
     fn update_suggestions(&mut self) -> InquireResult<()> {
-        self.suggested_options = self.autocompleter.get_suggestions(self.input.content())?;
+        if self.config.multiline {
+            return Ok(());
+        }
+
+        self.suggested_options = self
+            .autocompleter
+            .suggest(self.input.content(), self.input.pre_cursor().len())?;
         self.suggestion_cursor_index = None;
 
         Ok(())
     }
 
-    fn get_highlighted_suggestion(&self) -> Option<&str> {
-        if let Some(cursor) = self.suggestion_cursor_index {
-            let suggestion = self.suggested_options.get(cursor).unwrap().as_ref();
-            Some(suggestion)
-        } else {
-            None
-        }
+    fn get_highlighted_suggestion(&self) -> Option<&Suggestion> {
+        self.suggestion_cursor_index
+            .map(|cursor| self.suggested_options.get(cursor).unwrap())
     }
 
     fn move_cursor_up(&mut self, qty: usize) -> ActionResult {
@@ -118,39 +151,147 @@ impl<'a> TextPrompt<'a> {
     }
 
     fn use_current_suggestion(&mut self) -> InquireResult<ActionResult> {
-        let suggestion = self.get_highlighted_suggestion().map(|s| s.to_owned());
-        match self
-            .autocompleter
-            .get_completion(self.input.content(), suggestion)?
-        {
-            Replacement::Some(value) => {
-                self.input = Input::new_with(value);
+        let suggestion = self.get_highlighted_suggestion().cloned();
+        let completion = self.autocompleter.complete(
+            self.input.content(),
+            self.input.pre_cursor().len(),
+            suggestion.as_ref(),
+        )?;
+
+        match completion {
+            Some(completion) => {
+                let (value, cursor) = completion.apply(self.input.content());
+                let cursor = value[..cursor].graphemes(true).count();
+                self.input = Input::new_with(value).with_cursor(cursor);
+                self.update_suggestions()?;
                 Ok(ActionResult::NeedsRedraw)
             }
-            Replacement::None => Ok(ActionResult::Clean),
+            None => Ok(ActionResult::Clean),
+        }
+    }
+
+    /// Updates the inline completion displayed after the cursor when it is at
+    /// the end of the input, taken from the autocompleter or, failing that,
+    /// from the history.
+    fn update_ghost(&mut self) -> InquireResult<()> {
+        self.ghost = None;
+
+        let searching = matches!(&self.history, Some(history) if history.search_query().is_some());
+        if self.config.multiline
+            || searching
+            || self.input.is_empty()
+            || self.input.cursor() != self.input.length()
+        {
+            return Ok(());
+        }
+
+        let suggestion = self.get_highlighted_suggestion().cloned();
+        let input = self.input.content();
+
+        let completion = self
+            .autocompleter
+            .complete(input, input.len(), suggestion.as_ref())?
+            .and_then(|completion| {
+                let (value, _) = completion.apply(input);
+                value
+                    .strip_prefix(input)
+                    .filter(|rest| !rest.is_empty())
+                    .map(String::from)
+            });
+
+        self.ghost = match (completion, &self.history) {
+            (Some(completion), _) => Some(completion),
+            (None, Some(history)) => history.completion(input).map(String::from),
+            (None, None) => None,
+        };
+
+        Ok(())
+    }
+
+    /// Appends the inline completion, or only its next word, to the input.
+    fn accept_ghost(&mut self, magnitude: Magnitude) -> InquireResult<ActionResult> {
+        let Some(ghost) = self.ghost.take() else {
+            return Ok(ActionResult::Clean);
+        };
+
+        let accepted = match magnitude {
+            Magnitude::Word => {
+                let mut rest = Input::new_with(ghost).with_cursor(0);
+                rest.handle(InputAction::MoveCursor(
+                    Magnitude::Word,
+                    LineDirection::Right,
+                ));
+                rest.pre_cursor().to_owned()
+            }
+            Magnitude::Char | Magnitude::Line => ghost,
+        };
+
+        self.input.handle(InputAction::Insert(accepted));
+        self.update_suggestions()?;
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    fn handle_input(&mut self, input_action: InputAction) -> InquireResult<ActionResult> {
+        let result = match self.vim.as_mut() {
+            Some(vim) => vim.handle(input_action, &mut self.input),
+            None => self.input.handle(input_action),
+        };
+
+        let outdated_suggestions = match result {
+            InputActionResult::ContentChanged => true,
+            InputActionResult::PositionChanged => self.cursor_aware_suggestions,
+            InputActionResult::Clean => false,
+        };
+        if outdated_suggestions {
+            self.update_suggestions()?;
         }
+
+        Ok(result.into())
     }
 
-    fn get_current_answer(&self) -> &str {
+    fn navigate_history(
+        &mut self,
+        navigate: fn(&mut HistoryNavigator, &mut Input) -> ActionResult,
+    ) -> ActionResult {
+        let Some(history) = self.history.as_mut() else {
+            return ActionResult::Clean;
+        };
+
+        let result = navigate(history, &mut self.input);
+
+        // suggestions are refreshed on the next edit, so that the arrows keep
+        // going through the history meanwhile.
+        if result.needs_redraw() {
+            self.suggested_options.clear();
+            self.suggestion_cursor_index = None;
+        }
+
+        result
+    }
+
+    fn get_current_answer(&self) -> Cow<'_, str> {
         // If there is a highlighted suggestion, assume user wanted it as
         // the answer.
         if let Some(suggestion) = self.get_highlighted_suggestion() {
-            return suggestion;
+            return Cow::Owned(suggestion.apply(self.input.content()).0);
         }
 
         // Empty input with default values override any validators.
         if self.input.content().is_empty() {
             if let Some(val) = self.default {
-                return val;
+                return Cow::Borrowed(val);
             }
         }
 
-        self.input.content()
+        Cow::Borrowed(self.input.content())
     }
 
     fn validate_current_answer(&self) -> InquireResult<Validation> {
+        let answer = self.get_current_answer();
+
         for validator in &self.validators {
-            match validator.validate(self.get_current_answer()) {
+            match validator.validate(&answer) {
                 Ok(Validation::Valid) => {}
                 Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
                 Err(err) => return Err(InquireError::Custom(err)),
@@ -177,37 +318,83 @@ where
         &self.config
     }
 
+    fn key_map(&self) -> &KeyMap {
+        &self.key_map
+    }
+
     fn format_answer(&self, answer: &String) -> String {
         (self.formatter)(answer)
     }
 
     fn setup(&mut self) -> InquireResult<()> {
-        self.update_suggestions()
+        self.update_suggestions()?;
+        self.update_ghost()
+    }
+
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        let canceled_search = match self.history.as_mut() {
+            Some(history) => history.cancel_search(&mut self.input),
+            None => false,
+        };
+
+        let consumed = canceled_search
+            || match self.vim.as_mut() {
+                Some(vim) => vim.escape(&mut self.input),
+                None => false,
+            };
+
+        if consumed {
+            self.update_ghost()?;
+        }
+
+        Ok(!consumed)
     }
 
     fn submit(&mut self) -> InquireResult<Option<String>> {
+        if let Some(history) = self.history.as_mut() {
+            history.accept_search();
+        }
+
         let result = match self.validate_current_answer()? {
-            Validation::Valid => Some(self.get_current_answer().to_owned()),
+            Validation::Valid => Some(self.get_current_answer().into_owned()),
             Validation::Invalid(msg) => {
                 self.error = Some(msg);
                 None
             }
         };
 
+        if let (Some(answer), Some(history)) = (&result, &self.history) {
+            history.record(answer);
+        }
+
         Ok(result)
     }
 
-    fn handle(&mut self, action: TextPromptAction) -> InquireResult<ActionResult> {
-        let result = match action {
-            TextPromptAction::ValueInput(input_action) => {
-                let result = self.input.handle(input_action);
+    fn force_submit(&mut self) -> InquireResult<Option<String>> {
+        self.suggestion_cursor_index = None;
+        Prompt::<Backend>::submit(self)
+    }
 
-                if let InputActionResult::ContentChanged = result {
-                    self.update_suggestions()?;
+    fn handle(&mut self, action: TextPromptAction) -> InquireResult<ActionResult> {
+        if let Some(history) = self.history.as_mut() {
+            match &action {
+                TextPromptAction::ValueInput(input_action) => {
+                    if let Some(result) = history.handle_search_input(input_action, &mut self.input)
+                    {
+                        return Ok(result);
+                    }
                 }
-
-                result.into()
+                TextPromptAction::SearchHistory => {}
+                _ => history.accept_search(),
             }
+        }
+
+        let result = match action {
+            TextPromptAction::ValueInput(InputAction::MoveCursor(
+                Magnitude::Char | Magnitude::Line,
+                LineDirection::Right,
+            )) if self.ghost.is_some() => self.accept_ghost(Magnitude::Line)?,
+            TextPromptAction::ValueInput(input_action) => self.handle_input(input_action)?,
             TextPromptAction::MoveToSuggestionAbove => self.move_cursor_up(1),
             TextPromptAction::MoveToSuggestionBelow => self.move_cursor_down(1),
             TextPromptAction::MoveToSuggestionPageUp => self.move_cursor_up(self.config.page_size),
@@ -215,11 +402,46 @@ where
                 self.move_cursor_down(self.config.page_size)
             }
             TextPromptAction::UseCurrentSuggestion => self.use_current_suggestion()?,
+            TextPromptAction::AcceptCompletionWord if self.ghost.is_some() => {
+                self.accept_ghost(Magnitude::Word)?
+            }
+            TextPromptAction::AcceptCompletionWord => self.handle_input(
+                InputAction::MoveCursor(Magnitude::Word, LineDirection::Right),
+            )?,
+            TextPromptAction::PreviousHistoryEntry if !self.suggested_options.is_empty() => {
+                self.move_cursor_up(1)
+            }
+            TextPromptAction::NextHistoryEntry if !self.suggested_options.is_empty() => {
+                self.move_cursor_down(1)
+            }
+            TextPromptAction::PreviousHistoryEntry => {
+                self.navigate_history(HistoryNavigator::previous)
+            }
+            TextPromptAction::NextHistoryEntry => self.navigate_history(HistoryNavigator::next),
+            TextPromptAction::SearchHistory => self.navigate_history(HistoryNavigator::search),
         };
 
+        if result.needs_redraw() {
+            self.update_ghost()?;
+        }
+
         Ok(result)
     }
 
+    fn timeout(&self) -> Option<Timeout> {
+        self.timeout
+    }
+
+    fn default_answer(&mut self) -> Option<String> {
+        self.default.map(String::from)
+    }
+
+    fn resize(&mut self, terminal_size: TerminalSize) {
+        if self.config.auto_page_size {
+            self.config.page_size = auto_page_size(terminal_size.height());
+        }
+    }
+
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
@@ -227,14 +449,24 @@ where
             backend.render_error_message(err)?;
         }
 
-        backend.render_prompt(prompt, self.default, &self.input)?;
+        backend.set_vim_normal_mode(matches!(&self.vim, Some(vim) if vim.is_normal()));
+        match &self.ghost {
+            Some(ghost) => {
+                backend.render_prompt_with_ghost(prompt, self.default, &self.input, ghost)?;
+            }
+            None => backend.render_prompt(prompt, self.default, &self.input)?,
+        }
+
+        if let Some((query, found)) = self.history.as_ref().and_then(|h| h.search_query()) {
+            backend.render_history_search(query, found)?;
+        }
 
         let choices = self
             .suggested_options
             .iter()
             .enumerate()
-            .map(|(i, val)| ListOption::new(i, val.as_ref()))
-            .collect::<Vec<ListOption<&str>>>();
+            .map(|(i, val)| ListOption::new(i, val))
+            .collect::<Vec<ListOption<&Suggestion>>>();
 
         let page = paginate(
             self.config.page_size,
@@ -246,6 +478,8 @@ where
 
         if let Some(message) = self.help_message {
             backend.render_help_message(message)?;
+        } else if self.config.multiline {
+            backend.render_help_message(DEFAULT_HELP_MESSAGE_MULTILINE)?;
         } else if !choices.is_empty() {
             backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_AC)?;
         }
@@ -1,6 +1,7 @@
 use super::Text;
 use crate::ui::{Key, KeyModifiers};
 use crate::validator::{ErrorMessage, Validation};
+use crate::NewlinePolicy;
 
 fn default<'a>() -> Text<'a> {
     Text::new("Question?")
@@ -32,11 +33,14 @@ macro_rules! text_test {
     };
 }
 
-text_test!(empty, vec![Key::Enter], "");
+text_test!(empty, vec![Key::Enter(KeyModifiers::NONE)], "");
 
 text_test!(
     single_letter,
-    vec![Key::Char('b', KeyModifiers::NONE), Key::Enter],
+    vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE)
+    ],
     "b"
 );
 
@@ -57,12 +61,12 @@ text_test!(
     {
         let mut events = vec![];
         events.append(&mut text_to_events!("anor"));
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
         events.append(&mut text_to_events!("normal input"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events
     },
     "normal input"
@@ -73,18 +77,18 @@ text_test!(
     {
         let mut events = vec![];
         events.append(&mut text_to_events!("anor"));
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
         events.append(&mut text_to_events!("normal input"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events
     },
     "normal input"
@@ -95,14 +99,14 @@ text_test!(
     {
         let mut events = vec![];
         events.append(&mut text_to_events!("1234567890"));
-        events.push(Key::Enter);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
-        events.push(Key::Backspace);
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
+        events.push(Key::Backspace(KeyModifiers::NONE));
         events.append(&mut text_to_events!("yes"));
-        events.push(Key::Enter);
+        events.push(Key::Enter(KeyModifiers::NONE));
         events
     },
     "12345yes",
@@ -111,3 +115,503 @@ text_test!(
         _ => Ok(Validation::Invalid(ErrorMessage::Default)),
     })
 );
+
+text_test!(
+    paste_does_not_submit_prompt,
+    vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Paste(String::from("b\nc\n")),
+        Key::Enter(KeyModifiers::NONE),
+    ],
+    "abc"
+);
+
+text_test!(
+    paste_with_replaced_newlines,
+    vec![
+        Key::Paste(String::from("first\nsecond")),
+        Key::Enter(KeyModifiers::NONE)
+    ],
+    "first,second",
+    Text::new("").with_paste_newline_policy(NewlinePolicy::Replace(','))
+);
+
+text_test!(
+    paste_with_newlines_is_rejected,
+    vec![
+        Key::Paste(String::from("first\nsecond")),
+        Key::Paste(String::from("third")),
+        Key::Enter(KeyModifiers::NONE),
+    ],
+    "third",
+    Text::new("").with_paste_newline_policy(NewlinePolicy::Reject)
+);
+
+text_test!(
+    ctrl_d_is_typed_when_no_longer_bound_to_cancel,
+    vec![
+        Key::Char('d', KeyModifiers::CONTROL),
+        Key::Char('j', KeyModifiers::CONTROL),
+    ],
+    "d",
+    default().with_key_map("cancel = esc".parse().unwrap())
+);
+
+text_test!(
+    key_map_rebinds_submit,
+    vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+        Key::Char('s', KeyModifiers::CONTROL),
+    ],
+    "a",
+    default().with_key_map("[text]\nsubmit = ctrl+s\n[all]\nsubmit =".parse().unwrap())
+);
+
+text_test!(
+    ctrl_enter_submits_input_ignoring_highlighted_suggestion,
+    {
+        let mut events = text_to_events!("ap");
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Enter(KeyModifiers::CONTROL));
+        events
+    },
+    "ap",
+    default().with_autocomplete(&|_: &str| Ok(vec![String::from("apple")]))
+);
+
+text_test!(
+    cursor_moves_keep_the_highlighted_suggestion,
+    {
+        let mut events = text_to_events!("ap");
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Left(KeyModifiers::NONE));
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events
+    },
+    "apple",
+    default().with_autocomplete(&|_: &str| Ok(vec![String::from("apple")]))
+);
+
+#[cfg(feature = "async")]
+#[test]
+fn async_prompt_submits_answer() {
+    let mut events = text_to_events!("hello");
+    events.push(Key::Enter(KeyModifiers::NONE));
+
+    let mut backend = crate::prompts::test::fake_backend(events);
+    let prompt = super::prompt::TextPrompt::from(default());
+
+    let ans = futures_executor::block_on(crate::prompts::prompt::prompt_async(
+        prompt,
+        &mut backend,
+        std::future::pending(),
+    ))
+    .unwrap();
+
+    assert_eq!("hello", ans);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn async_prompt_is_aborted_by_cancellation() {
+    let mut backend = crate::prompts::test::fake_backend(text_to_events!("hel"));
+    let prompt = super::prompt::TextPrompt::from(default());
+
+    let result = futures_executor::block_on(crate::prompts::prompt::prompt_async(
+        prompt,
+        &mut backend,
+        std::future::ready(()),
+    ));
+
+    assert!(matches!(
+        result,
+        Err(crate::error::InquireError::OperationAborted)
+    ));
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn async_prompt_submits_default_when_timeout_expires() {
+    use crate::{Timeout, TimeoutBehavior};
+
+    let mut backend = crate::prompts::test::fake_backend(vec![]);
+    let prompt = super::prompt::TextPrompt::from(
+        default().with_default("hello").with_timeout(
+            Timeout::new(std::time::Duration::from_millis(50))
+                .with_behavior(TimeoutBehavior::SubmitDefault)
+                .with_countdown(true),
+        ),
+    );
+
+    let ans = futures_executor::block_on(crate::prompts::prompt::prompt_async(
+        prompt,
+        &mut backend,
+        std::future::pending(),
+    ))
+    .unwrap();
+
+    assert_eq!("hello", ans);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn async_prompt_raises_error_when_timeout_expires() {
+    let mut backend = crate::prompts::test::fake_backend(text_to_events!("hel"));
+    let prompt = super::prompt::TextPrompt::from(
+        default().with_timeout(std::time::Duration::from_millis(50)),
+    );
+
+    let result = futures_executor::block_on(crate::prompts::prompt::prompt_async(
+        prompt,
+        &mut backend,
+        std::future::pending(),
+    ));
+
+    assert!(matches!(result, Err(crate::error::InquireError::Timeout)));
+}
+
+text_test!(
+    multiline_enter_inserts_line_breaks,
+    {
+        let mut events = text_to_events!("first");
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events.append(&mut text_to_events!("second\nthird"));
+        events.push(Key::Enter(KeyModifiers::ALT));
+        events.push(Key::Char('d', KeyModifiers::CONTROL));
+        events
+    },
+    "first\nsecond\nthird\n",
+    default().with_multiline()
+);
+
+text_test!(
+    multiline_arrows_move_across_lines,
+    {
+        let mut events = text_to_events!("ab\ncde");
+        events.push(Key::Up(KeyModifiers::NONE));
+        events.append(&mut text_to_events!("!"));
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Home(KeyModifiers::NONE));
+        events.append(&mut text_to_events!(">"));
+        events.push(Key::Char('d', KeyModifiers::CONTROL));
+        events
+    },
+    "ab!\n>cde",
+    default().with_multiline()
+);
+
+text_test!(
+    multiline_paste_keeps_line_breaks,
+    vec![
+        Key::Paste(String::from("one\r\ntwo\rthree")),
+        Key::Char('d', KeyModifiers::CONTROL),
+    ],
+    "one\ntwo\nthree",
+    default()
+        .with_multiline()
+        .with_paste_newline_policy(NewlinePolicy::Reject)
+);
+
+#[test]
+fn multiline_validators_count_lines() {
+    let mut events = text_to_events!("one\ntwo\nthree");
+    events.push(Key::Char('d', KeyModifiers::CONTROL));
+    events.push(Key::Backspace(KeyModifiers::CONTROL));
+    events.push(Key::Backspace(KeyModifiers::NONE));
+    events.push(Key::Char('d', KeyModifiers::CONTROL));
+
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    // the first submission is rejected, having three lines
+    let ans = default()
+        .with_multiline()
+        .with_validator(crate::validator::MaxLinesValidator::new(2))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("one\ntwo", ans);
+}
+
+fn history() -> crate::history::History {
+    let history = crate::history::History::new();
+    for entry in ["cargo build", "git status", "cargo test"] {
+        history.push(entry).unwrap();
+    }
+    history
+}
+
+text_test!(
+    history_up_recalls_previous_entries,
+    vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter(KeyModifiers::NONE),
+    ],
+    "git status",
+    default().with_history(history())
+);
+
+text_test!(
+    history_down_restores_the_typed_input,
+    {
+        let mut events = text_to_events!("draft");
+        events.push(Key::Up(KeyModifiers::NONE));
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events
+    },
+    "draft",
+    default().with_history(history())
+);
+
+text_test!(
+    history_reverse_search,
+    {
+        let mut events = vec![Key::Char('r', KeyModifiers::CONTROL)];
+        events.append(&mut text_to_events!("cargo"));
+        events.push(Key::Char('r', KeyModifiers::CONTROL));
+        events.push(Key::End(KeyModifiers::NONE));
+        events.append(&mut text_to_events!(" --release\n"));
+        events
+    },
+    "cargo build --release",
+    default().with_history(history())
+);
+
+text_test!(
+    history_search_canceled_by_escape,
+    {
+        let mut events = text_to_events!("draft");
+        events.push(Key::Char('r', KeyModifiers::CONTROL));
+        events.append(&mut text_to_events!("git"));
+        events.push(Key::Escape(KeyModifiers::NONE));
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events
+    },
+    "draft",
+    default().with_history(history())
+);
+
+#[test]
+fn history_is_updated_on_submit_only() {
+    let history = crate::history::History::new();
+
+    let mut backend = crate::prompts::test::fake_backend(text_to_events!("first\n"));
+    default()
+        .with_history(history.clone())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let mut events = text_to_events!("second");
+    events.push(Key::Escape(KeyModifiers::NONE));
+    let mut backend = crate::prompts::test::fake_backend(events);
+    let result = default()
+        .with_history(history.clone())
+        .prompt_with_backend(&mut backend);
+
+    assert!(result.is_err());
+    assert_eq!(vec!["first"], history.entries());
+}
+
+struct FailingStorage;
+
+impl crate::history::HistoryStorage for FailingStorage {
+    fn load(&mut self) -> crate::error::InquireResult<Vec<String>> {
+        Ok(vec![])
+    }
+
+    fn store(&mut self, _entries: &[String]) -> crate::error::InquireResult<()> {
+        Err(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "read-only").into())
+    }
+}
+
+#[test]
+fn history_storage_errors_do_not_fail_the_prompt() {
+    let history = crate::history::History::with_storage(FailingStorage).unwrap();
+
+    let mut backend = crate::prompts::test::fake_backend(text_to_events!("answer\n"));
+    let ans = default()
+        .with_history(history.clone())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("answer", ans);
+    assert_eq!(vec!["answer"], history.entries());
+}
+
+text_test!(
+    vim_mode_edits_in_normal_mode,
+    {
+        let mut events = text_to_events!("hello world");
+        events.push(Key::Escape(KeyModifiers::NONE));
+        events.append(&mut text_to_events!("bcwthere"));
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events
+    },
+    "hello there",
+    default().with_vim_mode(true)
+);
+
+#[test]
+fn vim_mode_is_canceled_by_escape_in_normal_mode() {
+    let mut events = text_to_events!("draft");
+    events.push(Key::Escape(KeyModifiers::NONE));
+    events.push(Key::Escape(KeyModifiers::NONE));
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let result = default()
+        .with_vim_mode(true)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(
+        result,
+        Err(crate::InquireError::OperationCanceled)
+    ));
+}
+
+fn word_completer(
+    input: &str,
+    cursor: usize,
+) -> Result<Vec<crate::autocompletion::Suggestion>, crate::CustomUserError> {
+    let start = input[..cursor].rfind(' ').map(|i| i + 1).unwrap_or(0);
+    let end = input[cursor..]
+        .find(' ')
+        .map(|i| cursor + i)
+        .unwrap_or(input.len());
+    let word = &input[start..end];
+
+    let suggestions = ["checkout", "cherry-pick", "commit"]
+        .iter()
+        .filter(|s| !word.is_empty() && s.starts_with(word))
+        .map(|s| crate::autocompletion::Suggestion::new(*s, start..end))
+        .collect();
+
+    Ok(suggestions)
+}
+
+text_test!(
+    token_autocompletion_replaces_the_token_under_the_cursor,
+    {
+        let mut events = text_to_events!("git co -m");
+        events.append(&mut vec![Key::Left(KeyModifiers::NONE); 3]);
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Tab(KeyModifiers::NONE));
+        events.append(&mut text_to_events!(" x"));
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events
+    },
+    "git commit x -m",
+    default().with_token_autocomplete(word_completer)
+);
+
+text_test!(
+    token_autocompletion_applies_highlighted_suggestion_on_submit,
+    {
+        let mut events = text_to_events!("git ch");
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events
+    },
+    "git cherry-pick",
+    default().with_token_autocomplete(word_completer)
+);
+
+text_test!(
+    right_at_end_of_input_accepts_the_history_completion,
+    {
+        let mut events = text_to_events!("cargo");
+        events.push(Key::Right(KeyModifiers::NONE));
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events
+    },
+    "cargo test",
+    default().with_history(history())
+);
+
+text_test!(
+    end_at_end_of_input_accepts_the_history_completion,
+    {
+        let mut events = text_to_events!("git");
+        events.push(Key::End(KeyModifiers::NONE));
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events
+    },
+    "git status",
+    default().with_history(history())
+);
+
+text_test!(
+    right_moves_the_cursor_to_the_end_before_accepting_the_completion,
+    {
+        let mut events = text_to_events!("cargo");
+        events.push(Key::Left(KeyModifiers::NONE));
+        events.push(Key::Right(KeyModifiers::NONE));
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events
+    },
+    "cargo",
+    default().with_history(history())
+);
+
+text_test!(
+    alt_right_accepts_one_word_of_the_completion,
+    {
+        let mut events = text_to_events!("git");
+        events.push(Key::Right(KeyModifiers::ALT));
+        events.push(Key::Right(KeyModifiers::ALT));
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events
+    },
+    "git commit --amend",
+    {
+        let history = crate::history::History::new();
+        history.push("git commit --amend --no-edit").unwrap();
+        default().with_history(history)
+    }
+);
+
+#[derive(Clone)]
+struct GreetingCompleter;
+
+impl crate::Autocomplete for GreetingCompleter {
+    fn get_suggestions(&mut self, _: &str) -> Result<Vec<String>, crate::CustomUserError> {
+        Ok(vec![])
+    }
+
+    fn get_completion(
+        &mut self,
+        input: &str,
+        _: Option<String>,
+    ) -> Result<crate::autocompletion::Replacement, crate::CustomUserError> {
+        Ok(Some("hello world")
+            .filter(|greeting| greeting.starts_with(input))
+            .map(String::from))
+    }
+}
+
+text_test!(
+    completion_of_the_autocompleter_takes_precedence_over_history,
+    {
+        let mut events = text_to_events!("he");
+        events.push(Key::Right(KeyModifiers::NONE));
+        events.push(Key::Enter(KeyModifiers::NONE));
+        events
+    },
+    "hello world",
+    {
+        let history = crate::history::History::new();
+        history.push("help").unwrap();
+        default()
+            .with_history(history)
+            .with_autocomplete(GreetingCompleter)
+    }
+);
+
+text_test!(
+    completion_is_not_submitted_without_being_accepted,
+    text_to_events!("he\n"),
+    "he",
+    default().with_autocomplete(GreetingCompleter)
+);
@@ -0,0 +1,89 @@
+//! Definitions of the time limits that can be applied to prompts.
+
+use std::time::Duration;
+
+/// Describes what happens when a prompt is not answered before its
+/// [`Timeout`] expires.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimeoutBehavior {
+    /// The prompt is torn down and `Err(InquireError::Timeout)` is returned.
+    Error,
+
+    /// The prompt submits its default or starting value, e.g. `Confirm::default`,
+    /// `DateSelect::starting_date` or the option at `Select::starting_cursor`.
+    ///
+    /// When the prompt has no such value, it behaves just like
+    /// [`TimeoutBehavior::Error`].
+    SubmitDefault,
+}
+
+/// Time limit for a user to answer a prompt.
+///
+/// Can be created from a [`Duration`], in which case the prompt returns
+/// `Err(InquireError::Timeout)` on expiry and no countdown is displayed.
+///
+/// Timeouts are only applied by the blocking `prompt` methods and are not
+/// supported when using `console` as the terminal back-end. When using
+/// `prompt_async`, use your runtime's timer as the cancellation future instead.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use inquire::{Confirm, Timeout, TimeoutBehavior};
+///
+/// let ans = Confirm::new("Proceed with the deployment?")
+///     .with_default(true)
+///     .with_timeout(
+///         Timeout::new(Duration::from_secs(30))
+///             .with_behavior(TimeoutBehavior::SubmitDefault)
+///             .with_countdown(true),
+///     )
+///     .prompt();
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Timeout {
+    /// Time the user has to answer the prompt.
+    pub duration: Duration,
+
+    /// What to do when the time runs out.
+    pub behavior: TimeoutBehavior,
+
+    /// Whether the remaining time is displayed below the prompt.
+    pub show_countdown: bool,
+}
+
+impl Timeout {
+    /// Default behavior on expiry.
+    pub const DEFAULT_BEHAVIOR: TimeoutBehavior = TimeoutBehavior::Error;
+
+    /// Default value for whether the countdown is displayed.
+    pub const DEFAULT_SHOW_COUNTDOWN: bool = false;
+
+    /// Creates a timeout of the given duration with default settings.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            behavior: Self::DEFAULT_BEHAVIOR,
+            show_countdown: Self::DEFAULT_SHOW_COUNTDOWN,
+        }
+    }
+
+    /// Sets what happens when the time runs out.
+    pub fn with_behavior(mut self, behavior: TimeoutBehavior) -> Self {
+        self.behavior = behavior;
+        self
+    }
+
+    /// Sets whether the remaining time is displayed below the prompt.
+    pub fn with_countdown(mut self, show_countdown: bool) -> Self {
+        self.show_countdown = show_countdown;
+        self
+    }
+}
+
+impl From<Duration> for Timeout {
+    fn from(duration: Duration) -> Self {
+        Self::new(duration)
+    }
+}
@@ -1,32 +1,256 @@
-use std::io::{Result, Write};
+#[cfg(unix)]
+use std::{
+    fs::File,
+    io::Read,
+    os::unix::{io::AsRawFd, net::UnixStream},
+};
+use std::{
+    io::{Result, Write},
+    time::Duration,
+};
 
 use console::{Attribute, Color, Key, Style, Term};
+#[cfg(unix)]
+use signal_hook::{consts::SIGWINCH, SigId};
 
 use crate::{
     error::InquireResult,
-    ui::{Attributes, InputReader, StyleSheet, Styled},
+    ui::{Attributes, ColorSupport, InputReader, Output, Position, StyleSheet, Styled},
 };
 
 use super::Terminal;
 
-#[derive(Clone)]
-pub struct ConsoleTerminal {
+pub struct ConsoleKeyReader {
     term: Term,
+    last_size: (u16, u16),
+    /// Terminal polled for key presses, which console reads from stdin
+    /// when this one can't be opened.
+    #[cfg(unix)]
+    tty: Option<File>,
+    #[cfg(unix)]
+    resize_signal: UnixStream,
+    #[cfg(unix)]
+    resize_signal_id: SigId,
 }
 
-impl ConsoleTerminal {
+/// Input source that became ready while waiting for events.
+#[cfg(unix)]
+enum Readiness {
+    Key,
+    Resize,
+}
+
+impl ConsoleKeyReader {
     #[allow(unused)]
-    pub fn new() -> Self {
-        Self {
-            term: Term::stderr(),
+    pub fn new() -> InquireResult<Self> {
+        let term = input_term();
+        let last_size = term.size();
+
+        // SIGWINCH is forwarded to a socket so that it can be polled
+        // together with the tty, waking up any pending wait.
+        #[cfg(unix)]
+        let (resize_signal, signal_writer) = UnixStream::pair()?;
+        #[cfg(unix)]
+        resize_signal.set_nonblocking(true)?;
+        #[cfg(unix)]
+        let resize_signal_id = signal_hook::low_level::pipe::register(SIGWINCH, signal_writer)?;
+
+        Ok(Self {
+            term,
+            last_size,
+            #[cfg(unix)]
+            tty: crate::ui::open_tty().ok(),
+            #[cfg(unix)]
+            resize_signal,
+            #[cfg(unix)]
+            resize_signal_id,
+        })
+    }
+
+    /// Returns a resize event when the terminal size changed since the last check.
+    fn check_resize(&mut self) -> Option<crate::ui::Key> {
+        let size = self.term.size();
+
+        if size == self.last_size {
+            return None;
+        }
+
+        self.last_size = size;
+        let (rows, cols) = size;
+        Some(crate::ui::Key::Resize(cols, rows))
+    }
+
+    /// Returns the next key press or resize event, or `None` when there was
+    /// none within `timeout`.
+    #[cfg(unix)]
+    fn next_key(&mut self, timeout: Option<Duration>) -> InquireResult<Option<crate::ui::Key>> {
+        if let Some(resize) = self.check_resize() {
+            return Ok(Some(resize));
+        }
+
+        match self.wait_for_input(timeout)? {
+            Some(Readiness::Key) => Ok(console_key_to_key(self.term.read_key()?)),
+            Some(Readiness::Resize) => Ok(self.check_resize()),
+            None => Ok(None),
+        }
+    }
+
+    /// Waits until either a key press or a resize event is available,
+    /// returning `None` if none happens within `timeout`.
+    ///
+    /// The terminal is in raw mode while waiting, as console only puts it in
+    /// raw mode while reading, and key presses would otherwise be buffered
+    /// until a line break.
+    #[cfg(unix)]
+    #[allow(unsafe_code)]
+    fn wait_for_input(&mut self, timeout: Option<Duration>) -> Result<Option<Readiness>> {
+        let fd = match &self.tty {
+            Some(tty) => tty.as_raw_fd(),
+            None => libc::STDIN_FILENO,
+        };
+
+        let mut termios = std::mem::MaybeUninit::uninit();
+        // SAFETY: `termios` is a valid pointer to a `termios` struct, which
+        // is initialized when the call succeeds.
+        if unsafe { libc::tcgetattr(fd, termios.as_mut_ptr()) } != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        // SAFETY: `tcgetattr` succeeded, initializing the struct.
+        let original = unsafe { termios.assume_init() };
+
+        let mut raw = original;
+        // SAFETY: `raw` is a valid, initialized `termios` struct.
+        unsafe { libc::cfmakeraw(&mut raw) };
+        raw.c_oflag = original.c_oflag;
+
+        // SAFETY: `raw` and `original` are valid, initialized `termios` structs.
+        let set_attributes = |termios: &libc::termios| match unsafe {
+            libc::tcsetattr(fd, libc::TCSADRAIN, termios)
+        } {
+            0 => Ok(()),
+            _ => Err(std::io::Error::last_os_error()),
+        };
+
+        let mut pollfds = [
+            libc::pollfd {
+                fd: self.resize_signal.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
+            },
+            libc::pollfd {
+                fd,
+                events: libc::POLLIN,
+                revents: 0,
+            },
+        ];
+        let timeout_ms = timeout
+            .map(|t| t.as_millis().min(libc::c_int::MAX as u128) as libc::c_int)
+            .unwrap_or(-1);
+
+        set_attributes(&raw)?;
+        // SAFETY: `pollfds` is a valid pointer to an array of initialized
+        // `pollfd` structs, whose length is passed as the second argument.
+        let ready = unsafe { libc::poll(pollfds.as_mut_ptr(), pollfds.len() as _, timeout_ms) };
+        let poll_error = std::io::Error::last_os_error();
+        set_attributes(&original)?;
+
+        if ready < 0 {
+            return match poll_error.kind() {
+                // the signal handler interrupted the wait, the socket
+                // will be readable on the next poll.
+                std::io::ErrorKind::Interrupted => Ok(None),
+                _ => Err(poll_error),
+            };
+        }
+
+        if pollfds[0].revents & libc::POLLIN != 0 {
+            let mut buf = [0u8; 16];
+            while let Ok(n) = (&self.resize_signal).read(&mut buf) {
+                if n == 0 {
+                    break;
+                }
+            }
+            return Ok(Some(Readiness::Resize));
+        }
+
+        if pollfds[1].revents != 0 {
+            return Ok(Some(Readiness::Key));
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the next key press or resize event.
+    ///
+    /// console can't be waited on with a timeout on these platforms, so
+    /// timeouts are not supported and resizes are only noticed in between
+    /// key presses.
+    #[cfg(not(unix))]
+    fn next_key(&mut self, timeout: Option<Duration>) -> InquireResult<Option<crate::ui::Key>> {
+        if timeout.is_some() {
+            return Err(crate::error::InquireError::InvalidConfiguration(
+                "Prompt timeouts are not supported by the console terminal back-end on this platform"
+                    .into(),
+            ));
+        }
+
+        if let Some(resize) = self.check_resize() {
+            return Ok(Some(resize));
         }
+
+        Ok(console_key_to_key(self.term.read_key()?))
+    }
+}
+
+#[cfg(unix)]
+impl Drop for ConsoleKeyReader {
+    fn drop(&mut self) {
+        signal_hook::low_level::unregister(self.resize_signal_id);
     }
 }
 
-impl InputReader for ConsoleTerminal {
+impl InputReader for ConsoleKeyReader {
     fn read_key(&mut self) -> InquireResult<crate::ui::Key> {
-        let key = self.term.read_key()?;
-        Ok(key.into())
+        loop {
+            if let Some(key) = self.next_key(None)? {
+                return Ok(key);
+            }
+        }
+    }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<crate::ui::Key>> {
+        self.next_key(Some(timeout))
+    }
+}
+
+/// Returns a term attached to the terminal, through which keys are read.
+///
+/// console only reads keys through terms attached to a terminal, even though
+/// it reads them from `/dev/tty` whenever stdin is redirected.
+fn input_term() -> Term {
+    #[cfg(unix)]
+    if let Ok((read, write)) = crate::ui::open_tty().and_then(|tty| Ok((tty.try_clone()?, tty))) {
+        return Term::read_write_pair(read, write);
+    }
+
+    Term::stderr()
+}
+
+pub struct ConsoleTerminal {
+    term: Term,
+    output: Box<dyn Write + Send>,
+    color_support: ColorSupport,
+}
+
+impl ConsoleTerminal {
+    #[allow(unused)]
+    pub fn new(output: &Output) -> InquireResult<Self> {
+        Ok(Self {
+            term: input_term(),
+            output: output.open()?,
+            // console does not write RGB colors
+            color_support: ColorSupport::detect().min(ColorSupport::Ansi256),
+        })
     }
 }
 
@@ -34,41 +258,49 @@ impl Terminal for ConsoleTerminal {
     fn cursor_up(&mut self, cnt: u16) -> Result<()> {
         match cnt {
             0 => Ok(()),
-            cnt => self.term.move_cursor_up(cnt as usize),
+            cnt => write!(self.output, "\x1b[{}A", cnt),
         }
     }
 
     fn cursor_down(&mut self, cnt: u16) -> Result<()> {
         match cnt {
             0 => Ok(()),
-            cnt => self.term.move_cursor_down(cnt as usize),
+            cnt => write!(self.output, "\x1b[{}B", cnt),
         }
     }
 
     fn cursor_left(&mut self, cnt: u16) -> Result<()> {
         match cnt {
             0 => Ok(()),
-            cnt => self.term.move_cursor_left(cnt as usize),
+            cnt => write!(self.output, "\x1b[{}D", cnt),
         }
     }
 
     fn cursor_right(&mut self, cnt: u16) -> Result<()> {
         match cnt {
             0 => Ok(()),
-            cnt => self.term.move_cursor_right(cnt as usize),
+            cnt => write!(self.output, "\x1b[{}C", cnt),
         }
     }
 
     fn cursor_move_to_column(&mut self, idx: u16) -> Result<()> {
-        // console has no built-in method to set cursor column ¯\_(ツ)_/¯
-        self.term.move_cursor_left(1000)?;
-        self.term.move_cursor_right(idx as usize)?;
+        write!(self.output, "\x1b[{}G", idx.saturating_add(1))
+    }
+
+    fn cursor_position(&mut self) -> Result<Position> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "console does not report the cursor position",
+        ))
+    }
 
+    fn enable_mouse_capture(&mut self) -> Result<()> {
+        // console does not report mouse events, so clicks are never received.
         Ok(())
     }
 
     fn flush(&mut self) -> Result<()> {
-        self.term.flush()
+        self.output.flush()
     }
 
     fn get_size(&self) -> Result<super::TerminalSize> {
@@ -78,28 +310,29 @@ impl Terminal for ConsoleTerminal {
     }
 
     fn write<T: std::fmt::Display>(&mut self, val: T) -> Result<()> {
-        write!(self.term, "{}", val)
+        write!(self.output, "{}", val)
     }
 
     fn write_styled<T: std::fmt::Display>(&mut self, val: &Styled<T>) -> Result<()> {
-        let styled_object = Style::from(val.style).apply_to(&val.content);
-        write!(self.term, "{}", styled_object)
+        let style = val.style.downgrade(self.color_support);
+        let styled_object = Style::from(style).apply_to(&val.content);
+        write!(self.output, "{}", styled_object)
     }
 
     fn clear_line(&mut self) -> Result<()> {
-        self.term.clear_line()
+        write!(self.output, "\r\x1b[2K")
     }
 
     fn clear_until_new_line(&mut self) -> Result<()> {
-        write!(self.term, "\x1b[K")
+        write!(self.output, "\x1b[K")
     }
 
     fn cursor_hide(&mut self) -> Result<()> {
-        self.term.hide_cursor()
+        write!(self.output, "\x1b[?25l")
     }
 
     fn cursor_show(&mut self) -> Result<()> {
-        self.term.show_cursor()
+        write!(self.output, "\x1b[?25h")
     }
 }
 
@@ -153,27 +386,98 @@ impl crate::ui::Color {
     }
 }
 
-impl From<Key> for crate::ui::Key {
-    fn from(key: Key) -> Self {
-        use crate::ui::KeyModifiers;
-
-        match key {
-            Key::Escape => Self::Escape,
-            Key::Char('\n' | '\r') | Key::Enter => Self::Enter,
-            Key::Char('\t') | Key::Tab => Self::Tab,
-            Key::Backspace => Self::Backspace,
-            Key::Del => Self::Delete(KeyModifiers::empty()),
-            Key::Home => Self::Home,
-            Key::End => Self::End,
-            Key::PageUp => Self::PageUp(KeyModifiers::empty()),
-            Key::PageDown => Self::PageDown(KeyModifiers::empty()),
-            Key::ArrowUp => Self::Up(KeyModifiers::empty()),
-            Key::ArrowDown => Self::Down(KeyModifiers::empty()),
-            Key::ArrowLeft => Self::Left(KeyModifiers::empty()),
-            Key::ArrowRight => Self::Right(KeyModifiers::empty()),
-            Key::Char(c) => Self::Char(c, KeyModifiers::empty()),
-            #[allow(deprecated)]
-            _ => Self::Any,
+/// Converts a console key into a key, when it is one we care about.
+fn console_key_to_key(key: Key) -> Option<crate::ui::Key> {
+    use crate::ui::{Key as K, KeyModifiers};
+
+    let none = KeyModifiers::NONE;
+
+    let key = match key {
+        Key::Escape => K::Escape(none),
+        Key::Char('\n' | '\r') | Key::Enter => K::Enter(none),
+        Key::Char('\t') | Key::Tab => K::Tab(none),
+        Key::BackTab => K::BackTab(none),
+        Key::Backspace => K::Backspace(none),
+        Key::Insert => K::Insert(none),
+        Key::Del => K::Delete(none),
+        Key::Home => K::Home(none),
+        Key::End => K::End(none),
+        Key::PageUp => K::PageUp(none),
+        Key::PageDown => K::PageDown(none),
+        Key::ArrowUp => K::Up(none),
+        Key::ArrowDown => K::Down(none),
+        Key::ArrowLeft => K::Left(none),
+        Key::ArrowRight => K::Right(none),
+        Key::CtrlC => K::Char('c', KeyModifiers::CONTROL),
+        // console reports other control combinations as the raw control
+        // character, from Ctrl+A (0x01) to Ctrl+Z (0x1A).
+        Key::Char(c @ '\u{1}'..='\u{1a}') => {
+            K::Char((c as u8 - 1 + b'a') as char, KeyModifiers::CONTROL)
         }
+        Key::Char(c) => K::Char(c, none),
+        _ => return None,
+    };
+
+    Some(key)
+}
+
+#[cfg(test)]
+mod test {
+    use console::Key as NativeKey;
+
+    use crate::ui::{Key, KeyModifiers};
+
+    use super::console_key_to_key;
+
+    fn native_key(name: &str) -> NativeKey {
+        match name {
+            "esc" => NativeKey::Escape,
+            "enter" => NativeKey::Enter,
+            "tab" => NativeKey::Tab,
+            "shift+tab" => NativeKey::BackTab,
+            "backspace" => NativeKey::Backspace,
+            "insert" => NativeKey::Insert,
+            "delete" => NativeKey::Del,
+            "home" => NativeKey::Home,
+            "end" => NativeKey::End,
+            "pageup" => NativeKey::PageUp,
+            "pagedown" => NativeKey::PageDown,
+            "up" => NativeKey::ArrowUp,
+            "down" => NativeKey::ArrowDown,
+            "left" => NativeKey::ArrowLeft,
+            "right" => NativeKey::ArrowRight,
+            "a" => NativeKey::Char('a'),
+            "ctrl+c" => NativeKey::CtrlC,
+            "ctrl+d" => NativeKey::Char('\u{4}'),
+            _ => panic!("no console key for {}", name),
+        }
+    }
+
+    #[test]
+    fn maps_common_keys() {
+        for (name, expected) in crate::terminal::COMMON_KEYS {
+            assert_eq!(
+                Some(expected.clone()),
+                console_key_to_key(native_key(name)),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn maps_control_characters() {
+        assert_eq!(
+            Some(Key::Char('a', KeyModifiers::CONTROL)),
+            console_key_to_key(NativeKey::Char('\u{1}'))
+        );
+        assert_eq!(
+            Some(Key::Enter(KeyModifiers::NONE)),
+            console_key_to_key(NativeKey::Char('\n'))
+        );
+        assert_eq!(
+            Some(Key::Tab(KeyModifiers::NONE)),
+            console_key_to_key(NativeKey::Char('\t'))
+        );
+        assert_eq!(None, console_key_to_key(NativeKey::Unknown));
     }
 }
@@ -1,8 +1,14 @@
-use std::io::{stderr, Result, Stderr, Write};
+use std::{
+    io::{Result, Write},
+    time::{Duration, Instant},
+};
 
 use crossterm::{
     cursor,
-    event::{self, KeyCode, KeyEvent, KeyModifiers},
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEventKind,
+    },
     queue,
     style::{Attribute, Color, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
     terminal::{self, ClearType},
@@ -11,46 +17,153 @@ use crossterm::{
 
 use crate::{
     error::InquireResult,
-    ui::{Attributes, InputReader, Key, Styled},
+    ui::{Attributes, ColorSupport, InputReader, Key, MouseEvent, Output, Position, Styled},
 };
 
 use super::Terminal;
 
 enum IO {
-    Std(Stderr),
+    Std(Box<dyn Write + Send>),
     #[allow(unused)]
     Test(Vec<u8>),
 }
 
 pub struct CrosstermTerminal {
     io: IO,
+    mouse_capture: bool,
+    color_support: ColorSupport,
 }
 
 pub struct CrosstermKeyReader;
 
 impl CrosstermKeyReader {
+    // unused when only the `async` feature pulls crossterm in
+    #[cfg_attr(not(feature = "crossterm"), allow(unused))]
     pub fn new() -> Self {
         Self
     }
 }
 
+/// Converts a crossterm event into a key, when it is one we care about.
+fn event_to_key(event: event::Event) -> Option<Key> {
+    match event {
+        event::Event::Key(key_event) => key_event_to_key(key_event),
+        event::Event::Resize(width, height) => Some(Key::Resize(width, height)),
+        event::Event::Paste(text) => Some(Key::Paste(text)),
+        event::Event::Mouse(mouse_event) => {
+            let kind = match mouse_event.kind {
+                MouseEventKind::Down(MouseButton::Left) => crate::ui::MouseEventKind::LeftClick,
+                MouseEventKind::ScrollUp => crate::ui::MouseEventKind::ScrollUp,
+                MouseEventKind::ScrollDown => crate::ui::MouseEventKind::ScrollDown,
+                _ => return None,
+            };
+
+            Some(Key::Mouse(MouseEvent {
+                kind,
+                column: mouse_event.column,
+                row: mouse_event.row,
+            }))
+        }
+        _ => None,
+    }
+}
+
 impl InputReader for CrosstermKeyReader {
     fn read_key(&mut self) -> InquireResult<Key> {
         loop {
-            if let event::Event::Key(key_event) = event::read()? {
-                return Ok(key_event.into());
+            if let Some(key) = event_to_key(event::read()?) {
+                return Ok(key);
+            }
+        }
+    }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+
+            if !event::poll(remaining)? {
+                return Ok(None);
+            }
+            if let Some(key) = event_to_key(event::read()?) {
+                return Ok(Some(key));
+            }
+        }
+    }
+}
+
+/// Key reader backed by crossterm's [`EventStream`](event::EventStream),
+/// suitable for prompts running inside of an async runtime.
+#[cfg(feature = "async")]
+pub struct CrosstermAsyncKeyReader {
+    events: event::EventStream,
+}
+
+#[cfg(feature = "async")]
+impl CrosstermAsyncKeyReader {
+    pub fn new() -> Self {
+        Self {
+            events: event::EventStream::new(),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl InputReader for CrosstermAsyncKeyReader {
+    fn read_key(&mut self) -> InquireResult<Key> {
+        CrosstermKeyReader.read_key()
+    }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        CrosstermKeyReader.read_key_timeout(timeout)
+    }
+}
+
+#[cfg(feature = "async")]
+impl crate::ui::AsyncInputReader for CrosstermAsyncKeyReader {
+    fn poll_read_key(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<InquireResult<Key>> {
+        use futures_core::Stream;
+        use std::{pin::Pin, task::Poll};
+
+        loop {
+            match Pin::new(&mut self.events).poll_next(cx) {
+                Poll::Ready(Some(Ok(event))) => {
+                    if let Some(key) = event_to_key(event) {
+                        return Poll::Ready(Ok(key));
+                    }
+                }
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Err(err.into())),
+                Poll::Ready(None) => {
+                    return Poll::Ready(Err(std::io::Error::new(
+                        std::io::ErrorKind::UnexpectedEof,
+                        "terminal event stream was closed",
+                    )
+                    .into()))
+                }
+                Poll::Pending => return Poll::Pending,
             }
         }
     }
 }
 
 impl CrosstermTerminal {
-    pub fn new() -> InquireResult<Self> {
+    /// Renders to `output`, while raw mode is enabled on the terminal
+    /// crossterm reads from, which is `/dev/tty` when stdin is redirected.
+    pub fn new(output: &Output) -> InquireResult<Self> {
         crossterm::terminal::enable_raw_mode()?;
 
-        Ok(Self {
-            io: IO::Std(stderr()),
-        })
+        let mut terminal = Self {
+            io: IO::Std(output.open()?),
+            mouse_capture: false,
+            color_support: ColorSupport::detect(),
+        };
+        terminal.write_command(EnableBracketedPaste)?;
+
+        Ok(terminal)
     }
 
     fn get_writer(&mut self) -> &mut dyn Write {
@@ -129,6 +242,27 @@ impl Terminal for CrosstermTerminal {
         self.write_command(cursor::MoveToColumn(idx))
     }
 
+    fn cursor_position(&mut self) -> Result<Position> {
+        match self.io {
+            IO::Std(_) => {
+                // pending output might move the cursor before the query is answered
+                self.flush()?;
+                #[cfg(unix)]
+                let position = position_query::query_cursor_position();
+                #[cfg(not(unix))]
+                let position = cursor::position().map(|(col, row)| Position { row, col });
+                position
+            }
+            IO::Test(_) => Ok(Position::default()),
+        }
+    }
+
+    fn enable_mouse_capture(&mut self) -> Result<()> {
+        self.write_command(EnableMouseCapture)?;
+        self.mouse_capture = true;
+        self.flush()
+    }
+
     fn flush(&mut self) -> Result<()> {
         self.get_writer().flush()
     }
@@ -142,25 +276,27 @@ impl Terminal for CrosstermTerminal {
     }
 
     fn write_styled<T: std::fmt::Display>(&mut self, val: &Styled<T>) -> Result<()> {
-        if let Some(color) = val.style.fg {
+        let style = val.style.downgrade(self.color_support);
+
+        if let Some(color) = style.fg {
             self.set_fg_color(color)?;
         }
-        if let Some(color) = val.style.bg {
+        if let Some(color) = style.bg {
             self.set_bg_color(color)?;
         }
-        if !val.style.att.is_empty() {
-            self.set_attributes(val.style.att)?;
+        if !style.att.is_empty() {
+            self.set_attributes(style.att)?;
         }
 
         self.write(&val.content)?;
 
-        if val.style.fg.as_ref().is_some() {
+        if style.fg.is_some() {
             self.reset_fg_color()?;
         }
-        if val.style.bg.as_ref().is_some() {
+        if style.bg.is_some() {
             self.reset_bg_color()?;
         }
-        if !val.style.att.is_empty() {
+        if !style.att.is_empty() {
             self.reset_attributes()?;
         }
 
@@ -186,6 +322,12 @@ impl Terminal for CrosstermTerminal {
 
 impl Drop for CrosstermTerminal {
     fn drop(&mut self) {
+        if self.mouse_capture {
+            let _unused = self.write_command(DisableMouseCapture);
+        }
+        if let IO::Std(_) = self.io {
+            let _unused = self.write_command(DisableBracketedPaste);
+        }
         let _unused = self.flush();
         let _unused = match self.io {
             IO::Std(_) => terminal::disable_raw_mode(),
@@ -250,73 +392,129 @@ impl From<KeyModifiers> for crate::ui::KeyModifiers {
     }
 }
 
-impl From<KeyEvent> for Key {
-    fn from(event: KeyEvent) -> Self {
-        match event {
-            KeyEvent {
-                code: KeyCode::Esc, ..
-            } => Self::Escape,
-            KeyEvent {
-                code: KeyCode::Enter | KeyCode::Char('\n' | '\r'),
-                ..
-            } => Self::Enter,
-            KeyEvent {
-                code: KeyCode::Tab | KeyCode::Char('\t'),
-                ..
-            } => Self::Tab,
-            KeyEvent {
-                code: KeyCode::Backspace,
-                ..
-            } => Self::Backspace,
-            KeyEvent {
-                code: KeyCode::Delete,
-                modifiers: m,
-                ..
-            } => Self::Delete(m.into()),
-            KeyEvent {
-                code: KeyCode::Home,
-                ..
-            } => Self::Home,
-            KeyEvent {
-                code: KeyCode::End, ..
-            } => Self::End,
-            KeyEvent {
-                code: KeyCode::PageUp,
-                modifiers: m,
-                ..
-            } => Self::PageUp(m.into()),
-            KeyEvent {
-                code: KeyCode::PageDown,
-                modifiers: m,
-                ..
-            } => Self::PageDown(m.into()),
-            KeyEvent {
-                code: KeyCode::Up,
-                modifiers: m,
-                ..
-            } => Self::Up(m.into()),
-            KeyEvent {
-                code: KeyCode::Down,
-                modifiers: m,
-                ..
-            } => Self::Down(m.into()),
-            KeyEvent {
-                code: KeyCode::Left,
-                modifiers: m,
-                ..
-            } => Self::Left(m.into()),
-            KeyEvent {
-                code: KeyCode::Right,
-                modifiers: m,
-                ..
-            } => Self::Right(m.into()),
-            KeyEvent {
-                code: KeyCode::Char(c),
-                modifiers: m,
-                ..
-            } => Self::Char(c, m.into()),
-            #[allow(deprecated)]
-            _ => Self::Any,
+/// Converts a crossterm key event into a key, when it is one we care about.
+fn key_event_to_key(event: KeyEvent) -> Option<Key> {
+    let m = crate::ui::KeyModifiers::from(event.modifiers);
+
+    let key = match event.code {
+        KeyCode::Esc => Key::Escape(m),
+        KeyCode::Enter | KeyCode::Char('\n' | '\r') => Key::Enter(m),
+        KeyCode::Tab | KeyCode::BackTab | KeyCode::Char('\t')
+            if m.contains(crate::ui::KeyModifiers::SHIFT) =>
+        {
+            Key::BackTab(m - crate::ui::KeyModifiers::SHIFT)
+        }
+        KeyCode::BackTab => Key::BackTab(m),
+        KeyCode::Tab | KeyCode::Char('\t') => Key::Tab(m),
+        KeyCode::Backspace => Key::Backspace(m),
+        KeyCode::Insert => Key::Insert(m),
+        KeyCode::Delete => Key::Delete(m),
+        KeyCode::Home => Key::Home(m),
+        KeyCode::End => Key::End(m),
+        KeyCode::PageUp => Key::PageUp(m),
+        KeyCode::PageDown => Key::PageDown(m),
+        KeyCode::Up => Key::Up(m),
+        KeyCode::Down => Key::Down(m),
+        KeyCode::Left => Key::Left(m),
+        KeyCode::Right => Key::Right(m),
+        KeyCode::F(n) => Key::F(n, m),
+        KeyCode::Char(c) => Key::Char(c, m),
+        _ => return None,
+    };
+
+    Some(key)
+}
+
+/// Cursor position requests sent to the terminal the process is attached
+/// to. [`cursor::position`] always sends them through stdout, which might be
+/// redirected while prompts are rendered to stderr.
+#[cfg(unix)]
+mod position_query {
+    use std::{
+        fs::File,
+        io::{Error, ErrorKind, Read, Result, Write},
+        os::unix::io::AsRawFd,
+        time::{Duration, Instant},
+    };
+
+    use crate::ui::{open_tty, Position};
+
+    /// Same as crossterm, which gives up waiting for the answer after 2s.
+    const TIMEOUT: Duration = Duration::from_secs(2);
+
+    /// Writes `ESC [ 6 n` to the tty and reads its answer, formatted as
+    /// `ESC [ row ; col R`. Raw mode must be enabled, which is the case
+    /// while a [`super::CrosstermTerminal`] is alive.
+    pub(super) fn query_cursor_position() -> Result<Position> {
+        let mut tty = open_tty()?;
+        tty.write_all(b"\x1b[6n")?;
+        tty.flush()?;
+
+        let deadline = Instant::now() + TIMEOUT;
+        let mut response = vec![];
+        let mut buf = [0u8; 32];
+
+        loop {
+            if let Some(position) = parse_response(&response) {
+                return Ok(position);
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if !wait_for_input(&tty, remaining) {
+                return Err(Error::new(
+                    ErrorKind::TimedOut,
+                    "the terminal did not report the cursor position",
+                ));
+            }
+
+            let n = tty.read(&mut buf)?;
+            if n == 0 {
+                return Err(ErrorKind::UnexpectedEof.into());
+            }
+            response.extend_from_slice(&buf[..n]);
+        }
+    }
+
+    /// Parses the last complete answer in `response`, converting its
+    /// one-based coordinates. Any bytes around it are ignored.
+    fn parse_response(response: &[u8]) -> Option<Position> {
+        let end = response.iter().rposition(|&b| b == b'R')?;
+        let start = response[..end].windows(2).rposition(|w| w == b"\x1b[")?;
+        let answer = std::str::from_utf8(&response[start + 2..end]).ok()?;
+        let (row, col) = answer.split_once(';')?;
+
+        Some(Position {
+            row: row.parse::<u16>().ok()?.saturating_sub(1),
+            col: col.parse::<u16>().ok()?.saturating_sub(1),
+        })
+    }
+
+    #[allow(unsafe_code)]
+    fn wait_for_input(tty: &File, timeout: Duration) -> bool {
+        let mut pollfd = libc::pollfd {
+            fd: tty.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout_ms = timeout.as_millis().min(libc::c_int::MAX as u128) as libc::c_int;
+
+        // SAFETY: `pollfd` is a valid pointer to a single initialized
+        // `pollfd` struct, matching the length passed as second argument.
+        let ready = unsafe { libc::poll(&mut pollfd, 1, timeout_ms) };
+
+        ready > 0 && pollfd.revents & libc::POLLIN != 0
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::parse_response;
+
+        #[test]
+        fn cursor_position_responses_are_parsed() {
+            let position = parse_response(b"a\x1b[5;1R").unwrap();
+            assert_eq!((4, 0), (position.row, position.col));
+            assert!(parse_response(b"\x1b[5;1").is_none());
+            assert!(parse_response(b"\x1b[1;5").is_none());
         }
     }
 }
@@ -326,14 +524,107 @@ mod test {
     use crate::terminal::Terminal;
     use crate::ui::Color;
 
+    use crate::ui::{Key, KeyModifiers};
+
     use super::Attributes;
+    use super::ColorSupport;
     use super::CrosstermTerminal;
+    use super::Styled;
     use super::IO;
+    use super::{key_event_to_key, KeyCode, KeyEvent};
+
+    type Modifiers = crossterm::event::KeyModifiers;
+
+    fn key_event(code: KeyCode, modifiers: Modifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn native_key(name: &str) -> KeyEvent {
+        let (code, modifiers) = match name {
+            "esc" => (KeyCode::Esc, Modifiers::NONE),
+            "enter" => (KeyCode::Enter, Modifiers::NONE),
+            "tab" => (KeyCode::Tab, Modifiers::NONE),
+            "shift+tab" => (KeyCode::BackTab, Modifiers::SHIFT),
+            "backspace" => (KeyCode::Backspace, Modifiers::NONE),
+            "insert" => (KeyCode::Insert, Modifiers::NONE),
+            "delete" => (KeyCode::Delete, Modifiers::NONE),
+            "home" => (KeyCode::Home, Modifiers::NONE),
+            "end" => (KeyCode::End, Modifiers::NONE),
+            "pageup" => (KeyCode::PageUp, Modifiers::NONE),
+            "pagedown" => (KeyCode::PageDown, Modifiers::NONE),
+            "up" => (KeyCode::Up, Modifiers::NONE),
+            "down" => (KeyCode::Down, Modifiers::NONE),
+            "left" => (KeyCode::Left, Modifiers::NONE),
+            "right" => (KeyCode::Right, Modifiers::NONE),
+            "a" => (KeyCode::Char('a'), Modifiers::NONE),
+            "ctrl+c" => (KeyCode::Char('c'), Modifiers::CONTROL),
+            "ctrl+d" => (KeyCode::Char('d'), Modifiers::CONTROL),
+            _ => panic!("no crossterm key for {}", name),
+        };
+
+        key_event(code, modifiers)
+    }
+
+    #[test]
+    fn maps_common_keys() {
+        for (name, expected) in crate::terminal::COMMON_KEYS {
+            assert_eq!(
+                Some(expected.clone()),
+                key_event_to_key(native_key(name)),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn maps_keys_with_modifiers() {
+        let cases = [
+            (
+                key_event(KeyCode::Enter, Modifiers::CONTROL),
+                Key::Enter(KeyModifiers::CONTROL),
+            ),
+            (
+                key_event(KeyCode::Home, Modifiers::SHIFT),
+                Key::Home(KeyModifiers::SHIFT),
+            ),
+            (
+                key_event(KeyCode::Backspace, Modifiers::ALT),
+                Key::Backspace(KeyModifiers::ALT),
+            ),
+            (
+                key_event(KeyCode::F(1), Modifiers::NONE),
+                Key::F(1, KeyModifiers::NONE),
+            ),
+            (
+                key_event(KeyCode::F(12), Modifiers::CONTROL),
+                Key::F(12, KeyModifiers::CONTROL),
+            ),
+            (
+                key_event(KeyCode::Tab, Modifiers::SHIFT),
+                Key::BackTab(KeyModifiers::NONE),
+            ),
+            (
+                key_event(KeyCode::BackTab, Modifiers::SHIFT | Modifiers::CONTROL),
+                Key::BackTab(KeyModifiers::CONTROL),
+            ),
+        ];
+
+        for (event, expected) in cases {
+            assert_eq!(Some(expected), key_event_to_key(event));
+        }
+
+        assert_eq!(
+            None,
+            key_event_to_key(key_event(KeyCode::Null, Modifiers::NONE))
+        );
+    }
 
     impl CrosstermTerminal {
         pub fn new_in_memory_output() -> Self {
             Self {
                 io: IO::Test(Vec::new()),
+                mouse_capture: false,
+                color_support: ColorSupport::TrueColor,
             }
         }
 
@@ -428,4 +719,25 @@ mod test {
             std::str::from_utf8(&terminal.get_buffer_content()).unwrap()
         );
     }
+
+    #[test]
+    fn styled_colors_are_downgraded_to_color_support() {
+        let mut terminal = CrosstermTerminal::new_in_memory_output();
+        let orange = Styled::new("a").with_fg(Color::rgb(255, 135, 0));
+
+        terminal.color_support = ColorSupport::Ansi256;
+        terminal.write_styled(&orange).unwrap();
+        terminal.color_support = ColorSupport::Ansi16;
+        terminal.write_styled(&orange).unwrap();
+        terminal.color_support = ColorSupport::None;
+        terminal
+            .write_styled(&orange.with_attr(Attributes::BOLD))
+            .unwrap();
+
+        #[cfg(unix)]
+        assert_eq!(
+            "\x1B[38;5;208ma\x1B[39m\x1B[38;5;3ma\x1B[39m\x1B[1ma\x1B[0m",
+            std::str::from_utf8(&terminal.get_buffer_content()).unwrap()
+        );
+    }
 }
@@ -2,11 +2,11 @@ use std::{fmt::Display, io::Result};
 
 use crate::{
     error::InquireResult,
-    ui::{dimension::Dimension, InputReader, Styled},
+    ui::{dimension::Dimension, InputReader, Output, Position, Styled},
 };
 
-#[cfg(feature = "crossterm")]
-#[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
+#[cfg(any(feature = "crossterm", feature = "async"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "async"))))]
 pub mod crossterm;
 
 #[cfg(feature = "termion")]
@@ -19,6 +19,36 @@ pub mod console;
 
 pub type TerminalSize = Dimension;
 
+/// Keys every backend is able to report, named after what was pressed,
+/// along with the key each backend must map them to.
+#[cfg(test)]
+pub(crate) const COMMON_KEYS: &[(&str, crate::ui::Key)] = {
+    use crate::ui::{Key, KeyModifiers};
+
+    const NONE: KeyModifiers = KeyModifiers::NONE;
+
+    &[
+        ("esc", Key::Escape(NONE)),
+        ("enter", Key::Enter(NONE)),
+        ("tab", Key::Tab(NONE)),
+        ("shift+tab", Key::BackTab(NONE)),
+        ("backspace", Key::Backspace(NONE)),
+        ("insert", Key::Insert(NONE)),
+        ("delete", Key::Delete(NONE)),
+        ("home", Key::Home(NONE)),
+        ("end", Key::End(NONE)),
+        ("pageup", Key::PageUp(NONE)),
+        ("pagedown", Key::PageDown(NONE)),
+        ("up", Key::Up(NONE)),
+        ("down", Key::Down(NONE)),
+        ("left", Key::Left(NONE)),
+        ("right", Key::Right(NONE)),
+        ("a", Key::Char('a', NONE)),
+        ("ctrl+c", Key::Char('c', KeyModifiers::CONTROL)),
+        ("ctrl+d", Key::Char('d', KeyModifiers::CONTROL)),
+    ]
+};
+
 pub trait Terminal: Sized {
     fn get_size(&self) -> Result<TerminalSize>;
 
@@ -36,20 +66,49 @@ pub trait Terminal: Sized {
     fn cursor_right(&mut self, cnt: u16) -> Result<()>;
     fn cursor_move_to_column(&mut self, idx: u16) -> Result<()>;
 
+    /// Returns the zero-based screen position of the cursor.
+    fn cursor_position(&mut self) -> Result<Position>;
+
+    /// Starts reporting mouse events, until the terminal is dropped.
+    ///
+    /// Terminals that can't report mouse events should ignore this call.
+    fn enable_mouse_capture(&mut self) -> Result<()>;
+
     fn flush(&mut self) -> Result<()>;
 }
 
-pub fn get_default_terminal() -> InquireResult<(impl InputReader, impl Terminal)> {
-    #[cfg(feature = "crossterm")]
+/// Returns the terminal used by the `prompt_async` family of methods.
+///
+/// Only crossterm provides a non-blocking event source, so it is the
+/// backend used regardless of the other enabled terminal features. This
+/// does not change the back-end of the blocking `prompt` methods.
+#[cfg(feature = "async")]
+pub fn get_default_async_terminal(
+    output: &Output,
+) -> InquireResult<(impl crate::ui::AsyncInputReader, impl Terminal)> {
+    Ok((
+        crossterm::CrosstermAsyncKeyReader::new(),
+        crossterm::CrosstermTerminal::new(output)?,
+    ))
+}
+
+/// Returns the terminal of the enabled back-end, rendering to `output`.
+pub fn get_default_terminal(output: &Output) -> InquireResult<(impl InputReader, impl Terminal)> {
+    // `async` only pulls in the crossterm dependency for its event stream,
+    // which is then used as the blocking back-end only if no other is enabled.
+    #[cfg(any(
+        feature = "crossterm",
+        all(feature = "async", not(feature = "termion"), not(feature = "console"))
+    ))]
     return Ok((
         crossterm::CrosstermKeyReader::new(),
-        crossterm::CrosstermTerminal::new()?,
+        crossterm::CrosstermTerminal::new(output)?,
     ));
 
     #[cfg(all(feature = "termion", not(feature = "crossterm")))]
     return Ok((
         termion::TermionKeyReader::new()?,
-        termion::TermionTerminal::new()?,
+        termion::TermionTerminal::new(output)?,
     ));
 
     #[cfg(all(
@@ -57,16 +116,16 @@ pub fn get_default_terminal() -> InquireResult<(impl InputReader, impl Terminal)
         not(feature = "termion"),
         not(feature = "crossterm")
     ))]
-    {
-        let console_terminal = console::ConsoleTerminal::new();
-        let console_key_reader = console_terminal.clone();
-        return Ok((console_key_reader, console_terminal));
-    }
+    return Ok((
+        console::ConsoleKeyReader::new()?,
+        console::ConsoleTerminal::new(output)?,
+    ));
 
     #[cfg(all(
         not(feature = "crossterm"),
         not(feature = "termion"),
-        not(feature = "console")
+        not(feature = "console"),
+        not(feature = "async")
     ))]
     {
         compile_error!("At least one of crossterm, termion or console must be enabled");
@@ -1,70 +1,248 @@
 use core::fmt;
 use std::{
     fs::File,
-    io::{Result, Write},
+    io::{Read, Result, Write},
+    os::unix::{
+        io::{AsRawFd, RawFd},
+        net::UnixStream,
+    },
+    time::Duration,
 };
 
+use signal_hook::{consts::SIGWINCH, SigId};
+
 use termion::{
     color::{self, Color},
-    cursor,
-    event::Key,
-    input::{Keys, TermRead},
+    cursor::{self, DetectCursorPos},
+    event::{Event, Key, MouseButton, MouseEvent},
+    input::{Events, TermRead},
     raw::{IntoRawMode, RawTerminal},
     terminal_size,
 };
 
 use crate::{
     error::InquireResult,
-    ui::{Attributes, InputReader, Styled},
+    ui::{Attributes, ColorSupport, InputReader, Output, Position, Styled},
 };
 
 use super::Terminal;
 
 #[allow(clippy::upper_case_acronyms)]
 enum IO<'a> {
-    TTY(RawTerminal<File>),
+    /// Raw mode is enabled on the tty, while prompts are rendered to the
+    /// configured output.
+    TTY {
+        tty: RawTerminal<File>,
+        output: Box<dyn Write + Send>,
+    },
     #[allow(unused)]
     Custom(&'a mut (dyn Write)),
 }
 
 pub struct TermionKeyReader {
-    keys: Keys<File>,
+    fd: RawFd,
+    events: Events<File>,
+    resize_signal: UnixStream,
+    resize_signal_id: SigId,
+}
+
+/// Input source that became ready while waiting for events.
+enum Readiness {
+    Key,
+    Resize,
 }
 
 impl TermionKeyReader {
     #[allow(unused)]
     pub fn new() -> InquireResult<Self> {
+        let tty = termion::get_tty()?;
+
+        // SIGWINCH is forwarded to a socket so that it can be polled
+        // together with the tty, waking up any pending read.
+        let (resize_signal, signal_writer) = UnixStream::pair()?;
+        resize_signal.set_nonblocking(true)?;
+        let resize_signal_id = signal_hook::low_level::pipe::register(SIGWINCH, signal_writer)?;
+
         Ok(Self {
-            keys: termion::get_tty()?.keys(),
+            fd: tty.as_raw_fd(),
+            events: tty.events(),
+            resize_signal,
+            resize_signal_id,
         })
     }
+
+    /// Waits until either a key press or a resize event is available,
+    /// returning `None` if none happens within `timeout`.
+    #[allow(unsafe_code)]
+    fn wait_for_input(&mut self, timeout: Option<Duration>) -> Result<Option<Readiness>> {
+        let mut pollfds = [
+            libc::pollfd {
+                fd: self.resize_signal.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
+            },
+            libc::pollfd {
+                fd: self.fd,
+                events: libc::POLLIN,
+                revents: 0,
+            },
+        ];
+        let timeout_ms = timeout
+            .map(|t| t.as_millis().min(libc::c_int::MAX as u128) as libc::c_int)
+            .unwrap_or(-1);
+
+        // SAFETY: `pollfds` is a valid pointer to an array of initialized
+        // `pollfd` structs, whose length is passed as the second argument.
+        let ready = unsafe { libc::poll(pollfds.as_mut_ptr(), pollfds.len() as _, timeout_ms) };
+
+        if ready < 0 {
+            let err = std::io::Error::last_os_error();
+            return match err.kind() {
+                // the signal handler interrupted the wait, the socket
+                // will be readable on the next poll.
+                std::io::ErrorKind::Interrupted => Ok(None),
+                _ => Err(err),
+            };
+        }
+
+        if pollfds[0].revents & libc::POLLIN != 0 {
+            let mut buf = [0u8; 16];
+            while let Ok(n) = (&self.resize_signal).read(&mut buf) {
+                if n == 0 {
+                    break;
+                }
+            }
+            return Ok(Some(Readiness::Resize));
+        }
+
+        if pollfds[1].revents != 0 {
+            return Ok(Some(Readiness::Key));
+        }
+
+        Ok(None)
+    }
+
+    fn next_key(&mut self, readiness: Readiness) -> InquireResult<Option<crate::ui::Key>> {
+        match readiness {
+            Readiness::Resize => {
+                let (width, height) = terminal_size()?;
+                Ok(Some(crate::ui::Key::Resize(width, height)))
+            }
+            Readiness::Key => match self.events.next() {
+                Some(event) => match event? {
+                    Event::Unsupported(sequence) if sequence == PASTE_START => {
+                        self.read_paste().map(Some)
+                    }
+                    event => Ok(event_to_key(event)),
+                },
+                None => Ok(None),
+            },
+        }
+    }
+
+    /// Reads the content of a bracketed paste, up until its closing sequence.
+    fn read_paste(&mut self) -> InquireResult<crate::ui::Key> {
+        let mut text = String::new();
+
+        for event in &mut self.events {
+            match event? {
+                Event::Unsupported(sequence) if sequence == PASTE_END => break,
+                Event::Key(Key::Char(c)) => text.push(c),
+                _ => {}
+            }
+        }
+
+        Ok(crate::ui::Key::Paste(text))
+    }
+}
+
+/// Converts a termion event into a key, when it is one we care about.
+fn event_to_key(event: Event) -> Option<crate::ui::Key> {
+    use crate::ui::MouseEventKind;
+
+    match event {
+        Event::Key(key) => termion_key_to_key(key),
+        Event::Unsupported(sequence) => modified_key_from_sequence(&sequence),
+        Event::Mouse(MouseEvent::Press(button, column, row)) => {
+            let kind = match button {
+                MouseButton::Left => MouseEventKind::LeftClick,
+                MouseButton::WheelUp => MouseEventKind::ScrollUp,
+                MouseButton::WheelDown => MouseEventKind::ScrollDown,
+                _ => return None,
+            };
+
+            // termion reports one-based coordinates
+            Some(crate::ui::Key::Mouse(crate::ui::MouseEvent {
+                kind,
+                column: column.saturating_sub(1),
+                row: row.saturating_sub(1),
+            }))
+        }
+        Event::Mouse(_) => None,
+    }
+}
+
+impl Drop for TermionKeyReader {
+    fn drop(&mut self) {
+        signal_hook::low_level::unregister(self.resize_signal_id);
+    }
 }
 
 impl InputReader for TermionKeyReader {
     fn read_key(&mut self) -> InquireResult<crate::ui::Key> {
         loop {
-            if let Some(key) = self.keys.next() {
-                let key = key?;
-                return Ok(key.into());
+            if let Some(readiness) = self.wait_for_input(None)? {
+                if let Some(key) = self.next_key(readiness)? {
+                    return Ok(key);
+                }
             }
         }
     }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<crate::ui::Key>> {
+        match self.wait_for_input(Some(timeout))? {
+            Some(readiness) => self.next_key(readiness),
+            None => Ok(None),
+        }
+    }
 }
 
+/// Escape sequences that toggle mouse reporting, in both the normal and the
+/// SGR/urxvt extended coordinate encodings.
+const ENABLE_MOUSE_CAPTURE: &str = "\x1b[?1000h\x1b[?1002h\x1b[?1015h\x1b[?1006h";
+const DISABLE_MOUSE_CAPTURE: &str = "\x1b[?1006l\x1b[?1015l\x1b[?1002l\x1b[?1000l";
+
+/// Escape sequences that toggle bracketed paste, and those surrounding the
+/// pasted content, which termion does not recognize.
+const ENABLE_BRACKETED_PASTE: &str = "\x1b[?2004h";
+const DISABLE_BRACKETED_PASTE: &str = "\x1b[?2004l";
+const PASTE_START: &[u8] = b"\x1b[200~";
+const PASTE_END: &[u8] = b"\x1b[201~";
+
 pub struct TermionTerminal<'a> {
     io: IO<'a>,
+    mouse_capture: bool,
+    color_support: ColorSupport,
 }
 
 impl<'a> TermionTerminal<'a> {
     #[allow(unused)]
-    pub fn new() -> InquireResult<Self> {
+    pub fn new(output: &Output) -> InquireResult<Self> {
         let tty = termion::get_tty()?;
         let raw_terminal = tty.into_raw_mode()?;
         let keys = raw_terminal.try_clone()?.keys();
 
-        Ok(Self {
-            io: IO::TTY(raw_terminal),
-        })
+        let mut terminal = Self {
+            io: IO::TTY {
+                tty: raw_terminal,
+                output: output.open()?,
+            },
+            mouse_capture: false,
+            color_support: ColorSupport::detect(),
+        };
+        write!(terminal.get_writer(), "{}", ENABLE_BRACKETED_PASTE)?;
+
+        Ok(terminal)
     }
 
     /// # Errors
@@ -74,12 +252,14 @@ impl<'a> TermionTerminal<'a> {
     pub fn new_with_writer<W: 'a + Write>(writer: &'a mut W) -> Self {
         Self {
             io: IO::Custom(writer),
+            mouse_capture: false,
+            color_support: ColorSupport::TrueColor,
         }
     }
 
     fn get_writer(&mut self) -> &mut dyn Write {
         match &mut self.io {
-            IO::TTY(w) => w,
+            IO::TTY { output, .. } => output,
             IO::Custom(w) => w,
         }
     }
@@ -149,6 +329,22 @@ impl<'a> Terminal for TermionTerminal<'a> {
         write!(self.get_writer(), "\x1b[{}G", idx.saturating_add(1))
     }
 
+    fn cursor_position(&mut self) -> Result<Position> {
+        match &mut self.io {
+            IO::TTY { tty, .. } => tty.cursor_pos().map(|(col, row)| Position {
+                row: row.saturating_sub(1),
+                col: col.saturating_sub(1),
+            }),
+            IO::Custom(_) => Ok(Position::default()),
+        }
+    }
+
+    fn enable_mouse_capture(&mut self) -> Result<()> {
+        write!(self.get_writer(), "{}", ENABLE_MOUSE_CAPTURE)?;
+        self.mouse_capture = true;
+        self.flush()
+    }
+
     fn flush(&mut self) -> Result<()> {
         self.get_writer().flush()
     }
@@ -162,25 +358,27 @@ impl<'a> Terminal for TermionTerminal<'a> {
     }
 
     fn write_styled<T: std::fmt::Display>(&mut self, val: &Styled<T>) -> Result<()> {
-        if let Some(color) = val.style.fg {
+        let style = val.style.downgrade(self.color_support);
+
+        if let Some(color) = style.fg {
             self.set_fg_color(color)?;
         }
-        if let Some(color) = val.style.bg {
+        if let Some(color) = style.bg {
             self.set_bg_color(color)?;
         }
-        if !val.style.att.is_empty() {
-            self.set_attributes(val.style.att)?;
+        if !style.att.is_empty() {
+            self.set_attributes(style.att)?;
         }
 
         self.write(&val.content)?;
 
-        if val.style.fg.as_ref().is_some() {
+        if style.fg.is_some() {
             self.reset_fg_color()?;
         }
-        if val.style.bg.as_ref().is_some() {
+        if style.bg.is_some() {
             self.reset_bg_color()?;
         }
-        if !val.style.att.is_empty() {
+        if !style.att.is_empty() {
             self.reset_attributes()?;
         }
 
@@ -206,6 +404,12 @@ impl<'a> Terminal for TermionTerminal<'a> {
 
 impl<'a> Drop for TermionTerminal<'a> {
     fn drop(&mut self) {
+        if self.mouse_capture {
+            let _unused = write!(self.get_writer(), "{}", DISABLE_MOUSE_CAPTURE);
+        }
+        if let IO::TTY { .. } = self.io {
+            let _unused = write!(self.get_writer(), "{}", DISABLE_BRACKETED_PASTE);
+        }
         let _unused = self.flush();
     }
 }
@@ -246,41 +450,173 @@ impl Color for crate::ui::Color {
     }
 }
 
-impl From<Key> for crate::ui::Key {
-    fn from(key: Key) -> Self {
-        use crate::ui::KeyModifiers;
-
-        match key {
-            Key::Esc => Self::Escape,
-            Key::Char('\n' | '\r') => Self::Enter,
-            Key::Char('\t') => Self::Tab,
-            Key::Backspace => Self::Backspace,
-            Key::Delete => Self::Delete(KeyModifiers::empty()),
-            Key::Home => Self::Home,
-            Key::End => Self::End,
-            Key::PageUp => Self::PageUp(KeyModifiers::empty()),
-            Key::PageDown => Self::PageDown(KeyModifiers::empty()),
-            Key::Up => Self::Up(KeyModifiers::empty()),
-            Key::Down => Self::Down(KeyModifiers::empty()),
-            Key::Left => Self::Left(KeyModifiers::empty()),
-            Key::Right => Self::Right(KeyModifiers::empty()),
-            Key::Char(c) => Self::Char(c, KeyModifiers::empty()),
-            Key::Ctrl(c) => Self::Char(c, KeyModifiers::CONTROL),
-            Key::Alt(c) => Self::Char(c, KeyModifiers::ALT),
-            #[allow(deprecated)]
-            _ => Self::Any,
-        }
-    }
+/// Converts a termion key into a key, when it is one we care about.
+fn termion_key_to_key(key: Key) -> Option<crate::ui::Key> {
+    use crate::ui::{Key as K, KeyModifiers};
+
+    let none = KeyModifiers::NONE;
+
+    let key = match key {
+        Key::Esc => K::Escape(none),
+        Key::Char('\n' | '\r') => K::Enter(none),
+        Key::Char('\t') => K::Tab(none),
+        Key::BackTab => K::BackTab(none),
+        Key::Backspace => K::Backspace(none),
+        Key::Insert => K::Insert(none),
+        Key::Delete => K::Delete(none),
+        Key::Home => K::Home(none),
+        Key::End => K::End(none),
+        Key::PageUp => K::PageUp(none),
+        Key::PageDown => K::PageDown(none),
+        Key::Up => K::Up(none),
+        Key::Down => K::Down(none),
+        Key::Left => K::Left(none),
+        Key::Right => K::Right(none),
+        Key::F(n) => K::F(n, none),
+        Key::Char(c) => K::Char(c, none),
+        Key::Ctrl(c) => K::Char(c, KeyModifiers::CONTROL),
+        Key::Alt('\n' | '\r') => K::Enter(KeyModifiers::ALT),
+        Key::Alt(c) => K::Char(c, KeyModifiers::ALT),
+        _ => return None,
+    };
+
+    Some(key)
+}
+
+/// Parses the xterm sequences of keys pressed with modifiers, such as
+/// `ESC [ 1 ; 5 D` for Ctrl+Left, which termion reports as unsupported.
+fn modified_key_from_sequence(sequence: &[u8]) -> Option<crate::ui::Key> {
+    use crate::ui::{Key as K, KeyModifiers};
+
+    let (&last, params) = sequence.strip_prefix(b"\x1b[")?.split_last()?;
+    let params = std::str::from_utf8(params)
+        .ok()?
+        .split(';')
+        .map(|p| p.parse().ok())
+        .collect::<Option<Vec<u8>>>()?;
+
+    // The modifiers parameter is one plus a bitmask of the modifiers held.
+    let modifiers = |param: u8| {
+        let bits = param.saturating_sub(1);
+        [
+            (1, KeyModifiers::SHIFT),
+            (2, KeyModifiers::ALT),
+            (4, KeyModifiers::CONTROL),
+            (8, KeyModifiers::META),
+        ]
+        .iter()
+        .filter(|(bit, _)| bits & bit != 0)
+        .fold(KeyModifiers::NONE, |acc, (_, m)| acc | *m)
+    };
+
+    let key = match (last, params.as_slice()) {
+        // modifyOtherKeys encoding, e.g. Ctrl+Enter.
+        (b'~', &[27, m, code]) => match code {
+            13 => K::Enter(modifiers(m)),
+            9 => K::Tab(modifiers(m)),
+            27 => K::Escape(modifiers(m)),
+            127 => K::Backspace(modifiers(m)),
+            code => K::Char(code.into(), modifiers(m)),
+        },
+        (b'~', &[n, m]) => match n {
+            1 | 7 => K::Home(modifiers(m)),
+            2 => K::Insert(modifiers(m)),
+            3 => K::Delete(modifiers(m)),
+            4 | 8 => K::End(modifiers(m)),
+            5 => K::PageUp(modifiers(m)),
+            6 => K::PageDown(modifiers(m)),
+            11..=15 => K::F(n - 10, modifiers(m)),
+            17..=21 => K::F(n - 11, modifiers(m)),
+            23..=24 => K::F(n - 12, modifiers(m)),
+            _ => return None,
+        },
+        (_, &[1, m]) => match last {
+            b'A' => K::Up(modifiers(m)),
+            b'B' => K::Down(modifiers(m)),
+            b'C' => K::Right(modifiers(m)),
+            b'D' => K::Left(modifiers(m)),
+            b'H' => K::Home(modifiers(m)),
+            b'F' => K::End(modifiers(m)),
+            b'P'..=b'S' => K::F(last - b'P' + 1, modifiers(m)),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(key)
 }
 
 #[cfg(test)]
 mod test {
+    use termion::input::TermRead;
+
     use crate::terminal::Terminal;
-    use crate::ui::Color;
+    use crate::ui::{Color, Key, KeyModifiers};
 
     use super::Attributes;
     use super::TermionTerminal;
 
+    fn key_from_bytes(bytes: &[u8]) -> Option<Key> {
+        let event = bytes.events().next().unwrap().unwrap();
+        super::event_to_key(event)
+    }
+
+    fn native_key(name: &str) -> &'static [u8] {
+        match name {
+            "esc" => b"\x1b",
+            "enter" => b"\r",
+            "tab" => b"\t",
+            "shift+tab" => b"\x1b[Z",
+            "backspace" => b"\x7f",
+            "insert" => b"\x1b[2~",
+            "delete" => b"\x1b[3~",
+            "home" => b"\x1b[H",
+            "end" => b"\x1b[F",
+            "pageup" => b"\x1b[5~",
+            "pagedown" => b"\x1b[6~",
+            "up" => b"\x1b[A",
+            "down" => b"\x1b[B",
+            "left" => b"\x1b[D",
+            "right" => b"\x1b[C",
+            "a" => b"a",
+            "ctrl+c" => b"\x03",
+            "ctrl+d" => b"\x04",
+            _ => panic!("no termion sequence for {}", name),
+        }
+    }
+
+    #[test]
+    fn maps_common_keys() {
+        for (name, expected) in crate::terminal::COMMON_KEYS {
+            assert_eq!(
+                Some(expected.clone()),
+                key_from_bytes(native_key(name)),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn maps_keys_with_modifiers() {
+        let cases: [(&[u8], Key); 9] = [
+            (b"\x1bOP", Key::F(1, KeyModifiers::NONE)),
+            (b"\x1b[24~", Key::F(12, KeyModifiers::NONE)),
+            (b"\x1b[1;2P", Key::F(1, KeyModifiers::SHIFT)),
+            (b"\x1b[24;5~", Key::F(12, KeyModifiers::CONTROL)),
+            (b"\x1b[1;5D", Key::Left(KeyModifiers::CONTROL)),
+            (b"\x1b[1;2H", Key::Home(KeyModifiers::SHIFT)),
+            (b"\x1b[3;3~", Key::Delete(KeyModifiers::ALT)),
+            (b"\x1b[27;5;13~", Key::Enter(KeyModifiers::CONTROL)),
+            (b"\x1ba", Key::Char('a', KeyModifiers::ALT)),
+        ];
+
+        for (bytes, expected) in cases {
+            assert_eq!(Some(expected), key_from_bytes(bytes));
+        }
+
+        assert_eq!(None, key_from_bytes(b"\x1b[99;5~"));
+    }
+
     #[test]
     fn writer() {
         let mut write: Vec<u8> = Vec::new();
@@ -73,6 +73,56 @@ use crate::error::CustomUserError;
 /// ```
 pub type Scorer<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> Option<i64>;
 
+/// Type alias to represent a function used to score and filter options, like
+/// [`Scorer`], that also returns which characters of the option matched the
+/// input, so that they can be highlighted.
+///
+/// The function receives the same arguments as a [`Scorer`], except that the
+/// string value of the option is stripped of its ANSI escape sequences.
+///
+/// Along with the score, it returns the indices of the matched characters of
+/// the string value, counted in `char`s, which are rendered with the
+/// [`matched_text`](crate::ui::RenderConfig::matched_text) style sheet.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::MatchScorer;
+///
+/// // Case-sensitive prefix filter, highlighting the prefix
+/// let scorer: MatchScorer<&str> = &|input, _option, string_value, _idx| {
+///     match string_value.starts_with(input) {
+///         true => Some((0, (0..input.chars().count()).collect())),
+///         false => None,
+///     }
+/// };
+///
+/// assert_eq!(Some((0, vec![0, 1])), scorer("Sa", &"San Jose", "San Jose", 0));
+/// assert_eq!(None, scorer("Sa", &"Mesa", "Mesa", 1));
+/// ```
+pub type MatchScorer<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> Option<(i64, Vec<usize>)>;
+
+/// Type alias to represent the function returning the help message of an option
+/// of [`Select`](crate::Select) and [`MultiSelect`](crate::MultiSelect) prompts.
+///
+/// While an option with a help message is highlighted, its help message is
+/// displayed instead of the help message of the prompt.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::OptionHelp;
+///
+/// let option_help: OptionHelp<&str> = &|option| match *option {
+///     "Pro" => Some("Unlimited projects"),
+///     _ => None,
+/// };
+///
+/// assert_eq!(Some("Unlimited projects"), option_help(&"Pro"));
+/// assert_eq!(None, option_help(&"Free"));
+/// ```
+pub type OptionHelp<'a, T> = &'a dyn Fn(&T) -> Option<&'a str>;
+
 /// Type alias to represent the function used to retrieve text input suggestions.
 /// The function receives the current input and should return a collection of strings
 /// containing the suggestions to be made to the user.
@@ -0,0 +1,206 @@
+//! Append-only backend of the accessible mode, see
+//! [`set_global_accessible_mode`](crate::set_global_accessible_mode).
+
+use std::io::{self, BufRead, Write};
+
+use crate::{
+    error::{InquireError, InquireResult},
+    terminal::get_default_terminal,
+    ui::{InputReader, Key, KeyModifiers, Output},
+};
+
+/// Source of the lines typed by the user in the accessible mode.
+pub(crate) trait LineReader {
+    /// Reads a line, without its line terminator, returning `None` at the
+    /// end of the input.
+    fn read_line(&mut self) -> InquireResult<Option<String>>;
+
+    /// Reads a line without echoing it, returning `None` at the end of the
+    /// input.
+    fn read_hidden_line(&mut self) -> InquireResult<Option<String>>;
+}
+
+/// Reads lines from the standard input, letting the terminal and the screen
+/// reader handle their edition and echo.
+pub(crate) struct StdinLineReader {
+    output: Output,
+}
+
+impl StdinLineReader {
+    pub fn new(output: &Output) -> Self {
+        Self {
+            output: output.clone(),
+        }
+    }
+}
+
+impl LineReader for StdinLineReader {
+    fn read_line(&mut self) -> InquireResult<Option<String>> {
+        read_line(&mut io::stdin().lock())
+    }
+
+    /// Reads the keys typed in the terminal, without rendering anything.
+    /// When no terminal is available, e.g. in non-interactive sessions, the
+    /// line is read from the standard input instead.
+    fn read_hidden_line(&mut self) -> InquireResult<Option<String>> {
+        let (mut input_reader, terminal) = match get_default_terminal(&self.output) {
+            Ok(terminal) => terminal,
+            Err(_) => return self.read_line(),
+        };
+
+        let mut line = String::new();
+        let result = loop {
+            match input_reader.read_key()? {
+                Key::Enter(_) => break Ok(Some(line)),
+                Key::Escape(_) => break Err(InquireError::OperationCanceled),
+                Key::Char('c', modifiers) if modifiers.contains(KeyModifiers::CONTROL) => {
+                    break Err(InquireError::OperationInterrupted)
+                }
+                Key::Char('d', modifiers)
+                    if modifiers.contains(KeyModifiers::CONTROL) && line.is_empty() =>
+                {
+                    break Ok(None)
+                }
+                Key::Backspace(_) => {
+                    line.pop();
+                }
+                Key::Char(c, modifiers) if !modifiers.contains(KeyModifiers::CONTROL) => {
+                    line.push(c);
+                }
+                Key::Paste(text) => line.push_str(text.lines().next().unwrap_or_default()),
+                _ => {}
+            }
+        };
+
+        // leaves raw mode before the line break is printed
+        drop(terminal);
+        result
+    }
+}
+
+fn read_line<R: BufRead>(reader: &mut R) -> InquireResult<Option<String>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+
+    let len = line.trim_end_matches(&['\r', '\n'][..]).len();
+    line.truncate(len);
+    Ok(Some(line))
+}
+
+#[cfg(test)]
+impl LineReader for std::collections::VecDeque<String> {
+    fn read_line(&mut self) -> InquireResult<Option<String>> {
+        Ok(self.pop_front())
+    }
+
+    fn read_hidden_line(&mut self) -> InquireResult<Option<String>> {
+        Ok(self.pop_front())
+    }
+}
+
+/// Backend of the accessible mode, printing plain lines one after the other,
+/// without colors nor cursor movements, and reading answers line by line.
+pub(crate) struct AccessibleBackend<R, W> {
+    reader: R,
+    writer: W,
+}
+
+impl AccessibleBackend<StdinLineReader, Box<dyn Write + Send>> {
+    /// Creates a backend printing to `output` and reading the standard input.
+    pub fn new(output: &Output) -> InquireResult<Self> {
+        Ok(Self {
+            reader: StdinLineReader::new(output),
+            writer: output.open()?,
+        })
+    }
+}
+
+impl<R, W> AccessibleBackend<R, W>
+where
+    R: LineReader,
+    W: Write,
+{
+    #[cfg(test)]
+    pub fn with_reader_and_writer(reader: R, writer: W) -> Self {
+        Self { reader, writer }
+    }
+
+    #[cfg(test)]
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Prints a line.
+    pub fn print_line(&mut self, line: &str) -> InquireResult<()> {
+        writeln!(self.writer, "{}", line)?;
+        self.writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Prints an error message, announcing that the answer was rejected.
+    pub fn print_error(&mut self, message: &str) -> InquireResult<()> {
+        self.print_line(&format!("Error: {}", message))
+    }
+
+    /// Prints the final answer to a prompt.
+    pub fn print_answer(&mut self, answer: &str) -> InquireResult<()> {
+        self.print_line(&format!("Answer: {}", answer))
+    }
+
+    /// Reads an answer. The prompt is canceled at the end of the input or
+    /// when the line only contains an escape character, typed with ESC.
+    pub fn read_answer(&mut self) -> InquireResult<String> {
+        let line = self.reader.read_line()?;
+        Self::answer_or_cancel(line)
+    }
+
+    /// Reads an answer spanning multiple lines, ended by an empty line.
+    pub fn read_multiline_answer(&mut self) -> InquireResult<String> {
+        let mut lines = vec![];
+
+        loop {
+            match self.read_answer()? {
+                line if line.is_empty() => break,
+                line => lines.push(line),
+            }
+        }
+
+        Ok(lines.join("\n"))
+    }
+
+    /// Reads an answer without echoing it.
+    pub fn read_hidden_answer(&mut self) -> InquireResult<String> {
+        let line = self.reader.read_hidden_line()?;
+        // the line break typed by the user was not echoed
+        self.print_line("")?;
+        Self::answer_or_cancel(line)
+    }
+
+    fn answer_or_cancel(line: Option<String>) -> InquireResult<String> {
+        match line {
+            Some(line) if line.trim() != "\x1b" => Ok(line),
+            _ => Err(InquireError::OperationCanceled),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::read_line;
+
+    #[test]
+    fn lines_are_read_without_terminators() {
+        let mut input = Cursor::new("first\r\nsecond\n\nlast");
+
+        assert_eq!(Some(String::from("first")), read_line(&mut input).unwrap());
+        assert_eq!(Some(String::from("second")), read_line(&mut input).unwrap());
+        assert_eq!(Some(String::new()), read_line(&mut input).unwrap());
+        assert_eq!(Some(String::from("last")), read_line(&mut input).unwrap());
+        assert_eq!(None, read_line(&mut input).unwrap());
+    }
+}
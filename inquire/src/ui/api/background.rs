@@ -0,0 +1,279 @@
+#[cfg(feature = "background-detection")]
+use std::{env, time::Duration};
+
+/// Brightness of the terminal background, used to pick between the light and
+/// dark variants of a render configuration, e.g. with
+/// [`RenderConfig::default_for_background`](crate::ui::RenderConfig::default_for_background).
+///
+/// With the `background-detection` feature, it can be detected with
+/// `Background::detect`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Background {
+    /// Dark background, where light colors are the most readable.
+    Dark,
+
+    /// Light background, where dark colors are the most readable.
+    Light,
+}
+
+#[cfg(feature = "background-detection")]
+#[cfg_attr(docsrs, doc(cfg(feature = "background-detection")))]
+impl Background {
+    /// Time waited by [`Background::detect`] for the terminal to report its
+    /// background color.
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(100);
+
+    /// Detects the background of the terminal, waiting up to
+    /// [`Background::DEFAULT_TIMEOUT`] for it to answer.
+    ///
+    /// See [`Background::detect_with_timeout`].
+    pub fn detect() -> Option<Self> {
+        Self::detect_with_timeout(Self::DEFAULT_TIMEOUT)
+    }
+
+    /// Detects the background of the terminal.
+    ///
+    /// On Unix, the terminal is asked for its background color with an
+    /// `OSC 11` query, waiting up to `timeout` for the answer. Terminals that
+    /// do not answer it, and other platforms, fall back to the `COLORFGBG`
+    /// environment variable set by some terminals, such as `rxvt` and
+    /// Konsole.
+    ///
+    /// Returns `None` when the background could not be detected.
+    pub fn detect_with_timeout(timeout: Duration) -> Option<Self> {
+        #[cfg(unix)]
+        if let Some(background) = osc::query_background(timeout) {
+            return Some(background);
+        }
+        #[cfg(not(unix))]
+        let _ = timeout;
+
+        env::var("COLORFGBG")
+            .ok()
+            .and_then(|value| Self::from_colorfgbg(&value))
+    }
+
+    /// Background of an RGB color, from its relative luminance.
+    fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        let luminance = 0.2126 * f32::from(r) + 0.7152 * f32::from(g) + 0.0722 * f32::from(b);
+
+        if luminance > 127.5 {
+            Background::Light
+        } else {
+            Background::Dark
+        }
+    }
+
+    /// Parses `COLORFGBG`, formatted as `fg;bg` or `fg;default;bg`, where
+    /// colors are ANSI values. The background is light when it is white or
+    /// one of the light colors, except for dark grey.
+    fn from_colorfgbg(value: &str) -> Option<Self> {
+        let bg: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+
+        match bg {
+            7 | 9..=15 => Some(Background::Light),
+            0..=6 | 8 => Some(Background::Dark),
+            _ => None,
+        }
+    }
+
+    /// Parses the answer to an `OSC 11` query, e.g.
+    /// `ESC ] 11 ; rgb:ffff/ffff/dddd BEL`, whose channels have 1 to 4 hex
+    /// digits. Any bytes around the answer are ignored.
+    fn from_osc11_response(response: &[u8]) -> Option<Self> {
+        let response = String::from_utf8_lossy(response);
+        let start = response.find("\x1b]11;")?;
+        let answer = &response[start + 5..];
+        let end = answer.find(['\x07', '\x1b'])?;
+
+        let mut channels = answer[..end].strip_prefix("rgb:")?.split('/').map(|hex| {
+            if hex.is_empty() || hex.len() > 4 {
+                return None;
+            }
+            let value = u32::from_str_radix(hex, 16).ok()?;
+            let max = 16u32.pow(hex.len() as u32) - 1;
+            Some((value * 255 / max) as u8)
+        });
+
+        let r = channels.next()??;
+        let g = channels.next()??;
+        let b = channels.next()??;
+        if channels.next().is_some() {
+            return None;
+        }
+
+        Some(Self::from_rgb(r, g, b))
+    }
+}
+
+#[cfg(all(unix, feature = "background-detection"))]
+mod osc {
+    use std::{
+        fs::{File, OpenOptions},
+        io::{Read, Write},
+        os::unix::io::AsRawFd,
+        time::{Duration, Instant},
+    };
+
+    use super::Background;
+
+    /// Asks for the background color, followed by a primary device
+    /// attributes request. Every terminal answers the latter, so receiving
+    /// its answer first means the background query is not supported and
+    /// there is no need to wait for the timeout.
+    const QUERY: &[u8] = b"\x1b]11;?\x07\x1b[c";
+
+    pub(super) fn query_background(timeout: Duration) -> Option<Background> {
+        let mut tty = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open("/dev/tty")
+            .ok()?;
+
+        let original = RawMode::enable(&tty)?;
+        let response = read_response(&mut tty, timeout);
+        original.restore(&tty);
+
+        Background::from_osc11_response(&response?)
+    }
+
+    fn read_response(tty: &mut File, timeout: Duration) -> Option<Vec<u8>> {
+        tty.write_all(QUERY).ok()?;
+        tty.flush().ok()?;
+
+        let deadline = Instant::now() + timeout;
+        let mut response = vec![];
+        let mut buf = [0u8; 64];
+
+        while !answered_device_attributes(&response) {
+            let remaining = match deadline.checked_duration_since(Instant::now()) {
+                Some(remaining) => remaining,
+                None => break,
+            };
+            if !wait_for_input(tty, remaining) {
+                break;
+            }
+
+            let n = tty.read(&mut buf).ok()?;
+            if n == 0 {
+                break;
+            }
+            response.extend_from_slice(&buf[..n]);
+        }
+
+        Some(response)
+    }
+
+    /// Whether the answer to the device attributes request, formatted as
+    /// `ESC [ ? ... c`, was received.
+    fn answered_device_attributes(response: &[u8]) -> bool {
+        response
+            .windows(3)
+            .position(|w| w == b"\x1b[?")
+            .and_then(|start| response[start..].iter().position(|&b| b == b'c'))
+            .is_some()
+    }
+
+    #[allow(unsafe_code)]
+    fn wait_for_input(tty: &File, timeout: Duration) -> bool {
+        let mut pollfd = libc::pollfd {
+            fd: tty.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout_ms = timeout.as_millis().min(libc::c_int::MAX as u128) as libc::c_int;
+
+        // SAFETY: `pollfd` is a valid pointer to a single initialized
+        // `pollfd` struct, matching the length passed as second argument.
+        let ready = unsafe { libc::poll(&mut pollfd, 1, timeout_ms) };
+
+        ready > 0 && pollfd.revents & libc::POLLIN != 0
+    }
+
+    /// Terminal settings replaced while waiting for the answer, so that it is
+    /// neither echoed nor line buffered.
+    struct RawMode(libc::termios);
+
+    impl RawMode {
+        #[allow(unsafe_code)]
+        fn enable(tty: &File) -> Option<Self> {
+            let fd = tty.as_raw_fd();
+
+            // SAFETY: `termios` is a plain C struct for which zeroed memory is
+            // valid, and it is filled by `tcgetattr` before being read.
+            let mut original: libc::termios = unsafe { std::mem::zeroed() };
+            // SAFETY: `fd` is an open file descriptor and `original` a valid
+            // pointer to a `termios` struct.
+            if unsafe { libc::tcgetattr(fd, &mut original) } != 0 {
+                return None;
+            }
+
+            let mut raw = original;
+            // SAFETY: `raw` is a valid pointer to an initialized `termios`.
+            unsafe { libc::cfmakeraw(&mut raw) };
+            // SAFETY: `fd` is an open file descriptor and `raw` a valid
+            // pointer to an initialized `termios` struct.
+            if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw) } != 0 {
+                return None;
+            }
+
+            Some(Self(original))
+        }
+
+        #[allow(unsafe_code)]
+        fn restore(self, tty: &File) {
+            // SAFETY: `fd` is an open file descriptor and `self.0` a valid
+            // pointer to the `termios` struct previously read from it.
+            unsafe { libc::tcsetattr(tty.as_raw_fd(), libc::TCSANOW, &self.0) };
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "background-detection")]
+mod test {
+    use super::Background;
+
+    #[test]
+    fn osc11_responses_are_parsed() {
+        let light = b"\x1b]11;rgb:ffff/ffff/dddd\x07\x1b[?62;22c";
+        let dark = b"\x1b]11;rgb:0000/2b2b/3636\x1b\\";
+        let short = b"\x1b]11;rgb:f/f/f\x07";
+
+        assert_eq!(
+            Some(Background::Light),
+            Background::from_osc11_response(light)
+        );
+        assert_eq!(
+            Some(Background::Dark),
+            Background::from_osc11_response(dark)
+        );
+        assert_eq!(
+            Some(Background::Light),
+            Background::from_osc11_response(short)
+        );
+    }
+
+    #[test]
+    fn unanswered_osc11_queries_are_not_parsed() {
+        assert_eq!(None, Background::from_osc11_response(b"\x1b[?62;22c"));
+        assert_eq!(None, Background::from_osc11_response(b""));
+        assert_eq!(
+            None,
+            Background::from_osc11_response(b"\x1b]11;rgb:ffff/ffff\x07")
+        );
+    }
+
+    #[test]
+    fn colorfgbg_is_parsed() {
+        assert_eq!(Some(Background::Dark), Background::from_colorfgbg("15;0"));
+        assert_eq!(Some(Background::Light), Background::from_colorfgbg("0;15"));
+        assert_eq!(
+            Some(Background::Light),
+            Background::from_colorfgbg("0;default;7")
+        );
+        assert_eq!(Some(Background::Dark), Background::from_colorfgbg("7;8"));
+        assert_eq!(None, Background::from_colorfgbg("default"));
+        assert_eq!(None, Background::from_colorfgbg("0;255"));
+    }
+}
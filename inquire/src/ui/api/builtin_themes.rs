@@ -0,0 +1,435 @@
+use super::{Attributes, Color, ErrorMessageRenderConfig, RenderConfig, StyleSheet, Styled};
+use crate::formatter::DEFAULT_COUNTDOWN_FORMATTER;
+
+#[cfg(feature = "date")]
+use super::calendar::CalendarRenderConfig;
+
+/// Colors of the Solarized palette, by Ethan Schoonover, except for the
+/// darkest and lightest bases used as terminal backgrounds.
+mod solarized {
+    use crate::ui::Color;
+
+    pub const BASE02: Color = Color::Rgb {
+        r: 0x07,
+        g: 0x36,
+        b: 0x42,
+    };
+    pub const BASE01: Color = Color::Rgb {
+        r: 0x58,
+        g: 0x6e,
+        b: 0x75,
+    };
+    pub const BASE00: Color = Color::Rgb {
+        r: 0x65,
+        g: 0x7b,
+        b: 0x83,
+    };
+    pub const BASE0: Color = Color::Rgb {
+        r: 0x83,
+        g: 0x94,
+        b: 0x96,
+    };
+    pub const BASE1: Color = Color::Rgb {
+        r: 0x93,
+        g: 0xa1,
+        b: 0xa1,
+    };
+    pub const BASE2: Color = Color::Rgb {
+        r: 0xee,
+        g: 0xe8,
+        b: 0xd5,
+    };
+    pub const YELLOW: Color = Color::Rgb {
+        r: 0xb5,
+        g: 0x89,
+        b: 0x00,
+    };
+    pub const RED: Color = Color::Rgb {
+        r: 0xdc,
+        g: 0x32,
+        b: 0x2f,
+    };
+    #[cfg(feature = "editor")]
+    pub const VIOLET: Color = Color::Rgb {
+        r: 0x6c,
+        g: 0x71,
+        b: 0xc4,
+    };
+    pub const BLUE: Color = Color::Rgb {
+        r: 0x26,
+        g: 0x8b,
+        b: 0xd2,
+    };
+    pub const CYAN: Color = Color::Rgb {
+        r: 0x2a,
+        g: 0xa1,
+        b: 0x98,
+    };
+    pub const GREEN: Color = Color::Rgb {
+        r: 0x85,
+        g: 0x99,
+        b: 0x00,
+    };
+}
+
+/// Colors of the Okabe-Ito palette, distinguishable with the most common
+/// forms of color blindness.
+mod okabe_ito {
+    use crate::ui::Color;
+
+    pub const ORANGE: Color = Color::Rgb {
+        r: 0xe6,
+        g: 0x9f,
+        b: 0x00,
+    };
+    pub const SKY_BLUE: Color = Color::Rgb {
+        r: 0x56,
+        g: 0xb4,
+        b: 0xe9,
+    };
+    pub const VERMILLION: Color = Color::Rgb {
+        r: 0xd5,
+        g: 0x5e,
+        b: 0x00,
+    };
+}
+
+/// Built-in render configurations, in addition to
+/// [`RenderConfig::default_colored`] and [`RenderConfig::empty`].
+///
+/// Configurations made of RGB colors are downgraded to the nearest colors
+/// supported by the terminal, see [`ColorSupport`](crate::ui::ColorSupport).
+impl<'a> RenderConfig<'a> {
+    /// Variant of [`RenderConfig::default_colored`] for terminals with a
+    /// light background, using dark colors in place of light ones.
+    pub fn default_colored_light() -> Self {
+        let dark = Self::default_colored();
+
+        Self {
+            prompt_prefix: Styled::new("?").with_fg(Color::DarkGreen),
+            answered_prompt_prefix: Styled::new(">").with_fg(Color::DarkGreen),
+            vim_normal_prompt_prefix: Styled::new(":").with_fg(Color::DarkYellow),
+            help_message: StyleSheet::new().with_fg(Color::DarkCyan),
+            error_message: ErrorMessageRenderConfig::empty()
+                .with_prefix(Styled::new("#").with_fg(Color::DarkRed))
+                .with_message(StyleSheet::new().with_fg(Color::DarkRed)),
+            answer: StyleSheet::new().with_fg(Color::DarkCyan),
+            highlighted_option_prefix: Styled::new(">").with_fg(Color::DarkCyan),
+            selected_checkbox: Styled::new("[x]").with_fg(Color::DarkGreen),
+            selected_option: Some(StyleSheet::new().with_fg(Color::DarkCyan)),
+            matched_text: StyleSheet::new()
+                .with_fg(Color::DarkCyan)
+                .with_attr(Attributes::BOLD),
+
+            #[cfg(feature = "date")]
+            calendar: CalendarRenderConfig {
+                prefix: Styled::new(">").with_fg(Color::DarkGreen),
+                selected_date: Some(
+                    StyleSheet::new()
+                        .with_fg(Color::White)
+                        .with_bg(Color::DarkGrey),
+                ),
+                today_date: StyleSheet::new().with_fg(Color::DarkGreen),
+                ..dark.calendar
+            },
+
+            #[cfg(feature = "editor")]
+            editor_prompt: StyleSheet::new().with_fg(Color::DarkBlue),
+
+            ..dark
+        }
+    }
+
+    /// High contrast render configuration for dark backgrounds, made of white
+    /// text and bright, bold highlights.
+    pub fn high_contrast() -> Self {
+        let bold = |color| StyleSheet::new().with_fg(color).with_attr(Attributes::BOLD);
+        let white = StyleSheet::new().with_fg(Color::White);
+
+        Self {
+            prompt_prefix: Styled::new("?").with_style_sheet(bold(Color::LightYellow)),
+            answered_prompt_prefix: Styled::new(">").with_style_sheet(bold(Color::LightGreen)),
+            vim_normal_prompt_prefix: Styled::new(":").with_style_sheet(bold(Color::LightCyan)),
+            prompt: bold(Color::White),
+            default_value: white,
+            placeholder: StyleSheet::new().with_fg(Color::Grey),
+            help_message: white,
+            text_input: white,
+            error_message: ErrorMessageRenderConfig::empty()
+                .with_prefix(Styled::new("#").with_style_sheet(bold(Color::LightRed)))
+                .with_message(bold(Color::LightRed)),
+            password_mask: '*',
+            answer: bold(Color::LightYellow),
+            canceled_prompt_indicator: Styled::new("<canceled>")
+                .with_style_sheet(bold(Color::LightRed)),
+            highlighted_option_prefix: Styled::new(">").with_style_sheet(bold(Color::LightYellow)),
+            scroll_up_prefix: Styled::new("^").with_style_sheet(white),
+            scroll_down_prefix: Styled::new("v").with_style_sheet(white),
+            selected_checkbox: Styled::new("[x]").with_style_sheet(bold(Color::LightGreen)),
+            unselected_checkbox: Styled::new("[ ]").with_style_sheet(white),
+            option_index_prefix: super::IndexPrefix::None,
+            option: white,
+            selected_option: Some(bold(Color::Black).with_bg(Color::LightYellow)),
+            suggestion_description: StyleSheet::new().with_fg(Color::Grey),
+            ghost_text: StyleSheet::new().with_fg(Color::Grey),
+            matched_text: bold(Color::LightCyan),
+            countdown_formatter: DEFAULT_COUNTDOWN_FORMATTER,
+
+            #[cfg(feature = "date")]
+            calendar: CalendarRenderConfig {
+                prefix: Styled::new(">").with_style_sheet(bold(Color::LightYellow)),
+                header: bold(Color::White),
+                week_header: white,
+                selected_date: Some(bold(Color::Black).with_bg(Color::LightYellow)),
+                today_date: bold(Color::LightGreen),
+                different_month_date: StyleSheet::new().with_fg(Color::Grey),
+                unavailable_date: StyleSheet::new().with_fg(Color::DarkGrey),
+            },
+
+            #[cfg(feature = "editor")]
+            editor_prompt: StyleSheet::new().with_fg(Color::LightYellow),
+        }
+    }
+
+    /// Render configuration with the Solarized palette, for terminals whose
+    /// background is the dark Solarized base.
+    pub fn solarized_dark() -> Self {
+        Self::solarized(
+            solarized::BASE0,
+            solarized::BASE1,
+            solarized::BASE01,
+            solarized::BASE02,
+        )
+    }
+
+    /// Render configuration with the Solarized palette, for terminals whose
+    /// background is the light Solarized base.
+    pub fn solarized_light() -> Self {
+        Self::solarized(
+            solarized::BASE00,
+            solarized::BASE01,
+            solarized::BASE1,
+            solarized::BASE2,
+        )
+    }
+
+    /// Solarized configuration from the tones of its background, which are
+    /// the only colors that differ between its dark and light variants.
+    fn solarized(body: Color, emphasized: Color, secondary: Color, highlight: Color) -> Self {
+        let fg = |color| StyleSheet::new().with_fg(color);
+
+        Self {
+            prompt_prefix: Styled::new("?").with_fg(solarized::GREEN),
+            answered_prompt_prefix: Styled::new(">").with_fg(solarized::GREEN),
+            vim_normal_prompt_prefix: Styled::new(":").with_fg(solarized::YELLOW),
+            prompt: fg(emphasized),
+            default_value: fg(body),
+            placeholder: fg(secondary),
+            help_message: fg(solarized::CYAN),
+            text_input: fg(body),
+            error_message: ErrorMessageRenderConfig::empty()
+                .with_prefix(Styled::new("#").with_fg(solarized::RED))
+                .with_message(fg(solarized::RED)),
+            password_mask: '*',
+            answer: fg(solarized::BLUE),
+            canceled_prompt_indicator: Styled::new("<canceled>").with_fg(solarized::RED),
+            highlighted_option_prefix: Styled::new(">").with_fg(solarized::YELLOW),
+            scroll_up_prefix: Styled::new("^").with_fg(secondary),
+            scroll_down_prefix: Styled::new("v").with_fg(secondary),
+            selected_checkbox: Styled::new("[x]").with_fg(solarized::GREEN),
+            unselected_checkbox: Styled::new("[ ]").with_fg(body),
+            option_index_prefix: super::IndexPrefix::None,
+            option: fg(body),
+            selected_option: Some(fg(solarized::BLUE).with_bg(highlight)),
+            suggestion_description: fg(secondary),
+            ghost_text: fg(secondary),
+            matched_text: fg(solarized::YELLOW),
+            countdown_formatter: DEFAULT_COUNTDOWN_FORMATTER,
+
+            #[cfg(feature = "date")]
+            calendar: CalendarRenderConfig {
+                prefix: Styled::new(">").with_fg(solarized::GREEN),
+                header: fg(emphasized),
+                week_header: fg(body),
+                selected_date: Some(fg(emphasized).with_bg(highlight)),
+                today_date: fg(solarized::GREEN),
+                different_month_date: fg(secondary),
+                unavailable_date: fg(secondary),
+            },
+
+            #[cfg(feature = "editor")]
+            editor_prompt: fg(solarized::VIOLET),
+        }
+    }
+
+    /// Render configuration with the Okabe-Ito palette, whose colors remain
+    /// distinguishable with the most common forms of color blindness. Errors
+    /// and highlights are also set in bold, so that they do not rely on
+    /// colors alone.
+    pub fn colorblind_safe() -> Self {
+        let fg = |color| StyleSheet::new().with_fg(color);
+        let bold = |color| fg(color).with_attr(Attributes::BOLD);
+
+        Self {
+            prompt_prefix: Styled::new("?").with_fg(okabe_ito::SKY_BLUE),
+            answered_prompt_prefix: Styled::new(">").with_fg(okabe_ito::SKY_BLUE),
+            vim_normal_prompt_prefix: Styled::new(":").with_fg(okabe_ito::ORANGE),
+            prompt: StyleSheet::empty(),
+            default_value: StyleSheet::empty(),
+            placeholder: fg(Color::DarkGrey),
+            help_message: fg(okabe_ito::SKY_BLUE),
+            text_input: StyleSheet::empty(),
+            error_message: ErrorMessageRenderConfig::empty()
+                .with_prefix(Styled::new("#").with_style_sheet(bold(okabe_ito::VERMILLION)))
+                .with_message(bold(okabe_ito::VERMILLION)),
+            password_mask: '*',
+            answer: fg(okabe_ito::SKY_BLUE),
+            canceled_prompt_indicator: Styled::new("<canceled>").with_fg(okabe_ito::VERMILLION),
+            highlighted_option_prefix: Styled::new(">").with_style_sheet(bold(okabe_ito::ORANGE)),
+            scroll_up_prefix: Styled::new("^"),
+            scroll_down_prefix: Styled::new("v"),
+            selected_checkbox: Styled::new("[x]").with_style_sheet(bold(okabe_ito::SKY_BLUE)),
+            unselected_checkbox: Styled::new("[ ]"),
+            option_index_prefix: super::IndexPrefix::None,
+            option: StyleSheet::empty(),
+            selected_option: Some(bold(okabe_ito::ORANGE)),
+            suggestion_description: StyleSheet::empty(),
+            ghost_text: fg(Color::DarkGrey),
+            matched_text: bold(okabe_ito::SKY_BLUE),
+            countdown_formatter: DEFAULT_COUNTDOWN_FORMATTER,
+
+            #[cfg(feature = "date")]
+            calendar: CalendarRenderConfig {
+                prefix: Styled::new(">").with_fg(okabe_ito::ORANGE),
+                header: StyleSheet::empty(),
+                week_header: StyleSheet::empty(),
+                selected_date: Some(fg(Color::Black).with_bg(okabe_ito::ORANGE)),
+                today_date: bold(okabe_ito::SKY_BLUE),
+                different_month_date: fg(Color::DarkGrey),
+                unavailable_date: fg(Color::DarkGrey),
+            },
+
+            #[cfg(feature = "editor")]
+            editor_prompt: fg(okabe_ito::SKY_BLUE),
+        }
+    }
+
+    /// Render configuration without colors, where bold and italic text
+    /// replace them, for terminals without color support or users who prefer
+    /// no colors.
+    pub fn monochrome() -> Self {
+        let bold = StyleSheet::new().with_attr(Attributes::BOLD);
+        let italic = StyleSheet::new().with_attr(Attributes::ITALIC);
+
+        let mut config = Self::empty()
+            .with_prompt_prefix(Styled::new("?").with_style_sheet(bold))
+            .with_answered_prompt_prefix(Styled::new(">").with_style_sheet(bold))
+            .with_vim_normal_prompt_prefix(Styled::new(":").with_style_sheet(bold))
+            .with_help_message(italic)
+            .with_error_message(
+                ErrorMessageRenderConfig::empty()
+                    .with_prefix(Styled::new("#").with_style_sheet(bold))
+                    .with_message(bold),
+            )
+            .with_answer(bold)
+            .with_canceled_prompt_indicator(Styled::new("<canceled>").with_style_sheet(italic))
+            .with_highlighted_option_prefix(Styled::new(">").with_style_sheet(bold))
+            .with_selected_checkbox(Styled::new("[x]").with_style_sheet(bold))
+            .with_selected_option(Some(bold))
+            .with_suggestion_description(italic)
+            .with_ghost_text(italic)
+            .with_matched_text(StyleSheet::new().with_attr(Attributes::BOLD | Attributes::ITALIC));
+        config.placeholder = italic;
+
+        #[cfg(feature = "date")]
+        {
+            config.calendar = CalendarRenderConfig {
+                header: bold,
+                today_date: bold,
+                different_month_date: italic,
+                unavailable_date: italic,
+                ..config.calendar
+            };
+        }
+
+        #[cfg(feature = "editor")]
+        {
+            config.editor_prompt = italic;
+        }
+
+        config
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ui::{Attributes, Color, RenderConfig, StyleSheet};
+
+    #[test]
+    fn solarized_variants_only_differ_in_base_tones() {
+        let dark = RenderConfig::solarized_dark();
+        let light = RenderConfig::solarized_light();
+
+        assert_eq!(dark.prompt_prefix, light.prompt_prefix);
+        assert_eq!(dark.answer, light.answer);
+        assert_eq!(dark.error_message.message, light.error_message.message);
+        assert_eq!(Some(super::solarized::BASE0), dark.text_input.fg);
+        assert_eq!(Some(super::solarized::BASE00), light.text_input.fg);
+    }
+
+    #[test]
+    fn monochrome_has_no_colors() {
+        let config = RenderConfig::monochrome();
+
+        let sheets = [
+            config.prompt_prefix.style,
+            config.answered_prompt_prefix.style,
+            config.vim_normal_prompt_prefix.style,
+            config.placeholder,
+            config.ghost_text,
+            config.matched_text,
+            config.help_message,
+            config.answer,
+            config.error_message.message,
+            config.highlighted_option_prefix.style,
+            config.selected_checkbox.style,
+            config.selected_option.unwrap(),
+        ];
+        for sheet in sheets {
+            assert_eq!(None, sheet.fg);
+            assert_eq!(None, sheet.bg);
+            assert_ne!(Attributes::empty(), sheet.att);
+        }
+    }
+
+    #[test]
+    fn light_default_has_no_light_colors() {
+        let config = RenderConfig::default_colored_light();
+
+        let light = |sheet: StyleSheet| {
+            matches!(
+                sheet.fg,
+                Some(
+                    Color::LightRed
+                        | Color::LightGreen
+                        | Color::LightYellow
+                        | Color::LightBlue
+                        | Color::LightMagenta
+                        | Color::LightCyan
+                )
+            )
+        };
+
+        assert!(!light(config.prompt_prefix.style));
+        assert!(!light(config.answered_prompt_prefix.style));
+        assert!(!light(config.vim_normal_prompt_prefix.style));
+        assert!(!light(config.help_message));
+        assert!(!light(config.answer));
+        assert!(!light(config.error_message.prefix.style));
+        assert!(!light(config.error_message.message));
+        assert!(!light(config.highlighted_option_prefix.style));
+        assert!(!light(config.selected_checkbox.style));
+        assert!(!light(config.selected_option.unwrap()));
+        assert!(!light(config.matched_text));
+    }
+}
@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use crate::error::InquireError;
+
 /// Represents a color to be used for text styling purposes.
 ///
 /// Currently a clone of [crossterm::style::Color]. Check their documentation
@@ -166,3 +170,137 @@ impl Color {
         Color::Rgb { r, g, b }
     }
 }
+
+impl FromStr for Color {
+    type Err = InquireError;
+
+    /// Parses a color from its name, e.g. `light_red` or `dark-grey`, from an
+    /// ANSI value, e.g. `208`, or from a `#rrggbb` hex code.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some(hex) = s.strip_prefix('#') {
+            let component = |range: std::ops::Range<usize>| {
+                hex.get(range).and_then(|c| u8::from_str_radix(c, 16).ok())
+            };
+
+            return match (hex.len(), component(0..2), component(2..4), component(4..6)) {
+                (6, Some(r), Some(g), Some(b)) => Ok(Color::rgb(r, g, b)),
+                _ => Err(InquireError::InvalidConfiguration(format!(
+                    "invalid hex color `{}`, expected `#rrggbb`",
+                    s
+                ))),
+            };
+        }
+
+        if let Ok(value) = s.parse::<u8>() {
+            return Ok(Color::AnsiValue(value));
+        }
+
+        let name: String = s
+            .chars()
+            .filter(|c| !matches!(c, '_' | '-' | ' '))
+            .collect::<String>()
+            .to_lowercase()
+            .replace("gray", "grey");
+
+        let color = match name.as_str() {
+            "black" => Color::Black,
+            "lightred" => Color::LightRed,
+            "darkred" => Color::DarkRed,
+            "lightgreen" => Color::LightGreen,
+            "darkgreen" => Color::DarkGreen,
+            "lightyellow" => Color::LightYellow,
+            "darkyellow" => Color::DarkYellow,
+            "lightblue" => Color::LightBlue,
+            "darkblue" => Color::DarkBlue,
+            "lightmagenta" => Color::LightMagenta,
+            "darkmagenta" => Color::DarkMagenta,
+            "lightcyan" => Color::LightCyan,
+            "darkcyan" => Color::DarkCyan,
+            "white" => Color::White,
+            "grey" => Color::Grey,
+            "darkgrey" => Color::DarkGrey,
+            _ => {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "unknown color `{}`",
+                    s
+                )))
+            }
+        };
+
+        Ok(color)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    /// Deserializes a color from a string parsed with [`Color::from_str`], or
+    /// from an ANSI value.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use std::convert::TryFrom;
+
+        struct ColorVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ColorVisitor {
+            type Value = Color;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a color name, an ANSI value or a `#rrggbb` hex code")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Color, E> {
+                match u8::try_from(value) {
+                    Ok(value) => Ok(Color::AnsiValue(value)),
+                    Err(_) => Err(E::invalid_value(
+                        serde::de::Unexpected::Unsigned(value),
+                        &self,
+                    )),
+                }
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Color, E> {
+                match u8::try_from(value) {
+                    Ok(value) => Ok(Color::AnsiValue(value)),
+                    Err(_) => Err(E::invalid_value(
+                        serde::de::Unexpected::Signed(value),
+                        &self,
+                    )),
+                }
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Color, E> {
+                value.parse().map_err(|err| match err {
+                    InquireError::InvalidConfiguration(msg) => E::custom(msg),
+                    err => E::custom(err),
+                })
+            }
+        }
+
+        deserializer.deserialize_any(ColorVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Color;
+
+    #[test]
+    fn colors_are_parsed_from_names_ansi_values_and_hex_codes() {
+        assert_eq!(Color::LightRed, "light_red".parse().unwrap());
+        assert_eq!(Color::DarkGrey, "Dark-Gray".parse().unwrap());
+        assert_eq!(Color::AnsiValue(208), "208".parse().unwrap());
+        assert_eq!(Color::rgb(255, 135, 0), "#ff8700".parse().unwrap());
+    }
+
+    #[test]
+    fn invalid_colors_are_rejected() {
+        assert!("purple".parse::<Color>().is_err());
+        assert!("256".parse::<Color>().is_err());
+        assert!("#ff87".parse::<Color>().is_err());
+        assert!("#gg8700".parse::<Color>().is_err());
+    }
+}
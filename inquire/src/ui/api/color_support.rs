@@ -0,0 +1,337 @@
+use std::env;
+
+use super::{Color, StyleSheet};
+
+/// Colors a terminal is able to display, from none to 24-bit RGB colors.
+///
+/// Terminals detect the color support of the environment with
+/// [`ColorSupport::detect`] and render each color as the nearest one they
+/// support, see [`Color::downgrade`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorSupport {
+    /// Colors are not displayed, attributes are kept.
+    None,
+
+    /// The 16 named colors.
+    Ansi16,
+
+    /// The 256 colors of [`Color::AnsiValue`], which include the named ones.
+    Ansi256,
+
+    /// All colors, including [`Color::Rgb`].
+    TrueColor,
+}
+
+impl ColorSupport {
+    /// Detects the color support of the terminal from the environment:
+    ///
+    /// - `NO_COLOR`, when set to a non-empty value, disables colors.
+    /// - `CLICOLOR_FORCE`, when set to a value other than `0`, enables at
+    ///   least the 16 named colors, even if `NO_COLOR` is set.
+    /// - `COLORTERM` set to `truecolor` or `24bit` enables all colors.
+    /// - `TERM` set to `dumb` disables colors, while a value containing
+    ///   `256color` enables 256 colors and one containing `truecolor`,
+    ///   `24bit` or `direct` enables all colors.
+    ///
+    /// Otherwise, the 16 named colors are supported, or all colors on Windows
+    /// when `TERM` is not set.
+    pub fn detect() -> Self {
+        Self::from_env(|name| env::var(name).ok())
+    }
+
+    fn from_env<F>(var: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let set = |name: &str| var(name).filter(|value| !value.is_empty());
+
+        let forced = set("CLICOLOR_FORCE").filter(|value| value != "0").is_some();
+        if !forced && set("NO_COLOR").is_some() {
+            return ColorSupport::None;
+        }
+
+        let colorterm = set("COLORTERM").map(|value| value.to_lowercase());
+        let term = set("TERM").map(|value| value.to_lowercase());
+
+        let detected = if matches!(colorterm.as_deref(), Some("truecolor" | "24bit")) {
+            ColorSupport::TrueColor
+        } else {
+            match term.as_deref() {
+                None if cfg!(windows) => ColorSupport::TrueColor,
+                Some("dumb") => ColorSupport::None,
+                Some(term)
+                    if term.contains("truecolor")
+                        || term.contains("24bit")
+                        || term.contains("direct") =>
+                {
+                    ColorSupport::TrueColor
+                }
+                Some(term) if term.contains("256color") => ColorSupport::Ansi256,
+                _ => ColorSupport::Ansi16,
+            }
+        };
+
+        if forced {
+            detected.max(ColorSupport::Ansi16)
+        } else {
+            detected
+        }
+    }
+}
+
+/// Named colors, by ANSI value.
+const NAMED_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::DarkRed,
+    Color::DarkGreen,
+    Color::DarkYellow,
+    Color::DarkBlue,
+    Color::DarkMagenta,
+    Color::DarkCyan,
+    Color::Grey,
+    Color::DarkGrey,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+/// RGB components of the named colors, by ANSI value, as rendered by xterm.
+const NAMED_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Component levels of the 6x6x6 color cube, ANSI values 16 to 231.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+/// Index of the nearest cube level to the component.
+fn cube_index(component: u8) -> u8 {
+    (0..CUBE_LEVELS.len() as u8)
+        .min_by_key(|i| (i32::from(CUBE_LEVELS[*i as usize]) - i32::from(component)).abs())
+        .unwrap_or(0)
+}
+
+/// Nearest ANSI value, from 16 to 255, to an RGB color.
+pub(crate) fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube = 16 + 36 * ri + 6 * gi + bi;
+
+    // grey levels are 8, 18, ..., 238
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let grey_index = (average.saturating_sub(3) / 10).min(23) as u8;
+    let grey = 232 + grey_index;
+
+    if distance((r, g, b), ansi256_to_rgb(grey)) < distance((r, g, b), ansi256_to_rgb(cube)) {
+        grey
+    } else {
+        cube
+    }
+}
+
+/// RGB components of an ANSI value.
+pub(crate) fn ansi256_to_rgb(value: u8) -> (u8, u8, u8) {
+    match value {
+        0..=15 => NAMED_RGB[value as usize],
+        16..=231 => {
+            let index = value - 16;
+            (
+                CUBE_LEVELS[(index / 36) as usize],
+                CUBE_LEVELS[(index / 6 % 6) as usize],
+                CUBE_LEVELS[(index % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (value - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+/// Nearest named color to an ANSI value.
+pub(crate) fn ansi256_to_named(value: u8) -> Color {
+    if let Some(color) = NAMED_COLORS.get(value as usize) {
+        return *color;
+    }
+
+    let rgb = ansi256_to_rgb(value);
+    let index = (0..NAMED_RGB.len())
+        .min_by_key(|i| distance(rgb, NAMED_RGB[*i]))
+        .unwrap_or(0);
+
+    NAMED_COLORS[index]
+}
+
+impl Color {
+    /// Nearest color displayed with the given support, or `None` if colors
+    /// are not supported.
+    ///
+    /// RGB colors are quantized to the nearest of the 256 ANSI values, which
+    /// are quantized to the nearest of the 16 named colors.
+    ///
+    /// ```
+    /// use inquire::ui::{Color, ColorSupport};
+    ///
+    /// let orange = Color::rgb(255, 135, 0);
+    ///
+    /// assert_eq!(Some(orange), orange.downgrade(ColorSupport::TrueColor));
+    /// assert_eq!(Some(Color::AnsiValue(208)), orange.downgrade(ColorSupport::Ansi256));
+    /// assert_eq!(Some(Color::DarkYellow), orange.downgrade(ColorSupport::Ansi16));
+    /// assert_eq!(None, orange.downgrade(ColorSupport::None));
+    /// ```
+    pub fn downgrade(self, support: ColorSupport) -> Option<Color> {
+        let color = match (self, support) {
+            (_, ColorSupport::None) => return None,
+            (Color::Rgb { r, g, b }, ColorSupport::Ansi256) => {
+                Color::AnsiValue(rgb_to_ansi256(r, g, b))
+            }
+            (Color::Rgb { r, g, b }, ColorSupport::Ansi16) => {
+                ansi256_to_named(rgb_to_ansi256(r, g, b))
+            }
+            (Color::AnsiValue(value), ColorSupport::Ansi16) => ansi256_to_named(value),
+            (color, _) => color,
+        };
+
+        Some(color)
+    }
+}
+
+impl StyleSheet {
+    /// Style sheet with its colors downgraded to the given support, see
+    /// [`Color::downgrade`].
+    pub fn downgrade(self, support: ColorSupport) -> StyleSheet {
+        StyleSheet {
+            fg: self.fg.and_then(|color| color.downgrade(support)),
+            bg: self.bg.and_then(|color| color.downgrade(support)),
+            att: self.att,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ansi256_to_named, ansi256_to_rgb, rgb_to_ansi256, ColorSupport};
+    use crate::ui::Color;
+
+    fn detect(vars: &[(&str, &str)]) -> ColorSupport {
+        ColorSupport::from_env(|name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| (*value).to_string())
+        })
+    }
+
+    #[test]
+    fn support_is_detected_from_environment() {
+        assert_eq!(
+            ColorSupport::TrueColor,
+            detect(&[("COLORTERM", "truecolor"), ("TERM", "xterm-256color")])
+        );
+        assert_eq!(ColorSupport::TrueColor, detect(&[("COLORTERM", "24bit")]));
+        assert_eq!(ColorSupport::Ansi256, detect(&[("TERM", "xterm-256color")]));
+        assert_eq!(ColorSupport::TrueColor, detect(&[("TERM", "xterm-direct")]));
+        assert_eq!(ColorSupport::Ansi16, detect(&[("TERM", "xterm")]));
+        assert_eq!(ColorSupport::Ansi16, detect(&[("TERM", "linux")]));
+        assert_eq!(ColorSupport::None, detect(&[("TERM", "dumb")]));
+    }
+
+    #[test]
+    fn no_color_disables_colors_unless_forced() {
+        let no_color = ("NO_COLOR", "1");
+        let term = ("TERM", "xterm-256color");
+
+        assert_eq!(ColorSupport::None, detect(&[no_color, term]));
+        assert_eq!(ColorSupport::Ansi256, detect(&[("NO_COLOR", ""), term]));
+        assert_eq!(
+            ColorSupport::Ansi256,
+            detect(&[no_color, term, ("CLICOLOR_FORCE", "1")])
+        );
+        assert_eq!(
+            ColorSupport::None,
+            detect(&[no_color, term, ("CLICOLOR_FORCE", "0")])
+        );
+        assert_eq!(
+            ColorSupport::Ansi16,
+            detect(&[("TERM", "dumb"), ("CLICOLOR_FORCE", "1")])
+        );
+    }
+
+    #[test]
+    fn rgb_is_quantized_to_ansi256() {
+        // cube colors
+        assert_eq!(16, rgb_to_ansi256(0, 0, 0));
+        assert_eq!(196, rgb_to_ansi256(255, 0, 0));
+        assert_eq!(208, rgb_to_ansi256(255, 135, 0));
+        assert_eq!(231, rgb_to_ansi256(255, 255, 255));
+        assert_eq!(67, rgb_to_ansi256(90, 130, 170));
+        // grey levels
+        assert_eq!(232, rgb_to_ansi256(8, 8, 8));
+        assert_eq!(244, rgb_to_ansi256(128, 128, 128));
+        assert_eq!(250, rgb_to_ansi256(190, 188, 192));
+    }
+
+    #[test]
+    fn ansi256_values_round_trip_through_rgb() {
+        for value in 16..=255 {
+            let (r, g, b) = ansi256_to_rgb(value);
+            assert_eq!(value, rgb_to_ansi256(r, g, b), "{}", value);
+        }
+    }
+
+    #[test]
+    fn ansi256_is_quantized_to_named_colors() {
+        assert_eq!(Color::DarkRed, ansi256_to_named(1));
+        assert_eq!(Color::White, ansi256_to_named(15));
+        assert_eq!(Color::LightRed, ansi256_to_named(196));
+        assert_eq!(Color::DarkBlue, ansi256_to_named(19));
+        assert_eq!(Color::LightCyan, ansi256_to_named(51));
+        assert_eq!(Color::Black, ansi256_to_named(233));
+        assert_eq!(Color::DarkGrey, ansi256_to_named(244));
+        assert_eq!(Color::Grey, ansi256_to_named(253));
+    }
+
+    #[test]
+    fn colors_are_downgraded_to_support() {
+        let teal = Color::rgb(0, 128, 128);
+
+        assert_eq!(Some(teal), teal.downgrade(ColorSupport::TrueColor));
+        assert_eq!(
+            Some(Color::AnsiValue(30)),
+            teal.downgrade(ColorSupport::Ansi256)
+        );
+        assert_eq!(Some(Color::DarkCyan), teal.downgrade(ColorSupport::Ansi16));
+        assert_eq!(None, teal.downgrade(ColorSupport::None));
+
+        assert_eq!(
+            Some(Color::AnsiValue(208)),
+            Color::AnsiValue(208).downgrade(ColorSupport::Ansi256)
+        );
+        assert_eq!(
+            Some(Color::LightGreen),
+            Color::LightGreen.downgrade(ColorSupport::Ansi16)
+        );
+        assert_eq!(None, Color::LightGreen.downgrade(ColorSupport::None));
+    }
+}
@@ -3,36 +3,73 @@ use bitflags::bitflags;
 // Using the same struct, but without importing, to cut prompts' direct dependencies to crossterm
 // https://github.com/crossterm-rs/crossterm/blob/e1260446e94e9a8f7809fef61dc1369b6f8d6e12/src/event.rs#L376-L385
 bitflags! {
+    /// Modifier keys held during a key press.
     #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
     pub struct KeyModifiers: u8 {
+        /// Shift key.
         const SHIFT = 0b0000_0001;
+        /// Control key.
         const CONTROL = 0b0000_0010;
+        /// Alt key.
         const ALT = 0b0000_0100;
+        /// Super key.
         const SUPER = 0b0000_1000;
+        /// Hyper key.
         const HYPER = 0b0001_0000;
+        /// Meta key.
         const META = 0b0010_0000;
+        /// No modifiers.
         const NONE = 0b0000_0000;
     }
 }
 
+/// Kind of mouse interaction reported by the terminal.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MouseEventKind {
+    /// The left button was pressed.
+    LeftClick,
+    /// The wheel was scrolled up.
+    ScrollUp,
+    /// The wheel was scrolled down.
+    ScrollDown,
+}
+
+/// Mouse interaction at a zero-based position of the terminal screen.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MouseEvent {
+    pub kind: MouseEventKind,
+    pub column: u16,
+    pub row: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Key {
-    Escape,
-    Enter,
-    Backspace,
-    Tab,
+    Escape(KeyModifiers),
+    Enter(KeyModifiers),
+    Backspace(KeyModifiers),
+    Tab(KeyModifiers),
+    /// Shift+Tab, with the modifiers held besides shift.
+    BackTab(KeyModifiers),
+    Insert(KeyModifiers),
     Delete(KeyModifiers),
-    Home,
-    End,
+    Home(KeyModifiers),
+    End(KeyModifiers),
     PageUp(KeyModifiers),
     PageDown(KeyModifiers),
     Up(KeyModifiers),
     Down(KeyModifiers),
     Left(KeyModifiers),
     Right(KeyModifiers),
+    /// Function key with the given number, e.g. `F(1, _)` for F1.
+    F(u8, KeyModifiers),
     Char(char, KeyModifiers),
-    #[deprecated(note = "If the key you want isn't mapped, please open a PR.")]
-    Any,
+    /// The terminal was resized to the given width and height.
+    Resize(u16, u16),
+    /// A mouse interaction, only reported when mouse capture is enabled.
+    Mouse(MouseEvent),
+    /// Text pasted by the user, delivered at once when the terminal
+    /// supports bracketed paste.
+    Paste(String),
 }
 
 #[cfg(test)]
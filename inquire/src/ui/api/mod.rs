@@ -1,9 +1,24 @@
+mod background;
+mod builtin_themes;
 mod color;
+mod color_support;
 mod key;
+mod output;
 mod render_config;
 mod style;
+mod theme;
 
+pub use background::*;
 pub use color::*;
+pub use color_support::*;
+pub use key::KeyModifiers;
 pub(crate) use key::*;
+#[cfg(any(
+    feature = "console",
+    all(unix, any(feature = "crossterm", feature = "async"))
+))]
+pub(crate) use output::open_tty;
+pub use output::Output;
 pub use render_config::*;
 pub use style::*;
+pub use theme::*;
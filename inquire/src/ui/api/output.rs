@@ -0,0 +1,140 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{self, Write},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+/// Destination where prompts are rendered.
+///
+/// Regardless of the output, keys are always read from the terminal the
+/// process is attached to, which is `/dev/tty` on Unix-like systems when
+/// the standard input is redirected. This makes it possible to prompt
+/// users in programs whose standard streams are piped.
+///
+/// Supported on all terminal back-ends: `crossterm`, `termion` and `console`.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{ui::Output, Text};
+///
+/// // stdout is left untouched for the program's machine-readable output.
+/// let name = Text::new("What's your name?")
+///     .with_output(Output::Stderr)
+///     .prompt();
+///
+/// if let Ok(name) = name {
+///     println!("{{\"name\": \"{}\"}}", name);
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub enum Output {
+    /// Standard output of the process.
+    Stdout,
+
+    /// Standard error of the process. This is the default output.
+    #[default]
+    Stderr,
+
+    /// Terminal the process is attached to, opened directly so that prompts
+    /// are displayed even when both standard streams are redirected.
+    ///
+    /// This is `/dev/tty` on Unix-like systems and `CONOUT$` on Windows.
+    Tty,
+
+    /// Any writer, such as a file. Prefer [`Output::writer`] to build it.
+    Writer(Arc<Mutex<dyn Write + Send>>),
+}
+
+impl Output {
+    /// Creates an output that renders prompts to the given writer.
+    pub fn writer<W>(writer: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        Self::Writer(Arc::new(Mutex::new(writer)))
+    }
+
+    /// Opens the destination for writing.
+    pub(crate) fn open(&self) -> io::Result<Box<dyn Write + Send>> {
+        let writer: Box<dyn Write + Send> = match self {
+            Self::Stdout => Box::new(io::stdout()),
+            Self::Stderr => Box::new(io::stderr()),
+            Self::Tty => Box::new(open_tty()?),
+            Self::Writer(writer) => Box::new(SharedWriter(Arc::clone(writer))),
+        };
+
+        Ok(writer)
+    }
+}
+
+impl fmt::Debug for Output {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stdout => f.write_str("Stdout"),
+            Self::Stderr => f.write_str("Stderr"),
+            Self::Tty => f.write_str("Tty"),
+            Self::Writer(_) => f.write_str("Writer(..)"),
+        }
+    }
+}
+
+/// Opens the terminal the process is attached to for reading and writing.
+pub(crate) fn open_tty() -> io::Result<File> {
+    #[cfg(windows)]
+    let path = "CONOUT$";
+    #[cfg(not(windows))]
+    let path = "/dev/tty";
+
+    std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)
+}
+
+/// Writer shared with the user, locked for each write.
+struct SharedWriter(Arc<Mutex<dyn Write + Send>>);
+
+impl Write for SharedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::Write,
+        sync::{Arc, Mutex},
+    };
+
+    use super::Output;
+
+    #[test]
+    fn writer_output_renders_to_shared_buffer() {
+        let buffer = Arc::new(Mutex::new(Vec::<u8>::new()));
+        let output = Output::Writer(buffer.clone());
+
+        let mut writer = output.open().unwrap();
+        write!(writer, "hello").unwrap();
+        writer.flush().unwrap();
+
+        assert_eq!(b"hello", buffer.lock().unwrap().as_slice());
+    }
+
+    #[test]
+    fn default_output_is_stderr() {
+        assert!(matches!(Output::default(), Output::Stderr));
+    }
+}
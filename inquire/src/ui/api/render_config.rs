@@ -1,6 +1,8 @@
 use std::env;
 
-use super::{Color, StyleSheet, Styled};
+use crate::formatter::{CountdownFormatter, DEFAULT_COUNTDOWN_FORMATTER};
+
+use super::{Attributes, Background, Color, StyleSheet, Styled};
 
 /// Rendering configuration that can be applied to a prompt.
 ///
@@ -33,6 +35,13 @@ pub struct RenderConfig<'a> {
     /// and the prompt message.
     pub answered_prompt_prefix: Styled<&'a str>,
 
+    /// Prefix added before prompts whose text input is in vim normal mode,
+    /// in place of the prompt prefix.
+    ///
+    /// Note: a space character will be added to separate the prefix
+    /// and the prompt message.
+    pub vim_normal_prompt_prefix: Styled<&'a str>,
+
     /// Style of the prompt message, applicable to all prompt types.
     pub prompt: StyleSheet,
 
@@ -134,6 +143,26 @@ pub struct RenderConfig<'a> {
     /// a separator from the prefix.
     pub selected_option: Option<StyleSheet>,
 
+    /// Style sheet for the descriptions of autocompletion suggestions,
+    /// displayed in a second column next to them.
+    pub suggestion_description: StyleSheet,
+
+    /// Style sheet for the inline completion of text inputs, displayed after
+    /// the cursor until accepted.
+    pub ghost_text: StyleSheet,
+
+    /// Style sheet for the characters of options matched by the filter input,
+    /// when the prompt uses a [`MatchScorer`](crate::type_aliases::MatchScorer).
+    ///
+    /// Its colors and attributes are applied on top of the style sheet of the
+    /// option.
+    pub matched_text: StyleSheet,
+
+    /// Formatter of the time left to answer prompts with a
+    /// [`Timeout`](crate::Timeout) showing a countdown, which is rendered as
+    /// a help message.
+    pub countdown_formatter: CountdownFormatter,
+
     /// Render configuration for calendar
 
     #[cfg(feature = "date")]
@@ -154,6 +183,7 @@ impl<'a> RenderConfig<'a> {
         Self {
             prompt_prefix: Styled::new("?"),
             answered_prompt_prefix: Styled::new("?"),
+            vim_normal_prompt_prefix: Styled::new(":"),
             prompt: StyleSheet::empty(),
             default_value: StyleSheet::empty(),
             placeholder: StyleSheet::empty(),
@@ -171,6 +201,10 @@ impl<'a> RenderConfig<'a> {
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: None,
+            suggestion_description: StyleSheet::empty(),
+            ghost_text: StyleSheet::empty(),
+            matched_text: StyleSheet::empty(),
+            countdown_formatter: DEFAULT_COUNTDOWN_FORMATTER,
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::empty(),
@@ -185,6 +219,7 @@ impl<'a> RenderConfig<'a> {
         Self {
             prompt_prefix: Styled::new("?").with_fg(Color::LightGreen),
             answered_prompt_prefix: Styled::new(">").with_fg(Color::LightGreen),
+            vim_normal_prompt_prefix: Styled::new(":").with_fg(Color::LightYellow),
             prompt: StyleSheet::empty(),
             default_value: StyleSheet::empty(),
             placeholder: StyleSheet::new().with_fg(Color::DarkGrey),
@@ -202,6 +237,12 @@ impl<'a> RenderConfig<'a> {
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: Some(StyleSheet::new().with_fg(Color::LightCyan)),
+            suggestion_description: StyleSheet::new().with_fg(Color::DarkGrey),
+            ghost_text: StyleSheet::new().with_fg(Color::DarkGrey),
+            matched_text: StyleSheet::new()
+                .with_fg(Color::LightCyan)
+                .with_attr(Attributes::BOLD),
+            countdown_formatter: DEFAULT_COUNTDOWN_FORMATTER,
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::default_colored(),
@@ -223,6 +264,15 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the prompt prefix shown in vim normal mode and its style sheet.
+    pub fn with_vim_normal_prompt_prefix(
+        mut self,
+        vim_normal_prompt_prefix: Styled<&'a str>,
+    ) -> Self {
+        self.vim_normal_prompt_prefix = vim_normal_prompt_prefix;
+        self
+    }
+
     /// Sets style for text inputs.
     pub fn with_text_input(mut self, text_input: StyleSheet) -> Self {
         self.text_input = text_input;
@@ -304,6 +354,31 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the style sheet for the descriptions of autocompletion suggestions.
+    pub fn with_suggestion_description(mut self, suggestion_description: StyleSheet) -> Self {
+        self.suggestion_description = suggestion_description;
+        self
+    }
+
+    /// Sets the style sheet for the inline completion of text inputs.
+    pub fn with_ghost_text(mut self, ghost_text: StyleSheet) -> Self {
+        self.ghost_text = ghost_text;
+        self
+    }
+
+    /// Sets the style sheet for the characters of options matched by the
+    /// filter input.
+    pub fn with_matched_text(mut self, matched_text: StyleSheet) -> Self {
+        self.matched_text = matched_text;
+        self
+    }
+
+    /// Sets the formatter of the time left to answer prompts with a timeout.
+    pub fn with_countdown_formatter(mut self, countdown_formatter: CountdownFormatter) -> Self {
+        self.countdown_formatter = countdown_formatter;
+        self
+    }
+
     /// Sets the indicator for canceled prompts.
     pub fn with_canceled_prompt_indicator(
         mut self,
@@ -328,17 +403,68 @@ impl<'a> RenderConfig<'a> {
     }
 }
 
+impl<'a> RenderConfig<'a> {
+    /// Returns the default render configuration for terminals with the given
+    /// background, [`RenderConfig::default_colored_light`] being used in place
+    /// of [`RenderConfig::default_colored`] on light backgrounds.
+    ///
+    /// See [`RenderConfig::default`].
+    pub fn default_for_background(background: Background) -> Self {
+        Self::default_with_background(|| background)
+    }
+
+    /// Default render configuration for the background detected by
+    /// [`Background::detect`], assumed to be dark when it is unknown.
+    ///
+    /// Detection only happens when no other setting overrides the colors.
+    #[cfg(feature = "background-detection")]
+    pub(crate) fn default_detecting_background() -> Self {
+        Self::default_with_background(|| Background::detect().unwrap_or(Background::Dark))
+    }
+
+    fn default_with_background<F>(background: F) -> Self
+    where
+        F: FnOnce() -> Background,
+    {
+        if matches!(env::var("NO_COLOR"), Ok(value) if !value.is_empty()) {
+            return Self::empty();
+        }
+
+        #[cfg(feature = "theme")]
+        if let Some(theme) = super::theme::env_theme() {
+            return theme.render_config();
+        }
+
+        match background() {
+            Background::Dark => Self::default_colored(),
+            Background::Light => Self::default_colored_light(),
+        }
+    }
+}
+
 impl<'a> Default for RenderConfig<'a> {
+    /// Returns [`RenderConfig::empty`] when the `NO_COLOR` environment
+    /// variable is set to a non-empty value. Otherwise, with the `theme` feature, returns the
+    /// configuration of the theme file at `INQUIRE_THEME`, if it is set and
+    /// valid, see [`Theme::from_env`](crate::ui::Theme::from_env). Returns
+    /// [`RenderConfig::default_colored`] in all other cases.
+    ///
+    /// With the `background-detection` feature, the configuration used by
+    /// prompts when no global one is set picks
+    /// [`RenderConfig::default_colored_light`] instead on terminals detected
+    /// to have a light background, see `Background::detect`.
     fn default() -> Self {
-        match env::var("NO_COLOR") {
-            Ok(_) => Self::empty(),
-            Err(_) => Self::default_colored(),
-        }
+        Self::default_for_background(Background::Dark)
     }
 }
 
 /// Definition of index prefixes in option lists.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum IndexPrefix {
     /// Lists of options will not display any hints regarding
     /// the position/index of the positions.
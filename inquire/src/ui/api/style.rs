@@ -49,12 +49,18 @@ bitflags! {
 /// assert!(!style_sheet.is_empty());
 /// ```
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
 pub struct StyleSheet {
     /// Foreground color of text.
     pub fg: Option<Color>,
     /// Background color of text.
     pub bg: Option<Color>,
     /// Attributes applied to text.
+    #[cfg_attr(feature = "serde", serde(rename = "attributes"))]
     pub att: Attributes,
 }
 
@@ -128,7 +134,7 @@ impl Default for StyleSheet {
 
 /// Represents a content that when rendered must have the associated style
 /// applied to it.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Styled<T>
 where
     T: Display,
@@ -216,3 +222,108 @@ where
         Self::new(from)
     }
 }
+
+#[cfg(feature = "serde")]
+mod de {
+    use std::fmt;
+
+    use serde::{
+        de::{Error, MapAccess, SeqAccess, Visitor},
+        Deserialize, Deserializer,
+    };
+
+    use super::{Attributes, StyleSheet, Styled};
+
+    fn attribute<E: Error>(name: &str) -> Result<Attributes, E> {
+        match name.trim().to_lowercase().as_str() {
+            "bold" => Ok(Attributes::BOLD),
+            "italic" => Ok(Attributes::ITALIC),
+            _ => Err(E::unknown_variant(name, &["bold", "italic"])),
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Attributes {
+        /// Deserializes attributes from a list of names, e.g. `["bold"]`, or
+        /// from a single name.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct AttributesVisitor;
+
+            impl<'de> Visitor<'de> for AttributesVisitor {
+                type Value = Attributes;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("a list of attributes, such as `[\"bold\", \"italic\"]`")
+                }
+
+                fn visit_str<E: Error>(self, value: &str) -> Result<Attributes, E> {
+                    attribute(value)
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Attributes, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut attributes = Attributes::empty();
+                    while let Some(name) = seq.next_element::<String>()? {
+                        attributes |= attribute(&name)?;
+                    }
+
+                    Ok(attributes)
+                }
+            }
+
+            deserializer.deserialize_any(AttributesVisitor)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Styled<String> {
+        /// Deserializes styled content from a table with the `content` of the
+        /// style sheet fields, or from a string when no style is applied.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            const FIELDS: &[&str] = &["content", "fg", "bg", "attributes"];
+
+            struct StyledVisitor;
+
+            impl<'de> Visitor<'de> for StyledVisitor {
+                type Value = Styled<String>;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("a string or a table with `content` and style sheet fields")
+                }
+
+                fn visit_str<E: Error>(self, value: &str) -> Result<Styled<String>, E> {
+                    Ok(Styled::new(value.to_owned()))
+                }
+
+                fn visit_map<A>(self, mut map: A) -> Result<Styled<String>, A::Error>
+                where
+                    A: MapAccess<'de>,
+                {
+                    let mut content = None;
+                    let mut style = StyleSheet::empty();
+
+                    while let Some(key) = map.next_key::<String>()? {
+                        match key.as_str() {
+                            "content" => content = Some(map.next_value()?),
+                            "fg" => style.fg = map.next_value()?,
+                            "bg" => style.bg = map.next_value()?,
+                            "attributes" => style.att = map.next_value()?,
+                            _ => return Err(A::Error::unknown_field(&key, FIELDS)),
+                        }
+                    }
+
+                    let content = content.ok_or_else(|| A::Error::missing_field("content"))?;
+                    Ok(Styled::new(content).with_style_sheet(style))
+                }
+            }
+
+            deserializer.deserialize_any(StyledVisitor)
+        }
+    }
+}
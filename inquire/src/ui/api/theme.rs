@@ -0,0 +1,502 @@
+#[cfg(feature = "date")]
+use super::calendar::CalendarRenderConfig;
+use super::{ErrorMessageRenderConfig, IndexPrefix, RenderConfig, StyleSheet, Styled};
+use crate::formatter::DEFAULT_COUNTDOWN_FORMATTER;
+
+/// Owned counterpart of [`RenderConfig`], for render configurations built at
+/// runtime, such as those loaded from theme files.
+///
+/// A theme covers all fields of [`RenderConfig`], with owned strings instead
+/// of borrowed ones, except for the countdown formatter, which is always the
+/// default one. It can be created from any render configuration and
+/// converted back with [`Theme::render_config`].
+///
+/// With the `serde` feature, themes implement `Deserialize`. All fields are
+/// optional, defaulting to the ones of [`RenderConfig::default_colored`], and
+/// unknown fields are ignored, so that a theme file can be shared by tools
+/// built with different features. Colors are written as names, e.g.
+/// `light_red`, as ANSI values, e.g. `208`, or as `#rrggbb` hex codes. Styled
+/// contents, such as prefixes, are either a string or a table with a
+/// `content` and the fields of a style sheet.
+///
+/// With the `theme` feature, themes can be loaded from TOML and JSON files,
+/// and the theme file at the path set in the `INQUIRE_THEME` environment
+/// variable is used by [`RenderConfig::default`], restyling all prompts that
+/// do not set their own render configuration.
+///
+/// # Example
+///
+/// ```toml
+/// prompt_prefix = { content = "❯", fg = "#ff8700", attributes = ["bold"] }
+/// answered_prompt_prefix = "✔"
+/// help_message = { fg = "dark_grey", attributes = ["italic"] }
+/// option_index_prefix = "simple"
+///
+/// [error_message]
+/// prefix = { content = "✘", fg = "light_red" }
+/// default_message = "Invalid answer."
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(default))]
+pub struct Theme {
+    /// See [`RenderConfig::prompt_prefix`].
+    pub prompt_prefix: Styled<String>,
+
+    /// See [`RenderConfig::answered_prompt_prefix`].
+    pub answered_prompt_prefix: Styled<String>,
+
+    /// See [`RenderConfig::vim_normal_prompt_prefix`].
+    pub vim_normal_prompt_prefix: Styled<String>,
+
+    /// See [`RenderConfig::prompt`].
+    pub prompt: StyleSheet,
+
+    /// See [`RenderConfig::default_value`].
+    pub default_value: StyleSheet,
+
+    /// See [`RenderConfig::placeholder`].
+    pub placeholder: StyleSheet,
+
+    /// See [`RenderConfig::help_message`].
+    pub help_message: StyleSheet,
+
+    /// See [`RenderConfig::password_mask`].
+    pub password_mask: char,
+
+    /// See [`RenderConfig::text_input`].
+    pub text_input: StyleSheet,
+
+    /// See [`RenderConfig::answer`].
+    pub answer: StyleSheet,
+
+    /// See [`RenderConfig::canceled_prompt_indicator`].
+    pub canceled_prompt_indicator: Styled<String>,
+
+    /// See [`RenderConfig::error_message`].
+    pub error_message: ErrorMessageTheme,
+
+    /// See [`RenderConfig::highlighted_option_prefix`].
+    pub highlighted_option_prefix: Styled<String>,
+
+    /// See [`RenderConfig::scroll_up_prefix`].
+    pub scroll_up_prefix: Styled<String>,
+
+    /// See [`RenderConfig::scroll_down_prefix`].
+    pub scroll_down_prefix: Styled<String>,
+
+    /// See [`RenderConfig::selected_checkbox`].
+    pub selected_checkbox: Styled<String>,
+
+    /// See [`RenderConfig::unselected_checkbox`].
+    pub unselected_checkbox: Styled<String>,
+
+    /// See [`RenderConfig::option_index_prefix`].
+    pub option_index_prefix: IndexPrefix,
+
+    /// See [`RenderConfig::option`].
+    pub option: StyleSheet,
+
+    /// See [`RenderConfig::selected_option`].
+    pub selected_option: Option<StyleSheet>,
+
+    /// See [`RenderConfig::suggestion_description`].
+    pub suggestion_description: StyleSheet,
+
+    /// See [`RenderConfig::ghost_text`].
+    pub ghost_text: StyleSheet,
+
+    /// See [`RenderConfig::matched_text`].
+    pub matched_text: StyleSheet,
+
+    /// See [`RenderConfig::calendar`].
+    #[cfg(feature = "date")]
+    pub calendar: CalendarTheme,
+
+    /// See [`RenderConfig::editor_prompt`].
+    #[cfg(feature = "editor")]
+    pub editor_prompt: StyleSheet,
+}
+
+impl Theme {
+    /// Render configuration borrowing the contents of the theme.
+    pub fn render_config(&self) -> RenderConfig<'_> {
+        RenderConfig {
+            prompt_prefix: borrowed(&self.prompt_prefix),
+            answered_prompt_prefix: borrowed(&self.answered_prompt_prefix),
+            vim_normal_prompt_prefix: borrowed(&self.vim_normal_prompt_prefix),
+            prompt: self.prompt,
+            default_value: self.default_value,
+            placeholder: self.placeholder,
+            help_message: self.help_message,
+            password_mask: self.password_mask,
+            text_input: self.text_input,
+            answer: self.answer,
+            canceled_prompt_indicator: borrowed(&self.canceled_prompt_indicator),
+            error_message: self.error_message.render_config(),
+            highlighted_option_prefix: borrowed(&self.highlighted_option_prefix),
+            scroll_up_prefix: borrowed(&self.scroll_up_prefix),
+            scroll_down_prefix: borrowed(&self.scroll_down_prefix),
+            selected_checkbox: borrowed(&self.selected_checkbox),
+            unselected_checkbox: borrowed(&self.unselected_checkbox),
+            option_index_prefix: self.option_index_prefix,
+            option: self.option,
+            selected_option: self.selected_option,
+            suggestion_description: self.suggestion_description,
+            ghost_text: self.ghost_text,
+            matched_text: self.matched_text,
+            countdown_formatter: DEFAULT_COUNTDOWN_FORMATTER,
+            #[cfg(feature = "date")]
+            calendar: self.calendar.render_config(),
+            #[cfg(feature = "editor")]
+            editor_prompt: self.editor_prompt,
+        }
+    }
+}
+
+impl<'a> From<RenderConfig<'a>> for Theme {
+    fn from(config: RenderConfig<'a>) -> Self {
+        Self {
+            prompt_prefix: owned(config.prompt_prefix),
+            answered_prompt_prefix: owned(config.answered_prompt_prefix),
+            vim_normal_prompt_prefix: owned(config.vim_normal_prompt_prefix),
+            prompt: config.prompt,
+            default_value: config.default_value,
+            placeholder: config.placeholder,
+            help_message: config.help_message,
+            password_mask: config.password_mask,
+            text_input: config.text_input,
+            answer: config.answer,
+            canceled_prompt_indicator: owned(config.canceled_prompt_indicator),
+            error_message: config.error_message.into(),
+            highlighted_option_prefix: owned(config.highlighted_option_prefix),
+            scroll_up_prefix: owned(config.scroll_up_prefix),
+            scroll_down_prefix: owned(config.scroll_down_prefix),
+            selected_checkbox: owned(config.selected_checkbox),
+            unselected_checkbox: owned(config.unselected_checkbox),
+            option_index_prefix: config.option_index_prefix,
+            option: config.option,
+            selected_option: config.selected_option,
+            suggestion_description: config.suggestion_description,
+            ghost_text: config.ghost_text,
+            matched_text: config.matched_text,
+            #[cfg(feature = "date")]
+            calendar: config.calendar.into(),
+            #[cfg(feature = "editor")]
+            editor_prompt: config.editor_prompt,
+        }
+    }
+}
+
+impl Default for Theme {
+    /// Theme of [`RenderConfig::default_colored`].
+    fn default() -> Self {
+        RenderConfig::default_colored().into()
+    }
+}
+
+/// Owned counterpart of [`ErrorMessageRenderConfig`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(default))]
+pub struct ErrorMessageTheme {
+    /// See [`ErrorMessageRenderConfig::prefix`].
+    pub prefix: Styled<String>,
+
+    /// See [`ErrorMessageRenderConfig::separator`].
+    pub separator: StyleSheet,
+
+    /// See [`ErrorMessageRenderConfig::message`].
+    pub message: StyleSheet,
+
+    /// See [`ErrorMessageRenderConfig::default_message`].
+    pub default_message: String,
+}
+
+impl ErrorMessageTheme {
+    /// Render configuration borrowing the contents of the theme.
+    pub fn render_config(&self) -> ErrorMessageRenderConfig<'_> {
+        ErrorMessageRenderConfig {
+            prefix: borrowed(&self.prefix),
+            separator: self.separator,
+            message: self.message,
+            default_message: &self.default_message,
+        }
+    }
+}
+
+impl<'a> From<ErrorMessageRenderConfig<'a>> for ErrorMessageTheme {
+    fn from(config: ErrorMessageRenderConfig<'a>) -> Self {
+        Self {
+            prefix: owned(config.prefix),
+            separator: config.separator,
+            message: config.message,
+            default_message: config.default_message.to_owned(),
+        }
+    }
+}
+
+impl Default for ErrorMessageTheme {
+    /// Theme of [`ErrorMessageRenderConfig::default_colored`].
+    fn default() -> Self {
+        ErrorMessageRenderConfig::default_colored().into()
+    }
+}
+
+/// Owned counterpart of [`CalendarRenderConfig`].
+#[cfg(feature = "date")]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(default))]
+pub struct CalendarTheme {
+    /// See [`CalendarRenderConfig::prefix`].
+    pub prefix: Styled<String>,
+
+    /// See [`CalendarRenderConfig::header`].
+    pub header: StyleSheet,
+
+    /// See [`CalendarRenderConfig::week_header`].
+    pub week_header: StyleSheet,
+
+    /// See [`CalendarRenderConfig::selected_date`].
+    pub selected_date: Option<StyleSheet>,
+
+    /// See [`CalendarRenderConfig::today_date`].
+    pub today_date: StyleSheet,
+
+    /// See [`CalendarRenderConfig::different_month_date`].
+    pub different_month_date: StyleSheet,
+
+    /// See [`CalendarRenderConfig::unavailable_date`].
+    pub unavailable_date: StyleSheet,
+}
+
+#[cfg(feature = "date")]
+impl CalendarTheme {
+    /// Render configuration borrowing the contents of the theme.
+    pub fn render_config(&self) -> CalendarRenderConfig<'_> {
+        CalendarRenderConfig {
+            prefix: borrowed(&self.prefix),
+            header: self.header,
+            week_header: self.week_header,
+            selected_date: self.selected_date,
+            today_date: self.today_date,
+            different_month_date: self.different_month_date,
+            unavailable_date: self.unavailable_date,
+        }
+    }
+}
+
+#[cfg(feature = "date")]
+impl<'a> From<CalendarRenderConfig<'a>> for CalendarTheme {
+    fn from(config: CalendarRenderConfig<'a>) -> Self {
+        Self {
+            prefix: owned(config.prefix),
+            header: config.header,
+            week_header: config.week_header,
+            selected_date: config.selected_date,
+            today_date: config.today_date,
+            different_month_date: config.different_month_date,
+            unavailable_date: config.unavailable_date,
+        }
+    }
+}
+
+#[cfg(feature = "date")]
+impl Default for CalendarTheme {
+    /// Theme of [`CalendarRenderConfig::default_colored`].
+    fn default() -> Self {
+        CalendarRenderConfig::default_colored().into()
+    }
+}
+
+fn borrowed(styled: &Styled<String>) -> Styled<&str> {
+    Styled::new(styled.content.as_str()).with_style_sheet(styled.style)
+}
+
+fn owned(styled: Styled<&str>) -> Styled<String> {
+    Styled::new(styled.content.to_owned()).with_style_sheet(styled.style)
+}
+
+#[cfg(feature = "theme")]
+mod file {
+    use std::{fs, path::Path};
+
+    use once_cell::sync::Lazy;
+
+    use crate::error::{InquireError, InquireResult};
+
+    use super::Theme;
+
+    /// Environment variable holding the path of the theme file used by
+    /// default.
+    const THEME_ENV_VAR: &str = "INQUIRE_THEME";
+
+    fn invalid_theme(err: impl std::fmt::Display) -> InquireError {
+        InquireError::InvalidConfiguration(format!("invalid theme: {}", err))
+    }
+
+    impl Theme {
+        /// Parses a theme from a TOML string.
+        pub fn from_toml(s: &str) -> InquireResult<Self> {
+            toml::from_str(s).map_err(invalid_theme)
+        }
+
+        /// Parses a theme from a JSON string.
+        pub fn from_json(s: &str) -> InquireResult<Self> {
+            serde_json::from_str(s).map_err(invalid_theme)
+        }
+
+        /// Loads a theme from a file, parsed as JSON if its extension is
+        /// `json` and as TOML otherwise.
+        pub fn from_file<P: AsRef<Path>>(path: P) -> InquireResult<Self> {
+            let path = path.as_ref();
+            let contents = fs::read_to_string(path)?;
+
+            let is_json = path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .filter(|extension| extension.eq_ignore_ascii_case("json"))
+                .is_some();
+
+            let theme = if is_json {
+                Self::from_json(&contents)
+            } else {
+                Self::from_toml(&contents)
+            };
+
+            theme.map_err(|err| match err {
+                InquireError::InvalidConfiguration(msg) => {
+                    InquireError::InvalidConfiguration(format!("{}: {}", path.display(), msg))
+                }
+                err => err,
+            })
+        }
+
+        /// Loads the theme file at the path set in the `INQUIRE_THEME`
+        /// environment variable, returning `None` if it is not set.
+        ///
+        /// [`RenderConfig::default`](crate::ui::RenderConfig::default) falls
+        /// back to the default render configuration when the theme file can
+        /// not be loaded, this method can be used to report the error.
+        pub fn from_env() -> Option<InquireResult<Self>> {
+            let path = std::env::var_os(THEME_ENV_VAR).filter(|path| !path.is_empty())?;
+
+            Some(Self::from_file(path))
+        }
+    }
+
+    static ENV_THEME: Lazy<Option<Theme>> = Lazy::new(|| Theme::from_env().and_then(Result::ok));
+
+    /// Theme loaded from `INQUIRE_THEME`, once per process.
+    pub(in crate::ui) fn env_theme() -> Option<&'static Theme> {
+        ENV_THEME.as_ref()
+    }
+}
+
+#[cfg(feature = "theme")]
+pub(super) use file::env_theme;
+
+#[cfg(test)]
+#[cfg(feature = "theme")]
+mod test {
+    use crate::{
+        error::InquireError,
+        ui::{Attributes, Color, IndexPrefix, RenderConfig, StyleSheet, Styled},
+    };
+
+    use super::Theme;
+
+    #[test]
+    fn theme_is_loaded_from_toml() {
+        let theme = Theme::from_toml(
+            r##"
+            prompt_prefix = { content = "❯", fg = "#ff8700", attributes = ["bold"] }
+            answered_prompt_prefix = "✔"
+            help_message = { fg = 244, bg = "black", attributes = "italic" }
+            option_index_prefix = "zero_padded"
+            selected_option = { fg = "light_cyan" }
+
+            [error_message]
+            prefix = { content = "✘", fg = "light_red" }
+            default_message = "Invalid answer."
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Styled::new(String::from("❯"))
+                .with_fg(Color::rgb(255, 135, 0))
+                .with_attr(Attributes::BOLD),
+            theme.prompt_prefix
+        );
+        assert_eq!(Styled::new(String::from("✔")), theme.answered_prompt_prefix);
+        assert_eq!(
+            StyleSheet::new()
+                .with_fg(Color::AnsiValue(244))
+                .with_bg(Color::Black)
+                .with_attr(Attributes::ITALIC),
+            theme.help_message
+        );
+        assert_eq!(IndexPrefix::ZeroPadded, theme.option_index_prefix);
+        assert_eq!(
+            Some(StyleSheet::new().with_fg(Color::LightCyan)),
+            theme.selected_option
+        );
+        assert_eq!("Invalid answer.", theme.error_message.default_message);
+
+        // unset fields keep their default value
+        let default = Theme::default();
+        assert_eq!(default.answer, theme.answer);
+        assert_eq!(default.error_message.message, theme.error_message.message);
+    }
+
+    #[test]
+    fn theme_is_loaded_from_json() {
+        let theme = Theme::from_json(
+            r#"{
+                "prompt_prefix": { "content": "$", "fg": "dark_green" },
+                "password_mask": "•"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Styled::new(String::from("$")).with_fg(Color::DarkGreen),
+            theme.prompt_prefix
+        );
+        assert_eq!('•', theme.password_mask);
+    }
+
+    #[test]
+    fn invalid_themes_are_rejected() {
+        for theme in [
+            "prompt_prefix = { content = \"?\", fg = \"purple\" }",
+            "help_message = { underline = true }",
+            "prompt_prefix = { fg = \"red\" }",
+        ] {
+            assert!(matches!(
+                Theme::from_toml(theme),
+                Err(InquireError::InvalidConfiguration(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn unknown_fields_are_ignored() {
+        let theme = Theme::from_toml(
+            "spinner = \"dots\"\n[error_message]\nsuffix = \"!\"\n[calendar]\nweek_numbers = true",
+        )
+        .unwrap();
+
+        assert_eq!(Theme::default(), theme);
+    }
+
+    #[test]
+    fn theme_converts_to_and_from_render_config() {
+        let theme = Theme::from(RenderConfig::empty());
+        assert_eq!(Theme::from(theme.render_config()), theme);
+
+        let config = theme.render_config();
+        assert_eq!("?", config.prompt_prefix.content);
+        assert_eq!("Invalid input.", config.error_message.default_message);
+        assert!(config.help_message.is_empty());
+    }
+}
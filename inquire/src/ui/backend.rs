@@ -1,26 +1,40 @@
-use std::{collections::BTreeSet, fmt::Display, io::Result};
+use std::{collections::BTreeSet, fmt::Display, io::Result, time::Duration};
 
 use crate::{
+    autocompletion::Suggestion,
     error::InquireResult,
     input::Input,
     list_option::ListOption,
-    terminal::Terminal,
-    ui::{IndexPrefix, Key, RenderConfig, Styled},
-    utils::{int_log10, Page},
+    terminal::{Terminal, TerminalSize},
+    ui::{IndexPrefix, Key, RenderConfig, StyleSheet, Styled},
+    utils::{int_log10, matched_segments, text_width, Page},
     validator::ErrorMessage,
 };
 
-use super::{frame_renderer::FrameRenderer, InputReader};
+use super::{frame_renderer::FrameRenderer, HitTarget, InputReader};
 
 pub trait CommonBackend: InputReader {
     fn frame_setup(&mut self) -> Result<()>;
     fn frame_finish(&mut self) -> Result<()>;
 
+    fn terminal_size(&mut self) -> TerminalSize;
+
+    fn enable_mouse_capture(&mut self) -> Result<()>;
+    fn hit_test(&mut self, column: u16, row: u16) -> Option<HitTarget>;
+
+    /// Sets whether the text input of the current frame is in vim normal
+    /// mode, which is shown by the prompt prefix.
+    fn set_vim_normal_mode(&mut self, normal: bool);
+
     fn render_canceled_prompt(&mut self, prompt: &str) -> Result<()>;
     fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> Result<()>;
 
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()>;
     fn render_help_message(&mut self, help: &str) -> Result<()>;
+
+    /// Renders the time left to answer the prompt, formatted with the
+    /// countdown formatter of the render configuration.
+    fn render_countdown(&mut self, remaining: Duration) -> Result<()>;
 }
 
 pub trait TextBackend: CommonBackend {
@@ -30,7 +44,15 @@ pub trait TextBackend: CommonBackend {
         default: Option<&str>,
         cur_input: &Input,
     ) -> Result<()>;
-    fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()>;
+    fn render_prompt_with_ghost(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        ghost: &str,
+    ) -> Result<()>;
+    fn render_suggestions(&mut self, page: Page<'_, ListOption<&Suggestion>>) -> Result<()>;
+    fn render_history_search(&mut self, query: &Input, found: bool) -> Result<()>;
 }
 
 #[cfg(feature = "editor")]
@@ -40,15 +62,24 @@ pub trait EditorBackend: CommonBackend {
 
 pub trait SelectBackend: CommonBackend {
     fn render_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()>;
-    fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()>;
+    /// Renders the options of the page, highlighting the characters of each
+    /// option at the indices listed in `matches` for its index, if any.
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        matches: &[Vec<usize>],
+    ) -> Result<()>;
 }
 
 pub trait MultiSelectBackend: CommonBackend {
     fn render_multiselect_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()>;
+    /// Renders the options of the page, highlighting the characters of each
+    /// option at the indices listed in `matches` for its index, if any.
     fn render_options<D: Display>(
         &mut self,
         page: Page<'_, ListOption<D>>,
         checked: &BTreeSet<usize>,
+        matches: &[Vec<usize>],
     ) -> Result<()>;
 }
 
@@ -59,6 +90,14 @@ pub trait CustomTypeBackend: CommonBackend {
         default: Option<&str>,
         cur_input: &Input,
     ) -> Result<()>;
+    fn render_prompt_with_mask(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        remainder: &str,
+    ) -> Result<()>;
+    fn render_history_search(&mut self, query: &Input, found: bool) -> Result<()>;
 }
 
 pub trait PasswordBackend: CommonBackend {
@@ -81,6 +120,7 @@ where
     frame_renderer: FrameRenderer<T>,
     input_reader: I,
     render_config: RenderConfig<'a>,
+    vim_normal_mode: bool,
 }
 
 impl<'a, I, T> Backend<'a, I, T>
@@ -94,12 +134,13 @@ where
             frame_renderer: FrameRenderer::new(terminal)?,
             input_reader,
             render_config,
+            vim_normal_mode: false,
         };
 
         Ok(backend)
     }
 
-    fn print_option_prefix<D: Display>(
+    fn print_option_prefix<D>(
         &mut self,
         option_relative_index: usize,
         page: &Page<'_, ListOption<D>>,
@@ -124,18 +165,48 @@ where
         option_relative_index: usize,
         option: &ListOption<D>,
         page: &Page<'_, ListOption<D>>,
+        matched: &[usize],
     ) -> Result<()> {
-        let stylesheet = if let Some(selected_option_style) = self.render_config.selected_option {
+        let stylesheet = self.option_style_sheet(option_relative_index, page);
+
+        if matched.is_empty() {
+            return self
+                .frame_renderer
+                .write_styled(Styled::new(&option.value).with_style_sheet(stylesheet));
+        }
+
+        let matched_text = self.render_config.matched_text;
+        let matched_stylesheet = StyleSheet {
+            fg: matched_text.fg.or(stylesheet.fg),
+            bg: matched_text.bg.or(stylesheet.bg),
+            att: stylesheet.att | matched_text.att,
+        };
+
+        for (segment, is_matched) in matched_segments(&option.value.to_string(), matched) {
+            let stylesheet = match is_matched {
+                true => matched_stylesheet,
+                false => stylesheet,
+            };
+            self.frame_renderer
+                .write_styled(Styled::new(segment).with_style_sheet(stylesheet))?;
+        }
+
+        Ok(())
+    }
+
+    fn option_style_sheet<D>(
+        &self,
+        option_relative_index: usize,
+        page: &Page<'_, ListOption<D>>,
+    ) -> StyleSheet {
+        if let Some(selected_option_style) = self.render_config.selected_option {
             match page.cursor {
                 Some(cursor) if cursor == option_relative_index => selected_option_style,
                 _ => self.render_config.option,
             }
         } else {
             self.render_config.option
-        };
-
-        self.frame_renderer
-            .write_styled(Styled::new(&option.value).with_style_sheet(stylesheet))
+        }
     }
 
     fn print_option_index_prefix(&mut self, index: usize, max_index: usize) -> Option<Result<()>> {
@@ -179,17 +250,28 @@ where
     }
 
     fn print_prompt(&mut self, prompt: &str) -> Result<()> {
-        self.print_prompt_with_prefix(self.render_config.prompt_prefix, prompt)
+        let prefix = if self.vim_normal_mode {
+            self.render_config.vim_normal_prompt_prefix
+        } else {
+            self.render_config.prompt_prefix
+        };
+
+        self.print_prompt_with_prefix(prefix, prompt)
     }
 
     fn print_input(&mut self, input: &Input) -> Result<()> {
-        self.frame_renderer.write(" ")?;
+        self.print_input_with_remainder(input, Styled::new(""))
+    }
 
-        let cursor_offset = input.pre_cursor().chars().count();
-        self.frame_renderer
-            .mark_cursor_position(cursor_offset as isize);
+    /// Prints a text input followed by a remainder displayed after it without
+    /// being part of it, such as the slots left to fill in a mask or an
+    /// inline completion.
+    fn print_input_with_remainder(&mut self, input: &Input, remainder: Styled<&str>) -> Result<()> {
+        self.frame_renderer.write(" ")?;
 
         if input.is_empty() {
+            self.frame_renderer.mark_cursor_position(0);
+
             match input.placeholder() {
                 Some("") | None => {}
                 Some(p) => self.frame_renderer.write_styled(
@@ -197,15 +279,29 @@ where
                 )?,
             }
         } else {
+            // the cursor is marked once the content before it is written, so
+            // that its position accounts for wrapped rows and line breaks.
+            let pre_cursor = input.pre_cursor();
+            let post_cursor = &input.content()[pre_cursor.len()..];
+
+            self.frame_renderer.write_styled(
+                Styled::new(pre_cursor).with_style_sheet(self.render_config.text_input),
+            )?;
+            self.frame_renderer.mark_cursor_position(0);
             self.frame_renderer.write_styled(
-                Styled::new(input.content()).with_style_sheet(self.render_config.text_input),
+                Styled::new(post_cursor).with_style_sheet(self.render_config.text_input),
             )?;
         }
 
+        let remainder_is_empty = remainder.content.is_empty();
+        if !remainder_is_empty {
+            self.frame_renderer.write_styled(remainder)?;
+        }
+
         // if cursor is at end of input, we need to add
         // a space, otherwise the cursor will render on the
         // \n character, on the next line.
-        if input.cursor() == input.length() {
+        if input.cursor() == input.length() && remainder_is_empty {
             self.frame_renderer.write(' ')?;
         }
 
@@ -217,6 +313,16 @@ where
         prompt: &str,
         default: Option<&str>,
         input: &Input,
+    ) -> Result<()> {
+        self.print_prompt_with_remainder(prompt, default, input, Styled::new(""))
+    }
+
+    fn print_prompt_with_remainder(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        input: &Input,
+        remainder: Styled<&str>,
     ) -> Result<()> {
         self.print_prompt(prompt)?;
 
@@ -225,13 +331,26 @@ where
             self.print_default_value(default)?;
         }
 
-        self.print_input(input)?;
+        self.print_input_with_remainder(input, remainder)?;
 
         self.new_line()?;
 
         Ok(())
     }
 
+    fn print_history_search(&mut self, query: &Input, found: bool) -> Result<()> {
+        let label = match found {
+            true => "reverse search:",
+            false => "failing reverse search:",
+        };
+
+        self.frame_renderer
+            .write_styled(Styled::new(label).with_style_sheet(self.render_config.help_message))?;
+        self.print_input(query)?;
+
+        self.new_line()
+    }
+
     fn new_line(&mut self) -> Result<()> {
         self.frame_renderer.write("\n")?;
         Ok(())
@@ -244,6 +363,7 @@ where
     T: Terminal,
 {
     fn frame_setup(&mut self) -> Result<()> {
+        self.vim_normal_mode = false;
         self.frame_renderer.start_frame()
     }
 
@@ -251,6 +371,22 @@ where
         self.frame_renderer.finish_current_frame()
     }
 
+    fn terminal_size(&mut self) -> TerminalSize {
+        self.frame_renderer.terminal_size()
+    }
+
+    fn enable_mouse_capture(&mut self) -> Result<()> {
+        self.frame_renderer.enable_mouse_capture()
+    }
+
+    fn hit_test(&mut self, column: u16, row: u16) -> Option<HitTarget> {
+        self.frame_renderer.hit_test(column, row)
+    }
+
+    fn set_vim_normal_mode(&mut self, normal: bool) {
+        self.vim_normal_mode = normal;
+    }
+
     fn render_canceled_prompt(&mut self, prompt: &str) -> Result<()> {
         self.print_prompt(prompt)?;
 
@@ -313,6 +449,11 @@ where
 
         Ok(())
     }
+
+    fn render_countdown(&mut self, remaining: Duration) -> Result<()> {
+        let countdown = (self.render_config.countdown_formatter)(remaining);
+        self.render_help_message(&countdown)
+    }
 }
 
 impl<'a, I, T> TextBackend for Backend<'a, I, T>
@@ -329,18 +470,57 @@ where
         self.print_prompt_with_input(prompt, default, cur_input)
     }
 
-    fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
+    fn render_prompt_with_ghost(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        ghost: &str,
+    ) -> Result<()> {
+        let ghost = Styled::new(ghost).with_style_sheet(self.render_config.ghost_text);
+        self.print_prompt_with_remainder(prompt, default, cur_input, ghost)
+    }
+
+    fn render_suggestions(&mut self, page: Page<'_, ListOption<&Suggestion>>) -> Result<()> {
+        let label_width = page
+            .content
+            .iter()
+            .map(|option| text_width(option.value.label()))
+            .max()
+            .unwrap_or(0);
+
         for (idx, option) in page.content.iter().enumerate() {
             self.print_option_prefix(idx, &page)?;
 
             self.frame_renderer.write(" ")?;
-            self.print_option_value(idx, option, &page)?;
+
+            let suggestion = option.value;
+            let stylesheet = match (page.cursor, suggestion.style) {
+                (Some(cursor), _) if cursor == idx => self.option_style_sheet(idx, &page),
+                (_, Some(style)) => style,
+                (_, None) => self.option_style_sheet(idx, &page),
+            };
+            self.frame_renderer
+                .write_styled(Styled::new(suggestion.label()).with_style_sheet(stylesheet))?;
+
+            if let Some(description) = &suggestion.description {
+                let padding = label_width - text_width(suggestion.label()) + 2;
+                self.frame_renderer.write(" ".repeat(padding))?;
+                self.frame_renderer.write_styled(
+                    Styled::new(description.as_str())
+                        .with_style_sheet(self.render_config.suggestion_description),
+                )?;
+            }
 
             self.new_line()?;
         }
 
         Ok(())
     }
+
+    fn render_history_search(&mut self, query: &Input, found: bool) -> Result<()> {
+        self.print_history_search(query, found)
+    }
 }
 
 #[cfg(feature = "editor")]
@@ -377,8 +557,15 @@ where
         }
     }
 
-    fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        matches: &[Vec<usize>],
+    ) -> Result<()> {
         for (idx, option) in page.content.iter().enumerate() {
+            self.frame_renderer
+                .start_hit_region(HitTarget::ListOption(option.index));
+
             self.print_option_prefix(idx, &page)?;
 
             self.frame_renderer.write(" ")?;
@@ -388,9 +575,12 @@ where
                 self.frame_renderer.write(" ")?;
             }
 
-            self.print_option_value(idx, option, &page)?;
+            let matched = matches.get(option.index).map(Vec::as_slice);
+            self.print_option_value(idx, option, &page, matched.unwrap_or_default())?;
 
             self.new_line()?;
+
+            self.frame_renderer.finish_hit_region();
         }
 
         Ok(())
@@ -414,8 +604,12 @@ where
         &mut self,
         page: Page<'_, ListOption<D>>,
         checked: &BTreeSet<usize>,
+        matches: &[Vec<usize>],
     ) -> Result<()> {
         for (idx, option) in page.content.iter().enumerate() {
+            self.frame_renderer
+                .start_hit_region(HitTarget::ListOption(option.index));
+
             self.print_option_prefix(idx, &page)?;
 
             self.frame_renderer.write(" ")?;
@@ -439,9 +633,12 @@ where
 
             self.frame_renderer.write(" ")?;
 
-            self.print_option_value(idx, option, &page)?;
+            let matched = matches.get(option.index).map(Vec::as_slice);
+            self.print_option_value(idx, option, &page, matched.unwrap_or_default())?;
 
             self.new_line()?;
+
+            self.frame_renderer.finish_hit_region();
         }
 
         Ok(())
@@ -457,7 +654,7 @@ pub mod date {
     use crate::{
         date_utils::get_start_date,
         terminal::Terminal,
-        ui::{InputReader, Styled},
+        ui::{HitTarget, InputReader, Styled},
     };
 
     use super::{Backend, CommonBackend};
@@ -598,7 +795,10 @@ pub mod date {
                     }
 
                     let token = Styled::new(date).with_style_sheet(style_sheet);
+                    self.frame_renderer
+                        .start_hit_region(HitTarget::Date(date_it));
                     self.frame_renderer.write_styled(token)?;
+                    self.frame_renderer.finish_hit_region();
 
                     date_it = date_it.next_day().unwrap_or(date_it);
                 }
@@ -624,6 +824,21 @@ where
     ) -> Result<()> {
         self.print_prompt_with_input(prompt, default, cur_input)
     }
+
+    fn render_prompt_with_mask(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        remainder: &str,
+    ) -> Result<()> {
+        let remainder = Styled::new(remainder).with_style_sheet(self.render_config.placeholder);
+        self.print_prompt_with_remainder(prompt, default, cur_input, remainder)
+    }
+
+    fn render_history_search(&mut self, query: &Input, found: bool) -> Result<()> {
+        self.print_history_search(query, found)
+    }
 }
 
 impl<'a, I, T> PasswordBackend for Backend<'a, I, T>
@@ -660,6 +875,24 @@ where
     fn read_key(&mut self) -> InquireResult<Key> {
         self.input_reader.read_key()
     }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        self.input_reader.read_key_timeout(timeout)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a, I, T> crate::ui::AsyncInputReader for Backend<'a, I, T>
+where
+    I: crate::ui::AsyncInputReader,
+    T: Terminal,
+{
+    fn poll_read_key(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<InquireResult<Key>> {
+        self.input_reader.poll_read_key(cx)
+    }
 }
 
 #[cfg(test)]
@@ -685,6 +918,8 @@ pub(crate) mod test {
         AnsweredPrompt(String, String),
         ErrorMessage(ErrorMessage),
         HelpMessage(String),
+        HistorySearch(Input, bool),
+        MaskRemainder(String),
         Calendar {
             month: Month,
             year: i32,
@@ -748,6 +983,13 @@ pub(crate) mod test {
                     "No more keys in input",
                 )))
         }
+
+        fn read_key_timeout(
+            &mut self,
+            _timeout: std::time::Duration,
+        ) -> crate::error::InquireResult<Option<Key>> {
+            Ok(self.input.pop_front())
+        }
     }
 
     impl CommonBackend for FakeBackend {
@@ -765,6 +1007,20 @@ pub(crate) mod test {
             Ok(())
         }
 
+        fn terminal_size(&mut self) -> crate::terminal::TerminalSize {
+            crate::terminal::TerminalSize::new(80, 24)
+        }
+
+        fn enable_mouse_capture(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+
+        fn hit_test(&mut self, _column: u16, _row: u16) -> Option<crate::ui::HitTarget> {
+            None
+        }
+
+        fn set_vim_normal_mode(&mut self, _normal: bool) {}
+
         fn render_canceled_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
             self.push_token(Token::CanceledPrompt(prompt.to_string()));
             Ok(())
@@ -787,6 +1043,11 @@ pub(crate) mod test {
             self.push_token(Token::HelpMessage(help.to_string()));
             Ok(())
         }
+
+        fn render_countdown(&mut self, remaining: std::time::Duration) -> std::io::Result<()> {
+            let countdown = crate::formatter::DEFAULT_COUNTDOWN_FORMATTER(remaining);
+            self.render_help_message(&countdown)
+        }
     }
 
     #[cfg(feature = "date")]
@@ -833,5 +1094,22 @@ pub(crate) mod test {
             self.push_token(Token::Input(cur_input.clone()));
             Ok(())
         }
+
+        fn render_prompt_with_mask(
+            &mut self,
+            prompt: &str,
+            default: Option<&str>,
+            cur_input: &Input,
+            remainder: &str,
+        ) -> std::io::Result<()> {
+            CustomTypeBackend::render_prompt(self, prompt, default, cur_input)?;
+            self.push_token(Token::MaskRemainder(remainder.to_string()));
+            Ok(())
+        }
+
+        fn render_history_search(&mut self, query: &Input, found: bool) -> std::io::Result<()> {
+            self.push_token(Token::HistorySearch(query.clone(), found));
+            Ok(())
+        }
     }
 }
@@ -31,6 +31,36 @@ impl FrameRow {
     }
 }
 
+/// Element of a prompt that can be clicked on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HitTarget {
+    /// Option of a list, identified by its index in the original list.
+    ListOption(usize),
+    /// Day of a calendar.
+    #[cfg(feature = "date")]
+    Date(time::Date),
+}
+
+/// Area of a frame, from `start` (inclusive) to `end` (exclusive), covered by
+/// a clickable element.
+#[derive(Debug)]
+struct HitRegion {
+    target: HitTarget,
+    start: Position,
+    end: Position,
+}
+
+impl HitRegion {
+    fn contains(&self, position: Position) -> bool {
+        let after_start = position.row > self.start.row
+            || (position.row == self.start.row && position.col >= self.start.col);
+        let before_end = position.row < self.end.row
+            || (position.row == self.end.row && position.col < self.end.col);
+
+        after_start && before_end
+    }
+}
+
 #[derive(Debug)]
 struct FrameState {
     /// terminal size when the frame was rendered
@@ -46,6 +76,10 @@ struct FrameState {
     pub current_line: Vec<Styled<String>>,
     pub current_line_width: u16,
     pub current_line_hasher: FxHasher,
+    /// clickable regions of the frame, used for mouse hit-testing
+    pub hit_regions: Vec<HitRegion>,
+    /// clickable element whose content is currently being written
+    pub open_hit_region: Option<(HitTarget, Position)>,
 }
 
 impl FrameState {
@@ -59,9 +93,39 @@ impl FrameState {
             current_line_hasher: FxHasher::default(),
             current_line_width: 0,
             expected_cursor_position: None,
+            hit_regions: Vec::new(),
+            open_hit_region: None,
+        }
+    }
+
+    fn current_position(&self) -> Position {
+        Position {
+            row: self.finished_rows.len() as u16,
+            col: self.current_line_width,
+        }
+    }
+
+    pub fn start_hit_region(&mut self, target: HitTarget) {
+        self.open_hit_region = Some((target, self.current_position()));
+    }
+
+    pub fn finish_hit_region(&mut self) {
+        if let Some((target, start)) = self.open_hit_region.take() {
+            self.hit_regions.push(HitRegion {
+                target,
+                start,
+                end: self.current_position(),
+            });
         }
     }
 
+    pub fn hit_test(&self, position: Position) -> Option<HitTarget> {
+        self.hit_regions
+            .iter()
+            .find(|region| region.contains(position))
+            .map(|region| region.target)
+    }
+
     pub fn write(&mut self, value: &Styled<impl AsRef<str> + Display>) {
         self.current_styled.style = value.style;
 
@@ -79,7 +143,9 @@ impl FrameState {
             };
 
             if current_char == '\n' {
-                self.finish_line();
+                // explicit line breaks are kept even on empty lines, e.g.
+                // in multi-line inputs
+                self.finish_line(true);
                 continue;
             }
 
@@ -88,7 +154,7 @@ impl FrameState {
 
             if character_length > remaining_width_space {
                 // the character will (probably) not fit into the current line
-                self.finish_line();
+                self.finish_line(false);
             }
 
             self.current_line_width = self.current_line_width.saturating_add(character_length);
@@ -107,16 +173,15 @@ impl FrameState {
 
         col = col.saturating_add(offset as u16);
 
-        if col >= self.terminal_size.width() {
-            col -= self.terminal_size.width();
-            row += 1;
-        }
+        let width = self.terminal_size.width().max(1);
+        row = row.saturating_add(col / width);
+        col %= width;
 
         self.expected_cursor_position = Some(Position { row, col });
     }
 
     pub fn finish(&mut self) {
-        self.finish_line();
+        self.finish_line(false);
     }
 
     pub fn resize_if_needed(&mut self, new_size: TerminalSize) {
@@ -129,17 +194,19 @@ impl FrameState {
             for styled in row.get_content() {
                 new_state.write(styled);
             }
-            new_state.finish_line();
+            new_state.finish_line(true);
         }
         for styled in &self.current_line {
             new_state.write(styled);
         }
-        new_state.finish_line();
+        new_state.finish_line(false);
 
         *self = new_state;
     }
 
-    fn finish_line(&mut self) {
+    /// Moves on to the next row, only keeping the current one if it has
+    /// content or `keep_empty` is set.
+    fn finish_line(&mut self, keep_empty: bool) {
         let current_styled = std::mem::take(&mut self.current_styled);
         self.current_styled.style = current_styled.style;
 
@@ -150,7 +217,7 @@ impl FrameState {
         let hasher = std::mem::take(&mut self.current_line_hasher);
         let content = std::mem::take(&mut self.current_line);
 
-        if content.is_empty() {
+        if content.is_empty() && !keep_empty {
             return;
         }
 
@@ -190,6 +257,9 @@ where
     terminal: T,
     cursor_position: Position,
     state: RenderState,
+    /// screen position of the first rendered row, known only when mouse
+    /// capture is enabled
+    frame_origin: Option<Position>,
 }
 
 impl<T> FrameRenderer<T>
@@ -201,6 +271,7 @@ where
             terminal,
             cursor_position: Position::default(),
             state: RenderState::Initial,
+            frame_origin: None,
         })
     }
 
@@ -236,6 +307,69 @@ where
         }
     }
 
+    /// Marks the content written from now on, until [`Self::finish_hit_region`]
+    /// is called, as belonging to the given clickable element.
+    pub fn start_hit_region(&mut self, target: HitTarget) {
+        match &mut self.state {
+            RenderState::Rendered(_) | RenderState::Initial => {}
+            RenderState::ActiveRender { current_frame, .. } => {
+                current_frame.start_hit_region(target);
+            }
+        }
+    }
+
+    pub fn finish_hit_region(&mut self) {
+        match &mut self.state {
+            RenderState::Rendered(_) | RenderState::Initial => {}
+            RenderState::ActiveRender { current_frame, .. } => {
+                current_frame.finish_hit_region();
+            }
+        }
+    }
+
+    /// Enables mouse events on the terminal, remembering where the frame
+    /// starts on the screen so that clicks can be mapped to rendered content.
+    pub fn enable_mouse_capture(&mut self) -> io::Result<()> {
+        self.terminal.enable_mouse_capture()?;
+
+        // nothing has been rendered yet, so the cursor sits at the origin.
+        // without it, clicks can't be located and will be ignored.
+        self.frame_origin = self.terminal.cursor_position().ok();
+
+        Ok(())
+    }
+
+    /// Returns the element of the last rendered frame found at the given
+    /// screen coordinates, if any.
+    pub fn hit_test(&self, column: u16, row: u16) -> Option<HitTarget> {
+        let origin = self.frame_origin?;
+
+        let row = row.checked_sub(origin.row)?;
+        // only the first row is rendered after the origin column, the
+        // following ones start at the beginning of the line.
+        let col = match row {
+            0 => column.checked_sub(origin.col)?,
+            _ => column,
+        };
+
+        let frame = match &self.state {
+            RenderState::Initial => return None,
+            RenderState::ActiveRender {
+                last_rendered_frame,
+                ..
+            }
+            | RenderState::Rendered(last_rendered_frame) => last_rendered_frame,
+        };
+
+        frame.hit_test(Position { row, col })
+    }
+
+    /// Returns the current size of the terminal, re-wrapping the rendered
+    /// frames if it changed since the last check.
+    pub fn terminal_size(&mut self) -> TerminalSize {
+        self.refresh_terminal_size()
+    }
+
     pub fn start_frame(&mut self) -> io::Result<()> {
         let terminal_size = self.refresh_terminal_size();
 
@@ -328,6 +462,14 @@ where
         self.terminal.cursor_show()?;
         self.terminal.flush()?;
 
+        // rendering past the bottom of the screen scrolls the whole frame up
+        if let Some(origin) = &mut self.frame_origin {
+            let height = current_frame.terminal_size.height();
+            if origin.row.saturating_add(rows_to_iterate) > height {
+                origin.row = height.saturating_sub(rows_to_iterate);
+            }
+        }
+
         self.state = RenderState::Rendered(current_frame);
 
         Ok(())
@@ -433,3 +575,37 @@ where
         let _unused = self.terminal.flush();
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::FrameState;
+    use crate::{terminal::TerminalSize, ui::Styled};
+
+    #[test]
+    fn line_breaks_keep_empty_rows() {
+        let mut frame = FrameState::new(TerminalSize::new(80, 24));
+
+        frame.write(&Styled::new("first\n\nthird"));
+        frame.mark_cursor_position(0);
+        frame.finish();
+
+        assert_eq!(3, frame.finished_rows.len());
+        let cursor = frame.expected_cursor_position.unwrap();
+        assert_eq!((2, 5), (cursor.row, cursor.col));
+    }
+
+    #[test]
+    fn cursor_position_accounts_for_wrapped_rows() {
+        let mut frame = FrameState::new(TerminalSize::new(4, 24));
+
+        frame.write(&Styled::new("abcdefghij"));
+        frame.mark_cursor_position(0);
+        assert_eq!(2, frame.finished_rows.len());
+        let cursor = frame.expected_cursor_position.unwrap();
+        assert_eq!((2, 2), (cursor.row, cursor.col));
+
+        frame.mark_cursor_position(7);
+        let cursor = frame.expected_cursor_position.unwrap();
+        assert_eq!((4, 1), (cursor.row, cursor.col));
+    }
+}
@@ -1,7 +1,28 @@
+use std::time::Duration;
+
 use crate::error::InquireResult;
 
 use super::Key;
 
 pub trait InputReader: Sized {
     fn read_key(&mut self) -> InquireResult<Key>;
+
+    /// Waits at most `timeout` for a key press, returning `None` when
+    /// no key was pressed in time.
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>>;
+}
+
+/// Non-blocking counterpart of [`InputReader`], used by the `prompt_async`
+/// family of methods.
+///
+/// Implementors must still provide the blocking [`InputReader`] behavior,
+/// so that the same reader can back both flavors of prompts.
+#[cfg(feature = "async")]
+pub trait AsyncInputReader: InputReader {
+    /// Polls for the next key press, registering the current task to be woken
+    /// up when one is available.
+    fn poll_read_key(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<InquireResult<Key>>;
 }
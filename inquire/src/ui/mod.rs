@@ -1,12 +1,15 @@
 //! UI-related definitions for rendered content.
 
+mod accessible;
 mod api;
 mod backend;
 pub(crate) mod dimension;
 mod frame_renderer;
 mod input_reader;
 
+pub(crate) use accessible::*;
 pub(crate) use backend::*;
+pub(crate) use frame_renderer::HitTarget;
 pub(crate) use input_reader::*;
 
 pub use api::*;
@@ -2,6 +2,10 @@
 
 use std::fmt::Debug;
 
+use unicode_width::UnicodeWidthChar;
+
+use crate::ansi::{AnsiAware, AnsiAwareChar, AnsiStrippable};
+
 pub struct Page<'a, T> {
     /// Whether this is the first page.
     pub first: bool,
@@ -19,6 +23,17 @@ pub struct Page<'a, T> {
     pub total: usize,
 }
 
+/// Rows reserved for the prompt line, the help message and an error
+/// message when computing page sizes from the terminal height.
+const AUTO_PAGE_SIZE_RESERVED_ROWS: usize = 3;
+
+/// Computes the page size that fits in a terminal of the given height.
+pub fn auto_page_size(terminal_height: u16) -> usize {
+    (terminal_height as usize)
+        .saturating_sub(AUTO_PAGE_SIZE_RESERVED_ROWS)
+        .max(1)
+}
+
 pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<'_, T> {
     // if there is no selection, we default to the first page.
     // in practice, the same as selecting the 0 index.
@@ -84,6 +99,50 @@ where
     len
 }
 
+/// Width of a text on the terminal, ignoring its ANSI escape sequences.
+pub fn text_width(text: &str) -> usize {
+    text.ansi_stripped_chars()
+        .map(|c| UnicodeWidthChar::width(c).unwrap_or(0))
+        .sum()
+}
+
+/// Splits a text into consecutive segments, flagged by whether their
+/// characters are among the matched ones. Indices count the characters of the
+/// text without its ANSI escape sequences, which are kept in the segments.
+pub fn matched_segments(text: &str, matched: &[usize]) -> Vec<(String, bool)> {
+    let mut segments: Vec<(String, bool)> = vec![];
+    // escape sequences found before the first character open its segment.
+    let mut leading_sequences = String::new();
+    let mut char_index = 0;
+
+    for piece in text.ansi_aware_chars() {
+        let c = match piece {
+            AnsiAwareChar::AnsiEscapeSequence(sequence) => {
+                match segments.last_mut() {
+                    Some((segment, _)) => segment.push_str(sequence),
+                    None => leading_sequences.push_str(sequence),
+                }
+                continue;
+            }
+            AnsiAwareChar::Char(c) => c,
+        };
+
+        let is_matched = matched.contains(&char_index);
+        char_index += 1;
+
+        match segments.last_mut() {
+            Some((segment, segment_matched)) if *segment_matched == is_matched => segment.push(c),
+            Some(_) => segments.push((c.to_string(), is_matched)),
+            None => {
+                leading_sequences.push(c);
+                segments.push((std::mem::take(&mut leading_sequences), is_matched));
+            }
+        }
+    }
+
+    segments
+}
+
 impl<'a, T> Debug for Page<'a, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Page")
@@ -102,7 +161,7 @@ mod test {
 
     use crate::{
         list_option::ListOption,
-        utils::{int_log10, paginate},
+        utils::{auto_page_size, int_log10, matched_segments, paginate},
     };
 
     impl<T> ListOption<T> {
@@ -260,4 +319,40 @@ mod test {
         assert_eq!(false, page.last);
         assert_eq!(6, page.total);
     }
+
+    #[test]
+    fn auto_page_size_reserves_rows_for_prompt_and_messages() {
+        assert_eq!(21, auto_page_size(24));
+        assert_eq!(1, auto_page_size(4));
+    }
+
+    #[test]
+    fn auto_page_size_is_never_zero() {
+        assert_eq!(1, auto_page_size(3));
+        assert_eq!(1, auto_page_size(0));
+    }
+
+    #[test]
+    fn matched_segments_skip_ansi_escape_sequences() {
+        let segment = |text: &str, matched| (String::from(text), matched);
+
+        assert_eq!(
+            vec![
+                segment("S", true),
+                segment("an ", false),
+                segment("Jo", true),
+                segment("se", false),
+            ],
+            matched_segments("San Jose", &[0, 4, 5])
+        );
+        assert_eq!(
+            vec![
+                segment("\x1b[92mSa", true),
+                segment("n \x1b[1mJ\x1b[0m", false),
+                segment("o", true),
+            ],
+            matched_segments("\x1b[92mSan \x1b[1mJ\x1b[0mo", &[0, 1, 5])
+        );
+        assert_eq!(vec![segment("Mesa", false)], matched_segments("Mesa", &[]));
+    }
 }
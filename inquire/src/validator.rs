@@ -693,6 +693,184 @@ macro_rules! length {
     };
 }
 
+/// Built-in validator that checks whether the answer has at most the specified
+/// number of lines, e.g. in [`Text`](crate::Text) prompts in multi-line mode.
+///
+/// A line break at the end of the answer does not start a new line.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{MaxLinesValidator, StringValidator, Validation};
+///
+/// let validator = MaxLinesValidator::new(2);
+/// assert_eq!(Validation::Valid, validator.validate("Roses are red,\nviolets are blue.\n")?);
+/// assert_eq!(
+///     Validation::Invalid("The response should have at most 2 lines".into()),
+///     validator.validate("Roses are red,\nviolets are blue,\nsugar is sweet.")?,
+/// );
+///
+/// let validator = MaxLinesValidator::new(1).with_message("Keep it to one line!");
+/// assert_eq!(Validation::Invalid("Keep it to one line!".into()), validator.validate("a\nb")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct MaxLinesValidator {
+    limit: usize,
+    message: String,
+}
+
+impl MaxLinesValidator {
+    /// Create a new instance of this validator, requiring at most the given number of lines,
+    /// otherwise returning an error with default message.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            message: format!("The response should have at most {limit} lines"),
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The response should have at most $limit lines`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl StringValidator for MaxLinesValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if input.lines().count() <= self.limit {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Shorthand for the built-in [`MaxLinesValidator`] that checks whether the answer has at most
+/// the specified number of lines.
+///
+/// # Arguments
+///
+/// * `$lines` - Maximum number of lines of the input.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The response should have at most $lines lines"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{max_lines, validator::{StringValidator, Validation}};
+///
+/// let validator = max_lines!(2);
+/// assert_eq!(Validation::Valid, validator.validate("first\nsecond")?);
+/// assert_eq!(Validation::Invalid("The response should have at most 2 lines".into()), validator.validate("first\nsecond\nthird")?);
+///
+/// let validator = max_lines!(2, "Two lines at most!");
+/// assert_eq!(Validation::Invalid("Two lines at most!".into()), validator.validate("first\nsecond\nthird")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! max_lines {
+    ($lines:expr) => {
+        $crate::validator::MaxLinesValidator::new($lines)
+    };
+
+    ($lines:expr, $message:expr) => {
+        $crate::max_lines!($lines).with_message($message)
+    };
+}
+
+/// Built-in validator that checks whether the answer has at least the specified
+/// number of lines, e.g. in [`Text`](crate::Text) prompts in multi-line mode.
+///
+/// A line break at the end of the answer does not start a new line.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{MinLinesValidator, StringValidator, Validation};
+///
+/// let validator = MinLinesValidator::new(2);
+/// assert_eq!(Validation::Valid, validator.validate("Roses are red,\nviolets are blue.")?);
+/// assert_eq!(
+///     Validation::Invalid("The response should have at least 2 lines".into()),
+///     validator.validate("Roses are red,\n")?,
+/// );
+///
+/// let validator = MinLinesValidator::new(2).with_message("Tell me more!");
+/// assert_eq!(Validation::Invalid("Tell me more!".into()), validator.validate("Fine.")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct MinLinesValidator {
+    limit: usize,
+    message: String,
+}
+
+impl MinLinesValidator {
+    /// Create a new instance of this validator, requiring at least the given number of lines,
+    /// otherwise returning an error with default message.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            message: format!("The response should have at least {limit} lines"),
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The response should have at least $limit lines`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl StringValidator for MinLinesValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if input.lines().count() >= self.limit {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Shorthand for the built-in [`MinLinesValidator`] that checks whether the answer has at least
+/// the specified number of lines.
+///
+/// # Arguments
+///
+/// * `$lines` - Minimum number of lines of the input.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The response should have at least $lines lines"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{min_lines, validator::{StringValidator, Validation}};
+///
+/// let validator = min_lines!(2);
+/// assert_eq!(Validation::Valid, validator.validate("first\nsecond")?);
+/// assert_eq!(Validation::Invalid("The response should have at least 2 lines".into()), validator.validate("first")?);
+///
+/// let validator = min_lines!(2, "Two lines at least!");
+/// assert_eq!(Validation::Invalid("Two lines at least!".into()), validator.validate("first")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! min_lines {
+    ($lines:expr) => {
+        $crate::validator::MinLinesValidator::new($lines)
+    };
+
+    ($lines:expr, $message:expr) => {
+        $crate::min_lines!($lines).with_message($message)
+    };
+}
+
 #[cfg(test)]
 mod validators_test {
     use crate::{
@@ -720,7 +898,10 @@ mod validators_test {
         let validator: &dyn StringValidator = &validator;
 
         assert!(matches!(validator.validate("five!")?, Validation::Valid));
-        assert!(matches!(validator.validate("♥️♥️♥️♥️♥️")?, Validation::Valid));
+        assert!(matches!(
+            validator.validate("♥️♥️♥️♥️♥️")?,
+            Validation::Valid
+        ));
         assert!(matches!(
             validator.validate("🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️")?,
             Validation::Valid
@@ -770,7 +951,10 @@ mod validators_test {
 
         assert!(matches!(validator.validate("")?, Validation::Valid));
         assert!(matches!(validator.validate("five!")?, Validation::Valid));
-        assert!(matches!(validator.validate("♥️♥️♥️♥️♥️")?, Validation::Valid));
+        assert!(matches!(
+            validator.validate("♥️♥️♥️♥️♥️")?,
+            Validation::Valid
+        ));
         assert!(matches!(
             validator.validate("🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️")?,
             Validation::Valid
@@ -854,8 +1038,14 @@ mod validators_test {
 
         assert!(matches!(validator.validate("five!")?, Validation::Valid));
         assert!(matches!(validator.validate("five!!!")?, Validation::Valid));
-        assert!(matches!(validator.validate("♥️♥️♥️♥️♥️")?, Validation::Valid));
-        assert!(matches!(validator.validate("♥️♥️♥️♥️♥️♥️")?, Validation::Valid));
+        assert!(matches!(
+            validator.validate("♥️♥️♥️♥️♥️")?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate("♥️♥️♥️♥️♥️♥️")?,
+            Validation::Valid
+        ));
         assert!(matches!(
             validator.validate("🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️")?,
             Validation::Valid